@@ -34,17 +34,24 @@ pub struct BaseAddress<const PREFIX: u8>(Vec<u8>);
 
 impl<const PREFIX: u8> BaseAddress<PREFIX> {
     pub fn from_bech32(address: &str) -> Result<Self, AddressError> {
+        Self::from_bech32_with_hrp(address, BECH_32_PREFIX_ACC_ADDR)
+    }
+
+    /// Parses the address validating it against the chain's account address prefix `main_hrp`
+    /// rather than the prefix the crate was built with. Validator and consensus addresses
+    /// are expected to have the usual `valoper` and `valcons` suffixes.
+    pub fn from_bech32_with_hrp(address: &str, main_hrp: &str) -> Result<Self, AddressError> {
         let (hrp, data, variant) = bech32::decode(address)?;
 
         let prefix = match PREFIX {
-            0 => BECH_32_PREFIX_ACC_ADDR,
-            1 => BECH_32_PREFIX_VAL_ADDR,
-            _ => BECH_32_PREFIX_CONS_ADDR,
+            0 => main_hrp.to_owned(),
+            1 => format!("{main_hrp}{PREFIX_VALIDATOR}{PREFIX_OPERATOR}"),
+            _ => format!("{main_hrp}{PREFIX_VALIDATOR}{PREFIX_CONSENSUS}"),
         };
 
         if hrp != prefix {
             return Err(AddressError::InvalidPrefix {
-                expected: prefix.into(),
+                expected: prefix,
                 found: hrp,
             });
         };
@@ -318,6 +325,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_bech32_with_hrp_validates_prefix() {
+        let address = "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux";
+
+        AccAddress::from_bech32_with_hrp(address, "cosmos").unwrap_test();
+
+        let err = AccAddress::from_bech32_with_hrp(address, "gaia").unwrap_err();
+        assert_eq!(
+            err,
+            AddressError::InvalidPrefix {
+                expected: "gaia".into(),
+                found: "cosmos".into(),
+            }
+        );
+
+        let address = "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4";
+        ValAddress::from_bech32_with_hrp(address, "cosmos").unwrap_test();
+        ValAddress::from_bech32_with_hrp(address, "gaia").unwrap_err();
+    }
+
     #[test]
     fn from_bech32_failure_wrong_variant() {
         let encoded = bech32::encode(
@@ -1,2 +1,26 @@
 pub use ibc_proto::cosmos::auth::v1beta1::BaseAccount;
 pub use ibc_proto::cosmos::auth::v1beta1::ModuleAccount;
+
+/// BaseVestingAccount implements the VestingAccount interface. It contains all
+/// the necessary fields needed for any vesting account implementation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BaseVestingAccount {
+    #[prost(message, optional, tag = "1")]
+    pub base_account: Option<BaseAccount>,
+    #[prost(message, repeated, tag = "2")]
+    pub original_vesting: Vec<crate::base::coin::Coin>,
+    #[prost(message, repeated, tag = "3")]
+    pub delegated_free: Vec<crate::base::coin::Coin>,
+    #[prost(message, repeated, tag = "4")]
+    pub delegated_vesting: Vec<crate::base::coin::Coin>,
+    #[prost(int64, tag = "5")]
+    pub end_time: i64,
+}
+
+/// PermanentLockedAccount implements the VestingAccount interface. It does
+/// not ever release coins, locking them indefinitely.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PermanentLockedAccount {
+    #[prost(message, optional, tag = "1")]
+    pub base_vesting_account: Option<BaseVestingAccount>,
+}
@@ -8,7 +8,9 @@ pub mod rocks;
 #[cfg(feature = "sled")]
 pub mod sled;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
+
+use prefix::PrefixDB;
 
 pub use memory::*;
 
@@ -21,16 +23,47 @@ pub trait Database: Clone + Send + Sync + 'static {
 
     fn put(&self, key: Vec<u8>, value: Vec<u8>);
 
+    fn delete(&self, key: &[u8]);
+
+    /// Persists all pending writes to disk. Databases without a disk backing have nothing to flush.
+    fn flush(&self) {}
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a>;
 
     fn prefix_iterator<'a>(
         &'a self,
         prefix: Vec<u8>,
     ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a>;
+
+    /// Same as [`Database::prefix_iterator`] but yields keys in descending order.
+    fn reverse_prefix_iterator<'a>(
+        &'a self,
+        prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a>;
+
+    /// Returns the database holding the store `name`. By default stores share the database
+    /// and are separated by prefixing their keys with the store name.
+    fn store(db: &Arc<Self>, name: &str) -> PrefixDB<Self> {
+        PrefixDB::new(Arc::clone(db), name.as_bytes().to_vec())
+    }
 }
 
 pub trait DatabaseBuilder<DB> {
     type Err: Debug;
 
     fn build<P: AsRef<std::path::Path>>(self, path: P) -> Result<DB, Self::Err>;
+
+    /// Opens the database moving the prefixed keys of the stores `stores` into storage of
+    /// their own, see [`Database::store`]. Databases which keep every store under a key
+    /// prefix are opened as is.
+    fn build_migrated<P: AsRef<std::path::Path>>(
+        self,
+        path: P,
+        _stores: &[&str],
+    ) -> Result<DB, Self::Err>
+    where
+        Self: Sized,
+    {
+        self.build(path)
+    }
 }
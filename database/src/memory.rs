@@ -17,12 +17,27 @@ impl Default for MemDB {
     }
 }
 
+/// Copy of the contents of a [`MemDB`] taken with [`MemDB::snapshot`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemDbSnapshot(BTreeMap<Vec<u8>, Vec<u8>>);
+
 impl MemDB {
     pub fn new() -> MemDB {
         MemDB {
             store: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
+
+    /// Captures the current contents of the database.
+    pub fn snapshot(&self) -> MemDbSnapshot {
+        MemDbSnapshot(self.store.read().expect("poisoned lock").clone())
+    }
+
+    /// Replaces the contents of the database with `snapshot`. Clones of this database
+    /// share the storage, so they observe the restored contents too.
+    pub fn restore(&self, snapshot: MemDbSnapshot) {
+        *self.store.write().expect("poisoned lock") = snapshot.0;
+    }
 }
 
 impl Database for MemDB {
@@ -37,6 +52,10 @@ impl Database for MemDB {
             .insert(key, value);
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.store.write().expect("poisoned lock").remove(key);
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
             self.store
@@ -70,13 +89,32 @@ impl Database for MemDB {
 
         Box::new(pairs.into_iter())
     }
+
+    fn reverse_prefix_iterator<'a>(
+        &'a self,
+        prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
+        let start = Bound::Included(prefix.clone());
+        let end = prefix_end_bound(prefix);
+
+        let pairs = self
+            .store
+            .read()
+            .expect("poisoned lock")
+            .range((start, end))
+            .rev()
+            .map(|(k, v)| (k.clone().into_boxed_slice(), v.clone().into_boxed_slice()))
+            .collect::<Vec<_>>();
+
+        Box::new(pairs.into_iter())
+    }
 }
 
 /// Returns the Bound on a range query for a given prefix
 ///
 /// That is the smallest x such that, prefix + y < x for all y. If
 /// no such x exists (i.e. prefix = vec![255; N]; for some N) it returns Bound::Unbounded
-fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
+pub(crate) fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
     loop {
         let last = prefix.last_mut();
 
@@ -114,6 +152,47 @@ mod tests {
         assert!(got_pairs.iter().all(|e| { expected_pairs.contains(e) }));
     }
 
+    #[test]
+    fn restore_replaces_contents_with_snapshot() {
+        let db = MemDB::new();
+        db.put(vec![1], vec![1]);
+        db.put(vec![2], vec![2]);
+
+        let snapshot = db.snapshot();
+
+        db.put(vec![1], vec![10]);
+        db.put(vec![3], vec![3]);
+
+        db.restore(snapshot);
+
+        assert_eq!(db.get(&[1]), Some(vec![1]));
+        assert_eq!(db.get(&[2]), Some(vec![2]));
+        assert_eq!(db.get(&[3]), None);
+    }
+
+    #[test]
+    fn reverse_prefix_iterator_works() {
+        let db = MemDB::new();
+        db.put(vec![1, 1], vec![1]);
+        db.put(vec![2, 1], vec![2]);
+        db.put(vec![2, 3], vec![4]);
+        db.put(vec![2, 2], vec![3]);
+        db.put(vec![3, 1], vec![5]);
+
+        let got_keys: Vec<Box<[u8]>> = db
+            .reverse_prefix_iterator(vec![2])
+            .map(|(key, _)| key)
+            .collect();
+
+        let expected_keys: Vec<Box<[u8]>> = vec![
+            vec![2, 3].into_boxed_slice(),
+            vec![2, 2].into_boxed_slice(),
+            vec![2, 1].into_boxed_slice(),
+        ];
+
+        assert_eq!(expected_keys, got_keys);
+    }
+
     #[test]
     fn prefix_iterator_works() {
         let db = MemDB::new();
@@ -24,6 +24,15 @@ impl<T: Database> Database for PrefixDB<T> {
         self.db.put(key, value)
     }
 
+    fn delete(&self, key: &[u8]) {
+        let key = [self.prefix.as_slice(), key].concat();
+        self.db.delete(&key)
+    }
+
+    fn flush(&self) {
+        self.db.flush()
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         let prefix_length = self.prefix.len();
         Box::new(
@@ -48,6 +57,19 @@ impl<T: Database> Database for PrefixDB<T> {
             (key.into_boxed_slice(), v)
         }))
     }
+
+    fn reverse_prefix_iterator<'a>(
+        &'a self,
+        prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
+        let prefix = [self.prefix.clone(), prefix].concat();
+        let prefix_length = self.prefix.len();
+
+        Box::new(self.db.reverse_prefix_iterator(prefix).map(move |(k, v)| {
+            let key = k[prefix_length..].to_vec();
+            (key.into_boxed_slice(), v)
+        }))
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +99,19 @@ mod tests {
         assert_eq!(prefix_db.get(&[2]), Some(vec![1, 2, 3]));
     }
 
+    #[test]
+    fn delete_works() {
+        let db = Arc::new(MemDB::new());
+        db.put(vec![2], vec![4]);
+        let prefix_db = PrefixDB::new(Arc::clone(&db), vec![2]);
+        prefix_db.put(vec![2], vec![1, 2, 3]);
+
+        prefix_db.delete(&[2]);
+
+        assert_eq!(prefix_db.get(&[2]), None);
+        assert_eq!(db.get(&[2]), Some(vec![4]));
+    }
+
     #[test]
     fn iterator_works() {
         let db = MemDB::new();
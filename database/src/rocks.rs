@@ -1,7 +1,13 @@
-use crate::{error::DatabaseError, DBBuilder, Database, DatabaseBuilder};
-use std::{path::Path, sync::Arc};
+use crate::{
+    error::DatabaseError, memory::prefix_end_bound, prefix::PrefixDB, DBBuilder, Database,
+    DatabaseBuilder,
+};
+use std::{ops::Bound, path::Path, sync::Arc};
 
-use rocksdb::{DBWithThreadMode, SingleThreaded};
+use rocksdb::{
+    ColumnFamily, DBIteratorWithThreadMode, DBWithThreadMode, Direction, IteratorMode, Options,
+    SingleThreaded,
+};
 
 impl DatabaseBuilder<RocksDB> for DBBuilder {
     type Err = DatabaseError;
@@ -9,43 +15,145 @@ impl DatabaseBuilder<RocksDB> for DBBuilder {
     fn build<P: AsRef<std::path::Path>>(self, path: P) -> Result<RocksDB, DatabaseError> {
         RocksDB::new(path)
     }
+
+    fn build_migrated<P: AsRef<std::path::Path>>(
+        self,
+        path: P,
+        stores: &[&str],
+    ) -> Result<RocksDB, DatabaseError> {
+        RocksDB::migrate_to_cfs(path, stores)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct RocksDB {
     db: Arc<DBWithThreadMode<SingleThreaded>>, // QA: Are we sure? Probably
+    /// Column family used by this handle, the default one if `None`
+    cf: Option<Arc<str>>,
 }
 
 // TODO: remove panics
 
 impl RocksDB {
+    /// Opens the database along with all of its existing column families.
     pub fn new<P>(path: P) -> Result<RocksDB, DatabaseError>
     where
         P: AsRef<Path>,
     {
+        Self::new_with_cfs(path, &[])
+    }
+
+    /// Opens the database creating the column families `cfs` if they are missing. Stores
+    /// with a name matching a column family are kept in it instead of the default one,
+    /// see [`Database::store`].
+    pub fn new_with_cfs<P>(path: P, cfs: &[&str]) -> Result<RocksDB, DatabaseError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut opts = Options::default();
+        opts.create_if_missing(true);
+        opts.create_missing_column_families(true);
+
+        let mut names = rocksdb::DB::list_cf(&opts, path.as_ref()).unwrap_or_else(|_| Vec::new());
+        for cf in cfs {
+            if !names.iter().any(|this| this == cf) {
+                names.push((*cf).to_owned());
+            }
+        }
+
         Ok(RocksDB {
-            db: Arc::new(rocksdb::DB::open_default(path)?),
+            db: Arc::new(rocksdb::DB::open_cf(&opts, path, names)?),
+            cf: None,
         })
     }
+
+    /// Moves the stores `cfs` of a database which keeps all stores under key prefixes
+    /// in the default column family into column families of the same name.
+    pub fn migrate_to_cfs<P>(path: P, cfs: &[&str]) -> Result<RocksDB, DatabaseError>
+    where
+        P: AsRef<Path>,
+    {
+        let db = Self::new_with_cfs(path, cfs)?;
+
+        for name in cfs {
+            let cf = db
+                .db
+                .cf_handle(name)
+                .expect("column family was created on open");
+            let prefix = name.as_bytes();
+
+            for res in db
+                .db
+                .iterator(IteratorMode::From(prefix, Direction::Forward))
+            {
+                let (key, value) = res?;
+                if !key.starts_with(prefix) {
+                    break;
+                }
+
+                db.db.put_cf(cf, &key[prefix.len()..], value)?;
+                db.db.delete(key)?;
+            }
+        }
+
+        Ok(db)
+    }
+
+    fn column_family(&self) -> Option<&ColumnFamily> {
+        self.cf.as_ref().map(|name| {
+            self.db
+                .cf_handle(name)
+                .expect("handles are only created for existing column families")
+        })
+    }
+
+    fn raw_iterator(
+        &self,
+        mode: IteratorMode<'_>,
+    ) -> DBIteratorWithThreadMode<'_, DBWithThreadMode<SingleThreaded>> {
+        match self.column_family() {
+            Some(cf) => self.db.iterator_cf(cf, mode),
+            None => self.db.iterator(mode),
+        }
+    }
 }
 
 impl Database for RocksDB {
     fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
-        self.db
-            .get(key)
-            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e)) //TODO: this is probably not the right thing to do when handling a abci or REST query. Perhaps we should have a must_get method?
+        match self.column_family() {
+            Some(cf) => self.db.get_cf(cf, key),
+            None => self.db.get(key),
+        }
+        .unwrap_or_else(|e| panic!("unrecoverable database error {}", e)) //TODO: this is probably not the right thing to do when handling a abci or REST query. Perhaps we should have a must_get method?
     }
 
     fn put(&self, key: Vec<u8>, value: Vec<u8>) {
+        match self.column_family() {
+            Some(cf) => self.db.put_cf(cf, key, value),
+            None => self.db.put(key, value),
+        }
+        .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
+
+    fn delete(&self, key: &[u8]) {
+        match self.column_family() {
+            Some(cf) => self.db.delete_cf(cf, key),
+            None => self.db.delete(key),
+        }
+        .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
+
+    fn flush(&self) {
+        // syncing the WAL persists writes to every column family
         self.db
-            .put(key, value)
+            .flush_wal(true)
+            .and_then(|_| self.db.flush())
             .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
     }
 
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
-            self.db
-                .iterator(rocksdb::IteratorMode::Start)
+            self.raw_iterator(IteratorMode::Start)
                 .map(|res| res.unwrap_or_else(|e| panic!("unrecoverable database error {}", e))),
         )
     }
@@ -55,12 +163,47 @@ impl Database for RocksDB {
         prefix: Vec<u8>,
     ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
-            self.db
-                .prefix_iterator(&prefix)
+            self.raw_iterator(IteratorMode::From(&prefix, Direction::Forward))
                 .map(|res| res.unwrap_or_else(|e| panic!("unrecoverable database error {}", e)))
-                .take_while(move |(k, _)| k.starts_with(&prefix)), //rocks db returns keys beyond the prefix see https://github.com/rust-rocksdb/rust-rocksdb/issues/577
+                .take_while(move |(k, _)| k.starts_with(&prefix)),
+        )
+    }
+
+    fn reverse_prefix_iterator<'a>(
+        &'a self,
+        prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
+        // seek to the first key past the prefix and walk backwards from there
+        let end = match prefix_end_bound(prefix.clone()) {
+            Bound::Excluded(end) => Some(end),
+            _ => None,
+        };
+        let iter = match &end {
+            Some(end) => self.raw_iterator(IteratorMode::From(end, Direction::Reverse)),
+            None => self.raw_iterator(IteratorMode::End),
+        };
+
+        Box::new(
+            iter.map(|res| res.unwrap_or_else(|e| panic!("unrecoverable database error {}", e)))
+                .skip_while(move |(k, _)| {
+                    end.as_ref().is_some_and(|end| k.as_ref() >= end.as_slice())
+                })
+                .take_while(move |(k, _)| k.starts_with(&prefix)),
         )
     }
+
+    fn store(db: &Arc<Self>, name: &str) -> PrefixDB<Self> {
+        match db.db.cf_handle(name) {
+            Some(_) => PrefixDB::new(
+                Arc::new(RocksDB {
+                    db: Arc::clone(&db.db),
+                    cf: Some(name.into()),
+                }),
+                Vec::new(),
+            ),
+            None => PrefixDB::new(Arc::clone(db), name.as_bytes().to_vec()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -99,4 +242,80 @@ mod tests {
         assert_eq!(expected_pairs.len(), got_pairs.len());
         assert!(got_pairs.iter().all(|e| { expected_pairs.contains(e) }));
     }
+
+    #[test]
+    fn reverse_prefix_iterator_works() {
+        let db = RocksDB::new("tmp/3").expect("hardcoded is valid");
+        db.put(vec![1, 1], vec![1]);
+        db.put(vec![2, 1], vec![2]);
+        db.put(vec![2, 3], vec![4]);
+        db.put(vec![2, 2], vec![3]);
+        db.put(vec![3], vec![5]);
+        db.put(vec![3, 1], vec![6]);
+
+        let got_keys: Vec<Box<[u8]>> = db
+            .reverse_prefix_iterator(vec![2])
+            .map(|(key, _)| key)
+            .collect();
+
+        let expected_keys: Vec<Box<[u8]>> = vec![
+            vec![2, 3].into_boxed_slice(),
+            vec![2, 2].into_boxed_slice(),
+            vec![2, 1].into_boxed_slice(),
+        ];
+
+        assert_eq!(expected_keys, got_keys);
+    }
+
+    #[test]
+    fn column_families_are_isolated() {
+        let db =
+            Arc::new(RocksDB::new_with_cfs("tmp/4", &["bank", "acc"]).expect("hardcoded is valid"));
+        let bank = RocksDB::store(&db, "bank");
+        let acc = RocksDB::store(&db, "acc");
+
+        bank.put(vec![1], vec![1]);
+        acc.put(vec![1], vec![2]);
+
+        assert_eq!(bank.get(&[1]), Some(vec![1]));
+        assert_eq!(acc.get(&[1]), Some(vec![2]));
+        assert_eq!(bank.iterator().count(), 1);
+        assert_eq!(db.get(b"bank\x01"), None);
+        assert_eq!(db.iterator().count(), 0);
+    }
+
+    #[test]
+    fn migrate_to_cfs_moves_prefixed_stores() {
+        {
+            let db = RocksDB::new("tmp/5").expect("hardcoded is valid");
+            db.put(b"bank\x01".to_vec(), vec![1]);
+            db.put(b"acc\x01".to_vec(), vec![2]);
+        }
+
+        let db = Arc::new(RocksDB::migrate_to_cfs("tmp/5", &["bank"]).expect("hardcoded is valid"));
+
+        assert_eq!(RocksDB::store(&db, "bank").get(&[1]), Some(vec![1]));
+        assert_eq!(RocksDB::store(&db, "acc").get(&[1]), Some(vec![2]));
+        assert_eq!(db.get(b"bank\x01"), None);
+    }
+
+    #[test]
+    fn build_migrated_keeps_stores_in_cfs_on_reopen() {
+        {
+            let db = RocksDB::new("tmp/6").expect("hardcoded is valid");
+            db.put(b"bank\x01".to_vec(), vec![1]);
+        }
+
+        {
+            let _db: RocksDB = DBBuilder
+                .build_migrated("tmp/6", &["bank", "acc"])
+                .expect("hardcoded is valid");
+        }
+
+        let db: Arc<RocksDB> = Arc::new(DBBuilder.build("tmp/6").expect("hardcoded is valid"));
+
+        assert_eq!(RocksDB::store(&db, "bank").get(&[1]), Some(vec![1]));
+        assert!(db.db.cf_handle("acc").is_some());
+        assert_eq!(db.get(b"bank\x01"), None);
+    }
 }
@@ -31,6 +31,14 @@ impl Database for SledDb {
         let _ = self.0.insert(key, value).unwrap_or_corrupt();
     }
 
+    fn delete(&self, key: &[u8]) {
+        let _ = self.0.remove(key).unwrap_or_corrupt();
+    }
+
+    fn flush(&self) {
+        let _ = self.0.flush().unwrap_or_corrupt();
+    }
+
     fn iterator<'a>(&'a self) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
         Box::new(
             self.0
@@ -61,4 +69,22 @@ impl Database for SledDb {
                 }),
         )
     }
+
+    fn reverse_prefix_iterator<'a>(
+        &'a self,
+        prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + 'a> {
+        Box::new(
+            self.0
+                .scan_prefix(prefix)
+                .rev()
+                .map(|this| this.unwrap_or_corrupt())
+                .map(|(key, value)| {
+                    (
+                        key.to_vec().into_boxed_slice(),
+                        value.to_vec().into_boxed_slice(),
+                    )
+                }),
+        )
+    }
 }
@@ -11,17 +11,35 @@ pub(crate) enum PaginationVariant {
 }
 
 #[derive(Debug, Clone)]
-pub struct Pagination(pub(crate) PaginationVariant);
+pub struct Pagination {
+    pub(crate) variant: PaginationVariant,
+    pub(crate) count_total: bool,
+}
+
+impl Pagination {
+    /// Sets whether the total number of items should be counted.
+    /// Counting requires a full iteration over the items, so it is disabled by default.
+    pub fn count_total(mut self, count_total: bool) -> Self {
+        self.count_total = count_total;
+        self
+    }
+}
 
 impl From<PaginationByOffset> for Pagination {
     fn from(value: PaginationByOffset) -> Self {
-        Self(PaginationVariant::Offset(value))
+        Self {
+            variant: PaginationVariant::Offset(value),
+            count_total: false,
+        }
     }
 }
 
 impl From<PaginationByKey> for Pagination {
     fn from(value: PaginationByKey) -> Self {
-        Self(PaginationVariant::Key(value))
+        Self {
+            variant: PaginationVariant::Key(value),
+            count_total: false,
+        }
     }
 }
 
@@ -46,23 +64,27 @@ impl<T: Iterator<Item = U>, U: PaginationKey + Clone> IteratorPaginate for T {
         self,
         pagination: impl Into<Pagination>,
     ) -> (PaginationResult, impl Iterator<Item = Self::Item>) {
-        let Pagination(variant) = pagination.into();
-        match variant {
-            PaginationVariant::Offset(pagination) => {
-                let (PaginationByOffsetResult { total, next_key }, iter) =
-                    self.paginate_by_offset(pagination);
-                (
-                    PaginationResult {
-                        total,
-                        next_key: next_key.map(|this| this.iterator_key().into_owned()),
-                    },
-                    TwoIterators::First(iter),
-                )
-            }
-            PaginationVariant::Key(pagination) => {
-                let (result, iter) = self.paginate_by_key(pagination);
-                (result, TwoIterators::Second(iter))
-            }
+        let Pagination {
+            variant,
+            count_total,
+        } = pagination.into();
+
+        if count_total {
+            let items = self.collect::<Vec<_>>();
+            let total = items.len();
+            let (result, iter) = paginate_variant(items.into_iter(), variant);
+
+            (
+                PaginationResult { total, ..result },
+                TwoIterators::First(iter),
+            )
+        } else {
+            let (result, iter) = paginate_variant(self, variant);
+
+            (
+                PaginationResult { total: 0, ..result },
+                TwoIterators::Second(iter),
+            )
         }
     }
 
@@ -80,6 +102,29 @@ impl<T: Iterator<Item = U>, U: PaginationKey + Clone> IteratorPaginate for T {
     }
 }
 
+fn paginate_variant<T: Iterator<Item = U>, U: PaginationKey + Clone>(
+    iter: T,
+    variant: PaginationVariant,
+) -> (PaginationResult, impl Iterator<Item = U>) {
+    match variant {
+        PaginationVariant::Offset(pagination) => {
+            let (PaginationByOffsetResult { total, next_key }, iter) =
+                iter.paginate_by_offset(pagination);
+            (
+                PaginationResult {
+                    total,
+                    next_key: next_key.map(|this| this.iterator_key().into_owned()),
+                },
+                TwoIterators::First(iter),
+            )
+        }
+        PaginationVariant::Key(pagination) => {
+            let (result, iter) = iter.paginate_by_key(pagination);
+            (result, TwoIterators::Second(iter))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 enum TwoIterators<I, T: Iterator<Item = I>, U: Iterator<Item = I>> {
     First(T),
@@ -132,3 +177,22 @@ impl<T> PaginationResultElement<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn total_counted_only_when_requested() {
+        let items = (0_u8..3).map(|this| vec![this]).collect::<Vec<_>>();
+        let pagination = Pagination::from(PaginationByOffset::from((0, 1)));
+
+        let (result, iter) = items.clone().into_iter().paginate(pagination.clone());
+        assert_eq!(result, PaginationResult::new(0, Some(vec![1])));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![vec![0]]);
+
+        let (result, iter) = items.into_iter().paginate(pagination.count_total(true));
+        assert_eq!(result, PaginationResult::new(3, Some(vec![1])));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![vec![0]]);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     message::Message,
     modules::GaiaModules,
     store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
-    GaiaNodeQueryRequest, GaiaNodeQueryResponse,
+    GaiaNodeQuery, GaiaNodeQueryRequest, GaiaNodeQueryResponse,
 };
 use gears::store::database::Database;
 use gears::tendermint::types::request::query::RequestQuery;
@@ -131,7 +131,11 @@ impl GaiaABCIHandler {
             GaiaModules::NotBondedPool,
         );
 
-        let ibc_keeper = ibc_rs::keeper::Keeper::new(GaiaStoreKey::IBC, GaiaParamsStoreKey::IBC);
+        let ibc_keeper = ibc_rs::keeper::Keeper::new(
+            GaiaStoreKey::IBC,
+            GaiaParamsStoreKey::IBC,
+            auth::new_module_addr("gov"),
+        );
         let ante_handler = BaseAnteHandler::new(
             auth_keeper.clone(),
             bank_keeper.clone(),
@@ -206,6 +210,20 @@ impl ABCIHandler for GaiaABCIHandler {
         }
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, GaiaStoreKey>,
+    ) -> Option<GenesisState> {
+        Some(GenesisState {
+            bank: self.bank_abci_handler.export_genesis(ctx)?,
+            auth: self.auth_abci_handler.export_genesis(ctx)?,
+            staking: self.staking_abci_handler.export_genesis(ctx)?,
+            ibc: self.ibc_abci_handler.export_genesis(ctx),
+            // gentxs have already been applied to the exported staking state
+            genutil: Default::default(),
+        })
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, GaiaStoreKey>,
@@ -244,24 +262,24 @@ impl ABCIHandler for GaiaABCIHandler {
         ctx: &QueryContext<DB, GaiaStoreKey>,
         query: GaiaNodeQueryRequest,
     ) -> GaiaNodeQueryResponse {
-        match query {
-            GaiaNodeQueryRequest::Bank(req) => {
+        match query.query {
+            GaiaNodeQuery::Bank(req) => {
                 GaiaNodeQueryResponse::Bank(self.bank_abci_handler.typed_query(ctx, req))
             }
-            GaiaNodeQueryRequest::Auth(req) => {
+            GaiaNodeQuery::Auth(req) => {
                 GaiaNodeQueryResponse::Auth(self.auth_abci_handler.typed_query(ctx, req))
             }
-            GaiaNodeQueryRequest::Staking(req) => {
+            GaiaNodeQuery::Staking(req) => {
                 GaiaNodeQueryResponse::Staking(self.staking_abci_handler.typed_query(ctx, req))
             }
             // TODO: replace handler
-            GaiaNodeQueryRequest::Slashing(_req) => GaiaNodeQueryResponse::Slashing(
+            GaiaNodeQuery::Slashing(_req) => GaiaNodeQueryResponse::Slashing(
                 slashing::SlashingNodeQueryResponse::Params(slashing::QueryParamsResponse {
                     params: slashing::SlashingParams::default(),
                 }),
             ),
             // TODO: replace handler
-            GaiaNodeQueryRequest::Distribution(_req) => GaiaNodeQueryResponse::Distribution(
+            GaiaNodeQuery::Distribution(_req) => GaiaNodeQueryResponse::Distribution(
                 distribution::DistributionNodeQueryResponse::Params(
                     distribution::QueryParamsResponse {
                         params: distribution::DistributionParams::default(),
@@ -1,7 +1,7 @@
 use auth::GenesisState as AuthGenesis;
 use bank::GenesisState as BankGenesis;
 use gears::{
-    baseapp::genesis::GenesisError,
+    baseapp::genesis::{Genesis, GenesisError, GenesisValidationError},
     types::{address::AccAddress, base::coins::UnsignedCoins},
 };
 use genutil::genesis::GenutilGenesis;
@@ -19,13 +19,185 @@ pub struct GenesisState {
     pub genutil: GenutilGenesis,
 }
 
-impl gears::baseapp::genesis::Genesis for GenesisState {
+impl Genesis for GenesisState {
     fn add_genesis_account(
         &mut self,
         address: AccAddress,
         coins: UnsignedCoins,
     ) -> Result<(), GenesisError> {
-        self.bank.add_genesis_account(address.clone(), coins);
-        self.auth.add_genesis_account(address)
+        // auth rejects existing addresses so add it first to leave the bank state untouched on error
+        self.auth.add_genesis_account(address.clone())?;
+        self.bank.add_genesis_account(address, coins);
+
+        Ok(())
+    }
+
+    fn validate(&self) -> Result<(), GenesisValidationError> {
+        self.bank.validate()?;
+        self.auth.validate()?;
+        self.staking.validate()?;
+        self.genutil.validate()?;
+
+        Ok(())
+    }
+
+    fn validate_modules(&self) -> Vec<(&'static str, Result<(), GenesisValidationError>)> {
+        vec![
+            ("bank", self.bank.validate()),
+            ("auth", self.auth.validate()),
+            ("staking", self.staking.validate()),
+            ("genutil", self.genutil.validate()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, str::FromStr};
+
+    use bank::{Balance, SendEnabled};
+    use gears::{
+        commands::node::{
+            genesis::{
+                genesis_account_add, validate_genesis, GenesisCommand, ValidateGenesisCommand,
+            },
+            init::{init, InitCommand},
+        },
+        config::ConfigDirectory,
+        extensions::testing::UnwrapTesting,
+        tendermint::{informal::genesis::Genesis as TmGenesis, types::chain_id::ChainId},
+    };
+
+    use crate::config::AppConfig;
+
+    use super::*;
+
+    #[test]
+    fn init_rejects_invalid_bank_params() {
+        let home = PathBuf::from("./tmp/gaia_init_invalid_bank_params");
+
+        let send_enabled = SendEnabled {
+            denom: "uatom".parse().unwrap_test(),
+            enabled: true,
+        };
+        let mut genesis = GenesisState::default();
+        genesis.bank.params.send_enabled = vec![send_enabled.clone(), send_enabled];
+
+        let cmd = InitCommand::former()
+            .home(home.clone())
+            .moniker("test".to_owned())
+            .chain_id(ChainId::from_str("test-chain").unwrap_test())
+            .form();
+
+        let err = init::<_, AppConfig>(cmd, &genesis).expect_err("bank params are invalid");
+
+        assert!(!home.join("config").join("genesis.json").exists());
+        assert_eq!(
+            err.to_string(),
+            "invalid bank genesis: duplicate send enabled parameter found: uatom"
+        );
+    }
+
+    #[test]
+    fn validate_genesis_reports_broken_balances() {
+        let home = PathBuf::from("./tmp/gaia_validate_genesis_broken_balances");
+
+        let cmd = InitCommand::former()
+            .home(home.clone())
+            .moniker("test".to_owned())
+            .chain_id(ChainId::from_str("test-chain").unwrap_test())
+            .form();
+        init::<_, AppConfig>(cmd, &GenesisState::default()).unwrap_test();
+
+        let genesis_file = ConfigDirectory::GenesisFile.path_from_hone(&home);
+        let mut genesis: TmGenesis<GenesisState> =
+            serde_json::from_str(&std::fs::read_to_string(&genesis_file).unwrap_test())
+                .unwrap_test();
+        let balance = Balance {
+            address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+                .parse()
+                .unwrap_test(),
+            coins: "10uatom".parse().unwrap_test(),
+        };
+        genesis.app_state.bank.balances = vec![balance.clone(), balance];
+        std::fs::write(
+            &genesis_file,
+            serde_json::to_string_pretty(&genesis).unwrap_test(),
+        )
+        .unwrap_test();
+
+        let cmd = ValidateGenesisCommand::former()
+            .home(home.clone())
+            .file(genesis_file)
+            .form();
+        let report = validate_genesis::<GenesisState>(cmd).unwrap_test();
+
+        std::fs::remove_dir_all(home).unwrap_test();
+
+        let failed = report
+            .into_iter()
+            .filter_map(|(module, result)| result.err().map(|e| (module, e.reason)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            failed,
+            vec![(
+                "bank",
+                "duplicate balance for address cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+                    .to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn add_genesis_account_writes_bank_and_auth_state() {
+        let home = PathBuf::from("./tmp/gaia_add_genesis_account");
+
+        let cmd = InitCommand::former()
+            .home(home.clone())
+            .moniker("test".to_owned())
+            .chain_id(ChainId::from_str("test-chain").unwrap_test())
+            .form();
+        init::<_, AppConfig>(cmd, &GenesisState::default()).unwrap_test();
+
+        let address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .unwrap_test();
+        let coins: UnsignedCoins = "10uatom".parse().unwrap_test();
+        let cmd = GenesisCommand {
+            home: home.clone(),
+            address: address.clone(),
+            coins: coins.clone(),
+        };
+        genesis_account_add::<GenesisState>(cmd.clone()).unwrap_test();
+        let err = genesis_account_add::<GenesisState>(cmd).expect_err("address already exists");
+
+        let genesis_file = ConfigDirectory::GenesisFile.path_from_hone(&home);
+        let genesis: TmGenesis<GenesisState> =
+            serde_json::from_str(&std::fs::read_to_string(&genesis_file).unwrap_test())
+                .unwrap_test();
+
+        std::fs::remove_dir_all(home).unwrap_test();
+
+        assert_eq!(
+            err.to_string(),
+            format!("cannot add account at existing address {address}")
+        );
+        assert_eq!(
+            genesis.app_state.bank.balances,
+            vec![Balance {
+                address: address.clone(),
+                coins
+            }]
+        );
+        assert_eq!(
+            genesis
+                .app_state
+                .auth
+                .accounts
+                .iter()
+                .map(|account| account.get_address().clone())
+                .collect::<Vec<_>>(),
+            vec![address]
+        );
     }
 }
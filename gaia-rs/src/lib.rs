@@ -6,6 +6,8 @@ use anyhow::Result;
 use auth::cli::query::AuthQueryHandler;
 use auth::query::QueryAccountRequest;
 use auth::query::QueryAccountResponse;
+use auth::query::QueryParamsRequest as AuthQueryParamsRequest;
+use auth::query::QueryParamsResponse as AuthQueryParamsResponse;
 use auth::AuthNodeQueryRequest;
 use auth::AuthNodeQueryResponse;
 use axum::Router;
@@ -26,8 +28,8 @@ use gears::application::handlers::client::{QueryHandler, TxHandler};
 use gears::application::handlers::AuxHandler;
 use gears::application::node::Node;
 use gears::application::ApplicationInfo;
-use gears::baseapp::NodeQueryHandler;
-use gears::baseapp::{QueryRequest, QueryResponse};
+use gears::baseapp::{HeightQueryRequest, QueryRequest, QueryResponse};
+use gears::baseapp::{NodeHealthHandler, NodeQueryHandler, NodeSimulateHandler};
 use gears::commands::client::query::execute_query;
 use gears::commands::client::tx::ClientTxContext;
 use gears::commands::node::run::RouterBuilder;
@@ -35,9 +37,10 @@ use gears::commands::NilAux;
 use gears::commands::NilAuxCommand;
 use gears::core::Protobuf;
 use gears::crypto::public::PublicKey;
-use gears::grpc::health::health_server;
+use gears::grpc::health::{health_check_server, health_server};
 use gears::grpc::tx::tx_server;
 use gears::rest::RestState;
+use gears::tendermint::rpc::client::HttpClientUrl;
 use gears::types::address::AccAddress;
 use gears::types::tx::Messages;
 use ibc_rs::client::cli::query::IbcQueryHandler;
@@ -48,6 +51,7 @@ use slashing::SlashingNodeQueryResponse;
 use staking::cli::query::StakingQueryHandler;
 use staking::StakingNodeQueryRequest;
 use staking::StakingNodeQueryResponse;
+use tonic::server::NamedService;
 use tonic::transport::Server;
 use tonic::Status;
 use tower_layer::Identity;
@@ -192,7 +196,7 @@ impl AuxHandler for GaiaCoreClient {
 impl Client for GaiaCoreClient {}
 
 #[derive(Clone)]
-pub enum GaiaNodeQueryRequest {
+pub enum GaiaNodeQuery {
     Bank(BankNodeQueryRequest),
     Auth(AuthNodeQueryRequest),
     Staking(StakingNodeQueryRequest),
@@ -200,39 +204,97 @@ pub enum GaiaNodeQueryRequest {
     Distribution(DistributionNodeQueryRequest),
 }
 
+#[derive(Clone)]
+pub struct GaiaNodeQueryRequest {
+    pub query: GaiaNodeQuery,
+    /// Height of the state to query. `0` means latest committed state.
+    pub height: u32,
+}
+
+impl GaiaNodeQueryRequest {
+    pub fn new(query: impl Into<GaiaNodeQuery>, height: u32) -> Self {
+        Self {
+            query: query.into(),
+            height,
+        }
+    }
+}
+
 impl QueryRequest for GaiaNodeQueryRequest {
     fn height(&self) -> u32 {
-        0
+        self.height
+    }
+}
+
+impl HeightQueryRequest for GaiaNodeQueryRequest {
+    fn with_height(self, height: u32) -> Self {
+        Self { height, ..self }
+    }
+}
+
+impl From<GaiaNodeQuery> for GaiaNodeQueryRequest {
+    fn from(query: GaiaNodeQuery) -> Self {
+        Self { query, height: 0 }
+    }
+}
+
+impl From<BankNodeQueryRequest> for GaiaNodeQuery {
+    fn from(req: BankNodeQueryRequest) -> Self {
+        GaiaNodeQuery::Bank(req)
+    }
+}
+
+impl From<AuthNodeQueryRequest> for GaiaNodeQuery {
+    fn from(req: AuthNodeQueryRequest) -> Self {
+        GaiaNodeQuery::Auth(req)
+    }
+}
+
+impl From<StakingNodeQueryRequest> for GaiaNodeQuery {
+    fn from(req: StakingNodeQueryRequest) -> Self {
+        GaiaNodeQuery::Staking(req)
+    }
+}
+
+impl From<SlashingNodeQueryRequest> for GaiaNodeQuery {
+    fn from(req: SlashingNodeQueryRequest) -> Self {
+        GaiaNodeQuery::Slashing(req)
+    }
+}
+
+impl From<DistributionNodeQueryRequest> for GaiaNodeQuery {
+    fn from(req: DistributionNodeQueryRequest) -> Self {
+        GaiaNodeQuery::Distribution(req)
     }
 }
 
 impl From<BankNodeQueryRequest> for GaiaNodeQueryRequest {
     fn from(req: BankNodeQueryRequest) -> Self {
-        GaiaNodeQueryRequest::Bank(req)
+        GaiaNodeQuery::from(req).into()
     }
 }
 
 impl From<AuthNodeQueryRequest> for GaiaNodeQueryRequest {
     fn from(req: AuthNodeQueryRequest) -> Self {
-        GaiaNodeQueryRequest::Auth(req)
+        GaiaNodeQuery::from(req).into()
     }
 }
 
 impl From<StakingNodeQueryRequest> for GaiaNodeQueryRequest {
     fn from(req: StakingNodeQueryRequest) -> Self {
-        GaiaNodeQueryRequest::Staking(req)
+        GaiaNodeQuery::from(req).into()
     }
 }
 
 impl From<SlashingNodeQueryRequest> for GaiaNodeQueryRequest {
     fn from(req: SlashingNodeQueryRequest) -> Self {
-        GaiaNodeQueryRequest::Slashing(req)
+        GaiaNodeQuery::from(req).into()
     }
 }
 
 impl From<DistributionNodeQueryRequest> for GaiaNodeQueryRequest {
     fn from(req: DistributionNodeQueryRequest) -> Self {
-        GaiaNodeQueryRequest::Distribution(req)
+        GaiaNodeQuery::from(req).into()
     }
 }
 
@@ -330,28 +392,66 @@ impl RouterBuilder<GaiaNodeQueryRequest, GaiaNodeQueryResponse> for GaiaCore {
         get_router()
     }
 
-    fn build_grpc_router<App: NodeQueryHandler<GaiaNodeQueryRequest, GaiaNodeQueryResponse>>(
+    fn build_grpc_router<
+        App: NodeQueryHandler<GaiaNodeQueryRequest, GaiaNodeQueryResponse>
+            + NodeSimulateHandler
+            + NodeHealthHandler,
+    >(
         &self,
         app: App,
+        tendermint_rpc_address: HttpClientUrl,
     ) -> tonic::transport::server::Router<Identity> {
+        let staking_service = staking::grpc::new(app.clone());
+        let auth_service = auth::grpc::new(app.clone());
+        let bank_service = bank::grpc::new(app.clone());
+        let health_service = health_server();
+        let tx_service = tx_server::<_, message::Message>(app.clone(), tendermint_rpc_address);
+        let health_check_service = health_check_server(
+            app,
+            [
+                service_name(&staking_service),
+                service_name(&auth_service),
+                service_name(&bank_service),
+                service_name(&health_service),
+                service_name(&tx_service),
+            ],
+        );
+
+        // `ibc_proto::FILE_DESCRIPTOR_SET` is generated together with the cosmos sdk protos so it
+        // already contains the descriptors of every served cosmos sdk service, the `grpc.health.v1`
+        // descriptors come with tonic-health. Only the names of the served services are registered
+        // so that reflection doesn't list services which aren't served.
         let reflection_service = tonic_reflection::server::Builder::configure()
             .register_encoded_file_descriptor_set(ibc_proto::FILE_DESCRIPTOR_SET)
+            .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET)
+            .with_service_name(service_name(&staking_service))
+            .with_service_name(service_name(&auth_service))
+            .with_service_name(service_name(&bank_service))
+            .with_service_name(service_name(&health_service))
+            .with_service_name(service_name(&health_check_service))
+            .with_service_name(service_name(&tx_service))
             .build_v1()
-            .expect("ibc_proto::FILE_DESCRIPTOR_SET is a valid proto file descriptor set");
+            .expect("registered file descriptor sets are valid");
 
         Server::builder()
             .add_service(reflection_service)
-            .add_service(staking::grpc::new(app.clone()))
-            .add_service(auth::grpc::new(app.clone()))
-            .add_service(bank::grpc::new(app))
-            .add_service(health_server())
-            .add_service(tx_server())
+            .add_service(staking_service)
+            .add_service(auth_service)
+            .add_service(bank_service)
+            .add_service(health_service)
+            .add_service(health_check_service)
+            .add_service(tx_service)
     }
 }
 
+fn service_name<S: NamedService>(_service: &S) -> &'static str {
+    S::NAME
+}
+
 mod inner {
     pub use bank::types::query::inner::QueryDenomMetadataResponse;
     pub use gears::core::query::response::auth::QueryAccountResponse;
+    pub use ibc_proto::cosmos::auth::v1beta1::QueryParamsResponse as QueryAuthParamsResponse;
 }
 
 #[derive(Debug, Clone)]
@@ -414,4 +514,47 @@ impl NodeFetcher for QueryNodeFetcher {
             .metadata,
         )
     }
+
+    fn max_memo_characters(&self, node: impl AsRef<str>) -> anyhow::Result<u64> {
+        let query = AuthQueryParamsRequest {};
+
+        Ok(
+            execute_query::<AuthQueryParamsResponse, inner::QueryAuthParamsResponse>(
+                "/cosmos.auth.v1beta1.Query/Params".into(),
+                query.encode_vec(),
+                node.as_ref(),
+                None,
+            )?
+            .params
+            .max_memo_characters,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_request() -> AuthNodeQueryRequest {
+        AuthNodeQueryRequest::Account(QueryAccountRequest {
+            address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+                .expect("hardcoded address is valid"),
+        })
+    }
+
+    #[test]
+    fn height_defaults_to_latest() {
+        let req = GaiaNodeQueryRequest::from(account_request());
+
+        assert_eq!(req.height(), 0);
+    }
+
+    #[test]
+    fn height_is_threaded_through() {
+        let req = GaiaNodeQueryRequest::new(account_request(), 5);
+        assert_eq!(req.height(), 5);
+
+        let req = GaiaNodeQueryRequest::from(account_request()).with_height(5);
+        assert_eq!(req.height(), 5);
+    }
 }
@@ -1,7 +1,7 @@
 use gears::types::address::AccAddress;
 use gears::x::module::Module;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, strum::EnumIter)]
 pub enum GaiaModules {
     FeeCollector,
     BondedPool,
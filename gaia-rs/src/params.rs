@@ -166,3 +166,113 @@ impl ParamsDeserialize for DummyParams {
         Self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        context::{tx::TxContext, TransactionalContext},
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::{proto::header::Header, time::timestamp::Timestamp},
+        types::{base::coins::UnsignedCoins, gas::GasMeter},
+    };
+    use gov::{submission::param::ParamChange, types::proposal::ProposalStatus};
+    use staking::StakingParams;
+
+    use crate::store_keys::GaiaStoreKey;
+
+    use super::*;
+
+    fn staking_keeper() -> StakingParamsKeeper<GaiaParamsStoreKey> {
+        StakingParamsKeeper {
+            params_subspace_key: GaiaParamsStoreKey::Staking,
+        }
+    }
+
+    fn max_validators_proposal(value: &[u8]) -> Proposal {
+        let content = ParameterChangeProposal {
+            title: "Max validators".to_owned(),
+            description: "Change the maximum number of validators".to_owned(),
+            changes: vec![ParamChange {
+                subspace: GaiaParamsStoreKey::Staking,
+                key: b"MaxValidators".to_vec(),
+                value: value.to_vec(),
+            }],
+        };
+
+        Proposal {
+            proposal_id: 1,
+            content: content.into(),
+            status: ProposalStatus::Passed,
+            final_tally_result: None,
+            submit_time: Timestamp::UNIX_EPOCH,
+            deposit_end_time: Timestamp::UNIX_EPOCH,
+            total_deposit: UnsignedCoins::new(vec!["1uatom".parse().unwrap_test()]).unwrap_test(),
+            voting_start_time: None,
+            voting_end_time: None,
+        }
+    }
+
+    #[test]
+    fn param_change_updates_staking_max_validators() {
+        let multi_store =
+            ApplicationMultiBank::<_, GaiaStoreKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+        staking_keeper().set(&mut ctx, StakingParams::default());
+
+        let proposal = max_validators_proposal(b"42");
+        assert!(GaiaProposalHandler::check(&proposal));
+        GaiaProposalHandler
+            .handle(&proposal, &mut ctx)
+            .unwrap_test();
+
+        assert_eq!(staking_keeper().get(&ctx).max_validators(), 42);
+        assert!(ctx.events().iter().any(|e| e.r#type == "param_change"));
+    }
+
+    #[test]
+    fn param_change_rejects_invalid_staking_max_validators() {
+        let multi_store =
+            ApplicationMultiBank::<_, GaiaStoreKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+        staking_keeper().set(&mut ctx, StakingParams::default());
+
+        for value in [b"0".as_slice(), b"-1", b"many"] {
+            let proposal = max_validators_proposal(value);
+            assert!(!GaiaProposalHandler::check(&proposal));
+            assert!(matches!(
+                GaiaProposalHandler.handle(&proposal, &mut ctx),
+                Err(SubmissionHandlingError::InvalidProposal)
+            ));
+        }
+
+        assert_eq!(
+            staking_keeper().get(&ctx).max_validators(),
+            StakingParams::default().max_validators()
+        );
+        assert!(ctx.events().is_empty());
+    }
+}
@@ -4,14 +4,14 @@ use bank::{BankNodeQueryRequest, BankNodeQueryResponse};
 use distribution::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
 use gears::baseapp::NodeQueryHandler;
 use gears::{
-    baseapp::{QueryRequest, QueryResponse},
+    baseapp::{HeightQueryRequest, QueryResponse},
     rest::RestState,
 };
 use slashing::{SlashingNodeQueryRequest, SlashingNodeQueryResponse};
 use staking::{StakingNodeQueryRequest, StakingNodeQueryResponse};
 
 pub fn get_router<
-    QReq: QueryRequest
+    QReq: HeightQueryRequest
         + From<AuthNodeQueryRequest>
         + From<BankNodeQueryRequest>
         + From<StakingNodeQueryRequest>
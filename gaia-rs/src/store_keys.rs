@@ -33,3 +33,6 @@ pub enum GaiaParamsStoreKey {
     #[pkey(to_string = "capability/")]
     Capability,
 }
+
+gears::store::assert_disjoint_keys!(GaiaStoreKey::STORE_KEYS);
+gears::store::assert_disjoint_keys!(GaiaParamsStoreKey::PARAMS_KEYS);
@@ -0,0 +1,95 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
+use bank::{BankNodeQueryRequest, BankNodeQueryResponse};
+use gaia_rs::{GaiaNodeQueryRequest, GaiaNodeQueryResponse};
+use gears::baseapp::NodeQueryHandler;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::types::uint::Uint256;
+use gears::utils::node::generate_txs;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node;
+
+const BLOCKS: u64 = 10;
+
+#[test]
+/// In this scenario, we query a balance on one thread while blocks which change it are committed on another
+fn query_while_committing_blocks() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+
+    let query = {
+        let app = node.app().clone();
+        let to_address = to_address.clone();
+
+        move || {
+            let res = app
+                .typed_query(GaiaNodeQueryRequest::new(
+                    BankNodeQueryRequest::Balance(QueryBalanceRequest {
+                        address: to_address.clone(),
+                        denom: "uatom".parse().expect("hard coded denom is valid"),
+                    }),
+                    0,
+                ))
+                .expect("latest committed state is always available");
+
+            match res {
+                GaiaNodeQueryResponse::Bank(BankNodeQueryResponse::Balance(
+                    QueryBalanceResponse { balance },
+                )) => balance.map(|coin| coin.amount).unwrap_or_default(),
+                _ => panic!("unexpected response"),
+            }
+        }
+    };
+
+    let done = Arc::new(AtomicBool::new(false));
+    let query_thread = {
+        let done = Arc::clone(&done);
+        let query = query.clone();
+
+        std::thread::spawn(move || {
+            let mut last = Uint256::zero();
+            let mut queries = 0;
+            while !done.load(Ordering::Acquire) {
+                let amount = query();
+
+                // every committed block sends exactly 1uatom, so a consistent snapshot
+                // never goes backwards or exceeds the number of blocks
+                assert!(amount >= last);
+                assert!(amount <= Uint256::from(BLOCKS));
+
+                last = amount;
+                queries += 1;
+            }
+
+            queries
+        })
+    };
+
+    for sequence in 0..BLOCKS {
+        let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+            from_address: user.address(),
+            to_address: to_address.clone(),
+            amount: Coins::new(vec!["1uatom".parse().expect("hard coded coin is valid")])
+                .expect("hard coded coins are valid"),
+        }));
+
+        let txs = generate_txs([(sequence, msg)], &user, node.chain_id().clone());
+        node.step(txs, Timestamp::UNIX_EPOCH);
+    }
+
+    done.store(true, Ordering::Release);
+    let queries = query_thread.join().expect("query thread should not panic");
+
+    assert!(queries > 0);
+    assert_eq!(query(), Uint256::from(BLOCKS));
+}
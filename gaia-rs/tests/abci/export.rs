@@ -0,0 +1,61 @@
+use gears::types::base::coins::UnsignedCoins;
+use gears::types::msg::send::MsgSend;
+use gears::utils::node::generate_txs;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, we check that the exported genesis state reflects a transfer made after genesis
+fn export_reflects_transfer() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let amount: UnsignedCoins = "10uatom".parse().expect("hard coded coins are valid");
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: to_address.clone(),
+        amount: amount.clone(),
+    }));
+    let txs = generate_txs([(0, msg)], &user, node.chain_id().clone());
+    node.step(txs, Timestamp::UNIX_EPOCH);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let (version, genesis) = node.app().export_genesis(0).expect("export is supported");
+
+    assert_eq!(version, 3);
+
+    let balance = |address: &AccAddress| {
+        genesis
+            .bank
+            .balances
+            .iter()
+            .find(|balance| &balance.address == address)
+            .map(|balance| balance.coins.clone())
+    };
+    assert_eq!(balance(&to_address), Some(amount));
+    // 34uatom at genesis minus the 10uatom transfer and the 1uatom fee
+    assert_eq!(
+        balance(&user.address()),
+        Some("23uatom".parse().expect("hard coded coins are valid"))
+    );
+    assert!(genesis
+        .auth
+        .accounts
+        .iter()
+        .any(|account| account.get_address() == &user.address()));
+
+    // an earlier version still holds the balances from before the transfer
+    let (_, genesis) = node.app().export_genesis(1).expect("export is supported");
+
+    assert!(genesis
+        .bank
+        .balances
+        .iter()
+        .all(|balance| balance.address != to_address));
+}
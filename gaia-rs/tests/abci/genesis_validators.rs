@@ -0,0 +1,30 @@
+use std::path::Path;
+
+use gears::tendermint::types::proto::{crypto::PublicKey, validator::VotingPower};
+
+use crate::setup_mock_node;
+
+#[test]
+/// The validator created by the genesis gentx makes up the initial validator set.
+fn genesis_gentx_sets_initial_validator_set() {
+    let genesis_path = Path::new("./tests/abci/assets/scenario_3_genesis.json");
+    let (node, _) = setup_mock_node(Some(genesis_path));
+
+    let consensus_pub_key = serde_json::from_str::<PublicKey>(
+        r#"{
+    "type": "tendermint/PubKeyEd25519",
+    "value": "AFn3B2/Dvyu9csqfifLNiW1B+D8FvcabD5NW+fGZLPc="
+    }"#,
+    )
+    .expect("hardcoded is valid");
+
+    let validators = node.validator_set();
+
+    assert_eq!(validators.len(), 1);
+    assert_eq!(validators[0].pub_key, consensus_pub_key);
+    // 10000000000uatom self delegation with the default power reduction of 10^6
+    assert_eq!(
+        validators[0].power,
+        VotingPower::new(10_000).expect("hardcoded power is less the max voting power")
+    );
+}
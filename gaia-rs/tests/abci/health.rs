@@ -0,0 +1,17 @@
+use gears::baseapp::NodeHealthHandler;
+use gears::tendermint::types::time::timestamp::Timestamp;
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, we check that the node only reports readiness once the first block is committed
+fn ready_after_first_commit() {
+    let (mut node, _) = setup_mock_node(None::<&str>);
+
+    // init chain state isn't committed until the first block
+    assert!(!node.app().is_ready());
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    assert!(node.app().is_ready());
+}
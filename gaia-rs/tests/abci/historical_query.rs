@@ -0,0 +1,65 @@
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
+use gears::core::Protobuf;
+use gears::tendermint::types::request::query::RequestQuery;
+use gears::types::address::AccAddress;
+use gears::types::msg::send::MsgSend;
+use gears::types::uint::Uint256;
+use gears::utils::node::generate_txs;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::denom::Denom};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, we query a balance at heights before and after a transfer
+fn query_balance_at_past_height() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: to_address.clone(),
+        amount: "10uatom".parse().expect("hard coded coins are valid"),
+    }));
+    let txs = generate_txs([(0, msg)], &user, node.chain_id().clone());
+    node.step(txs, Timestamp::UNIX_EPOCH);
+
+    let query = |height: i64| {
+        node.query(RequestQuery {
+            data: QueryBalanceRequest {
+                address: to_address.clone(),
+                denom: Denom::try_from("uatom".to_owned()).expect("hard coded denom is valid"),
+            }
+            .encode_vec()
+            .into(),
+            path: "/cosmos.bank.v1beta1.Query/Balance".to_string(),
+            height,
+            prove: false,
+        })
+    };
+    let balance = |value| {
+        QueryBalanceResponse::decode(value)
+            .expect("response is valid")
+            .balance
+            .map(|coin| coin.amount)
+            .unwrap_or_default()
+    };
+
+    let res = query(0);
+    assert_eq!(res.code, 0);
+    assert_eq!(res.height, 2);
+    assert_eq!(balance(res.value), Uint256::from(10_u32));
+
+    // the transfer happened at height 2 so the recipient had nothing at height 1
+    let res = query(1);
+    assert_eq!(res.code, 0);
+    assert_eq!(res.height, 1);
+    assert_eq!(balance(res.value), Uint256::zero());
+
+    let res = query(5);
+    assert_eq!(res.code, 22);
+}
@@ -18,11 +18,29 @@ use keyring::key::pair::KeyPair;
 use std::fs;
 use std::path::Path;
 
+#[cfg(test)]
+mod concurrent_query;
+#[cfg(test)]
+mod export;
+#[cfg(test)]
+mod genesis_validators;
+#[cfg(test)]
+mod health;
+#[cfg(test)]
+mod historical_query;
+#[cfg(test)]
+mod min_fee;
+#[cfg(test)]
+mod multi_signer;
 mod scenario_1;
 mod scenario_2;
 mod scenario_3;
 #[cfg(test)]
+mod simulate;
+#[cfg(test)]
 mod two_tx;
+#[cfg(test)]
+mod validator_updates;
 
 const USER_0: &str = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
 const USER_1: &str = "unfair live spike near cushion blanket club salad poet cigar venue above north speak harbor salute curve tail appear obvious month end boss priority";
@@ -44,9 +62,18 @@ fn setup_mock_node(
 ) -> (
     MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>,
     User,
+) {
+    setup_mock_node_with_options(genesis_path, NodeOptions::default())
+}
+
+fn setup_mock_node_with_options(
+    genesis_path: Option<impl AsRef<Path>>,
+    node_options: NodeOptions,
+) -> (
+    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>,
+    User,
 ) {
     let db = MemDB::new();
-    let node_options = NodeOptions::default();
     let config: Config<AppConfig> = Config::default();
     let app: BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication> = BaseApp::new(
         db,
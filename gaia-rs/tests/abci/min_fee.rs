@@ -0,0 +1,57 @@
+use gears::baseapp::options::NodeOptions;
+use gears::tendermint::application::ABCIApplication;
+use gears::tendermint::types::request::check_tx::RequestCheckTx;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::utils::node::{generate_txs, generate_txs_with};
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node_with_options;
+
+#[test]
+/// In this scenario, we check that a tx without a fee is rejected by a node which sets minimum gas prices
+fn zero_fee_rejected_by_min_gas_prices() {
+    let node_options = NodeOptions::new(
+        "0.000005uatom"
+            .parse()
+            .expect("hard coded gas prices are valid"),
+    );
+    let (mut node, user) = setup_mock_node_with_options(None::<&str>, node_options);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address,
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }));
+
+    let txs = generate_txs_with(
+        [(0, msg.clone())],
+        &user,
+        node.chain_id().clone(),
+        None,
+        200_000_u64
+            .try_into()
+            .expect("hard coded gas limit is valid"),
+    );
+
+    let res = node.app().check_tx(RequestCheckTx {
+        tx: txs[0].clone(),
+        r#type: 0,
+    });
+    assert_ne!(res.code, 0);
+    assert!(res.log.contains("fee required"), "{}", res.log);
+
+    // the default fee covers the 1uatom required for 200_000 gas
+    let txs = generate_txs([(0, msg)], &user, node.chain_id().clone());
+
+    let res = node.app().check_tx(RequestCheckTx {
+        tx: txs[0].clone(),
+        r#type: 0,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
+use bank::{BankNodeQueryRequest, BankNodeQueryResponse};
+use gaia_rs::{GaiaNodeQueryRequest, GaiaNodeQueryResponse};
+use gears::baseapp::NodeQueryHandler;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::types::uint::Uint256;
+use gears::utils::node::generate_txs_multi;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::{setup_mock_node, USER_0, USER_1};
+
+#[test]
+/// In this scenario, two different accounts submit a balance transfer in the same block
+fn txs_from_two_signers_in_single_block() {
+    let genesis_path = Path::new("./tests/abci/assets/scenario_2_genesis.json");
+    let (mut node, _) = setup_mock_node(Some(genesis_path));
+    let user_0 = crate::user(4, USER_0);
+    let user_1 = crate::user(5, USER_1);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+
+    let send = |from_address: AccAddress| {
+        gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+            from_address,
+            to_address: to_address.clone(),
+            amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+                .expect("hard coded coins are valid"),
+        }))
+    };
+
+    let txs = generate_txs_multi(
+        [
+            (0, send(user_0.address()), &user_0),
+            (0, send(user_1.address()), &user_1),
+        ],
+        node.chain_id().clone(),
+    );
+
+    node.step(txs, Timestamp::UNIX_EPOCH);
+
+    let balance = |address: &AccAddress| {
+        let res = node
+            .app()
+            .typed_query(GaiaNodeQueryRequest::new(
+                BankNodeQueryRequest::Balance(QueryBalanceRequest {
+                    address: address.clone(),
+                    denom: "uatom".parse().expect("hard coded denom is valid"),
+                }),
+                0,
+            ))
+            .expect("latest committed state is always available");
+
+        match res {
+            GaiaNodeQueryResponse::Bank(BankNodeQueryResponse::Balance(QueryBalanceResponse {
+                balance,
+            })) => balance.map(|coin| coin.amount).unwrap_or_default(),
+            _ => panic!("unexpected response"),
+        }
+    };
+
+    assert_eq!(balance(&to_address), Uint256::from(20u32));
+    // 34uatom less the transfer and the 1uatom fee
+    assert_eq!(balance(&user_0.address()), Uint256::from(23u32));
+}
@@ -1,6 +1,9 @@
 use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::address::AccAddress;
 use gears::types::base::coins::Coins;
+use gears::types::denom::Denom;
 use gears::types::msg::send::MsgSend;
+use gears::types::uint::Uint256;
 use gears::utils::node::generate_txs;
 
 use crate::setup_mock_node;
@@ -19,7 +22,7 @@ fn scenario_1() {
     node.step(vec![], Timestamp::UNIX_EPOCH);
     node.step(vec![], Timestamp::UNIX_EPOCH);
 
-    let to_address = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
         .parse()
         .expect("hard coded address is valid");
     let amount = Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
@@ -27,7 +30,7 @@ fn scenario_1() {
 
     let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
         from_address: user.address(),
-        to_address,
+        to_address: to_address.clone(),
         amount,
     }));
 
@@ -38,4 +41,15 @@ fn scenario_1() {
         hex::encode(app_hash),
         "8eb5f41a3f77e034185be06e5385ff0d0a42f8d0f59171b1cc12b1ac6a66bbef"
     );
+
+    let uatom: Denom = "uatom".parse().expect("hard coded denom is valid");
+    assert_eq!(
+        node.query_balance(&to_address, &uatom).amount,
+        Uint256::from(10_u32)
+    );
+    // 34uatom less the transfer and the 1uatom fee
+    assert_eq!(
+        node.query_balance(&user.address(), &uatom).amount,
+        Uint256::from(23_u32)
+    );
 }
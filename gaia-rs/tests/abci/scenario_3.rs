@@ -72,7 +72,7 @@ fn scenario_3() {
 
     // query the validator list
     let query = QueryValidatorsRequest {
-        status: BondStatus::Bonded,
+        status: Some(BondStatus::Bonded),
         pagination: None,
     };
 
@@ -0,0 +1,100 @@
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
+use bank::{BankNodeQueryRequest, BankNodeQueryResponse};
+use gaia_rs::{GaiaNodeQueryRequest, GaiaNodeQueryResponse};
+use gears::baseapp::{NodeQueryHandler, NodeSimulateHandler};
+use gears::tendermint::application::ABCIApplication;
+use gears::tendermint::types::request::check_tx::RequestCheckTx;
+use gears::types::base::coins::Coins;
+use gears::types::msg::send::MsgSend;
+use gears::types::uint::Uint256;
+use gears::utils::node::generate_txs;
+use gears::{tendermint::types::time::timestamp::Timestamp, types::address::AccAddress};
+
+use crate::setup_mock_node;
+
+#[test]
+/// In this scenario, we simulate a balance transfer and check that it reports the used gas without changing the state
+fn simulate_send_does_not_change_state() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+
+    // the app shares its state with the node, so it sees the blocks stepped below
+    let app = node.app().clone();
+    let balance = |address: &AccAddress| {
+        let res = app
+            .typed_query(GaiaNodeQueryRequest::new(
+                BankNodeQueryRequest::Balance(QueryBalanceRequest {
+                    address: address.clone(),
+                    denom: "uatom".parse().expect("hard coded denom is valid"),
+                }),
+                0,
+            ))
+            .expect("latest committed state is always available");
+
+        match res {
+            GaiaNodeQueryResponse::Bank(BankNodeQueryResponse::Balance(QueryBalanceResponse {
+                balance,
+            })) => balance.map(|coin| coin.amount).unwrap_or_default(),
+            _ => panic!("unexpected response"),
+        }
+    };
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: to_address.clone(),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }));
+    let txs = generate_txs([(0, msg)], &user, node.chain_id().clone());
+
+    let info = app
+        .simulate_tx(txs[0].clone())
+        .expect("simulation of a valid tx succeeds");
+
+    assert!(u64::from(info.gas_used) > 0);
+    assert!(!info.events.is_empty());
+    assert_eq!(balance(&user.address()), Uint256::from(34_u64));
+    assert_eq!(balance(&to_address), Uint256::zero());
+
+    // the sequence wasn't incremented by the simulation so the same tx can be delivered
+    node.step(txs, Timestamp::UNIX_EPOCH);
+    assert_eq!(balance(&user.address()), Uint256::from(23_u64));
+    assert_eq!(balance(&to_address), Uint256::from(10_u64));
+}
+
+#[test]
+/// In this scenario, we check that the simulated gas covers the message execution on top of the ante checks
+fn simulate_gas_used_includes_msg_execution() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let msg = gaia_rs::message::Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    }));
+    let txs = generate_txs([(0, msg)], &user, node.chain_id().clone());
+
+    let info = node
+        .app()
+        .simulate_tx(txs[0].clone())
+        .expect("simulation of a valid tx succeeds");
+
+    // `CheckTx` only runs the ante checks
+    let res = node.app().check_tx(RequestCheckTx {
+        tx: txs[0].clone(),
+        r#type: 0,
+    });
+    assert_eq!(res.code, 0, "{}", res.log);
+
+    let ante_gas_used = u64::try_from(res.gas_used).expect("gas used is never negative");
+    assert!(ante_gas_used > 0);
+    assert!(u64::from(info.gas_used) > ante_gas_used);
+}
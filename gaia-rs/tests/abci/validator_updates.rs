@@ -0,0 +1,71 @@
+use std::path::Path;
+
+use gears::{
+    tendermint::types::{
+        proto::{
+            crypto::PublicKey,
+            validator::{ValidatorUpdate, VotingPower},
+        },
+        time::timestamp::Timestamp,
+    },
+    types::uint::Uint256,
+    utils::node::generate_txs,
+};
+use staking::{CommissionRates, CreateValidator, Description};
+
+use crate::{setup_mock_node, USER_0, USER_1};
+
+#[test]
+/// Bonding a new validator is reported to Tendermint in the `EndBlock` response.
+fn end_block_returns_new_validator_update() {
+    let genesis_path = Path::new("./tests/abci/assets/scenario_3_genesis.json");
+    let (mut node, _) = setup_mock_node(Some(genesis_path));
+    let user_0 = crate::user(2, USER_0);
+    let user_1 = crate::user(5, USER_1);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+    assert!(node.last_validator_updates().is_empty());
+
+    let consensus_pub_key = serde_json::from_str::<PublicKey>(
+        r#"{
+    "type": "tendermint/PubKeyEd25519",
+    "value": "NJWo4rSXCswNmK0Bttxzb8/1ioFNkRVi6Fio2KzAlCo="
+    }"#,
+    )
+    .expect("hardcoded is valid");
+
+    let msg =
+        gaia_rs::message::Message::Staking(staking::Message::CreateValidator(CreateValidator {
+            description: Description {
+                moniker: "test".to_string(),
+                identity: "".to_string(),
+                website: "".to_string(),
+                details: "".to_string(),
+                security_contact: "".to_string(),
+            },
+            commission: CommissionRates::new(
+                "0.1".parse().expect("hardcoded is valid"),
+                "1".parse().expect("hardcoded is valid"),
+                "0.1".parse().expect("hardcoded is valid"),
+            )
+            .expect("hardcoded is valid"),
+            min_self_delegation: Uint256::from(100u32),
+            delegator_address: user_0.address(),
+            validator_address: user_1.address().into(),
+            pubkey: consensus_pub_key.clone(),
+            value: "5000000uatom".parse().expect("hardcoded is valid"),
+        }));
+
+    let txs = generate_txs([(1, msg)], &user_0, node.chain_id().clone());
+    node.step(txs, Timestamp::UNIX_EPOCH);
+
+    // 5000000uatom self delegation with the default power reduction of 10^6
+    let expected = ValidatorUpdate {
+        pub_key: consensus_pub_key,
+        power: VotingPower::new(5).expect("hardcoded power is less the max voting power"),
+    };
+
+    assert_eq!(node.last_validator_updates(), [expected.clone()]);
+    assert!(node.validator_set().contains(&expected));
+    assert_eq!(node.validator_set().len(), 2);
+}
@@ -10,7 +10,7 @@ use gaia_rs::{
     GaiaCoreClient,
 };
 use gears::{
-    commands::client::query::{run_query, QueryCommand},
+    commands::client::query::{run_query, OutputFormat, QueryCommand},
     config::DEFAULT_TENDERMINT_RPC_ADDRESS,
     types::account::{Account, BaseAccount},
 };
@@ -31,6 +31,7 @@ fn account_query() -> anyhow::Result<()> {
     let cmd = QueryCommand {
         node: DEFAULT_TENDERMINT_RPC_ADDRESS.parse()?,
         height: None,
+        output: OutputFormat::default(),
         inner: WrappedGaiaQueryCommands(GaiaQueryCommands::Auth(AuthQueryCli {
             command: AuthCommands::Account(query),
         })),
@@ -18,8 +18,8 @@ use gaia_rs::{
 };
 use gears::{
     commands::client::{
-        query::{run_query, QueryCommand},
-        tx::{run_tx, ClientTxContext, TxCommand},
+        query::{run_query, OutputFormat, QueryCommand},
+        tx::{run_tx, BroadcastResponse, ClientTxContext, TxCommand},
     },
     config::DEFAULT_TENDERMINT_RPC_ADDRESS,
     tendermint::{
@@ -49,6 +49,7 @@ fn balances_query() -> anyhow::Result<()> {
         QueryCommand {
             node: DEFAULT_TENDERMINT_RPC_ADDRESS.parse()?,
             height: None,
+            output: OutputFormat::default(),
             inner: WrappedGaiaQueryCommands(GaiaQueryCommands::Bank(BankQueryCli {
                 command: BankQueryCommands::Balances(query),
             })),
@@ -71,6 +72,34 @@ fn balances_query() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn balances_query_json_output() -> anyhow::Result<()> {
+    let _tendermint = tendermint();
+
+    let query = BalancesCommand {
+        address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")?,
+        pagination: None,
+    };
+
+    let cmd = QueryCommand {
+        node: DEFAULT_TENDERMINT_RPC_ADDRESS.parse()?,
+        height: None,
+        output: OutputFormat::Json,
+        inner: WrappedGaiaQueryCommands(GaiaQueryCommands::Bank(BankQueryCli {
+            command: BankQueryCommands::Balances(query),
+        })),
+    };
+    let output = cmd.output;
+
+    let result = run_query(cmd, &GaiaCoreClient)?;
+
+    let json: serde_json::Value = serde_json::from_str(&output.format(&result)?)?;
+
+    assert!(json.is_object());
+
+    Ok(())
+}
+
 #[test]
 fn denom_query() -> anyhow::Result<()> {
     let _tendermint = tendermint();
@@ -79,6 +108,7 @@ fn denom_query() -> anyhow::Result<()> {
         QueryCommand {
             node: DEFAULT_TENDERMINT_RPC_ADDRESS.parse()?,
             height: None,
+            output: OutputFormat::default(),
             inner: WrappedGaiaQueryCommands(GaiaQueryCommands::Bank(BankQueryCli {
                 command: BankQueryCommands::DenomMetadata { pagination: None },
             })),
@@ -122,7 +152,11 @@ fn send_tx() -> anyhow::Result<()> {
         &QueryNodeFetcher,
     )?
     .broadcast()
-    .expect("broadcast tx inside");
+    .expect("broadcast tx inside")
+    .into_iter()
+    .map(BroadcastResponse::block)
+    .collect::<Option<Vec<_>>>()
+    .expect("tx is broadcasted in block mode");
 
     assert_eq!(responses.len(), 1);
     let Response {
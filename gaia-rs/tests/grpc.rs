@@ -0,0 +1,57 @@
+#![cfg(feature = "it")]
+
+use gears::config::DEFAULT_GRPC_LISTEN_ADDR;
+use tonic_reflection::pb::v1::{
+    server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+    server_reflection_response::MessageResponse, ServerReflectionRequest,
+};
+
+use utilities::tendermint;
+
+#[path = "./utilities.rs"]
+mod utilities;
+
+#[test]
+fn reflection_lists_served_services() -> anyhow::Result<()> {
+    let _tendermint = tendermint();
+
+    let services = tokio::runtime::Runtime::new()?.block_on(list_services())?;
+
+    for service in [
+        "cosmos.auth.v1beta1.Query",
+        "cosmos.bank.v1beta1.Query",
+        "cosmos.staking.v1beta1.Query",
+    ] {
+        assert!(
+            services.iter().any(|this| this == service),
+            "{service} is not listed in {services:?}"
+        );
+    }
+
+    Ok(())
+}
+
+async fn list_services() -> anyhow::Result<Vec<String>> {
+    let mut client =
+        ServerReflectionClient::connect(format!("http://{DEFAULT_GRPC_LISTEN_ADDR}")).await?;
+
+    let request = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(MessageRequest::ListServices(String::new())),
+    };
+    let mut responses = client
+        .server_reflection_info(tokio_stream::once(request))
+        .await?
+        .into_inner();
+
+    match responses
+        .message()
+        .await?
+        .and_then(|this| this.message_response)
+    {
+        Some(MessageResponse::ListServicesResponse(response)) => {
+            Ok(response.service.into_iter().map(|this| this.name).collect())
+        }
+        other => Err(anyhow::anyhow!("unexpected reflection response: {other:?}")),
+    }
+}
@@ -0,0 +1,99 @@
+#![cfg(all(feature = "it", unix))]
+
+use std::{
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::Path,
+    process::{Child, Command, ExitStatus, Stdio},
+    time::{Duration, Instant},
+};
+
+#[cfg(all(feature = "rocksdb", not(feature = "sled")))]
+use gears::store::database::rocks::RocksDB as DB;
+#[cfg(all(feature = "sled", not(feature = "rocksdb")))]
+use gears::store::database::sled::SledDb as DB;
+use gears::{
+    store::database::{DBBuilder, DatabaseBuilder},
+    utils::tendermint::TempDir,
+};
+
+const GAIA_BIN: &str = env!("CARGO_BIN_EXE_gaia-rs");
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn free_addr() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .expect("failed to bind to a free port")
+        .local_addr()
+        .expect("bound listener has an address")
+}
+
+fn start_node(home: &Path, address: SocketAddr) -> Child {
+    Command::new(GAIA_BIN)
+        .arg("run")
+        .arg("--home")
+        .arg(home)
+        .args(["--address", &address.to_string()])
+        .args(["--rest-listen-addr", &free_addr().to_string()])
+        .args(["--grpc-listen-addr", &free_addr().to_string()])
+        .args(["--min-gas-prices", "0uatom"])
+        .args(["--log-level", "off"])
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("failed to start node")
+}
+
+fn wait_for_exit(node: &mut Child) -> ExitStatus {
+    let started = Instant::now();
+    loop {
+        if let Some(status) = node.try_wait().expect("failed to poll node") {
+            return status;
+        }
+
+        if started.elapsed() > TIMEOUT {
+            let _ = node.kill();
+            panic!("node didn't exit after shutdown signal");
+        }
+
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[test]
+fn node_exits_cleanly_on_sigterm() {
+    let home = TempDir::new().expect("failed to create temp dir");
+    let home_path = home.to_path_buf();
+
+    let status = Command::new(GAIA_BIN)
+        .args(["init", "test"])
+        .arg("--home")
+        .arg(&home_path)
+        .stdout(Stdio::null())
+        .status()
+        .expect("failed to run init");
+    assert!(status.success());
+
+    let address = free_addr();
+    let mut node = start_node(&home_path, address);
+
+    let started = Instant::now();
+    while TcpStream::connect(address).is_err() {
+        if started.elapsed() > TIMEOUT {
+            let _ = node.kill();
+            panic!("node didn't start listening for ABCI connections");
+        }
+
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    let status = Command::new("kill")
+        .args(["-TERM", &node.id().to_string()])
+        .status()
+        .expect("failed to send SIGTERM");
+    assert!(status.success());
+
+    let status = wait_for_exit(&mut node);
+    assert!(status.success(), "node exited with {status}");
+
+    let _db: DB = DBBuilder
+        .build(home_path.join("data").join("application.db"))
+        .expect("database should reopen after shutdown");
+}
@@ -7,8 +7,8 @@ use gaia_rs::{
 };
 use gears::{
     commands::client::{
-        query::{run_query, QueryCommand},
-        tx::{run_tx, ClientTxContext, TxCommand},
+        query::{run_query, OutputFormat, QueryCommand},
+        tx::{run_tx, BroadcastResponse, ClientTxContext, TxCommand},
     },
     config::DEFAULT_TENDERMINT_RPC_ADDRESS,
     tendermint::{rpc::response::tx::broadcast::Response, types::chain_id::ChainId},
@@ -59,7 +59,10 @@ fn run_tx_local(
     .broadcast()
     .expect("broadcast tx inside");
     assert_eq!(responses.len(), 1);
-    Ok(responses.pop().expect("vector has exactly single element"))
+    Ok(responses
+        .pop()
+        .and_then(BroadcastResponse::block)
+        .expect("vector has exactly single block response"))
 }
 
 fn run_query_local(command: GaiaQueryCommands) -> anyhow::Result<GaiaQueryResponse> {
@@ -67,6 +70,7 @@ fn run_query_local(command: GaiaQueryCommands) -> anyhow::Result<GaiaQueryRespon
         QueryCommand {
             node: DEFAULT_TENDERMINT_RPC_ADDRESS.parse()?,
             height: None,
+            output: OutputFormat::default(),
             inner: WrappedGaiaQueryCommands(command),
         },
         &GaiaCoreClient,
@@ -13,7 +13,7 @@ use gears::{
     commands::{
         client::keys::{keys, AddKeyCommand, KeyCommand, KeyringBackend},
         node::{
-            run::{LogLevel, RunCommand},
+            run::{LogFormat, LogLevel, RunCommand},
             AppCommands,
         },
     },
@@ -99,8 +99,10 @@ pub fn run_gaia_and_tendermint(
             grpc_listen_addr: Some(DEFAULT_GRPC_LISTEN_ADDR),
             read_buf_size: 1048576,
             log_level: LogLevel::Off,
+            log_format: LogFormat::Text,
             min_gas_prices: Default::default(),
             tendermint_rpc_addr: None,
+            pruning: None,
         };
 
         let _ = node.execute::<GaiaApplication>(AppCommands::Run(cmd));
@@ -133,6 +135,8 @@ pub fn key_add(home: impl Into<PathBuf>, name: &str, mnemonic: &str) -> anyhow::
         home: home.into(),
         keyring_backend: KeyringBackend::Test,
         bip39_mnemonic: Some(mnemonic.to_owned()),
+        account: 0,
+        index: 0,
     };
 
     keys(KeyCommand::Add(cmd))?;
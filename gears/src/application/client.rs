@@ -44,19 +44,22 @@ impl<Core: Client, F: NodeFetcher + Clone> ClientApplication<Core, F> {
                 }
             }
             ClientCommands::Query(cmd) => {
+                let output = cmd.output;
                 let query = run_query(cmd, &self.core)?;
 
-                println!("{}", serde_json::to_string_pretty(&query)?);
+                println!("{}", output.format(&query)?);
             }
             ClientCommands::QueryTx(cmd) => {
+                let output = cmd.output;
                 let query = run_query(cmd, &TxQueryHandler::<Core::Message>::new())?;
 
-                println!("{}", serde_json::to_string_pretty(&query)?);
+                println!("{}", output.format(&query)?);
             }
             ClientCommands::QueryTxs(cmd) => {
+                let output = cmd.output;
                 let query = run_query(cmd, &TxsQueryHandler::<Core::Message>::new())?;
 
-                println!("{}", serde_json::to_string_pretty(&query)?);
+                println!("{}", output.format(&query)?);
             }
             ClientCommands::Keys(cmd) => keys(cmd)?,
         };
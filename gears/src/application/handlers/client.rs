@@ -2,7 +2,10 @@ use std::path::PathBuf;
 
 use crate::{
     baseapp::Query,
-    commands::client::tx::{broadcast_tx_commit, AccountProvider, ClientTxContext},
+    commands::client::tx::{
+        broadcast_tx, AccountNotFoundError, AccountProvider, BroadcastResponse, ClientTxContext,
+        DEFAULT_MAX_MEMO_CHARACTERS,
+    },
     crypto::{
         info::{create_signed_transaction_direct, create_signed_transaction_textual, SigningInfo},
         keys::{GearsPublicKey, ReadAccAddress, SigningKey},
@@ -14,7 +17,7 @@ use crate::{
         account::{Account, BaseAccount},
         address::AccAddress,
         denom::Denom,
-        tx::{body::TxBody, metadata::Metadata, Messages, Tx, TxMessage},
+        tx::{metadata::Metadata, Messages, Tx, TxMessage},
     },
 };
 
@@ -23,23 +26,20 @@ use core_types::tx::mode_info::SignMode;
 use serde::Serialize;
 
 use tendermint::{
-    rpc::{
-        client::{Client, HttpClient},
-        response::tx::broadcast::Response,
-    },
+    rpc::client::{Client, HttpClient},
     types::proto::block::Height,
 };
 
 #[derive(Debug, Clone, Default)]
 pub enum TxExecutionResult {
-    Broadcast(Response),
+    Broadcast(BroadcastResponse),
     File(PathBuf),
     #[default]
     None,
 }
 
 impl TxExecutionResult {
-    pub fn broadcast(self) -> Option<Response> {
+    pub fn broadcast(self) -> Option<BroadcastResponse> {
         match self {
             TxExecutionResult::Broadcast(var) => Some(var),
             TxExecutionResult::File(_) => None,
@@ -56,8 +56,8 @@ impl TxExecutionResult {
     }
 }
 
-impl From<Response> for TxExecutionResult {
-    fn from(value: Response) -> Self {
+impl From<BroadcastResponse> for TxExecutionResult {
+    fn from(value: BroadcastResponse) -> Self {
         Self::Broadcast(value)
     }
 }
@@ -89,8 +89,31 @@ pub trait TxHandler {
                 account_number,
                 sequence,
             }))),
-            AccountProvider::Online => {
-                fetcher.latest_account(address, client_tx_context.node.as_str())
+            AccountProvider::Online {
+                sequence: Some(sequence),
+                account_number: Some(account_number),
+            } => Ok(Some(Account::Base(BaseAccount {
+                address,
+                pub_key: None,
+                account_number,
+                sequence,
+            }))),
+            AccountProvider::Online {
+                sequence,
+                account_number,
+            } => {
+                let account = fetcher.latest_account(address, client_tx_context.node.as_str())?;
+
+                Ok(account.map(|mut account| {
+                    if let Some(sequence) = sequence {
+                        account.set_sequence(sequence);
+                    }
+                    if let Some(account_number) = account_number {
+                        account.set_account_number(account_number);
+                    }
+
+                    account
+                }))
             }
         }
     }
@@ -107,7 +130,7 @@ pub trait TxHandler {
 
         let account = self
             .account(address.to_owned(), ctx, fetcher)?
-            .ok_or_else(|| anyhow!("account not found: {}", address))?;
+            .ok_or_else(|| AccountNotFoundError(address.to_owned()))?;
 
         let signing_infos = vec![SigningInfo {
             key,
@@ -115,13 +138,7 @@ pub trait TxHandler {
             account_number: account.get_account_number(),
         }];
 
-        let tx_body = TxBody {
-            messages: msgs.into_msgs(),
-            memo: ctx.memo.clone().unwrap_or_default(),
-            timeout_height: ctx.timeout_height.unwrap_or_default(),
-            extension_options: vec![], // TODO: remove hard coded
-            non_critical_extension_options: vec![], // TODO: remove hard coded
-        };
+        let tx_body = ctx.tx_body(msgs.into_msgs(), ctx.max_memo_bytes(fetcher)?)?;
 
         let tip = None; //TODO: remove hard coded
 
@@ -162,11 +179,16 @@ pub trait TxHandler {
 
                 Ok(TxExecutionResult::None)
             }
-            AccountProvider::Online => {
+            AccountProvider::Online { .. } => {
                 let client = HttpClient::new(tendermint::rpc::url::Url::try_from(
                     client_tx_context.node.clone(),
                 )?)?;
-                broadcast_tx_commit(client, Into::into(&raw_tx)).map(Into::into)
+                broadcast_tx(
+                    &client,
+                    Into::into(&raw_tx),
+                    client_tx_context.broadcast_mode,
+                )
+                .map(Into::into)
             }
         }
     }
@@ -238,6 +260,12 @@ pub trait NodeFetcher {
         base: Denom,
         node: impl AsRef<str>,
     ) -> anyhow::Result<Option<Metadata>>;
+
+    /// Query node to get the `max_memo_characters` param of the auth module. Fetchers which don't
+    /// access the node fall back to the param's default value.
+    fn max_memo_characters(&self, _node: impl AsRef<str>) -> anyhow::Result<u64> {
+        Ok(DEFAULT_MAX_MEMO_CHARACTERS)
+    }
 }
 
 pub struct MetadataViaRPC<F: NodeFetcher> {
@@ -89,6 +89,16 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         genesis: Self::Genesis,
     ) -> Vec<ValidatorUpdate>;
 
+    /// Reconstructs the genesis state from the committed state, it's the counterpart of
+    /// `init_genesis`. Returns `None` if the handler doesn't support exporting its state.
+    #[allow(unused_variables)]
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+    ) -> Option<Self::Genesis> {
+        None
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
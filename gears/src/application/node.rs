@@ -7,9 +7,11 @@ use super::{
     ApplicationInfo,
 };
 use crate::commands::node::{
-    genesis::genesis_account_add,
+    export::export,
+    genesis::{genesis_account_add, validate_genesis},
     init::init,
     run::{run, RouterBuilder},
+    store::{store_migrate, store_versions, StoreCommand},
     AppCommands,
 };
 use crate::{
@@ -83,6 +85,47 @@ impl<
             AppCommands::GenesisAdd(cmd) => {
                 genesis_account_add::<<<Core as Node>::Handler as ABCIHandler>::Genesis>(cmd)?
             }
+            AppCommands::ValidateGenesis(cmd) => {
+                let report =
+                    validate_genesis::<<<Core as Node>::Handler as ABCIHandler>::Genesis>(cmd)?;
+
+                let mut valid = true;
+                for (module, result) in report {
+                    match result {
+                        Ok(()) => println!("{module}: pass"),
+                        Err(e) => {
+                            valid = false;
+                            println!("{module}: fail ({})", e.reason)
+                        }
+                    }
+                }
+
+                if !valid {
+                    anyhow::bail!("genesis file is invalid");
+                }
+            }
+            AppCommands::Store(StoreCommand::Versions(cmd)) => {
+                let versions = store_versions::<
+                    DB,
+                    DBO,
+                    <<Core as Node>::Handler as ABCIHandler>::StoreKey,
+                >(cmd, self.db_builder)?;
+
+                for version in versions {
+                    println!("{version}");
+                }
+            }
+            AppCommands::Store(StoreCommand::Migrate(cmd)) => store_migrate::<
+                DB,
+                DBO,
+                <<Core as Node>::Handler as ABCIHandler>::StoreKey,
+            >(cmd, self.db_builder)?,
+            AppCommands::Export(cmd) => export::<DB, DBO, _, _, Core::ApplicationConfig, AI>(
+                cmd,
+                self.db_builder,
+                self.params_subspace_key,
+                self.abci_handler_builder,
+            )?,
             AppCommands::Aux(cmd) => {
                 let cmd = self.core.prepare_aux(cmd)?;
                 self.core.handle_aux(cmd)?;
@@ -14,6 +14,7 @@ use crate::{baseapp::RunTxInfo, context::block::BlockContext};
 use bytes::Bytes;
 use database::Database;
 use extensions::lock::AcquireRwLock;
+use sha2::{Digest, Sha256};
 use tendermint::{
     application::ABCIApplication,
     types::{
@@ -45,7 +46,7 @@ use tendermint::{
         },
     },
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, info_span};
 
 impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     ABCIApplication<H::Genesis> for BaseApp<DB, PSK, H, AI>
@@ -61,8 +62,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             ..
         }: RequestInitChain<H::Genesis>,
     ) -> ResponseInitChain {
-        let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
-        let mut state = self.state.write().expect(POISONED_LOCK);
+        let (mut state, mut multi_store) = self.lock_block_state();
 
         //TODO: handle request height > 1 as is done in SDK
         // On a new chain, we consider the init chain block height as 0, even though
@@ -110,7 +110,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
     fn query(&self, request: RequestQuery) -> ResponseQuery {
         match self.run_query(&request) {
-            Ok(res) => ResponseQuery {
+            Ok((res, height)) => ResponseQuery {
                 code: 0,
                 log: "exists".to_string(),
                 info: "".to_string(),
@@ -118,11 +118,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
                 key: request.data,
                 value: res,
                 proof_ops: None,
-                height: request.height as u32,
+                height,
                 codespace: "".to_string(),
             },
             Err(e) => ResponseQuery {
-                code: 1,
+                code: e.code(),
                 log: e.to_string(),
                 info: "".to_string(),
                 index: 0,
@@ -189,6 +189,13 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn deliver_tx(&self, RequestDeliverTx { tx }: RequestDeliverTx) -> ResponseDeliverTx {
+        let _span = info_span!(
+            "deliver_tx",
+            height = self.get_block_header().height,
+            tx_hash = hex::encode_upper(Sha256::digest(&tx))
+        )
+        .entered();
+
         let mut state = self.state.write().expect(POISONED_LOCK);
 
         let DeliverTxMode {
@@ -230,13 +237,26 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn commit(&self) -> ResponseCommit {
-        let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
-        let mut state = self.state.write().expect(POISONED_LOCK);
+        let (mut state, mut multi_store) = self.lock_block_state();
 
         let height = self.get_block_header().height;
 
+        let _span = info_span!("commit", height).entered();
+
         let hash = state.commit(&mut multi_store);
 
+        if let Some(retain_from) = self
+            .options
+            .pruning()
+            .retain_from(multi_store.head_version())
+        {
+            multi_store.prune(retain_from);
+        }
+
+        self.block_events.write().expect(POISONED_LOCK).commit();
+
+        self.set_ready();
+
         info!(
             "Committed state, block height: {} app hash: {}",
             height,
@@ -258,10 +278,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     fn begin_block(&self, request: RequestBeginBlock) -> ResponseBeginBlock {
         //TODO: Cosmos SDK validates the request height here
 
+        let _span = info_span!("begin_block", height = request.header.height).entered();
+
         self.set_block_header(request.header.clone());
 
-        let mut state = self.state.write().expect(POISONED_LOCK);
-        let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
+        let (mut state, mut multi_store) = self.lock_block_state();
 
         let ctx = SimpleContext::new(
             SimpleBackend::Application(&mut multi_store),
@@ -286,23 +307,30 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             consensus_params,
         );
 
+        let height = request.header.height;
         self.abci_handler.begin_block(&mut ctx, request);
 
         let events = ctx.events;
 
         state.append_block_cache(&mut multi_store);
 
+        self.block_events
+            .write()
+            .expect(POISONED_LOCK)
+            .begin_block(height, events.clone());
+
         ResponseBeginBlock {
             events: events.into_iter().collect(),
         }
     }
 
     fn end_block(&self, request: RequestEndBlock) -> ResponseEndBlock {
-        let mut state = self.state.write().expect(POISONED_LOCK);
-        let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
+        let (mut state, mut multi_store) = self.lock_block_state();
 
         let header = self.get_block_header();
 
+        let _span = info_span!("end_block", height = header.height).entered();
+
         let consensus_params = {
             let ctx = SimpleContext::new(
                 SimpleBackend::Application(&mut multi_store),
@@ -326,6 +354,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
         state.append_block_cache(&mut multi_store);
 
+        self.block_events
+            .write()
+            .expect(POISONED_LOCK)
+            .end_block(events.clone());
+
         ResponseEndBlock {
             events: events.into_iter().collect(),
             validator_updates,
@@ -364,3 +397,63 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use extensions::testing::UnwrapTesting;
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id},
+        Subscriber,
+    };
+    use tracing_subscriber::{
+        layer::{Context as LayerContext, SubscriberExt},
+        Layer, Registry,
+    };
+
+    use crate::baseapp::block_events::tests::{init_app, step};
+
+    /// Records the name and fields of every created span
+    #[derive(Debug, Clone, Default)]
+    struct SpanRecorder(Arc<Mutex<Vec<(&'static str, Vec<(&'static str, String)>)>>>);
+
+    struct FieldRecorder<'a>(&'a mut Vec<(&'static str, String)>);
+
+    impl Visit for FieldRecorder<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0.push((field.name(), format!("{value:?}")));
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for SpanRecorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: LayerContext<'_, S>) {
+            let mut fields = Vec::new();
+            attrs.record(&mut FieldRecorder(&mut fields));
+
+            self.0
+                .lock()
+                .unwrap_test()
+                .push((attrs.metadata().name(), fields));
+        }
+    }
+
+    #[test]
+    fn block_execution_records_spans() {
+        let recorder = SpanRecorder::default();
+        let subscriber = Registry::default().with(recorder.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let app = init_app();
+            step(&app, 1);
+        });
+
+        let spans = recorder.0.lock().unwrap_test().clone();
+        let height = vec![("height", "1".to_owned())];
+
+        assert!(spans.contains(&("begin_block", height.clone())));
+        assert!(spans.contains(&("end_block", height.clone())));
+        assert!(spans.contains(&("commit", height)));
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+
+use tendermint::types::proto::event::Event;
+
+/// Path of the query returning [`BlockEvents`] of a committed block.
+/// Height of the request selects the block, `0` means latest committed block.
+///
+/// Events aren't persisted: only the last [`BLOCK_EVENTS_RETAIN_BLOCKS`] blocks committed since
+/// the node started can be queried, older blocks or blocks from before a restart aren't found.
+pub const BLOCK_EVENTS_QUERY_PATH: &str = "/gears.baseapp.Query/BlockEvents";
+
+/// Number of latest committed blocks whose events are kept in memory, they are lost on restart
+pub const BLOCK_EVENTS_RETAIN_BLOCKS: usize = 100;
+
+/// Events emitted in `BeginBlock` and `EndBlock` of a block. These aren't attached to any tx,
+/// e.g. validator set changes and slashing events.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockEvents {
+    #[prost(uint32, tag = "1")]
+    pub height: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub begin_block: Vec<Event>,
+    #[prost(message, repeated, tag = "3")]
+    pub end_block: Vec<Event>,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct BlockEventsCache {
+    pending: BlockEvents,
+    committed: BTreeMap<u32, BlockEvents>,
+}
+
+impl BlockEventsCache {
+    pub fn begin_block(&mut self, height: u32, events: Vec<Event>) {
+        self.pending = BlockEvents {
+            height,
+            begin_block: events,
+            end_block: Vec::new(),
+        };
+    }
+
+    pub fn end_block(&mut self, events: Vec<Event>) {
+        self.pending.end_block = events;
+    }
+
+    pub fn commit(&mut self) {
+        let events = std::mem::take(&mut self.pending);
+        self.committed.insert(events.height, events);
+
+        while self.committed.len() > BLOCK_EVENTS_RETAIN_BLOCKS {
+            self.committed.pop_first();
+        }
+    }
+
+    /// Returns events of the committed block at `height`, `0` means latest committed block.
+    pub fn get(&self, height: u32) -> Option<&BlockEvents> {
+        match height {
+            0 => self.committed.last_key_value().map(|(_, events)| events),
+            height => self.committed.get(&height),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use bytes::Bytes;
+    use database::{Database, MemDB};
+    use extensions::testing::UnwrapTesting;
+    use key_derive::{ParamsKeys, StoreKeys};
+    use prost::Message;
+    use serde::{Deserialize, Serialize};
+    use tendermint::{
+        application::ABCIApplication,
+        types::{
+            chain_id::ChainId,
+            proto::{
+                consensus::ConsensusParams, event::EventAttribute, header::Header,
+                info::LastCommitInfo, validator::ValidatorUpdate,
+            },
+            request::{
+                begin_block::RequestBeginBlock, end_block::RequestEndBlock,
+                init_chain::RequestInitChain, query::RequestQuery,
+            },
+            time::timestamp::Timestamp,
+        },
+    };
+
+    use crate::{
+        application::{
+            handlers::node::{ABCIHandler, TxError},
+            ApplicationInfo,
+        },
+        baseapp::{
+            errors::QueryError,
+            genesis::{Genesis, GenesisError},
+            options::NodeOptions,
+            BaseApp, NullQueryRequest, NullQueryResponse,
+        },
+        context::{
+            block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
+            QueryableContext, TransactionalContext,
+        },
+        types::{
+            address::AccAddress,
+            base::coins::UnsignedCoins,
+            tx::{raw::TxWithRaw, NullTxMsg},
+        },
+    };
+
+    use super::*;
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+    #[skey(params = Params, gears)]
+    #[pkey(gears)]
+    pub(crate) enum SubspaceKey {
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "baseapp")]
+        Params,
+    }
+
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    pub(crate) struct TestGenesis;
+
+    impl Genesis for TestGenesis {
+        fn add_genesis_account(
+            &mut self,
+            _address: AccAddress,
+            _coins: UnsignedCoins,
+        ) -> Result<(), GenesisError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub(crate) struct TestApplication;
+
+    impl ApplicationInfo for TestApplication {}
+
+    /// Emits a single event in both `BeginBlock` and `EndBlock`
+    #[derive(Debug, Clone)]
+    pub(crate) struct EventsHandler;
+
+    pub(crate) fn block_event(kind: &str, height: u32) -> Event {
+        Event::new(
+            kind,
+            [EventAttribute {
+                key: "height".into(),
+                value: height.to_string().into(),
+                index: false,
+            }],
+        )
+    }
+
+    impl ABCIHandler for EventsHandler {
+        type Message = NullTxMsg;
+        type Genesis = TestGenesis;
+        type StoreKey = SubspaceKey;
+        type QReq = NullQueryRequest;
+        type QRes = NullQueryResponse;
+
+        fn typed_query<DB: Database>(
+            &self,
+            _ctx: &QueryContext<DB, Self::StoreKey>,
+            query: Self::QReq,
+        ) -> Self::QRes {
+            match query {}
+        }
+
+        fn run_ante_checks<DB: Database>(
+            &self,
+            _ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+            _tx: &TxWithRaw<Self::Message>,
+            _is_check: bool,
+        ) -> Result<(), TxError> {
+            Ok(())
+        }
+
+        fn msg<DB: Database>(
+            &self,
+            _ctx: &mut TxContext<'_, DB, Self::StoreKey>,
+            msg: &Self::Message,
+        ) -> Result<(), TxError> {
+            match *msg {}
+        }
+
+        fn begin_block<'a, DB: Database>(
+            &self,
+            ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+            _request: RequestBeginBlock,
+        ) {
+            let height = ctx.height();
+            ctx.push_event(block_event("begin", height));
+        }
+
+        fn end_block<'a, DB: Database>(
+            &self,
+            ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+            request: RequestEndBlock,
+        ) -> Vec<ValidatorUpdate> {
+            ctx.push_event(block_event("end", request.height as u32));
+
+            Vec::new()
+        }
+
+        fn init_genesis<DB: Database>(
+            &self,
+            _ctx: &mut InitContext<'_, DB, Self::StoreKey>,
+            _genesis: Self::Genesis,
+        ) -> Vec<ValidatorUpdate> {
+            Vec::new()
+        }
+
+        fn query<DB: Database + Send + Sync>(
+            &self,
+            _ctx: &QueryContext<DB, Self::StoreKey>,
+            _query: RequestQuery,
+        ) -> Result<Vec<u8>, QueryError> {
+            Err(QueryError::PathNotFound)
+        }
+    }
+
+    pub(crate) fn query_block_events<App: ABCIApplication<TestGenesis>>(
+        app: &App,
+        height: i64,
+    ) -> Result<BlockEvents, String> {
+        let res = app.query(RequestQuery {
+            data: Bytes::new(),
+            path: BLOCK_EVENTS_QUERY_PATH.to_owned(),
+            height,
+            prove: false,
+        });
+
+        match res.code {
+            0 => Ok(BlockEvents::decode(res.value).unwrap_test()),
+            _ => Err(res.log),
+        }
+    }
+
+    pub(crate) fn init_app() -> BaseApp<MemDB, SubspaceKey, EventsHandler, TestApplication> {
+        let app = BaseApp::new(
+            MemDB::new(),
+            SubspaceKey::Params,
+            EventsHandler,
+            NodeOptions::default(),
+        );
+
+        app.init_chain(RequestInitChain {
+            time: Timestamp::UNIX_EPOCH,
+            chain_id: ChainId::default(),
+            consensus_params: ConsensusParams::default(),
+            validators: vec![],
+            app_genesis: TestGenesis,
+            initial_height: 1,
+        });
+
+        app
+    }
+
+    pub(crate) fn step<App: ABCIApplication<TestGenesis>>(app: &App, height: u32) {
+        app.begin_block(RequestBeginBlock {
+            hash: Bytes::new(),
+            header: Header {
+                height,
+                ..Default::default()
+            },
+            last_commit_info: LastCommitInfo {
+                round: 0,
+                votes: vec![],
+            },
+            byzantine_validators: vec![],
+        });
+        app.end_block(RequestEndBlock {
+            height: height.into(),
+        });
+        app.commit();
+    }
+
+    #[test]
+    fn query_block_events_of_committed_block() {
+        let app = init_app();
+
+        for height in 1..=2 {
+            step(&app, height);
+        }
+
+        let expected = |height| BlockEvents {
+            height,
+            begin_block: vec![block_event("begin", height)],
+            end_block: vec![block_event("end", height)],
+        };
+
+        assert_eq!(query_block_events(&app, 1).unwrap_test(), expected(1));
+        assert_eq!(query_block_events(&app, 0).unwrap_test(), expected(2));
+        assert_eq!(
+            query_block_events(&app, 3).expect_err("block isn't committed"),
+            format!(
+                "no events found for block at height 3, only the last {BLOCK_EVENTS_RETAIN_BLOCKS} blocks committed since the node started are kept"
+            )
+        );
+    }
+}
@@ -6,6 +6,9 @@ const INVALID_TRANSACTION_CODE: u32 = u16::MAX as u32 + 2;
 const INVALID_MESSAGE_CODE: u32 = u16::MAX as u32 + 3;
 const GAS_ERRORS_CODE: u32 = u16::MAX as u32 + 4;
 
+const QUERY_ERROR_CODE: u32 = 1;
+const INVALID_HEIGHT_CODE: u32 = 22;
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum RunTxError {
     #[error("there is no block gas left to run the transaction")]
@@ -45,13 +48,37 @@ pub enum QueryError {
     PathNotFound,
     #[error("Block height must be greater than or equal to zero")]
     InvalidHeight,
+    #[error("state at height {0} is not available, it is either pruned or not committed yet")]
+    HeightNotAvailable(u32),
     #[error(transparent)]
     Store(#[from] kv_store::error::KVStoreError),
     #[error("error decoding query: {0}")]
     Proto(String),
+    #[error("no events found for block at height {0}, only the last {retain} blocks committed since the node started are kept", retain = super::block_events::BLOCK_EVENTS_RETAIN_BLOCKS)]
+    BlockEventsNotFound(u32),
+    #[error("{0}")]
+    NotFound(String),
     #[error("TODO: {0}")]
     TODO(#[from] anyhow::Error),
 }
+
+impl QueryError {
+    pub fn code(&self) -> u32 {
+        match self {
+            QueryError::InvalidHeight | QueryError::HeightNotAvailable(_) => INVALID_HEIGHT_CODE,
+            _ => QUERY_ERROR_CODE,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportGenesisError {
+    #[error("application doesn't support exporting its genesis state")]
+    Unsupported,
+    #[error(transparent)]
+    Query(#[from] QueryError),
+}
+
 impl From<prost::DecodeError> for QueryError {
     fn from(value: prost::DecodeError) -> Self {
         Self::Proto(value.to_string())
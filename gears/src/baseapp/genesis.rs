@@ -5,6 +5,13 @@ use serde::{de::DeserializeOwned, Serialize};
 #[error("cannot add account at existing address {0}")]
 pub struct GenesisError(pub AccAddress);
 
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("invalid {module} genesis: {reason}")]
+pub struct GenesisValidationError {
+    pub module: &'static str,
+    pub reason: String,
+}
+
 pub trait Genesis:
     std::fmt::Debug + Default + DeserializeOwned + Serialize + Clone + Send + Sync + 'static
 {
@@ -13,4 +20,16 @@ pub trait Genesis:
         address: AccAddress,
         coins: UnsignedCoins,
     ) -> Result<(), GenesisError>;
+
+    /// Checks that the genesis state is valid. Modules should override this to validate their
+    /// params and state, the default implementation accepts any state.
+    fn validate(&self) -> Result<(), GenesisValidationError> {
+        Ok(())
+    }
+
+    /// Validates the genesis state module by module so that every failure can be reported. The
+    /// default implementation reports the result of [`Genesis::validate`] as a single entry.
+    fn validate_modules(&self) -> Vec<(&'static str, Result<(), GenesisValidationError>)> {
+        vec![("app", self.validate())]
+    }
 }
@@ -2,27 +2,33 @@ pub mod options;
 use std::{
     fmt::Debug,
     marker::PhantomData,
-    sync::{Arc, RwLock},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, RwLock, RwLockWriteGuard,
+    },
 };
 
 use crate::{
     application::{handlers::node::ABCIHandler, ApplicationInfo},
-    context::{query::QueryContext, simple::SimpleContext, tx::TxContext},
+    context::{query::QueryContext, simple::SimpleContext, tx::TxContext, QueryableContext},
     error::POISONED_LOCK,
     params::ParamsSubspaceKey,
     types::{
-        gas::{descriptor::BLOCK_GAS_DESCRIPTOR, kind::BlockKind, FiniteGas, Gas, GasMeter},
+        gas::{
+            descriptor::BLOCK_GAS_DESCRIPTOR, infinite_meter::InfiniteGasMeter, kind::BlockKind,
+            FiniteGas, Gas, GasMeter,
+        },
         tx::raw::TxWithRaw,
     },
 };
 use bytes::Bytes;
 use database::Database;
-use errors::QueryError;
+use errors::{ExportGenesisError, QueryError};
 use kv_store::{
     bank::multi::{ApplicationMultiBank, TransactionMultiBank},
+    error::KVStoreError,
     query::QueryMultiStore,
 };
-use mode::build_tx_gas_meter;
 use tendermint::types::{
     chain_id::ChainId,
     proto::{event::Event, header::Header},
@@ -30,10 +36,15 @@ use tendermint::types::{
 };
 
 use self::{
-    errors::RunTxError, mode::ExecutionMode, options::NodeOptions, state::ApplicationState,
+    block_events::{BlockEventsCache, BLOCK_EVENTS_QUERY_PATH},
+    errors::RunTxError,
+    mode::{simulate::SimulateTxMode, ExecutionMode},
+    options::NodeOptions,
+    state::ApplicationState,
 };
 
 mod abci;
+pub mod block_events;
 pub mod errors;
 pub mod genesis;
 pub mod mode;
@@ -52,8 +63,12 @@ pub struct BaseApp<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: App
     multi_store: Arc<RwLock<ApplicationMultiBank<DB, H::StoreKey>>>,
     abci_handler: H,
     block_header: Arc<RwLock<Header>>, // passed by Tendermint in call to begin_block
+    block_events: Arc<RwLock<BlockEventsCache>>,
     baseapp_params_keeper: BaseAppParamsKeeper<PSK>,
     options: NodeOptions,
+    db: Arc<DB>,
+    ready: Arc<AtomicBool>, // set once there is committed state to serve queries from
+    halted: Arc<AtomicBool>, // set by shutdown, no state is written afterwards
     _info_marker: PhantomData<AI>,
 }
 
@@ -61,7 +76,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     BaseApp<DB, PSK, H, AI>
 {
     pub fn new(db: DB, params_subspace_key: PSK, abci_handler: H, options: NodeOptions) -> Self {
-        let multi_store = ApplicationMultiBank::new(Arc::new(db));
+        let db = Arc::new(db);
+        let multi_store = ApplicationMultiBank::new(Arc::clone(&db));
         let mut multi_store = match multi_store {
             Ok(ms) => ms,
             Err(err) => panic!("Failed to init MultiStore with err: {err}"),
@@ -82,6 +98,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         Self {
             abci_handler,
             block_header: Arc::new(RwLock::new(Default::default())),
+            block_events: Arc::new(RwLock::new(Default::default())),
             baseapp_params_keeper,
             state: Arc::new(RwLock::new(ApplicationState::new(
                 Gas::from(max_gas),
@@ -89,32 +106,134 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             ))),
             multi_store: Arc::new(RwLock::new(multi_store)),
             options,
+            db,
+            // a restarted node already has committed state
+            ready: Arc::new(AtomicBool::new(height > 0)),
+            halted: Arc::new(AtomicBool::new(false)),
             _info_marker: PhantomData,
         }
     }
 
+    /// Waits for the ABCI call in progress, if any, to finish and flushes the committed state
+    /// to disk. A block which was started but not committed is replayed by Tendermint on restart.
+    ///
+    /// The ABCI server can't be stopped, so the app is marked as halted instead: block calls
+    /// still arriving from Tendermint never return rather than writing after the flush, while
+    /// queries are served until the process exits.
+    pub fn shutdown(&self) {
+        let _state = self.state.write().expect(POISONED_LOCK);
+        let _multi_store = self.multi_store.write().expect(POISONED_LOCK);
+
+        self.halted.store(true, Ordering::Release);
+
+        self.db.flush();
+    }
+
+    /// Locks the state and the multi store for a block call, always in this order so that
+    /// concurrent calls can't deadlock. Once the app is halted the locks are released and
+    /// the call blocks until the process exits.
+    #[allow(clippy::type_complexity)]
+    fn lock_block_state(
+        &self,
+    ) -> (
+        RwLockWriteGuard<'_, ApplicationState<DB, H>>,
+        RwLockWriteGuard<'_, ApplicationMultiBank<DB, H::StoreKey>>,
+    ) {
+        let state = self.state.write().expect(POISONED_LOCK);
+        let multi_store = self.multi_store.write().expect(POISONED_LOCK);
+
+        if self.halted.load(Ordering::Acquire) {
+            drop((state, multi_store));
+
+            loop {
+                std::thread::park();
+            }
+        }
+
+        (state, multi_store)
+    }
+
     fn get_block_header(&self) -> Header {
         self.block_header.read().expect(POISONED_LOCK).clone()
     }
 
+    fn set_ready(&self) {
+        self.ready.store(true, Ordering::Release);
+    }
+
     fn set_block_header(&self, header: Header) {
         let mut current_header = self.block_header.write().expect(POISONED_LOCK);
         *current_header = header;
     }
 
-    fn run_query(&self, request: &RequestQuery) -> Result<Bytes, QueryError> {
+    /// Runs the query against the committed state at the requested height, `0` means the
+    /// latest committed state. Returns the response along with the height it was read at.
+    fn run_query(&self, request: &RequestQuery) -> Result<(Bytes, u32), QueryError> {
         //TODO: request height u32
         let version: u32 = request
             .height
             .try_into()
             .map_err(|_| QueryError::InvalidHeight)?;
 
-        let store = self.multi_store.read().expect(POISONED_LOCK);
-        let ctx = QueryContext::new(QueryMultiStore::new(&*store, version)?, version)?;
+        if request.path == BLOCK_EVENTS_QUERY_PATH {
+            return self
+                .block_events
+                .read()
+                .expect(POISONED_LOCK)
+                .get(version)
+                .map(|events| (prost::Message::encode_to_vec(events).into(), version))
+                .ok_or(QueryError::BlockEventsNotFound(version));
+        }
+
+        let ctx = self.query_context(version)?;
 
         self.abci_handler
             .query(&ctx, request.clone())
-            .map(Into::into)
+            .map(|res| (res.into(), ctx.height()))
+    }
+
+    /// Creates a query context over a snapshot of the committed state at `version`,
+    /// `0` means latest committed state. The store lock is only held while the snapshot
+    /// is taken so running a query doesn't block block production.
+    fn query_context(&self, version: u32) -> Result<QueryContext<DB, H::StoreKey>, QueryError> {
+        let store = self.multi_store.read().expect(POISONED_LOCK);
+        let head_version = store.head_version();
+
+        // pin the latest version so that all stores are read at the same height
+        let version = match version {
+            0 => head_version,
+            version if version > head_version => Err(QueryError::HeightNotAvailable(version))?,
+            version => version,
+        };
+        let multi_store = QueryMultiStore::new(&*store, version).map_err(|e| match e {
+            KVStoreError::Tree(trees::Error::VersionNotFound(version)) => {
+                QueryError::HeightNotAvailable(version)
+            }
+            e => QueryError::Store(e),
+        })?;
+        drop(store);
+
+        // the header is the one of the latest committed block
+        let header = self.get_block_header();
+        Ok(QueryContext::new(
+            multi_store,
+            version,
+            header.chain_id,
+            (version == head_version).then_some(header.time),
+        )?)
+    }
+
+    /// Exports the committed state at `version` as a genesis state, `0` means latest committed
+    /// state. Returns the exported version along with the genesis state.
+    pub fn export_genesis(&self, version: u32) -> Result<(u32, H::Genesis), ExportGenesisError> {
+        let ctx = self.query_context(version)?;
+
+        let genesis = self
+            .abci_handler
+            .export_genesis(&ctx)
+            .ok_or(ExportGenesisError::Unsupported)?;
+
+        Ok((ctx.height(), genesis))
     }
 
     fn run_tx<MD: ExecutionMode<DB, H>>(
@@ -145,7 +264,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             height,
             header,
             consensus_params,
-            build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
+            MD::build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
             gas_meter,
             self.options.clone(),
         );
@@ -154,7 +273,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         MD::run_ante_checks(&mut ctx, &self.abci_handler, &tx_with_raw)?;
 
         let gas_wanted = ctx.gas_meter.borrow().limit();
-        let gas_used = ctx.gas_meter.borrow().consumed_or_limit();
+        let mut gas_used = ctx.gas_meter.borrow().consumed_or_limit();
 
         let events = MD::run_msg(
             &mut ctx,
@@ -162,6 +281,10 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             tx_with_raw.tx.get_msgs().iter(),
         )?;
 
+        if MD::GAS_INCLUDES_MSGS {
+            gas_used = ctx.gas_meter.borrow().consumed_or_limit();
+        }
+
         ctx.block_gas_meter
             .consume_gas(gas_used, BLOCK_GAS_DESCRIPTOR)?;
 
@@ -175,6 +298,36 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 }
 
+pub trait NodeSimulateHandler: Clone + Send + Sync + 'static {
+    /// Runs a raw transaction against the latest committed state without persisting any changes.
+    fn simulate_tx(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError>;
+}
+
+impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> NodeSimulateHandler
+    for BaseApp<DB, PSK, H, AI>
+{
+    fn simulate_tx(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError> {
+        // the transaction store is dropped at the end of the simulation so nothing is committed
+        let mut multi_store = self.multi_store.read().expect(POISONED_LOCK).to_tx_kind();
+        let mut block_gas_meter = GasMeter::new(Box::<InfiniteGasMeter>::default());
+
+        self.run_tx::<SimulateTxMode<_, _>>(raw, &mut multi_store, &mut block_gas_meter)
+    }
+}
+
+pub trait NodeHealthHandler: Clone + Send + Sync + 'static {
+    /// Returns `true` once the node has committed state and its store is readable.
+    fn is_ready(&self) -> bool;
+}
+
+impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> NodeHealthHandler
+    for BaseApp<DB, PSK, H, AI>
+{
+    fn is_ready(&self) -> bool {
+        self.ready.load(Ordering::Acquire) && self.multi_store.read().is_ok()
+    }
+}
+
 impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> ApplicationInfo
     for BaseApp<DB, PSK, H, AI>
 {
@@ -188,3 +341,49 @@ pub struct RunTxInfo {
     pub gas_wanted: Gas,
     pub gas_used: FiniteGas,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use extensions::testing::UnwrapTesting;
+
+    use super::block_events::tests::{init_app, step};
+    use super::*;
+
+    #[test]
+    fn shutdown_halts_block_calls() {
+        let app = init_app();
+        step(&app, 1);
+
+        app.shutdown();
+
+        // queries aren't locked out after the flush
+        assert!(app.query_context(0).is_ok());
+
+        let halted_app = app.clone();
+        let block_call = std::thread::spawn(move || step(&halted_app, 2));
+        std::thread::sleep(Duration::from_millis(100));
+
+        assert!(!block_call.is_finished());
+        assert_eq!(
+            app.multi_store.read().expect(POISONED_LOCK).head_version(),
+            1
+        );
+        assert!(app.state.try_write().is_ok());
+    }
+
+    #[test]
+    fn query_context_only_knows_time_of_latest_block() {
+        let app = init_app();
+        step(&app, 1);
+        step(&app, 2);
+
+        let header = app.get_block_header();
+        let ctx = app.query_context(0).unwrap_test();
+        assert_eq!(ctx.get_time(), Some(header.time));
+        assert_eq!(ctx.chain_id(), &header.chain_id);
+
+        assert_eq!(app.query_context(1).unwrap_test().get_time(), None);
+    }
+}
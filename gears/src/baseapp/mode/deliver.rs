@@ -1,6 +1,7 @@
 use database::Database;
 use kv_store::bank::multi::TransactionMultiBank;
 use tendermint::types::proto::event::Event;
+use tracing::info_span;
 
 use super::ExecutionMode;
 use crate::types::gas::basic_meter::BasicGasMeter;
@@ -11,7 +12,7 @@ use crate::{
     application::handlers::node::ABCIHandler,
     baseapp::errors::RunTxError,
     context::{tx::TxContext, TransactionalContext},
-    types::tx::raw::TxWithRaw,
+    types::tx::{raw::TxWithRaw, TxMessage},
 };
 
 #[derive(Debug)]
@@ -39,6 +40,8 @@ impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for DeliverTxMode<DB,
         msgs: impl Iterator<Item = &'m AH::Message>,
     ) -> Result<Vec<Event>, RunTxError> {
         for msg in msgs {
+            let _span = info_span!("msg", type_url = msg.type_url()).entered();
+
             handler
                 .msg(ctx, msg)
                 .inspect_err(|_| ctx.multi_store_mut().clear_cache())?
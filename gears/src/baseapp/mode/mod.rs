@@ -16,8 +16,17 @@ use crate::{
 
 pub mod check;
 pub mod deliver;
+pub mod simulate;
 
 pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
+    /// Whether the reported gas covers the message execution on top of the ante checks.
+    /// The gas reported by check and deliver is part of consensus so it stays the ante gas.
+    const GAS_INCLUDES_MSGS: bool = false;
+
+    fn build_tx_gas_meter(block_height: u32, fee: Option<&Fee>) -> GasMeter<TxKind> {
+        build_tx_gas_meter(block_height, fee)
+    }
+
     fn runnable(ctx: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError>;
 
     fn run_ante_checks(
@@ -36,12 +45,13 @@ pub trait ExecutionMode<DB, AH: ABCIHandler>: Sealed {
 mod sealed {
     use crate::application::handlers::node::ABCIHandler;
 
-    use super::{check::CheckTxMode, deliver::DeliverTxMode};
+    use super::{check::CheckTxMode, deliver::DeliverTxMode, simulate::SimulateTxMode};
 
     pub trait Sealed {}
 
     impl<DB, AH: ABCIHandler> Sealed for CheckTxMode<DB, AH> {}
     impl<DB, AH: ABCIHandler> Sealed for DeliverTxMode<DB, AH> {}
+    impl<DB, AH: ABCIHandler> Sealed for SimulateTxMode<DB, AH> {}
 }
 
 pub(crate) fn build_tx_gas_meter(block_height: u32, fee: Option<&Fee>) -> GasMeter<TxKind> {
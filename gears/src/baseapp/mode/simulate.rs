@@ -0,0 +1,60 @@
+use std::marker::PhantomData;
+
+use database::Database;
+use tendermint::types::proto::event::Event;
+
+use super::ExecutionMode;
+use crate::{
+    application::handlers::node::ABCIHandler,
+    baseapp::errors::RunTxError,
+    context::{tx::TxContext, TransactionalContext},
+    types::{
+        auth::fee::Fee,
+        gas::{infinite_meter::InfiniteGasMeter, kind::TxKind, GasMeter},
+        tx::raw::TxWithRaw,
+    },
+};
+
+/// Mode used to estimate the gas of a transaction. The transaction is executed
+/// in the same way as in deliver mode, but with an infinite gas meter and against
+/// a store which is dropped afterwards so the committed state is never changed.
+#[derive(Debug)]
+pub struct SimulateTxMode<DB, AH: ABCIHandler>(PhantomData<(DB, AH)>);
+
+impl<DB: Database, AH: ABCIHandler> ExecutionMode<DB, AH> for SimulateTxMode<DB, AH> {
+    // simulations estimate the gas of the whole tx
+    const GAS_INCLUDES_MSGS: bool = true;
+
+    fn build_tx_gas_meter(_block_height: u32, _fee: Option<&Fee>) -> GasMeter<TxKind> {
+        GasMeter::new(Box::<InfiniteGasMeter>::default())
+    }
+
+    fn run_msg<'m>(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        msgs: impl Iterator<Item = &'m AH::Message>,
+    ) -> Result<Vec<Event>, RunTxError> {
+        for msg in msgs {
+            handler
+                .msg(ctx, msg)
+                .inspect_err(|_| ctx.multi_store_mut().clear_cache())?
+        }
+
+        Ok(ctx.events_drain())
+    }
+
+    fn run_ante_checks(
+        ctx: &mut TxContext<'_, DB, AH::StoreKey>,
+        handler: &AH,
+        tx_with_raw: &TxWithRaw<AH::Message>,
+    ) -> Result<(), RunTxError> {
+        handler
+            .run_ante_checks(ctx, tx_with_raw, false)
+            .inspect_err(|_| ctx.multi_store_mut().clear_cache())
+            .map_err(RunTxError::from)
+    }
+
+    fn runnable(_: &mut TxContext<'_, DB, AH::StoreKey>) -> Result<(), RunTxError> {
+        Ok(())
+    }
+}
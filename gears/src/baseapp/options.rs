@@ -1,6 +1,6 @@
 use std::sync::{Arc, RwLock};
 
-use crate::{error::POISONED_LOCK, types::base::min_gas::MinGasPrices};
+use crate::{config::PruningConfig, error::POISONED_LOCK, types::base::min_gas::MinGasPrices};
 
 #[derive(Debug, Clone, Default)]
 pub struct NodeOptions(Arc<RwLock<InnerOptions>>);
@@ -8,11 +8,20 @@ pub struct NodeOptions(Arc<RwLock<InnerOptions>>);
 #[derive(Debug, Default)]
 struct InnerOptions {
     pub min_gas_prices: MinGasPrices,
+    pub pruning: PruningConfig,
 }
 
 impl NodeOptions {
     pub fn new(min_gas_prices: MinGasPrices) -> Self {
-        Self(Arc::new(RwLock::new(InnerOptions { min_gas_prices })))
+        Self(Arc::new(RwLock::new(InnerOptions {
+            min_gas_prices,
+            pruning: PruningConfig::default(),
+        })))
+    }
+
+    pub fn with_pruning(self, pruning: PruningConfig) -> Self {
+        self.0.write().expect(POISONED_LOCK).pruning = pruning;
+        self
     }
 
     pub fn min_gas_prices(&self) -> MinGasPrices {
@@ -22,4 +31,8 @@ impl NodeOptions {
             .min_gas_prices
             .to_owned()
     }
+
+    pub fn pruning(&self) -> PruningConfig {
+        self.0.read().expect(POISONED_LOCK).pruning.to_owned()
+    }
 }
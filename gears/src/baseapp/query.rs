@@ -1,11 +1,8 @@
 use database::Database;
-use kv_store::query::QueryMultiStore;
 use serde::Serialize;
 
 use crate::{
     application::{handlers::node::ABCIHandler, ApplicationInfo},
-    context::query::QueryContext,
-    error::POISONED_LOCK,
     params::ParamsSubspaceKey,
 };
 
@@ -21,6 +18,12 @@ pub trait QueryRequest: Clone + Send + Sync + 'static {
     fn height(&self) -> u32;
 }
 
+/// Query request which could be executed against a historical state
+pub trait HeightQueryRequest: QueryRequest {
+    /// Pin request to state at `height`. Height `0` means latest committed state.
+    fn with_height(self, height: u32) -> Self;
+}
+
 pub trait QueryResponse: Clone + Send + Sync + 'static + Serialize {
     fn into_bytes(self) -> Vec<u8>;
 }
@@ -34,10 +37,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 {
     fn typed_query<Q: Into<H::QReq>>(&self, request: Q) -> Result<H::QRes, QueryError> {
         let request = request.into();
-        let version = request.height();
-
-        let store = self.multi_store.read().expect(POISONED_LOCK);
-        let ctx = QueryContext::new(QueryMultiStore::new(&*store, version)?, version)?;
+        let ctx = self.query_context(request.height())?;
         Ok(self.abci_handler.typed_query(&ctx, request))
     }
 }
@@ -0,0 +1,38 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{application::ApplicationInfo, commands::node::export::ExportCommand};
+
+/// Export the application state to a genesis file
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliExportCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// Height to export the state at, defaults to the latest height
+    #[arg(long)]
+    height: Option<u32>,
+    /// File to write the genesis to, defaults to stdout
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    output: Option<PathBuf>,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliExportCommand<T>> for ExportCommand {
+    fn from(value: CliExportCommand<T>) -> Self {
+        let CliExportCommand {
+            home,
+            height,
+            output,
+            _marker,
+        } = value;
+
+        Self {
+            home,
+            height,
+            output,
+        }
+    }
+}
@@ -4,7 +4,7 @@ use clap::{ArgAction, ValueHint};
 
 use crate::{
     application::ApplicationInfo,
-    commands::node::genesis::GenesisCommand,
+    commands::node::genesis::{GenesisCommand, ValidateGenesisCommand},
     types::{address::AccAddress, base::coins::UnsignedCoins},
 };
 
@@ -39,3 +39,27 @@ impl<T: ApplicationInfo> From<CliGenesisCommand<T>> for GenesisCommand {
         }
     }
 }
+
+/// Validate the genesis file at the default location or at the location passed as an argument.
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliValidateGenesisCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    #[arg(value_hint = ValueHint::FilePath)]
+    file: Option<PathBuf>,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliValidateGenesisCommand<T>> for ValidateGenesisCommand {
+    fn from(value: CliValidateGenesisCommand<T>) -> Self {
+        let CliValidateGenesisCommand {
+            home,
+            file,
+            _marker,
+        } = value;
+
+        Self { home, file }
+    }
+}
@@ -1,7 +1,7 @@
 use std::{marker::PhantomData, path::PathBuf, str::FromStr};
 
 use clap::{ArgAction, ValueHint};
-use tendermint::types::chain_id::ChainId;
+use tendermint::{config::Address, informal::Time, types::chain_id::ChainId};
 
 use crate::{application::ApplicationInfo, commands::node::init::InitCommand};
 
@@ -14,6 +14,24 @@ pub struct CliInitCommand<T: ApplicationInfo> {
     pub moniker: String,
     #[arg(long =  "chain-id",  action = ArgAction::Set, default_value_t = ChainId::from_str( "test-chain" ).expect("unrechable: default should be valid"), help = "genesis file chain-id",)]
     pub chain_id: ChainId,
+    /// Genesis time in RFC3339 format, defaults to the current time
+    #[arg(long, action = ArgAction::Set)]
+    pub genesis_time: Option<Time>,
+    /// Height of the first block
+    #[arg(long, action = ArgAction::Set, value_parser = clap::value_parser!(i64).range(1..))]
+    pub initial_height: Option<i64>,
+    /// Address to listen for incoming p2p connections
+    #[arg(long, action = ArgAction::Set)]
+    pub p2p_laddr: Option<Address>,
+    /// Address to listen for RPC requests
+    #[arg(long, action = ArgAction::Set)]
+    pub rpc_laddr: Option<Address>,
+    /// Comma separated list of seed nodes to connect to
+    #[arg(long, value_delimiter = ',')]
+    pub seeds: Vec<Address>,
+    /// Comma separated list of nodes to keep persistent connections to
+    #[arg(long, value_delimiter = ',')]
+    pub persistent_peers: Vec<Address>,
 
     #[arg(skip)]
     _marker: PhantomData<T>,
@@ -25,6 +43,12 @@ impl<T: ApplicationInfo> From<CliInitCommand<T>> for InitCommand {
             home,
             moniker,
             chain_id,
+            genesis_time,
+            initial_height,
+            p2p_laddr,
+            rpc_laddr,
+            seeds,
+            persistent_peers,
             _marker,
         } = value;
 
@@ -32,6 +56,12 @@ impl<T: ApplicationInfo> From<CliInitCommand<T>> for InitCommand {
             home,
             moniker,
             chain_id,
+            genesis_time,
+            initial_height,
+            p2p_laddr,
+            rpc_laddr,
+            seeds,
+            persistent_peers,
         }
     }
 }
@@ -21,6 +21,12 @@ pub struct CliAddKeyCommand<T: ApplicationInfo> {
     /// select keyring's backend
     #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = KeyringBackend::File )]
     keyring_backend: KeyringBackend,
+    /// account number for HD derivation
+    #[arg(long, action = ArgAction::Set, default_value_t = 0)]
+    account: u32,
+    /// address index number for HD derivation
+    #[arg(long, action = ArgAction::Set, default_value_t = 0)]
+    index: u32,
 
     #[arg(skip)]
     _marker: PhantomData<T>,
@@ -33,6 +39,8 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             recover,
             home,
             keyring_backend,
+            account,
+            index,
             _marker,
         } = value;
 
@@ -42,6 +50,8 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             home,
             keyring_backend,
             bip39_mnemonic: None,
+            account,
+            index,
         }
     }
 }
@@ -0,0 +1,48 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    commands::client::keys::{ImportKeyCommand, KeyringBackend},
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(
+    about = "Import an ASCII-armored, passphrase encrypted private key saving it to <NAME> file"
+)]
+pub struct CliImportKeyCommand<T: ApplicationInfo> {
+    #[arg(required = true)]
+    name: String,
+    /// file with the armored key, read from stdin if not set
+    #[arg(action = ArgAction::Set, value_hint = ValueHint::FilePath)]
+    file: Option<PathBuf>,
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = KeyringBackend::File )]
+    keyring_backend: KeyringBackend,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliImportKeyCommand<T>> for ImportKeyCommand {
+    fn from(value: CliImportKeyCommand<T>) -> Self {
+        let CliImportKeyCommand {
+            name,
+            file,
+            home,
+            keyring_backend,
+            _marker,
+        } = value;
+
+        Self {
+            name,
+            home,
+            keyring_backend,
+            file,
+            passphrase: None,
+        }
+    }
+}
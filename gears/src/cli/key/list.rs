@@ -0,0 +1,44 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    commands::client::{
+        keys::{KeyringBackend, ListKeysCommand},
+        query::OutputFormat,
+    },
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(about = "List all keys with their addresses and public keys")]
+pub struct CliListKeysCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = KeyringBackend::File )]
+    keyring_backend: KeyringBackend,
+    /// Output format of the keys
+    #[arg(long, value_enum, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliListKeysCommand<T>> for ListKeysCommand {
+    fn from(value: CliListKeysCommand<T>) -> Self {
+        let CliListKeysCommand {
+            home,
+            keyring_backend,
+            output,
+            _marker,
+        } = value;
+
+        Self {
+            home,
+            keyring_backend,
+            output,
+        }
+    }
+}
@@ -1,19 +1,31 @@
 use crate::{application::ApplicationInfo, commands::client::keys::KeyCommand};
 
-use self::add::CliAddKeyCommand;
+use self::{
+    add::CliAddKeyCommand, export::CliExportKeyCommand, import::CliImportKeyCommand,
+    list::CliListKeysCommand,
+};
 
 pub mod add;
+pub mod export;
+pub mod import;
+pub mod list;
 
 #[derive(Debug, Clone, ::clap::Subcommand)]
 #[command(about = "Manage your application's keys")]
 pub enum CliKeyCommand<T: ApplicationInfo> {
     Add(CliAddKeyCommand<T>),
+    Export(CliExportKeyCommand<T>),
+    Import(CliImportKeyCommand<T>),
+    List(CliListKeysCommand<T>),
 }
 
 impl<T: ApplicationInfo> From<CliKeyCommand<T>> for KeyCommand {
     fn from(value: CliKeyCommand<T>) -> Self {
         match value {
             CliKeyCommand::Add(cmd) => KeyCommand::Add(cmd.into()),
+            CliKeyCommand::Export(cmd) => KeyCommand::Export(cmd.into()),
+            CliKeyCommand::Import(cmd) => KeyCommand::Import(cmd.into()),
+            CliKeyCommand::List(cmd) => KeyCommand::List(cmd.into()),
         }
     }
 }
@@ -6,20 +6,24 @@ use clap_complete::{generate, Generator};
 
 use crate::{
     application::ApplicationInfo,
-    commands::{client::ClientCommands, node::AppCommands, ApplicationCommands},
+    commands::{client::ClientCommands, node::AppCommands, version::version, ApplicationCommands},
 };
 
 use self::{
-    genesis::CliGenesisCommand,
+    export::CliExportCommand,
+    genesis::{CliGenesisCommand, CliValidateGenesisCommand},
     init::CliInitCommand,
     key::CliKeyCommand,
     query::CliQueryCommand,
     query_txs::{CliQueryTxCommand, CliQueryTxsCommand},
     run::CliRunCommand,
+    store::CliStoreCommand,
     tx::CliTxCommand,
+    version::CliVersionCommand,
 };
 
 pub mod aux;
+pub mod export;
 pub mod genesis;
 pub mod init;
 pub mod key;
@@ -27,7 +31,9 @@ pub mod pagination;
 pub mod query;
 pub mod query_txs;
 pub mod run;
+pub mod store;
 pub mod tx;
+pub mod version;
 
 fn write_completions<G: Generator>(gen: G, cmd: &mut Command, buf: &mut dyn Write) {
     generate(gen, cmd, cmd.get_name().to_string(), buf);
@@ -75,6 +81,11 @@ where
                 let mut cmd = <Self as CommandFactory>::command();
                 write_completions(command.shell, &mut cmd, &mut std::io::stdout());
 
+                Ok(())
+            }
+            CliCommands::Version(command) => {
+                println!("{}", version::<T>(command.into())?);
+
                 Ok(())
             }
         }
@@ -125,6 +136,7 @@ where
     #[command(flatten, value_parser = value_parser!(PhantomData))]
     Cli(CliApplicationCommands<T, CliClientAUX, CliAppAUX, CliTX, CliQue>),
     Completions(CliCompletionArgs),
+    Version(CliVersionCommand),
 }
 
 /// If provided, outputs the completion file for given shell
@@ -199,6 +211,11 @@ pub enum CliAppCommands<T: ApplicationInfo, CliAUX: Subcommand> {
     Run(CliRunCommand<T>),
     #[command(name = "add-genesis-account")]
     GenesisAdd(CliGenesisCommand<T>),
+    #[command(name = "validate-genesis")]
+    ValidateGenesis(CliValidateGenesisCommand<T>),
+    #[command(subcommand)]
+    Store(CliStoreCommand<T>),
+    Export(CliExportCommand<T>),
     #[command(flatten)]
     Aux(CliAUX),
 }
@@ -216,6 +233,9 @@ where
             CliAppCommands::Init(cmd) => Self::Init(cmd.into()),
             CliAppCommands::Run(cmd) => Self::Run(cmd.into()),
             CliAppCommands::GenesisAdd(cmd) => Self::GenesisAdd(cmd.into()),
+            CliAppCommands::ValidateGenesis(cmd) => Self::ValidateGenesis(cmd.into()),
+            CliAppCommands::Store(cmd) => Self::Store(cmd.into()),
+            CliAppCommands::Export(cmd) => Self::Export(cmd.into()),
             CliAppCommands::Aux(cmd) => Self::Aux(cmd.try_into()?),
         };
 
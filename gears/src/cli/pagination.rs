@@ -33,20 +33,32 @@ pub struct CliPaginationRequest {
     /// If left empty it will default to a value to be set by each app.
     #[arg(short, long, default_value_t = QUERY_DEFAULT_LIMIT, action = ArgAction::Set, help_heading = "Pagination")]
     pub limit: u8,
+    /// count_total is set to true to indicate that the result set should include
+    /// a count of the total number of items available for pagination.
+    #[arg(long, default_value_t = false, help_heading = "Pagination")]
+    pub count_total: bool,
 }
 
 impl From<PaginationRequest> for CliPaginationRequest {
-    fn from(PaginationRequest { kind, limit }: PaginationRequest) -> Self {
+    fn from(
+        PaginationRequest {
+            kind,
+            limit,
+            count_total,
+        }: PaginationRequest,
+    ) -> Self {
         match kind {
             PaginationKind::Key { key } => Self {
                 limit,
                 key: Some(CliVec1(key)),
                 offset: None,
+                count_total,
             },
             PaginationKind::Offset { offset } => Self {
                 limit,
                 key: None,
                 offset: Some(offset),
+                count_total,
             },
         }
     }
@@ -56,20 +68,28 @@ impl TryFrom<CliPaginationRequest> for PaginationRequest {
     type Error = CoreError;
 
     fn try_from(
-        CliPaginationRequest { key, offset, limit }: CliPaginationRequest,
+        CliPaginationRequest {
+            key,
+            offset,
+            limit,
+            count_total,
+        }: CliPaginationRequest,
     ) -> Result<Self, Self::Error> {
         match (key, offset) {
             (None, None) => Ok(Self {
                 kind: PaginationKind::Offset { offset: 0 },
                 limit,
+                count_total,
             }),
             (None, Some(offset)) => Ok(Self {
                 kind: PaginationKind::Offset { offset },
                 limit,
+                count_total,
             }),
             (Some(key), None) => Ok(Self {
                 kind: PaginationKind::Key { key: key.0 },
                 limit,
+                count_total,
             }),
             (Some(_), Some(_)) => Err(CoreError::DecodeGeneral(
                 "`offset` and `key` exclusive to each other".to_owned(),
@@ -1,4 +1,7 @@
-use crate::{commands::client::query::QueryCommand, config::DEFAULT_TENDERMINT_RPC_ADDRESS};
+use crate::{
+    commands::client::query::{OutputFormat, QueryCommand},
+    config::DEFAULT_TENDERMINT_RPC_ADDRESS,
+};
 use clap::{ArgAction, Subcommand, ValueHint};
 use tendermint::types::proto::block::Height;
 
@@ -11,6 +14,9 @@ pub struct CliQueryCommand<C: Subcommand> {
     /// TODO
     #[arg(long, global = true)]
     pub height: Option<Height>,
+    /// Output format of the query result
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::default())]
+    pub output: OutputFormat,
 
     #[command(subcommand)]
     pub command: C,
@@ -27,12 +33,14 @@ where
         let CliQueryCommand {
             node,
             height,
+            output,
             command,
         } = value;
 
         Ok(QueryCommand {
             node,
             height,
+            output,
             inner: command.try_into()?,
         })
     }
@@ -1,4 +1,7 @@
-use crate::{commands::client::query::QueryCommand, config::DEFAULT_TENDERMINT_RPC_ADDRESS};
+use crate::{
+    commands::client::query::{OutputFormat, QueryCommand},
+    config::DEFAULT_TENDERMINT_RPC_ADDRESS,
+};
 use clap::{ArgAction, Args, ValueHint};
 use tendermint::types::proto::block::Height;
 
@@ -39,6 +42,9 @@ pub struct CliQueryTxCommand {
     /// TODO
     #[arg(long, global = true)]
     pub height: Option<Height>,
+    /// Output format of the query result
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::default())]
+    pub output: OutputFormat,
 
     #[command(flatten)]
     pub command: TxQueryCli,
@@ -49,12 +55,14 @@ impl From<CliQueryTxCommand> for QueryCommand<TxQueryCli> {
         let CliQueryTxCommand {
             node,
             height,
+            output,
             command,
         } = value;
 
         QueryCommand {
             node,
             height,
+            output,
             inner: command,
         }
     }
@@ -69,6 +77,9 @@ pub struct CliQueryTxsCommand {
     /// TODO
     #[arg(long, global = true)]
     pub height: Option<Height>,
+    /// Output format of the query result
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::default())]
+    pub output: OutputFormat,
 
     #[command(flatten)]
     pub command: TxsQueryCli,
@@ -79,12 +90,14 @@ impl From<CliQueryTxsCommand> for QueryCommand<TxsQueryCli> {
         let CliQueryTxsCommand {
             node,
             height,
+            output,
             command,
         } = value;
 
         QueryCommand {
             node,
             height,
+            output,
             inner: command,
         }
     }
@@ -4,9 +4,9 @@ use clap::{ArgAction, ValueHint};
 
 use crate::{
     application::ApplicationInfo,
-    commands::node::run::{LogLevel, RunCommand},
+    commands::node::run::{LogFormat, LogLevel, RunCommand},
     config::{
-        DEFAULT_ADDRESS, DEFAULT_GRPC_LISTEN_ADDR, DEFAULT_REST_LISTEN_ADDR,
+        PruningConfig, DEFAULT_ADDRESS, DEFAULT_GRPC_LISTEN_ADDR, DEFAULT_REST_LISTEN_ADDR,
         DEFAULT_TENDERMINT_RPC_ADDRESS,
     },
     types::base::min_gas::MinGasPrices,
@@ -30,14 +30,38 @@ pub struct CliRunCommand<T: ApplicationInfo> {
     /// The logging level
     #[arg(long, action = ArgAction::Set, default_value_t = LogLevel::Info)]
     pub log_level: LogLevel,
+    /// The logging output format
+    #[arg(long, global = true, action = ArgAction::Set, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
     /// Minimum gas prices to accept for transactions; Any fee in a tx must meet this minimum (e.g. 0.01photino,0.0001stake)
     #[arg(long, action = ArgAction::Set)]
     pub min_gas_prices: Option<MinGasPrices>,
+    /// Pruning strategy, overrides the strategy in the config
+    #[arg(long, action = ArgAction::Set)]
+    pub pruning: Option<PruningStrategy>,
+    /// Number of recent versions to keep, required by the `custom` pruning strategy
+    #[arg(long, action = ArgAction::Set, requires = "pruning", required_if_eq("pruning", "custom"))]
+    pub pruning_keep_recent: Option<u32>,
+    /// Height interval at which versions are pruned, required by the `custom` pruning strategy
+    #[arg(long, action = ArgAction::Set, requires = "pruning", required_if_eq("pruning", "custom"), value_parser = clap::value_parser!(u32).range(1..))]
+    pub pruning_interval: Option<u32>,
 
     #[arg(skip)]
     pub _marker: PhantomData<T>,
 }
 
+#[derive(Debug, Clone, ::clap::ValueEnum)]
+pub enum PruningStrategy {
+    /// Keep all versions
+    Nothing,
+    /// Keep only the latest version
+    Everything,
+    /// Default strategy of the config, which keeps all versions
+    Default,
+    /// Keep `--pruning-keep-recent` versions, pruning every `--pruning-interval` blocks
+    Custom,
+}
+
 impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
     fn from(
         CliRunCommand {
@@ -47,11 +71,27 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             read_buf_size,
             _marker,
             log_level,
+            log_format,
             min_gas_prices,
             grpc_listen_addr,
             rpc_addr,
+            pruning,
+            pruning_keep_recent,
+            pruning_interval,
         }: CliRunCommand<T>,
     ) -> Self {
+        let pruning = pruning.map(|strategy| match strategy {
+            PruningStrategy::Nothing => PruningConfig::Nothing,
+            PruningStrategy::Everything => PruningConfig::Everything,
+            PruningStrategy::Default => PruningConfig::default(),
+            PruningStrategy::Custom => PruningConfig::Custom {
+                keep_recent: pruning_keep_recent
+                    .expect("unreachable: clap requires keep recent for custom pruning"),
+                interval: pruning_interval
+                    .expect("unreachable: clap requires interval for custom pruning"),
+            },
+        });
+
         Self {
             home,
             address,
@@ -59,8 +99,105 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             grpc_listen_addr,
             read_buf_size,
             log_level,
+            log_format,
             min_gas_prices,
             tendermint_rpc_addr: rpc_addr,
+            pruning,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct TestApplication;
+
+    impl ApplicationInfo for TestApplication {}
+
+    #[derive(Debug, Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        run: CliRunCommand<TestApplication>,
+    }
+
+    #[test]
+    fn custom_pruning_requires_numeric_args() {
+        let err = TestCli::try_parse_from(["test", "--pruning", "custom"])
+            .expect_err("custom pruning without numeric args is invalid");
+
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+        let err = err.to_string();
+        assert!(err.contains("--pruning-keep-recent"));
+        assert!(err.contains("--pruning-interval"));
+    }
+
+    #[test]
+    fn custom_pruning_args_populate_config() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "--pruning",
+            "custom",
+            "--pruning-keep-recent",
+            "100",
+            "--pruning-interval",
+            "5",
+        ])
+        .unwrap_test();
+
+        let cmd = RunCommand::from(cli.run);
+
+        assert_eq!(
+            cmd.pruning,
+            Some(PruningConfig::Custom {
+                keep_recent: 100,
+                interval: 5
+            })
+        );
+    }
+
+    #[test]
+    fn pruning_defaults_to_config() {
+        let cli = TestCli::try_parse_from(["test"]).unwrap_test();
+
+        assert_eq!(RunCommand::from(cli.run).pruning, None);
+    }
+
+    #[test]
+    fn default_pruning_matches_config_default() {
+        let cli = TestCli::try_parse_from(["test", "--pruning", "default"]).unwrap_test();
+
+        assert_eq!(
+            RunCommand::from(cli.run).pruning,
+            Some(PruningConfig::default())
+        );
+    }
+
+    #[test]
+    fn pruning_args_require_strategy() {
+        let err = TestCli::try_parse_from(["test", "--pruning-keep-recent", "100"])
+            .expect_err("keep recent without a strategy is invalid");
+
+        assert_eq!(err.kind(), clap::error::ErrorKind::MissingRequiredArgument);
+    }
+
+    #[test]
+    fn zero_pruning_interval_is_invalid() {
+        let err = TestCli::try_parse_from([
+            "test",
+            "--pruning",
+            "custom",
+            "--pruning-keep-recent",
+            "100",
+            "--pruning-interval",
+            "0",
+        ])
+        .expect_err("zero interval is invalid");
+
+        assert_eq!(err.kind(), clap::error::ErrorKind::ValueValidation);
+    }
+}
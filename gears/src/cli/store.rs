@@ -0,0 +1,68 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    commands::node::store::{StoreCommand, StoreMigrateCommand, StoreVersionsCommand},
+};
+
+/// Inspect the stores of the application database
+#[derive(Debug, Clone, ::clap::Subcommand)]
+pub enum CliStoreCommand<T: ApplicationInfo> {
+    Versions(CliStoreVersionsCommand<T>),
+    Migrate(CliStoreMigrateCommand<T>),
+}
+
+impl<T: ApplicationInfo> From<CliStoreCommand<T>> for StoreCommand {
+    fn from(value: CliStoreCommand<T>) -> Self {
+        match value {
+            CliStoreCommand::Versions(cmd) => Self::Versions(cmd.into()),
+            CliStoreCommand::Migrate(cmd) => Self::Migrate(cmd.into()),
+        }
+    }
+}
+
+/// List all saved versions of a store and their root hashes
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliStoreVersionsCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// Name of the store
+    #[arg(required = true)]
+    store: String,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliStoreVersionsCommand<T>> for StoreVersionsCommand {
+    fn from(value: CliStoreVersionsCommand<T>) -> Self {
+        let CliStoreVersionsCommand {
+            home,
+            store,
+            _marker,
+        } = value;
+
+        Self { home, store }
+    }
+}
+
+/// Move every store into storage of its own, for RocksDB a column family per store. Stop the
+/// node before running it
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliStoreMigrateCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliStoreMigrateCommand<T>> for StoreMigrateCommand {
+    fn from(value: CliStoreMigrateCommand<T>) -> Self {
+        let CliStoreMigrateCommand { home, _marker } = value;
+
+        Self { home }
+    }
+}
@@ -9,9 +9,13 @@ use crate::{
     application::ApplicationInfo,
     commands::client::{
         keys::KeyringBackend,
-        tx::{AccountProvider, ClientTxContext, Keyring as TxKeyring, LocalInfo, TxCommand},
+        tx::{
+            AccountProvider, BroadcastMode, ClientTxContext, GenerateOnly, Keyring as TxKeyring,
+            LocalInfo, TxCommand, TxSignMode,
+        },
     },
     config::DEFAULT_TENDERMINT_RPC_ADDRESS,
+    crypto::public::PublicKey,
     types::{
         auth::{fee::Fee, gas::Gas},
         base::coins::UnsignedCoins,
@@ -41,17 +45,41 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     pub local: Option<Local>,
 
     #[command(flatten)]
-    #[group(id = "Broadcast mode", global = true)]
+    #[group(id = "Account", global = true)]
     pub mode: Mode,
 
+    /// Transaction broadcasting mode
+    #[arg(long, global = true, value_enum, default_value_t = BroadcastMode::default())]
+    pub broadcast_mode: BroadcastMode,
+
+    /// Sign mode used to sign the transaction, ledger devices always use the textual sign mode
+    #[arg(long, global = true, value_enum, default_value_t = TxSignMode::default())]
+    pub sign_mode: TxSignMode,
+
     /// Note to add a description to the transaction
-    #[arg(long, global = true, action = ArgAction::Set, required = false )]
+    #[arg(long, visible_alias = "memo", global = true, action = ArgAction::Set, required = false )]
     pub note: Option<String>,
 
+    /// Maximum memo length in bytes, defaults to the `max_memo_characters` auth param of the chain
+    #[arg(long, global = true, action = ArgAction::Set)]
+    pub max_memo_bytes: Option<u64>,
+
     /// Set a block timeout height to prevent the tx from being committed past a certain height
     #[arg(long, global = true, action = ArgAction::Set, required = false )]
     pub timeout_height: Option<u32>,
 
+    /// Build an unsigned transaction without accessing the keyring
+    #[arg(long, global = true, default_value_t = false, requires = "pubkey")]
+    pub generate_only: bool,
+
+    /// Public key of the signer in JSON format (generate only mode)
+    #[arg(long, global = true, action = ArgAction::Set, value_parser = parse_public_key)]
+    pub pubkey: Option<PublicKey>,
+
+    /// File to write the generated transaction to instead of stdout (generate only mode)
+    #[arg(long, global = true, action = ArgAction::Set, value_hint = ValueHint::FilePath, requires = "generate_only")]
+    pub output_document: Option<PathBuf>,
+
     #[command(flatten)]
     pub command: C,
 
@@ -59,6 +87,10 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     _marker: PhantomData<T>,
 }
 
+fn parse_public_key(value: &str) -> Result<PublicKey, serde_json::Error> {
+    serde_json::from_str(value)
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct FeeCli {
     // TODO: Cosmos has "auto" feature to calculate gas price if needed
@@ -111,13 +143,13 @@ pub struct Mode {
     /// As a result, the account and sequence number queries will not be performed and
     /// it is required to set such parameters manually. Note, invalid values will cause
     /// the transaction to fail.
-    #[arg(long, default_value_t = false, help_heading = "Broadcast mode")]
+    #[arg(long, default_value_t = false, help_heading = "Account")]
     pub offline: bool,
-    /// The sequence number of the signing account (offline mode only)
-    #[arg(long, required = false, help_heading = "Broadcast mode")]
+    /// The sequence number of the signing account, overrides the value fetched from the node
+    #[arg(long, required = false, help_heading = "Account")]
     pub sequence: Option<u64>,
-    /// The account number of the signing account (offline mode only)
-    #[arg(long, required = false, help_heading = "Broadcast mode")]
+    /// The account number of the signing account, overrides the value fetched from the node
+    #[arg(long, required = false, help_heading = "Account")]
     pub account_number: Option<u64>,
 }
 
@@ -171,13 +203,25 @@ where
             keyring,
             local,
             mode,
+            broadcast_mode,
+            sign_mode,
             note,
+            max_memo_bytes,
             timeout_height,
+            generate_only,
+            pubkey,
+            output_document,
             fee,
             command,
         } = value;
 
         let keyring = match keyring {
+            _ if generate_only => TxKeyring::GenerateOnly(GenerateOnly {
+                pubkey: pubkey.ok_or(MissingCliOptions(
+                    "generate only options: pubkey".to_owned(),
+                ))?,
+                output_document,
+            }),
             Keyring::Ledger => TxKeyring::Ledger,
             Keyring::Local => {
                 let Local {
@@ -205,21 +249,12 @@ where
             },
             Mode {
                 offline: false,
-                sequence: Some(sequence),
-                account_number,
-            } => AccountProvider::Offline {
                 sequence,
-                account_number: account_number.unwrap_or_default(),
-            },
-            Mode {
-                offline: false,
+                account_number,
+            } => AccountProvider::Online {
                 sequence,
-                account_number: Some(account_number),
-            } => AccountProvider::Offline {
-                sequence: sequence.unwrap_or_default(),
                 account_number,
             },
-            _ => AccountProvider::Online,
         };
 
         Ok(Self {
@@ -232,6 +267,9 @@ where
                 account,
                 memo: note,
                 timeout_height,
+                max_memo_bytes,
+                broadcast_mode,
+                sign_mode,
                 fee: fee.try_into()?,
             },
         })
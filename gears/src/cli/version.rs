@@ -0,0 +1,17 @@
+use crate::commands::{client::query::OutputFormat, version::VersionCommand};
+
+/// Print the application name, version and build information
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliVersionCommand {
+    /// Output format of the version information
+    #[arg(long, value_enum, default_value_t = OutputFormat::default())]
+    pub output: OutputFormat,
+}
+
+impl From<CliVersionCommand> for VersionCommand {
+    fn from(value: CliVersionCommand) -> Self {
+        let CliVersionCommand { output } = value;
+
+        Self { output }
+    }
+}
@@ -1,10 +1,18 @@
 use anyhow::Result;
 use bip32::Mnemonic;
+use serde::Serialize;
 use std::path::PathBuf;
 use strum::Display;
 use text_io::read;
 
-use crate::crypto::keys::ReadAccAddress;
+use crate::{
+    commands::client::query::OutputFormat,
+    crypto::{
+        keys::{GearsPublicKey, ReadAccAddress},
+        public::PublicKey,
+    },
+    types::address::AccAddress,
+};
 
 const KEYRING_SUB_DIR_FILE: &str = "keyring-file";
 const KEYRING_SUB_DIR_TEST: &str = "keyring-test";
@@ -38,6 +46,9 @@ impl KeyringBackend {
 #[derive(Debug, Clone)]
 pub enum KeyCommand {
     Add(AddKeyCommand),
+    Export(ExportKeyCommand),
+    Import(ImportKeyCommand),
+    List(ListKeysCommand),
 }
 
 #[derive(Debug, Clone, former::Former)]
@@ -47,6 +58,124 @@ pub struct AddKeyCommand {
     pub home: PathBuf,
     pub keyring_backend: KeyringBackend,
     pub bip39_mnemonic: Option<String>,
+    /// Account number of the `m/44'/118'/<account>'/0/<index>` derivation path
+    pub account: u32,
+    /// Address index of the `m/44'/118'/<account>'/0/<index>` derivation path
+    pub index: u32,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ExportKeyCommand {
+    pub name: String,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+    /// Passphrase to encrypt the exported key with, prompted for if not set
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ImportKeyCommand {
+    pub name: String,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+    /// File with the armored key, stdin is read if not set
+    pub file: Option<PathBuf>,
+    /// Passphrase the imported key is encrypted with, prompted for if not set
+    pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ListKeysCommand {
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
+    pub output: OutputFormat,
+}
+
+/// Key stored in the keyring
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct KeyInfo {
+    pub name: String,
+    pub address: AccAddress,
+    /// Base64 encoded public key
+    pub pubkey: String,
+}
+
+/// Returns all keys of the keyring formatted with `output` format.
+pub fn list_keys(cmd: ListKeysCommand) -> Result<String> {
+    let ListKeysCommand {
+        home,
+        keyring_backend,
+        output,
+    } = cmd;
+
+    let keyring_home = home.join(keyring_backend.get_sub_dir());
+    let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+    let keys = keyring::list_keys(backend)?
+        .into_iter()
+        .map(|(name, key_pair)| {
+            let pubkey: Vec<u8> = match key_pair.get_gears_public_key() {
+                PublicKey::Secp256k1(key) => key.into(),
+                PublicKey::Ed25519(key) => key.into(),
+            };
+
+            KeyInfo {
+                name,
+                address: key_pair.get_address(),
+                pubkey: data_encoding::BASE64.encode(&pubkey),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    output.format(&keys)
+}
+
+fn passphrase_or_prompt(passphrase: Option<String>, prompt: &str) -> Result<String> {
+    match passphrase {
+        Some(passphrase) => Ok(passphrase),
+        None => Ok(rpassword::prompt_password(prompt)?),
+    }
+}
+
+/// Returns the key stored under `name` as an ASCII-armored private key encrypted with a passphrase.
+pub fn export_key(cmd: ExportKeyCommand) -> Result<String> {
+    let ExportKeyCommand {
+        name,
+        home,
+        keyring_backend,
+        passphrase,
+    } = cmd;
+
+    let keyring_home = home.join(keyring_backend.get_sub_dir());
+    let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+    let passphrase =
+        passphrase_or_prompt(passphrase, "Enter passphrase to encrypt the exported key: ")?;
+
+    Ok(keyring::export_key(&name, passphrase, backend)?.to_string())
+}
+
+/// Decrypts an ASCII-armored private key and stores it under `name`.
+pub fn import_key(cmd: ImportKeyCommand) -> Result<keyring::key::pair::KeyPair> {
+    let ImportKeyCommand {
+        name,
+        home,
+        keyring_backend,
+        file,
+        passphrase,
+    } = cmd;
+
+    let armor = match file {
+        Some(file) => std::fs::read_to_string(file)?,
+        None => std::io::read_to_string(std::io::stdin())?,
+    };
+
+    let keyring_home = home.join(keyring_backend.get_sub_dir());
+    let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+    let passphrase = passphrase_or_prompt(passphrase, "Enter passphrase to decrypt the key: ")?;
+
+    Ok(keyring::import_key(&name, &armor, passphrase, backend)?)
 }
 
 // TODO: remove this cli code
@@ -59,8 +188,11 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 home,
                 keyring_backend,
                 bip39_mnemonic,
+                account,
+                index,
             } = cmd;
 
+            let hd_path = keyring::HdPath::new(account, index);
             let keyring_home = home.join(keyring_backend.get_sub_dir());
 
             let backend = keyring_backend.to_keyring_backend(&keyring_home);
@@ -76,10 +208,16 @@ pub fn keys(command: KeyCommand) -> Result<()> {
 
                 let mnemonic = Mnemonic::new(phrase, bip32::Language::English)?;
 
-                keyring::add_key(&name, &mnemonic, keyring::KeyType::Secp256k1, backend)?;
+                keyring::add_key(
+                    &name,
+                    &mnemonic,
+                    keyring::KeyType::Secp256k1,
+                    hd_path,
+                    backend,
+                )?;
             } else {
                 let (mnemonic, key_pair) =
-                    keyring::create_key(&name, keyring::KeyType::Secp256k1, backend)?;
+                    keyring::create_key(&name, keyring::KeyType::Secp256k1, hd_path, backend)?;
 
                 println!("Created key {}\nAddress: {}", name, key_pair.get_address());
 
@@ -87,7 +225,156 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 println!("{}", mnemonic.phrase());
             }
         }
+        KeyCommand::Export(cmd) => println!("{}", export_key(cmd)?),
+        KeyCommand::List(cmd) => println!("{}", list_keys(cmd)?),
+        KeyCommand::Import(cmd) => {
+            let name = cmd.name.clone();
+            let key_pair = import_key(cmd)?;
+
+            println!("Imported key {}\nAddress: {}", name, key_pair.get_address());
+        }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn add_key_derives_account_and_index() {
+        let home = PathBuf::from("./tmp/gears/src/commands/client/keys/add_key_derives_account");
+        let _ = std::fs::remove_dir_all(&home);
+
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        for (name, account, index) in [("alice", 0, 0), ("bob", 1, 0), ("carol", 0, 1)] {
+            keys(KeyCommand::Add(AddKeyCommand {
+                name: name.to_owned(),
+                recover: true,
+                home: home.clone(),
+                keyring_backend: KeyringBackend::Test,
+                bip39_mnemonic: Some(mnemonic.to_owned()),
+                account,
+                index,
+            }))
+            .expect("key should be added");
+        }
+
+        let keyring_home = home.join(KeyringBackend::Test.get_sub_dir());
+        let address = |name: &str| {
+            keyring::key_by_name(name, KeyringBackend::Test.to_keyring_backend(&keyring_home))
+                .unwrap_test()
+                .get_address()
+                .to_string()
+        };
+
+        assert_eq!(
+            address("alice"),
+            "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+        );
+        assert_eq!(
+            address("bob"),
+            "cosmos199e4sxk8sjlecwhafl9e4nuznvuaaxmn5y7cdv"
+        );
+        assert_eq!(
+            address("carol"),
+            "cosmos1ke8j8qq2pfjhyr9manpwt7dhlyy6gepn4fusxl"
+        );
+
+        std::fs::remove_dir_all(home).expect("tmp directory should be deleted");
+    }
+
+    #[test]
+    fn export_import_key_round_trip() {
+        let home = PathBuf::from("./tmp/gears/src/commands/client/keys/export_import_key");
+        let _ = std::fs::remove_dir_all(&home);
+
+        keys(KeyCommand::Add(AddKeyCommand {
+            name: "alice".to_owned(),
+            recover: false,
+            home: home.clone(),
+            keyring_backend: KeyringBackend::Test,
+            bip39_mnemonic: None,
+            account: 0,
+            index: 0,
+        }))
+        .expect("key should be added");
+
+        let armor = export_key(
+            ExportKeyCommand::former()
+                .name("alice".to_owned())
+                .home(home.clone())
+                .keyring_backend(KeyringBackend::Test)
+                .passphrase("passphrase".to_owned())
+                .form(),
+        )
+        .unwrap_test();
+
+        let armor_file = home.join("alice.armor");
+        std::fs::write(&armor_file, armor).unwrap_test();
+
+        let imported = import_key(
+            ImportKeyCommand::former()
+                .name("bob".to_owned())
+                .home(home.clone())
+                .keyring_backend(KeyringBackend::Test)
+                .file(armor_file)
+                .passphrase("passphrase".to_owned())
+                .form(),
+        )
+        .unwrap_test();
+
+        let keyring_home = home.join(KeyringBackend::Test.get_sub_dir());
+        let address = |name: &str| {
+            keyring::key_by_name(name, KeyringBackend::Test.to_keyring_backend(&keyring_home))
+                .unwrap_test()
+                .get_address()
+        };
+
+        assert_eq!(imported.get_address(), address("alice"));
+        assert_eq!(address("bob"), address("alice"));
+
+        std::fs::remove_dir_all(home).expect("tmp directory should be deleted");
+    }
+
+    #[test]
+    fn list_keys_json_output() {
+        let home = PathBuf::from("./tmp/gears/src/commands/client/keys/list_keys_json");
+        let _ = std::fs::remove_dir_all(&home);
+
+        for name in ["bob", "alice"] {
+            keys(KeyCommand::Add(AddKeyCommand {
+                name: name.to_owned(),
+                recover: false,
+                home: home.clone(),
+                keyring_backend: KeyringBackend::Test,
+                bip39_mnemonic: None,
+                account: 0,
+                index: 0,
+            }))
+            .expect("key should be added");
+        }
+
+        let output = list_keys(
+            ListKeysCommand::former()
+                .home(home.clone())
+                .keyring_backend(KeyringBackend::Test)
+                .output(OutputFormat::Json)
+                .form(),
+        );
+
+        std::fs::remove_dir_all(&home).expect("tmp directory should be deleted");
+
+        let keys: Vec<serde_json::Value> =
+            serde_json::from_str(&output.unwrap_test()).unwrap_test();
+        assert_eq!(keys.len(), 2);
+        for (key, name) in keys.iter().zip(["alice", "bob"]) {
+            assert_eq!(key["name"], name);
+            AccAddress::from_bech32(key["address"].as_str().unwrap_test()).unwrap_test();
+            assert!(!key["pubkey"].as_str().unwrap_test().is_empty());
+        }
+    }
+}
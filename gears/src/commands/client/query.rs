@@ -4,15 +4,86 @@ use crate::application::handlers::client::QueryHandler;
 use crate::runtime::runtime;
 use anyhow::anyhow;
 use prost::Message;
+use serde::Serialize;
+use serde_json::Value;
 use tendermint::{
     rpc::client::{Client, HttpClient},
     types::proto::block::Height,
 };
 
+/// Output format of query results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum OutputFormat {
+    #[default]
+    #[strum(to_string = "text")]
+    Text,
+    #[strum(to_string = "json")]
+    Json,
+}
+
+impl OutputFormat {
+    /// Formats a query response as pretty JSON or as indented `key: value` text.
+    pub fn format<T: Serialize>(&self, value: &T) -> anyhow::Result<String> {
+        match self {
+            OutputFormat::Json => Ok(serde_json::to_string_pretty(value)?),
+            OutputFormat::Text => {
+                let mut lines = Vec::new();
+                push_text_lines(&mut lines, &serde_json::to_value(value)?, 0);
+
+                Ok(lines.join("\n"))
+            }
+        }
+    }
+}
+
+fn push_text_lines(lines: &mut Vec<String>, value: &Value, indent: usize) {
+    let pad = "  ".repeat(indent);
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                match value {
+                    Value::Object(map) if !map.is_empty() => {
+                        lines.push(format!("{pad}{key}:"));
+                        push_text_lines(lines, value, indent + 1);
+                    }
+                    Value::Array(items) if !items.is_empty() => {
+                        lines.push(format!("{pad}{key}:"));
+                        push_text_lines(lines, value, indent);
+                    }
+                    _ => lines.push(format!("{pad}{key}: {}", text_scalar(value))),
+                }
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                let mut item_lines = Vec::new();
+                push_text_lines(&mut item_lines, item, 0);
+
+                for (i, line) in item_lines.into_iter().enumerate() {
+                    let prefix = if i == 0 { "- " } else { "  " };
+                    lines.push(format!("{pad}{prefix}{line}"));
+                }
+            }
+        }
+        _ => lines.push(format!("{pad}{}", text_scalar(value))),
+    }
+}
+
+fn text_scalar(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Object(_) => "{}".to_owned(),
+        Value::Array(_) => "[]".to_owned(),
+        _ => value.to_string(),
+    }
+}
+
 #[derive(Debug, Clone, former::Former)]
 pub struct QueryCommand<C> {
     pub node: url::Url,
     pub height: Option<Height>,
+    pub output: OutputFormat,
 
     pub inner: C,
 }
@@ -21,6 +92,7 @@ pub fn run_query<Q, QC, QR, H>(
     QueryCommand {
         node,
         height,
+        output: _,
         inner,
     }: QueryCommand<QC>,
     handler: &H,
@@ -59,3 +131,56 @@ where
 
     Response::try_from(Raw::decode(&*res.value)?).map_err(|e| anyhow!(e.to_string()))
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Coin {
+        amount: String,
+        denom: String,
+    }
+
+    #[derive(Serialize)]
+    struct Balances {
+        balances: Vec<Coin>,
+        pagination: Option<u32>,
+    }
+
+    fn balances() -> Balances {
+        Balances {
+            balances: vec![
+                Coin {
+                    amount: "10".to_owned(),
+                    denom: "uatom".to_owned(),
+                },
+                Coin {
+                    amount: "20".to_owned(),
+                    denom: "ustake".to_owned(),
+                },
+            ],
+            pagination: None,
+        }
+    }
+
+    #[test]
+    fn json_output_is_parseable() {
+        let output = OutputFormat::Json.format(&balances()).unwrap_test();
+
+        let value: Value = serde_json::from_str(&output).unwrap_test();
+        assert_eq!(value["balances"][1]["denom"], "ustake");
+    }
+
+    #[test]
+    fn text_output() {
+        let output = OutputFormat::Text.format(&balances()).unwrap_test();
+
+        assert_eq!(
+            output,
+            "balances:\n- amount: 10\n  denom: uatom\n- amount: 20\n  denom: ustake\npagination: null"
+        );
+    }
+}
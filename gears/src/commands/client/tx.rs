@@ -2,26 +2,101 @@ use std::path::PathBuf;
 
 use core_types::tx::mode_info::SignMode;
 use prost::Message;
-use tendermint::rpc::client::{Client, HttpClient};
-use tendermint::rpc::response::tx::broadcast::Response;
+use serde::Serialize;
+use tendermint::rpc::client::Client;
+use tendermint::rpc::response::tx::broadcast::{AsyncResponse, Response, SyncResponse};
 use tendermint::types::chain_id::ChainId;
+use vec1::Vec1;
 
 use crate::application::handlers::client::{NodeFetcher, TxExecutionResult, TxHandler};
 use crate::commands::client::query::execute_query;
 use crate::crypto::any_key::AnyKey;
 use crate::crypto::keys::GearsPublicKey;
 use crate::crypto::ledger::LedgerProxyKey;
+use crate::crypto::public::PublicKey;
 use crate::runtime::runtime;
+use crate::types::address::AccAddress;
 use crate::types::auth::fee::Fee;
 use crate::types::auth::gas::Gas;
+use crate::types::auth::info::AuthInfo;
+use crate::types::tx::body::TxBody;
 use crate::types::tx::raw::TxRaw;
+use crate::types::tx::Tx;
 
 use super::keys::KeyringBackend;
 
+/// Default value of the auth module `max_memo_characters` param
+pub const DEFAULT_MAX_MEMO_CHARACTERS: u64 = 256;
+
+/// Defines how long the client waits for the node to process a broadcasted transaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum BroadcastMode {
+    /// Wait for the tx to pass `CheckTx`
+    #[strum(serialize = "sync")]
+    Sync,
+    /// Return right after the tx is sent to the node
+    #[strum(serialize = "async")]
+    Async,
+    /// Wait for the tx to be committed in a block
+    #[default]
+    #[strum(serialize = "block")]
+    Block,
+}
+
+/// Defines which bytes of the transaction are signed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::EnumString, strum::Display)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum TxSignMode {
+    /// Sign the protobuf encoded sign doc
+    #[default]
+    #[strum(serialize = "direct")]
+    Direct,
+    /// Sign the CBOR encoded screens rendered for the transaction
+    #[strum(serialize = "textual")]
+    Textual,
+}
+
+impl From<TxSignMode> for SignMode {
+    fn from(mode: TxSignMode) -> Self {
+        match mode {
+            TxSignMode::Direct => SignMode::Direct,
+            TxSignMode::Textual => SignMode::Textual,
+        }
+    }
+}
+
+/// Response of the node for a broadcasted transaction, depends on `BroadcastMode`
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum BroadcastResponse {
+    Sync(SyncResponse),
+    Async(AsyncResponse),
+    Block(Response),
+}
+
+impl BroadcastResponse {
+    pub fn block(self) -> Option<Response> {
+        match self {
+            Self::Block(var) => Some(var),
+            Self::Sync(_) => None,
+            Self::Async(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum AccountProvider {
-    Offline { sequence: u64, account_number: u64 },
-    Online,
+    Offline {
+        sequence: u64,
+        account_number: u64,
+    },
+    /// Account is fetched from the node, values which are set take precedence over the fetched ones.
+    /// The node isn't queried for the account if both values are set.
+    Online {
+        sequence: Option<u64>,
+        account_number: Option<u64>,
+    },
 }
 
 #[derive(Debug, Clone, former::Former)]
@@ -39,6 +114,10 @@ pub struct ClientTxContext {
     pub account: AccountProvider,
     pub chain_id: ChainId,
     pub timeout_height: Option<u32>,
+    /// Overrides the `max_memo_characters` param of the chain's auth module
+    pub max_memo_bytes: Option<u64>,
+    pub broadcast_mode: BroadcastMode,
+    pub sign_mode: TxSignMode,
 
     pub fee: Fee,
 }
@@ -55,6 +134,43 @@ impl ClientTxContext {
         execute_query(path, query_bytes, self.node.as_str(), None)
     }
 
+    /// Maximum memo length in bytes. Unless overridden, it's the `max_memo_characters` auth param
+    /// of the chain or its default value when the node isn't accessed.
+    pub fn max_memo_bytes<F: NodeFetcher>(&self, fetcher: &F) -> anyhow::Result<u64> {
+        match (self.max_memo_bytes, &self.account) {
+            (Some(max), _) => Ok(max),
+            (None, AccountProvider::Online { .. }) => {
+                fetcher.max_memo_characters(self.node.as_str())
+            }
+            (None, AccountProvider::Offline { .. }) => Ok(DEFAULT_MAX_MEMO_CHARACTERS),
+        }
+    }
+
+    /// Builds the body of a transaction carrying the memo and timeout height of the context.
+    /// Returns an error if the memo is longer than `max_memo_bytes` as the chain would reject such tx.
+    /// The chain compares the byte length of the memo against its `max_memo_characters` param.
+    pub fn tx_body<M>(
+        &self,
+        messages: Vec1<M>,
+        max_memo_bytes: u64,
+    ) -> Result<TxBody<M>, MemoTooLongError> {
+        let memo = self.memo.clone().unwrap_or_default();
+        if memo.len() as u64 > max_memo_bytes {
+            return Err(MemoTooLongError {
+                length: memo.len(),
+                max: max_memo_bytes,
+            });
+        }
+
+        Ok(TxBody {
+            messages,
+            memo,
+            timeout_height: self.timeout_height.unwrap_or_default(),
+            extension_options: vec![], // TODO: remove hard coded
+            non_critical_extension_options: vec![], // TODO: remove hard coded
+        })
+    }
+
     pub fn new_online(
         home: PathBuf,
         gas_limit: Gas,
@@ -63,7 +179,10 @@ impl ClientTxContext {
         from_key: &str,
     ) -> Self {
         Self {
-            account: crate::commands::client::tx::AccountProvider::Online,
+            account: crate::commands::client::tx::AccountProvider::Online {
+                sequence: None,
+                account_number: None,
+            },
             home,
             keyring: Keyring::Local(LocalInfo {
                 keyring_backend: KeyringBackend::Test,
@@ -73,6 +192,9 @@ impl ClientTxContext {
             chain_id,
             memo: None,
             timeout_height: None,
+            max_memo_bytes: None,
+            broadcast_mode: BroadcastMode::default(),
+            sign_mode: TxSignMode::default(),
             fee: Fee {
                 amount: None,
                 gas_limit,
@@ -87,6 +209,16 @@ impl ClientTxContext {
 pub enum Keyring {
     Ledger,
     Local(LocalInfo),
+    /// No key is used, the tx is written unsigned for offline signing
+    GenerateOnly(GenerateOnly),
+}
+
+#[derive(Debug, Clone)]
+pub struct GenerateOnly {
+    /// Public key of the signer, used in place of the keyring key to build messages
+    pub pubkey: PublicKey,
+    /// File to write the tx to, stdout is used if not set
+    pub output_document: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -97,13 +229,13 @@ pub struct LocalInfo {
 
 #[derive(Debug, Clone)]
 pub enum RuntxResult {
-    Broadcast(Vec<Response>),
+    Broadcast(Vec<BroadcastResponse>),
     File(PathBuf),
     None,
 }
 
 impl RuntxResult {
-    pub fn broadcast(self) -> Option<Vec<Response>> {
+    pub fn broadcast(self) -> Option<Vec<BroadcastResponse>> {
         match self {
             Self::Broadcast(var) => Some(var),
             Self::File(_) => None,
@@ -130,6 +262,29 @@ impl From<TxExecutionResult> for RuntxResult {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error("memo is too long: {length} bytes, maximum is {max}")]
+pub struct MemoTooLongError {
+    pub length: usize,
+    pub max: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("--chunk-size requires --broadcast-mode block, got {0}")]
+pub struct ChunkingBroadcastModeError(pub BroadcastMode);
+
+#[derive(Debug, thiserror::Error)]
+#[error("account {0} not found on chain; use --offline with --sequence/--account-number")]
+pub struct AccountNotFoundError(pub AccAddress);
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeyError {
+    #[error("key not found: {0}")]
+    NotFound(String),
+    #[error("failed to access keyring: {0}")]
+    Keyring(#[from] keyring::error::Error),
+}
+
 fn handle_key(client_tx_context: &ClientTxContext) -> anyhow::Result<AnyKey> {
     match client_tx_context.keyring {
         Keyring::Ledger => Ok(AnyKey::Ledger(LedgerProxyKey::new()?)),
@@ -140,10 +295,67 @@ fn handle_key(client_tx_context: &ClientTxContext) -> anyhow::Result<AnyKey> {
             let key = keyring::key_by_name(
                 &local.from_key,
                 local.keyring_backend.to_keyring_backend(&keyring_home),
-            )?;
+            )
+            .map_err(|e| match e {
+                keyring::error::Error::DoesNotExist { name, .. } => KeyError::NotFound(name),
+                e => KeyError::Keyring(e),
+            })?;
 
             Ok(AnyKey::Local(key))
         }
+        Keyring::GenerateOnly(_) => {
+            Err(anyhow::anyhow!("no key is used to sign a generate only tx"))
+        }
+    }
+}
+
+/// The Cosmos ledger app can't display `SIGN_MODE_DIRECT` sign bytes so the device always signs
+/// the textual sign doc
+fn sign_mode(key: &AnyKey, requested: TxSignMode) -> SignMode {
+    match key {
+        AnyKey::Local(_) => requested.into(),
+        AnyKey::Ledger(_) => SignMode::Textual,
+    }
+}
+
+/// Writes the unsigned tx as JSON to `output_document` or stdout
+fn generate_tx<C, H: TxHandler<TxCommands = C>>(
+    GenerateOnly {
+        pubkey,
+        output_document,
+    }: GenerateOnly,
+    mut ctx: ClientTxContext,
+    inner: C,
+    handler: &H,
+) -> anyhow::Result<RuntxResult> {
+    let messages = handler.prepare_tx(&mut ctx, inner, pubkey)?;
+
+    // the node isn't accessed when generating the tx
+    let max_memo_bytes = ctx.max_memo_bytes.unwrap_or(DEFAULT_MAX_MEMO_CHARACTERS);
+
+    let tx = Tx {
+        body: ctx.tx_body(messages.into_msgs(), max_memo_bytes)?,
+        auth_info: AuthInfo {
+            signer_infos: vec![],
+            fee: ctx.fee,
+            tip: None,
+        },
+        signatures: vec![],
+        signatures_data: vec![],
+    };
+
+    let tx = serde_json::to_string_pretty(&tx)?;
+    match output_document {
+        Some(path) => {
+            std::fs::write(&path, tx)?;
+
+            Ok(RuntxResult::File(path))
+        }
+        None => {
+            println!("{tx}");
+
+            Ok(RuntxResult::None)
+        }
     }
 }
 
@@ -152,14 +364,22 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
     handler: &H,
     fetcher: &F,
 ) -> anyhow::Result<RuntxResult> {
+    if let Keyring::GenerateOnly(generate_only) = &ctx.keyring {
+        return generate_tx(generate_only.clone(), ctx, inner, handler);
+    }
+
     let key = handle_key(&mut ctx)?;
+    let mode = sign_mode(&key, ctx.sign_mode);
 
     let messages = handler.prepare_tx(&mut ctx, inner, key.get_gears_public_key())?;
 
-    if messages.chunk_size() > 0
-    // TODO: uncomment and update logic when command will be extended by broadcast_mode
-    /* && command.broadcast_mode == BroadcastMode::Block */
-    {
+    // chunks are signed with sequences of committed state so they are only sent one by one
+    // when each tx gets committed before the next one is signed
+    if messages.chunk_size() > 0 && ctx.broadcast_mode != BroadcastMode::Block {
+        Err(ChunkingBroadcastModeError(ctx.broadcast_mode))?
+    }
+
+    if messages.chunk_size() > 0 {
         let chunk_size = messages.chunk_size();
         let msgs = messages.into_msgs();
 
@@ -172,7 +392,7 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
                         .try_into()
                         .expect("chunking of the messages excludes empty vectors"),
                     &key,
-                    SignMode::Direct,
+                    mode.clone(),
                     &mut ctx,
                     fetcher,
                 )?,
@@ -188,17 +408,581 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
         // TODO: can be reduced by changing variable `step`. Do we need it?
         handler
             .handle_tx(
-                handler.sign_msg(messages, &key, SignMode::Direct, &mut ctx, fetcher)?,
+                handler.sign_msg(messages, &key, mode, &mut ctx, fetcher)?,
                 &mut ctx,
             )
             .map(Into::into)
     }
 }
 
-pub fn broadcast_tx_commit(client: HttpClient, raw_tx: TxRaw) -> anyhow::Result<Response> {
-    let res = runtime().block_on(
-        client.broadcast_tx_commit(core_types::tx::raw::TxRaw::from(raw_tx).encode_to_vec()),
-    )?;
+/// Broadcasts the tx using the RPC endpoint matching `mode`
+pub fn broadcast_tx<C: Client + Sync>(
+    client: &C,
+    raw_tx: TxRaw,
+    mode: BroadcastMode,
+) -> anyhow::Result<BroadcastResponse> {
+    let tx = core_types::tx::raw::TxRaw::from(raw_tx).encode_to_vec();
+
+    let res = match mode {
+        BroadcastMode::Sync => {
+            BroadcastResponse::Sync(runtime().block_on(client.broadcast_tx_sync(tx))?)
+        }
+        BroadcastMode::Async => {
+            BroadcastResponse::Async(runtime().block_on(client.broadcast_tx_async(tx))?)
+        }
+        BroadcastMode::Block => {
+            BroadcastResponse::Block(runtime().block_on(client.broadcast_tx_commit(tx))?)
+        }
+    };
 
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bip32::Mnemonic;
+    use extensions::testing::UnwrapTesting;
+    use tendermint::rpc::client::{Method, Request as _, Response as _, SimpleRequest};
+
+    use keyring::key::pair::KeyPair;
+
+    use core_types::tx::mode_info::ModeInfo;
+
+    use crate::application::handlers::client::MetadataViaRPC;
+    use crate::crypto::keys::ReadAccAddress;
+    use crate::crypto::ledger::mock::MockDevice;
+    use crate::signing::handler::SignModeHandler;
+    use crate::types::tx::signer::SignerData;
+    use crate::types::{
+        account::Account,
+        address::AccAddress,
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        denom::Denom,
+        msg::send::MsgSend,
+        tx::{metadata::Metadata, Messages},
+    };
+
+    use super::*;
+
+    const SYNC_RESPONSE: &str = r#"{
+        "jsonrpc": "2.0",
+        "id": "",
+        "result": {
+            "code": 0,
+            "codespace": "",
+            "data": "",
+            "log": "",
+            "hash": "13BB2C6817D0EDA960EDB0C6D6D5CB752D341BB603EF4BCE990F4EA5A99500C1"
+        }
+    }"#;
+
+    /// Client recording called RPC methods and responding with the same sync response to every request
+    #[derive(Debug, Default)]
+    struct MockClient {
+        methods: std::sync::Mutex<Vec<Method>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Client for MockClient {
+        async fn perform<R>(&self, request: R) -> Result<R::Response, tendermint::rpc::error::Error>
+        where
+            R: SimpleRequest,
+        {
+            self.methods.lock().unwrap_test().push(request.method());
+
+            R::Response::from_string(SYNC_RESPONSE)
+        }
+    }
+
+    #[test]
+    fn broadcast_mode_from_str() {
+        assert_eq!(
+            BroadcastMode::from_str("sync").unwrap_test(),
+            BroadcastMode::Sync
+        );
+        assert_eq!(
+            BroadcastMode::from_str("async").unwrap_test(),
+            BroadcastMode::Async
+        );
+        assert_eq!(
+            BroadcastMode::from_str("block").unwrap_test(),
+            BroadcastMode::Block
+        );
+        assert!(BroadcastMode::from_str("commit").is_err());
+        assert_eq!(BroadcastMode::default(), BroadcastMode::Block);
+    }
+
+    #[test]
+    fn broadcast_tx_sync_uses_sync_rpc() {
+        let client = MockClient::default();
+        let raw_tx = TxRaw {
+            body_bytes: vec![1],
+            auth_info_bytes: vec![2],
+            signatures: vec![vec![3]],
+        };
+
+        let res = broadcast_tx(&client, raw_tx, BroadcastMode::Sync).unwrap_test();
+
+        assert!(matches!(res, BroadcastResponse::Sync(_)));
+        assert_eq!(
+            *client.methods.lock().unwrap_test(),
+            vec![Method::BroadcastTxSync]
+        );
+    }
+
+    #[test]
+    fn handle_key_missing_key_not_found() {
+        let home = PathBuf::from("./tmp/gears/src/commands/client/tx/handle_key_missing_key");
+        let _ = std::fs::remove_dir_all(&home);
+
+        let keyring_home = home.join(KeyringBackend::Test.get_sub_dir());
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+        keyring::add_key(
+            "alice",
+            &mnemonic,
+            keyring::KeyType::Secp256k1,
+            keyring::HdPath::default(),
+            keyring::Backend::Test(&keyring_home),
+        )
+        .expect("key should be added");
+
+        let ctx = ClientTxContext::new_online(
+            home.clone(),
+            Gas::default(),
+            url::Url::parse("http://localhost:26657").unwrap_test(),
+            ChainId::from_str("test-chain").unwrap_test(),
+            "bob",
+        );
+
+        let err = handle_key(&ctx).expect_err("key should not be found");
+        assert_eq!(err.to_string(), "key not found: bob");
+        assert!(matches!(
+            err.downcast_ref::<KeyError>(),
+            Some(KeyError::NotFound(_))
+        ));
+
+        handle_key(&ClientTxContext::new_online(
+            home.clone(),
+            Gas::default(),
+            url::Url::parse("http://localhost:26657").unwrap_test(),
+            ChainId::from_str("test-chain").unwrap_test(),
+            "alice",
+        ))
+        .expect("key should be found");
+
+        std::fs::remove_dir_all(home).expect("tmp directory should be deleted");
+    }
+
+    fn tx_ctx() -> ClientTxContext {
+        ClientTxContext::new_online(
+            PathBuf::from("./tmp/gears/src/commands/client/tx/tx_body"),
+            Gas::default(),
+            url::Url::parse("http://localhost:26657").unwrap_test(),
+            ChainId::from_str("test-chain").unwrap_test(),
+            "alice",
+        )
+    }
+
+    fn msg_send() -> MsgSend {
+        MsgSend {
+            from_address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+                .unwrap_test(),
+            to_address: AccAddress::from_bech32("cosmos199e4sxk8sjlecwhafl9e4nuznvuaaxmn5y7cdv")
+                .unwrap_test(),
+            amount: UnsignedCoins::new(vec![UnsignedCoin::from_str("10uatom").unwrap_test()])
+                .unwrap_test(),
+        }
+    }
+
+    fn encode_decode(body: TxBody<MsgSend>) -> core_types::tx::body::TxBody {
+        let encoded = core_types::tx::body::TxBody::from(body).encode_to_vec();
+
+        core_types::tx::body::TxBody::decode(encoded.as_slice()).unwrap_test()
+    }
+
+    #[test]
+    fn tx_body_carries_timeout_height() {
+        let mut ctx = tx_ctx();
+        ctx.timeout_height = Some(100);
+
+        let body = ctx
+            .tx_body(vec1::vec1![msg_send()], DEFAULT_MAX_MEMO_CHARACTERS)
+            .unwrap_test();
+
+        assert_eq!(encode_decode(body).timeout_height, 100);
+    }
+
+    #[test]
+    fn tx_body_carries_memo() {
+        let mut ctx = tx_ctx();
+        ctx.memo = Some("for the coffee".to_owned());
+
+        let body = ctx
+            .tx_body(vec1::vec1![msg_send()], DEFAULT_MAX_MEMO_CHARACTERS)
+            .unwrap_test();
+
+        assert_eq!(encode_decode(body).memo, "for the coffee");
+    }
+
+    #[test]
+    fn tx_body_rejects_too_long_memo() {
+        let mut ctx = tx_ctx();
+        ctx.memo = Some("a".repeat(DEFAULT_MAX_MEMO_CHARACTERS as usize + 1));
+
+        let err = ctx
+            .tx_body(vec1::vec1![msg_send()], DEFAULT_MAX_MEMO_CHARACTERS)
+            .expect_err("memo exceeds the limit");
+
+        assert_eq!(err.length, 257);
+        assert_eq!(err.max, DEFAULT_MAX_MEMO_CHARACTERS);
+    }
+
+    #[test]
+    fn tx_body_counts_memo_bytes() {
+        let mut ctx = tx_ctx();
+        // 4 characters encoded in 8 bytes
+        ctx.memo = Some("кофе".to_owned());
+
+        let body = ctx.tx_body(vec1::vec1![msg_send()], 8).unwrap_test();
+        assert_eq!(encode_decode(body).memo, "кофе");
+
+        let err = ctx
+            .tx_body(vec1::vec1![msg_send()], 7)
+            .expect_err("memo exceeds the limit");
+        assert_eq!(err.length, 8);
+    }
+
+    #[test]
+    fn max_memo_bytes_uses_chain_param() {
+        let mut ctx = tx_ctx();
+        assert_eq!(
+            ctx.max_memo_bytes(&MissingAccountFetcher).unwrap_test(),
+            100
+        );
+
+        ctx.max_memo_bytes = Some(10);
+        assert_eq!(ctx.max_memo_bytes(&NoNodeFetcher).unwrap_test(), 10);
+
+        ctx.max_memo_bytes = None;
+        ctx.account = AccountProvider::Offline {
+            sequence: 0,
+            account_number: 0,
+        };
+        assert_eq!(
+            ctx.max_memo_bytes(&NoNodeFetcher).unwrap_test(),
+            DEFAULT_MAX_MEMO_CHARACTERS
+        );
+    }
+
+    #[derive(Debug, Clone)]
+    struct SendHandler;
+
+    impl TxHandler for SendHandler {
+        type Message = MsgSend;
+        type TxCommands = MsgSend;
+
+        fn prepare_tx(
+            &self,
+            _client_tx_context: &mut ClientTxContext,
+            command: Self::TxCommands,
+            _pubkey: PublicKey,
+        ) -> anyhow::Result<Messages<Self::Message>> {
+            Ok(command.into())
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct NoNodeFetcher;
+
+    impl NodeFetcher for NoNodeFetcher {
+        fn latest_account(
+            &self,
+            _address: AccAddress,
+            _node: impl AsRef<str>,
+        ) -> anyhow::Result<Option<Account>> {
+            Err(anyhow::anyhow!("node should not be queried"))
+        }
+
+        fn denom_metadata(
+            &self,
+            _base: Denom,
+            _node: impl AsRef<str>,
+        ) -> anyhow::Result<Option<Metadata>> {
+            Err(anyhow::anyhow!("node should not be queried"))
+        }
+
+        fn max_memo_characters(&self, _node: impl AsRef<str>) -> anyhow::Result<u64> {
+            Err(anyhow::anyhow!("node should not be queried"))
+        }
+    }
+
+    #[test]
+    fn run_tx_generate_only_writes_unsigned_tx() {
+        let home = PathBuf::from("./tmp/gears/src/commands/client/tx/generate_only");
+        std::fs::create_dir_all(&home).unwrap_test();
+        let output_document = home.join("tx.json");
+
+        // there is no key in the keyring
+        let mut ctx = tx_ctx();
+        ctx.keyring = Keyring::GenerateOnly(GenerateOnly {
+            pubkey: serde_json::from_str(
+                r#"{
+                    "@type": "/cosmos.crypto.secp256k1.PubKey",
+                    "key": "Auvdf+T963bciiBe9l15DNMOijdaXCUo6zqSOvH7TXlN"
+                }"#,
+            )
+            .unwrap_test(),
+            output_document: Some(output_document.clone()),
+        });
+
+        let result = run_tx(
+            TxCommand {
+                ctx,
+                inner: msg_send(),
+            },
+            &SendHandler,
+            &NoNodeFetcher,
+        );
+        let tx = std::fs::read_to_string(&output_document);
+        std::fs::remove_dir_all(&home).unwrap_test();
+
+        assert!(matches!(result.unwrap_test(), RuntxResult::File(path) if path == output_document));
+        let tx: Tx<MsgSend> = serde_json::from_str(&tx.unwrap_test()).unwrap_test();
+        assert!(tx.signatures.is_empty());
+        assert!(tx.auth_info.signer_infos.is_empty());
+        assert_eq!(tx.body.messages, vec1::vec1![msg_send()]);
+    }
+
+    #[derive(Debug, Clone)]
+    struct ChunkedSendHandler;
+
+    impl TxHandler for ChunkedSendHandler {
+        type Message = MsgSend;
+        type TxCommands = MsgSend;
+
+        fn prepare_tx(
+            &self,
+            _client_tx_context: &mut ClientTxContext,
+            command: Self::TxCommands,
+            _pubkey: PublicKey,
+        ) -> anyhow::Result<Messages<Self::Message>> {
+            Ok(Messages::new(vec![command.clone(), command], 1)?)
+        }
+    }
+
+    #[test]
+    fn run_tx_rejects_chunks_without_block_mode() {
+        let home = PathBuf::from("./tmp/gears/src/commands/client/tx/chunks_without_block_mode");
+        let _ = std::fs::remove_dir_all(&home);
+
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        keyring::add_key(
+            "alice",
+            &Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test(),
+            keyring::KeyType::Secp256k1,
+            keyring::HdPath::default(),
+            keyring::Backend::Test(&home.join(KeyringBackend::Test.get_sub_dir())),
+        )
+        .expect("key should be added");
+
+        let mut ctx = tx_ctx();
+        ctx.home = home.clone();
+        ctx.broadcast_mode = BroadcastMode::Sync;
+
+        let result = run_tx(
+            TxCommand {
+                ctx,
+                inner: msg_send(),
+            },
+            &ChunkedSendHandler,
+            &NoNodeFetcher,
+        );
+        std::fs::remove_dir_all(home).expect("tmp directory should be deleted");
+
+        let err = result.expect_err("chunks are only sent in block mode");
+        assert!(matches!(
+            err.downcast_ref::<ChunkingBroadcastModeError>(),
+            Some(ChunkingBroadcastModeError(BroadcastMode::Sync))
+        ));
+    }
+
+    #[derive(Debug, Clone)]
+    struct MissingAccountFetcher;
+
+    impl NodeFetcher for MissingAccountFetcher {
+        fn latest_account(
+            &self,
+            _address: AccAddress,
+            _node: impl AsRef<str>,
+        ) -> anyhow::Result<Option<Account>> {
+            Ok(None)
+        }
+
+        fn denom_metadata(
+            &self,
+            _base: Denom,
+            _node: impl AsRef<str>,
+        ) -> anyhow::Result<Option<Metadata>> {
+            Ok(None)
+        }
+
+        fn max_memo_characters(&self, _node: impl AsRef<str>) -> anyhow::Result<u64> {
+            Ok(100)
+        }
+    }
+
+    fn key_pair() -> KeyPair {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+
+        KeyPair::from_mnemonic(&Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test())
+    }
+
+    #[test]
+    fn sign_msg_account_not_found() {
+        let key = key_pair();
+
+        let err = SendHandler
+            .sign_msg(
+                Messages::from(msg_send()),
+                &key,
+                SignMode::Direct,
+                &mut tx_ctx(),
+                &MissingAccountFetcher,
+            )
+            .expect_err("account doesn't exist");
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "account {} not found on chain; use --offline with --sequence/--account-number",
+                key.get_address()
+            )
+        );
+    }
+
+    #[test]
+    fn sign_mode_from_str() {
+        assert_eq!(
+            TxSignMode::from_str("direct").unwrap_test(),
+            TxSignMode::Direct
+        );
+        assert_eq!(
+            TxSignMode::from_str("textual").unwrap_test(),
+            TxSignMode::Textual
+        );
+        assert!(TxSignMode::from_str("amino-json").is_err());
+        assert_eq!(TxSignMode::default(), TxSignMode::Direct);
+    }
+
+    #[test]
+    fn sign_msg_textual_signs_textual_sign_bytes() {
+        let key = key_pair();
+        let mut ctx = tx_ctx();
+        ctx.account = AccountProvider::Offline {
+            sequence: 3,
+            account_number: 7,
+        };
+
+        let tx = SendHandler
+            .sign_msg(
+                Messages::from(msg_send()),
+                &key,
+                SignMode::Textual,
+                &mut ctx,
+                &MissingAccountFetcher,
+            )
+            .unwrap_test();
+
+        assert_eq!(
+            tx.auth_info.signer_infos[0].mode_info,
+            ModeInfo::Single(SignMode::Textual)
+        );
+
+        let sign_bytes = SignModeHandler
+            .sign_bytes_get(
+                &MetadataViaRPC {
+                    node: ctx.node.clone(),
+                    fetcher: MissingAccountFetcher,
+                },
+                SignerData {
+                    address: key.get_address(),
+                    chain_id: ctx.chain_id.clone(),
+                    account_number: 7,
+                    sequence: 3,
+                    pub_key: key.get_gears_public_key(),
+                },
+                &tx.body,
+                &tx.auth_info,
+            )
+            .unwrap_test();
+
+        key.get_gears_public_key()
+            .verify_signature(&sign_bytes, &tx.signatures[0])
+            .unwrap_test();
+    }
+
+    #[test]
+    fn account_online_with_values_skips_node() {
+        let mut ctx = tx_ctx();
+        ctx.account = AccountProvider::Online {
+            sequence: Some(3),
+            account_number: Some(7),
+        };
+
+        let account = SendHandler
+            .account(key_pair().get_address(), &mut ctx, &NoNodeFetcher)
+            .unwrap_test()
+            .expect("account is built from the given values");
+
+        assert_eq!(account.get_sequence(), 3);
+        assert_eq!(account.get_account_number(), 7);
+    }
+
+    #[test]
+    fn sign_msg_textual_sends_textual_sign_bytes_to_ledger() {
+        let device = MockDevice::new([0xcd; 32]);
+        let signed = device.signed.clone();
+        let key = LedgerProxyKey::with_device(device).unwrap_test();
+        let mut ctx = tx_ctx();
+        ctx.account = AccountProvider::Offline {
+            sequence: 3,
+            account_number: 7,
+        };
+
+        let tx = SendHandler
+            .sign_msg(
+                Messages::from(msg_send()),
+                &key,
+                SignMode::Textual,
+                &mut ctx,
+                &MissingAccountFetcher,
+            )
+            .unwrap_test();
+
+        let sign_bytes = SignModeHandler
+            .sign_bytes_get(
+                &MetadataViaRPC {
+                    node: ctx.node.clone(),
+                    fetcher: MissingAccountFetcher,
+                },
+                SignerData {
+                    address: key.get_address(),
+                    chain_id: ctx.chain_id.clone(),
+                    account_number: 7,
+                    sequence: 3,
+                    pub_key: key.get_gears_public_key(),
+                },
+                &tx.body,
+                &tx.auth_info,
+            )
+            .unwrap_test();
+
+        assert_eq!(*signed.lock().unwrap_test(), vec![sign_bytes.clone()]);
+        key.get_gears_public_key()
+            .verify_signature(&sign_bytes, &tx.signatures[0])
+            .unwrap_test();
+    }
+}
@@ -2,6 +2,7 @@ use self::{client::ClientCommands, node::AppCommands};
 
 pub mod client;
 pub mod node;
+pub mod version;
 
 /// An empty AUX command if the user does not want to add auxillary commands.
 #[derive(Debug, Clone)]
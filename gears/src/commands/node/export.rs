@@ -0,0 +1,85 @@
+use std::path::PathBuf;
+
+use database::{Database, DatabaseBuilder};
+use tendermint::informal::genesis::Genesis;
+
+use crate::{
+    application::{handlers::node::ABCIHandler, ApplicationInfo},
+    baseapp::{errors::ExportGenesisError, options::NodeOptions, BaseApp},
+    config::{ApplicationConfig, Config, ConfigDirectory},
+    params::ParamsSubspaceKey,
+};
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ExportCommand {
+    pub home: PathBuf,
+    /// Height to export the state at, defaults to the latest committed height
+    pub height: Option<u32>,
+    /// File to write the genesis to, defaults to stdout
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Serde(#[from] serde_json::Error),
+    #[error("{0}")]
+    Database(String),
+    #[error("Error reading config file: {0}")]
+    Config(String),
+    #[error("{0}")]
+    Genesis(#[from] ExportGenesisError),
+}
+
+/// Exports the committed application state as a genesis file. All fields other than the
+/// application state and the initial height are taken from the existing genesis file.
+pub fn export<
+    DB: Database,
+    DBO: DatabaseBuilder<DB>,
+    PSK: ParamsSubspaceKey,
+    H: ABCIHandler,
+    AC: ApplicationConfig,
+    AI: ApplicationInfo,
+>(
+    cmd: ExportCommand,
+    db_builder: DBO,
+    params_subspace_key: PSK,
+    abci_handler_builder: impl FnOnce(Config<AC>) -> H,
+) -> Result<(), ExportError> {
+    let ExportCommand {
+        home,
+        height,
+        output,
+    } = cmd;
+
+    let db = db_builder
+        .build(home.join("data").join("application.db"))
+        .map_err(|e| ExportError::Database(format!("{e:?}")))?;
+
+    let config: Config<AC> = Config::from_file(ConfigDirectory::ConfigFile.path_from_hone(&home))
+        .map_err(|e| ExportError::Config(e.to_string()))?;
+
+    let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(
+        db,
+        params_subspace_key,
+        abci_handler_builder(config),
+        NodeOptions::default(),
+    );
+
+    let (version, app_state) = app.export_genesis(height.unwrap_or_default())?;
+
+    let raw_genesis = std::fs::read_to_string(ConfigDirectory::GenesisFile.path_from_hone(&home))?;
+    let mut genesis: Genesis<H::Genesis> = serde_json::from_str(&raw_genesis)?;
+    genesis.app_state = app_state;
+    genesis.initial_height = i64::from(version) + 1;
+
+    let genesis = serde_json::to_string_pretty(&genesis)?;
+    match output {
+        Some(output) => std::fs::write(output, genesis)?,
+        None => println!("{genesis}"),
+    }
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anyhow::Result;
 use tendermint::informal::genesis::Genesis;
 
 use crate::{
-    baseapp::genesis::{Genesis as SDKGenesis, GenesisError},
+    baseapp::genesis::{Genesis as SDKGenesis, GenesisError, GenesisValidationError},
     config::ConfigDirectory,
     types::{address::AccAddress, base::coins::UnsignedCoins},
 };
@@ -16,6 +16,13 @@ pub struct GenesisCommand {
     pub coins: UnsignedCoins,
 }
 
+#[derive(Debug, Clone, former::Former)]
+pub struct ValidateGenesisCommand {
+    pub home: PathBuf,
+    /// Genesis file to validate, defaults to the genesis file in `home`
+    pub file: Option<PathBuf>,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum GenesisInitError {
     #[error("{0}")]
@@ -42,3 +49,18 @@ pub fn genesis_account_add<G: SDKGenesis>(cmd: GenesisCommand) -> Result<(), Gen
 
     Ok(())
 }
+
+/// Parses the genesis file and validates the state of each module.
+pub fn validate_genesis<G: SDKGenesis>(
+    cmd: ValidateGenesisCommand,
+) -> Result<Vec<(&'static str, Result<(), GenesisValidationError>)>, GenesisInitError> {
+    let ValidateGenesisCommand { home, file } = cmd;
+
+    let genesis_file_path =
+        file.unwrap_or_else(|| ConfigDirectory::GenesisFile.path_from_hone(&home));
+
+    let raw_genesis = std::fs::read_to_string(genesis_file_path)?;
+    let genesis: Genesis<G> = serde_json::from_str(&raw_genesis)?;
+
+    Ok(genesis.app_state.validate_modules())
+}
@@ -1,18 +1,30 @@
 use std::path::PathBuf;
 
-use serde::Serialize;
-use tendermint::types::chain_id::ChainId;
+use tendermint::{config::Address, informal::Time, types::chain_id::ChainId, TmConfigOverrides};
 
-use crate::config::{ApplicationConfig, ConfigDirectory};
+use crate::{
+    baseapp::genesis::{Genesis, GenesisValidationError},
+    config::{ApplicationConfig, ConfigDirectory},
+};
 
 #[derive(Debug, Clone, former::Former)]
 pub struct InitCommand {
     pub home: PathBuf,
     pub moniker: String,
     pub chain_id: ChainId,
+    /// Genesis time of the chain, current time is used if not set
+    pub genesis_time: Option<Time>,
+    /// Height of the first block, `1` is used if not set
+    pub initial_height: Option<i64>,
+    /// Address to listen for incoming p2p connections
+    pub p2p_laddr: Option<Address>,
+    /// Address to listen for RPC requests
+    pub rpc_laddr: Option<Address>,
+    pub seeds: Vec<Address>,
+    pub persistent_peers: Vec<Address>,
 }
 
-pub fn init<G: Serialize, AC: ApplicationConfig>(
+pub fn init<G: Genesis, AC: ApplicationConfig>(
     cmd: InitCommand,
     app_genesis_state: &G,
 ) -> Result<(), InitError> {
@@ -20,8 +32,17 @@ pub fn init<G: Serialize, AC: ApplicationConfig>(
         moniker,
         home,
         chain_id,
+        genesis_time,
+        initial_height,
+        p2p_laddr,
+        rpc_laddr,
+        seeds,
+        persistent_peers,
     } = cmd;
 
+    // Never write a genesis file which the node would fail to start with
+    app_genesis_state.validate()?;
+
     // Create config directory
     let config_dir = home.join("config");
     std::fs::create_dir_all(&config_dir).map_err(InitError::CreateConfigDirectory)?;
@@ -35,7 +56,17 @@ pub fn init<G: Serialize, AC: ApplicationConfig>(
     let tm_config_file =
         std::fs::File::create(&tm_config_file_path).map_err(InitError::CreateConfigDirectory)?;
 
-    tendermint::write_tm_config(tm_config_file, &moniker).map_err(InitError::WriteConfigFile)?;
+    tendermint::write_tm_config(
+        tm_config_file,
+        &moniker,
+        TmConfigOverrides {
+            p2p_laddr,
+            rpc_laddr,
+            seeds,
+            persistent_peers,
+        },
+    )
+    .map_err(InitError::WriteConfigFile)?;
 
     #[cfg(not(feature = "utils"))]
     println!("Tendermint config written to {tm_config_file_path:?}");
@@ -74,6 +105,8 @@ pub fn init<G: Serialize, AC: ApplicationConfig>(
         genesis_file,
         app_state,
         chain_id,
+        genesis_time.unwrap_or_else(Time::now),
+        initial_height.unwrap_or(1),
     )
     .map_err(InitError::WriteKeysAndGenesis)?;
 
@@ -131,4 +164,116 @@ pub enum InitError {
     WriteConfigError(#[source] std::io::Error),
     #[error("Error writing key and genesis files {0}")]
     WriteKeysAndGenesis(#[source] tendermint::error::Error),
+    #[error("{0}")]
+    InvalidGenesis(#[from] GenesisValidationError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use extensions::testing::UnwrapTesting;
+    use serde::{Deserialize, Serialize};
+    use tendermint::informal::genesis::Genesis as TmGenesis;
+
+    use crate::{baseapp::genesis::GenesisError, types::address::AccAddress};
+
+    use super::*;
+
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    struct TestConfig {}
+
+    impl ApplicationConfig for TestConfig {}
+
+    #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+    struct TestGenesis {
+        valid: bool,
+    }
+
+    impl Genesis for TestGenesis {
+        fn add_genesis_account(
+            &mut self,
+            _address: AccAddress,
+            _coins: crate::types::base::coins::UnsignedCoins,
+        ) -> Result<(), GenesisError> {
+            Ok(())
+        }
+
+        fn validate(&self) -> Result<(), GenesisValidationError> {
+            match self.valid {
+                true => Ok(()),
+                false => Err(GenesisValidationError {
+                    module: "test",
+                    reason: "state is invalid".to_owned(),
+                }),
+            }
+        }
+    }
+
+    const VALID_GENESIS: TestGenesis = TestGenesis { valid: true };
+
+    #[test]
+    fn init_writes_genesis_time_and_initial_height() {
+        let home = PathBuf::from("./tmp/init_genesis_time_and_height");
+        let genesis_time = Time::from_str("2030-01-01T00:00:00Z").unwrap_test();
+
+        let cmd = InitCommand::former()
+            .home(home.clone())
+            .moniker("test".to_owned())
+            .chain_id(ChainId::from_str("test-chain").unwrap_test())
+            .genesis_time(genesis_time)
+            .initial_height(100)
+            .form();
+
+        let result = init::<_, TestConfig>(cmd, &VALID_GENESIS);
+
+        let genesis = std::fs::read_to_string(ConfigDirectory::GenesisFile.path_from_hone(&home));
+        std::fs::remove_dir_all(&home).unwrap_test();
+
+        result.unwrap_test();
+        let genesis: TmGenesis<serde_json::Value> =
+            serde_json::from_str(&genesis.unwrap_test()).unwrap_test();
+
+        assert_eq!(genesis.genesis_time, genesis_time);
+        assert_eq!(genesis.initial_height, 100);
+    }
+
+    #[test]
+    fn init_writes_rpc_laddr() {
+        let home = PathBuf::from("./tmp/init_rpc_laddr");
+
+        let cmd = InitCommand::former()
+            .home(home.clone())
+            .moniker("test".to_owned())
+            .chain_id(ChainId::from_str("test-chain").unwrap_test())
+            .rpc_laddr(Address::from_str("tcp://0.0.0.0:36657").unwrap_test())
+            .form();
+
+        let result = init::<_, TestConfig>(cmd, &VALID_GENESIS);
+
+        let config = std::fs::read_to_string(home.join("config").join("config.toml"));
+        std::fs::remove_dir_all(&home).unwrap_test();
+
+        result.unwrap_test();
+        assert!(config
+            .unwrap_test()
+            .contains("laddr = \"tcp://0.0.0.0:36657\""));
+    }
+
+    #[test]
+    fn init_rejects_invalid_genesis() {
+        let home = PathBuf::from("./tmp/init_invalid_genesis");
+
+        let cmd = InitCommand::former()
+            .home(home.clone())
+            .moniker("test".to_owned())
+            .chain_id(ChainId::from_str("test-chain").unwrap_test())
+            .form();
+
+        let err = init::<_, TestConfig>(cmd, &TestGenesis { valid: false })
+            .expect_err("genesis is invalid");
+
+        assert!(!home.exists());
+        assert_eq!(err.to_string(), "invalid test genesis: state is invalid");
+    }
 }
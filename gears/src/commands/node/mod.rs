@@ -1,11 +1,16 @@
+pub mod export;
 pub mod genesis;
 pub mod init;
 pub mod run;
+pub mod store;
 
 #[derive(Debug, Clone)]
 pub enum AppCommands<AUX> {
     Init(init::InitCommand),
     Run(run::RunCommand),
     GenesisAdd(genesis::GenesisCommand),
+    ValidateGenesis(genesis::ValidateGenesisCommand),
+    Store(store::StoreCommand),
+    Export(export::ExportCommand),
     Aux(AUX),
 }
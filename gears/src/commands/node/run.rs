@@ -1,11 +1,12 @@
 use crate::application::handlers::node::ABCIHandler;
 use crate::application::ApplicationInfo;
 use crate::baseapp::options::NodeOptions;
-use crate::baseapp::{BaseApp, NodeQueryHandler};
-use crate::config::{ApplicationConfig, Config, ConfigDirectory};
+use crate::baseapp::{BaseApp, NodeHealthHandler, NodeQueryHandler, NodeSimulateHandler};
+use crate::config::{ApplicationConfig, Config, ConfigDirectory, PruningConfig};
 use crate::grpc::run_grpc_server;
 use crate::params::ParamsSubspaceKey;
 use crate::rest::{run_rest_server, RestState};
+use crate::runtime::runtime;
 use crate::types::base::min_gas::MinGasPrices;
 use axum::Router;
 use database::{Database, DatabaseBuilder};
@@ -13,9 +14,12 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use tendermint::abci::ServerBuilder;
 use tendermint::application::ABCI;
+use tendermint::rpc::client::HttpClientUrl;
 use tower_layer::Identity;
 use tracing::metadata::LevelFilter;
-use tracing::{error, info};
+use tracing::{error, info, warn, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::util::SubscriberInitExt;
 
 #[derive(Debug, Clone)]
 pub struct RunCommand {
@@ -26,7 +30,9 @@ pub struct RunCommand {
     pub tendermint_rpc_addr: Option<tendermint::rpc::url::Url>,
     pub read_buf_size: usize,
     pub log_level: LogLevel,
+    pub log_format: LogFormat,
     pub min_gas_prices: Option<MinGasPrices>,
+    pub pruning: Option<PruningConfig>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -71,13 +77,65 @@ impl From<LogLevel> for LevelFilter {
     }
 }
 
+#[derive(Debug, Clone, Default, strum::Display)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum LogFormat {
+    #[default]
+    #[strum(to_string = "text")]
+    Text,
+    #[strum(to_string = "json")]
+    Json,
+}
+
+/// Builds the node logger, events are written to `writer` in the selected format
+pub fn log_subscriber<W>(
+    log_level: LogLevel,
+    log_format: LogFormat,
+    writer: W,
+) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(log_level)
+        .with_writer(writer);
+
+    match log_format {
+        LogFormat::Text => Box::new(builder.finish()),
+        LogFormat::Json => Box::new(builder.json().flatten_event(true).finish()),
+    }
+}
+
+/// Read buffer sizes above this threshold are most likely a misconfiguration
+pub const READ_BUF_SIZE_WARN_THRESHOLD: usize = 64 * 1024 * 1024;
+
+/// Checks that the ABCI server read buffer size is usable
+pub fn validate_read_buf_size(read_buf_size: usize) -> Result<usize, RunError> {
+    if read_buf_size == 0 {
+        return Err(RunError::Custom(
+            "Invalid read buffer size: `read_buf_size` must be greater than zero".to_owned(),
+        ));
+    }
+
+    if read_buf_size > READ_BUF_SIZE_WARN_THRESHOLD {
+        warn!(
+            "Read buffer size of {read_buf_size} bytes exceeds {READ_BUF_SIZE_WARN_THRESHOLD} bytes, each incoming connection will allocate a buffer of this size"
+        );
+    }
+
+    Ok(read_buf_size)
+}
+
 pub trait RouterBuilder<QReq, QRes> {
     fn build_router<App: NodeQueryHandler<QReq, QRes>>(&self)
         -> Router<RestState<QReq, QRes, App>>;
 
-    fn build_grpc_router<App: NodeQueryHandler<QReq, QRes>>(
+    fn build_grpc_router<
+        App: NodeQueryHandler<QReq, QRes> + NodeSimulateHandler + NodeHealthHandler,
+    >(
         &self,
         app: App,
+        tendermint_rpc_address: HttpClientUrl,
     ) -> tonic::transport::server::Router<Identity>;
 }
 
@@ -103,15 +161,18 @@ pub fn run<
         grpc_listen_addr,
         read_buf_size,
         log_level,
+        log_format,
         min_gas_prices,
         tendermint_rpc_addr: tendermint_addr,
+        pruning,
     } = cmd;
 
-    tracing_subscriber::fmt()
-        .with_max_level(log_level)
+    log_subscriber(log_level, log_format, std::io::stdout)
         .try_init()
         .map_err(|e| RunError::Custom(format!("Failed to set logger: {}", e)))?;
 
+    let read_buf_size = validate_read_buf_size(read_buf_size)?;
+
     info!("Using directory {} for config and data", home.display());
 
     let db_dir = home.join("data");
@@ -130,26 +191,128 @@ pub fn run<
         RunError::HomeDirectory(
             "Failed to get `min_gas_prices` set it via cli or in config file".to_owned(),
         ),
-    )?);
+    )?)
+    .with_pruning(pruning.unwrap_or(config.pruning.clone()));
 
     let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options);
 
+    let tendermint_rpc_address: HttpClientUrl = tendermint_addr
+        .unwrap_or(config.tendermint_rpc_address)
+        .try_into()?;
+
     run_rest_server::<H::Message, H::QReq, H::QRes, _>(
         app.clone(),
         rest_listen_addr.unwrap_or(config.rest_listen_addr),
         router_builder.build_router::<BaseApp<DB, PSK, H, AI>>(),
-        tendermint_addr
-            .unwrap_or(config.tendermint_rpc_address)
-            .try_into()?,
+        tendermint_rpc_address.clone(),
     );
 
     run_grpc_server(
-        router_builder.build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone()),
+        router_builder
+            .build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone(), tendermint_rpc_address),
         grpc_listen_addr.unwrap_or(config.grpc_listen_addr),
     );
 
     let server = ServerBuilder::new(read_buf_size)
-        .bind(address.unwrap_or(config.address), ABCI::from(app))?;
+        .bind(address.unwrap_or(config.address), ABCI::from(app.clone()))?;
+
+    // the server never returns unless it fails, so it's left on a blocking thread which is
+    // abandoned once the node is shut down
+    let listener = runtime().spawn_blocking(move || server.listen());
+
+    let res = runtime().block_on(async {
+        tokio::select! {
+            res = listener => match res {
+                Ok(res) => res.map_err(RunError::from),
+                Err(e) => Err(RunError::Custom(format!("ABCI server stopped: {e}"))),
+            },
+            res = shutdown_signal() => res.map_err(|e| {
+                RunError::Custom(format!("Failed to listen for shutdown signal: {e}"))
+            }),
+        }
+    });
+
+    info!("Shutting down");
+    app.shutdown();
 
-    server.listen().map_err(|e| e.into())
+    res
+}
+
+/// Resolves once the process receives SIGINT or, on unix, SIGTERM
+async fn shutdown_signal() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut terminate = signal(SignalKind::terminate())?;
+
+        tokio::select! {
+            res = tokio::signal::ctrl_c() => res,
+            _ = terminate.recv() => Ok(()),
+        }
+    }
+
+    #[cfg(not(unix))]
+    tokio::signal::ctrl_c().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_read_buf_size_rejected() {
+        let err = validate_read_buf_size(0).expect_err("zero buffer size is invalid");
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid read buffer size: `read_buf_size` must be greater than zero"
+        );
+    }
+
+    #[test]
+    fn read_buf_size_accepted() {
+        assert_eq!(validate_read_buf_size(1048576).ok(), Some(1048576));
+        assert_eq!(
+            validate_read_buf_size(READ_BUF_SIZE_WARN_THRESHOLD + 1).ok(),
+            Some(READ_BUF_SIZE_WARN_THRESHOLD + 1)
+        );
+    }
+
+    #[derive(Clone, Default)]
+    struct CapturedWriter(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().expect("poisoned lock").extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn json_log_format_writes_json_lines() {
+        let captured = CapturedWriter::default();
+        let writer = captured.clone();
+        let subscriber = log_subscriber(LogLevel::Info, LogFormat::Json, move || writer.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            info!("node started");
+            tracing::debug!("filtered out by level");
+        });
+
+        let output = String::from_utf8(captured.0.lock().expect("poisoned lock").clone())
+            .expect("log output is utf8");
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1);
+
+        let line: serde_json::Value =
+            serde_json::from_str(lines[0]).expect("log line should be json");
+        let object = line.as_object().expect("log line should be a json object");
+        assert_eq!(object["level"], "INFO");
+        assert_eq!(object["message"], "node started");
+    }
 }
@@ -0,0 +1,142 @@
+use std::{fmt::Display, path::PathBuf, sync::Arc};
+
+use database::{Database, DatabaseBuilder};
+use kv_store::StoreKey;
+use trees::iavl::NodeDB;
+
+#[derive(Debug, Clone)]
+pub enum StoreCommand {
+    Versions(StoreVersionsCommand),
+    Migrate(StoreMigrateCommand),
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct StoreVersionsCommand {
+    pub home: PathBuf,
+    pub store: String,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct StoreMigrateCommand {
+    pub home: PathBuf,
+}
+
+/// Version of a store saved in the application database
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreVersion {
+    pub version: u32,
+    pub hash: [u8; 32],
+}
+
+impl Display for StoreVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.version, hex::encode_upper(self.hash))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreCommandError {
+    #[error("unknown store: {0}")]
+    UnknownStore(String),
+    #[error("database error: {0}")]
+    Database(String),
+}
+
+pub fn store_versions<DB: Database, DBO: DatabaseBuilder<DB>, SK: StoreKey>(
+    cmd: StoreVersionsCommand,
+    db_builder: DBO,
+) -> Result<Vec<StoreVersion>, StoreCommandError> {
+    let StoreVersionsCommand { home, store } = cmd;
+
+    let store_key = SK::iter()
+        .find(|this| this.name() == store)
+        .ok_or(StoreCommandError::UnknownStore(store))?;
+
+    let db = db_builder
+        .build(home.join("data").join("application.db"))
+        .map_err(|e| StoreCommandError::Database(format!("{e:?}")))?;
+
+    Ok(list_store_versions(Arc::new(db), &store_key))
+}
+
+/// Moves every store of the application out of the shared key space into storage of its own,
+/// for RocksDB a column family per store. Stores already moved are left untouched.
+pub fn store_migrate<DB: Database, DBO: DatabaseBuilder<DB>, SK: StoreKey>(
+    cmd: StoreMigrateCommand,
+    db_builder: DBO,
+) -> Result<(), StoreCommandError> {
+    let StoreMigrateCommand { home } = cmd;
+
+    let stores = SK::iter().map(|this| this.name()).collect::<Vec<_>>();
+
+    let db = db_builder
+        .build_migrated(home.join("data").join("application.db"), &stores)
+        .map_err(|e| StoreCommandError::Database(format!("{e:?}")))?;
+    db.flush();
+
+    Ok(())
+}
+
+/// Lists all versions of the store along with their root hashes in ascending version order.
+pub fn list_store_versions<DB: Database, SK: StoreKey>(
+    db: Arc<DB>,
+    store_key: &SK,
+) -> Vec<StoreVersion> {
+    let node_db = NodeDB::new(
+        DB::store(&db, store_key.name()),
+        1_usize.try_into().expect("Unreachable. Cache size is > 0"),
+    );
+
+    node_db
+        .get_version_roots()
+        .into_iter()
+        .map(|(version, hash)| StoreVersion { version, hash })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use database::{prefix::PrefixDB, MemDB};
+    use extensions::testing::UnwrapTesting;
+    use key_derive::{ParamsKeys, StoreKeys};
+    use trees::iavl::Tree;
+
+    use super::*;
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+    #[skey(params = Params, gears)]
+    #[pkey(gears)]
+    enum SubspaceKey {
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+        #[skey(to_string = "bank")]
+        #[pkey(to_string = "bank")]
+        Bank,
+    }
+
+    #[test]
+    fn list_store_versions_lists_all_versions() {
+        let db = Arc::new(MemDB::new());
+        let mut tree = Tree::new(
+            PrefixDB::new(Arc::clone(&db), b"bank".to_vec()),
+            None,
+            1_usize.try_into().unwrap_test(),
+            None,
+        )
+        .unwrap_test();
+
+        let mut expected = Vec::new();
+        for i in 1..=3_u8 {
+            tree.set(vec![i], vec![i; 4]);
+            let (hash, version) = tree.save_version().unwrap_test();
+
+            expected.push(StoreVersion { version, hash });
+        }
+
+        let versions = list_store_versions(Arc::clone(&db), &SubspaceKey::Bank);
+
+        assert_eq!(versions, expected);
+        assert!(list_store_versions(db, &SubspaceKey::Params).is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+use serde::Serialize;
+
+use crate::{application::ApplicationInfo, commands::client::query::OutputFormat};
+
+#[derive(Debug, Clone, former::Former)]
+pub struct VersionCommand {
+    pub output: OutputFormat,
+}
+
+/// Version and build information of an application
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionInfo {
+    pub name: String,
+    pub version: String,
+    pub target_os: String,
+    pub target_arch: String,
+}
+
+impl VersionInfo {
+    pub fn new<AI: ApplicationInfo>() -> Self {
+        Self {
+            name: AI::APP_NAME.to_owned(),
+            version: AI::APP_VERSION.to_owned(),
+            target_os: std::env::consts::OS.to_owned(),
+            target_arch: std::env::consts::ARCH.to_owned(),
+        }
+    }
+}
+
+/// Returns version information of the application in requested format
+pub fn version<AI: ApplicationInfo>(cmd: VersionCommand) -> anyhow::Result<String> {
+    cmd.output.format(&VersionInfo::new::<AI>())
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct TestApplication;
+
+    impl ApplicationInfo for TestApplication {
+        const APP_NAME: &'static str = "test-app";
+        const APP_VERSION: &'static str = "0a1b2c3d";
+    }
+
+    #[test]
+    fn version_text_output() {
+        let output = version::<TestApplication>(VersionCommand {
+            output: OutputFormat::Text,
+        })
+        .unwrap_test();
+
+        assert!(output.contains("name: test-app"));
+        assert!(output.contains("version: 0a1b2c3d"));
+    }
+
+    #[test]
+    fn version_json_output() {
+        let output = version::<TestApplication>(VersionCommand {
+            output: OutputFormat::Json,
+        })
+        .unwrap_test();
+
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap_test();
+        assert_eq!(json["version"], TestApplication::APP_VERSION);
+    }
+}
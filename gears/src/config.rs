@@ -38,6 +38,40 @@ impl ConfigDirectory {
 
 pub trait ApplicationConfig: Serialize + DeserializeOwned + Default + Clone {}
 
+/// Which committed versions of the application state are kept on disk.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum PruningConfig {
+    /// Keep all versions
+    #[default]
+    Nothing,
+    /// Keep only the latest version
+    Everything,
+    /// Every `interval` versions delete all but the `keep_recent` latest versions
+    Custom { keep_recent: u32, interval: u32 },
+}
+
+impl PruningConfig {
+    /// Returns the oldest version to keep if versions should be pruned after committing `version`.
+    pub fn retain_from(&self, version: u32) -> Option<u32> {
+        match self {
+            PruningConfig::Nothing => None,
+            PruningConfig::Everything => Some(version),
+            PruningConfig::Custom {
+                keep_recent,
+                interval,
+            } => {
+                if *interval == 0 || version % interval != 0 {
+                    return None;
+                }
+
+                // the latest version is always kept
+                Some((version + 1).saturating_sub(*keep_recent).min(version))
+            }
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -47,6 +81,7 @@ pub struct Config<AC: Default + Clone> {
     pub grpc_listen_addr: SocketAddr,
     pub address: SocketAddr,
     pub min_gas_prices: Option<MinGasPrices>,
+    pub pruning: PruningConfig,
     pub app_config: AC,
 }
 
@@ -88,6 +123,7 @@ impl<AC: ApplicationConfig> Config<AC> {
             grpc_listen_addr: self.grpc_listen_addr.to_owned(),
             address: self.address.to_owned(),
             min_gas_prices: self.min_gas_prices.to_owned(),
+            pruning: self.pruning.to_owned(),
             app_config: AC::default(),
         }
     }
@@ -103,6 +139,7 @@ impl<AC: ApplicationConfig> Default for Config<AC> {
             address: DEFAULT_ADDRESS,
             app_config: AC::default(),
             min_gas_prices: None,
+            pruning: PruningConfig::default(),
             grpc_listen_addr: DEFAULT_GRPC_LISTEN_ADDR,
         }
     }
@@ -128,4 +165,91 @@ grpc_listen_addr = "{{grpc_listen_addr}}"
 tendermint_rpc_address = "{{tendermint_rpc_address}}"
 
 min_gas_prices = "{{min_gas_prices}}"
+
+[pruning]
+# One of "nothing", "everything" or "custom". The "custom" strategy also
+# requires `keep_recent` and `interval` to be set e.g.
+# strategy = "custom"
+# keep_recent = 100
+# interval = 10
+strategy = "{{pruning.strategy}}"
 "#;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use key_derive::{ParamsKeys, StoreKeys};
+    use kv_store::{bank::multi::ApplicationMultiBank, query::QueryMultiStore};
+
+    use super::*;
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+    #[skey(params = Params, gears)]
+    #[pkey(gears)]
+    enum SubspaceKey {
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+        #[skey(to_string = "bank")]
+        #[pkey(to_string = "bank")]
+        Bank,
+    }
+
+    #[derive(Serialize, Deserialize, Clone, Default)]
+    struct TestConfig;
+
+    impl ApplicationConfig for TestConfig {}
+
+    #[test]
+    fn pruning_config_parses_from_toml() {
+        let config: Config<TestConfig> = toml::from_str(
+            r#"
+            [pruning]
+            strategy = "custom"
+            keep_recent = 2
+            interval = 10
+            "#,
+        )
+        .unwrap_test();
+
+        assert_eq!(
+            config.pruning,
+            PruningConfig::Custom {
+                keep_recent: 2,
+                interval: 10
+            }
+        );
+
+        let config: Config<TestConfig> = toml::from_str("").unwrap_test();
+        assert_eq!(config.pruning, PruningConfig::Nothing);
+    }
+
+    #[test]
+    fn custom_pruning_keeps_recent_versions() {
+        let pruning = PruningConfig::Custom {
+            keep_recent: 2,
+            interval: 1,
+        };
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+
+        for i in 1..=5_u8 {
+            multi_store.kv_store_mut(&SubspaceKey::Bank).set([i], [i]);
+            multi_store.commit();
+
+            if let Some(retain_from) = pruning.retain_from(multi_store.head_version()) {
+                multi_store.prune(retain_from);
+            }
+        }
+
+        for version in 1..=3 {
+            assert!(QueryMultiStore::new(&multi_store, version).is_err());
+        }
+        for version in 4..=5 {
+            assert!(QueryMultiStore::new(&multi_store, version).is_ok());
+        }
+    }
+}
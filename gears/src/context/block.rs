@@ -96,6 +96,10 @@ impl<DB: Database, SK: StoreKey> TransactionalContext<DB, SK> for BlockContext<'
         self.events.append(&mut events);
     }
 
+    fn events(&self) -> &[Event] {
+        &self.events
+    }
+
     fn events_drain(&mut self) -> Vec<Event> {
         std::mem::take(&mut self.events)
     }
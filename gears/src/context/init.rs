@@ -90,6 +90,10 @@ impl<DB: Database, SK: StoreKey> TransactionalContext<DB, SK> for InitContext<'_
         self.events.append(&mut events);
     }
 
+    fn events(&self) -> &[Event] {
+        &self.events
+    }
+
     fn events_drain(&mut self) -> Vec<Event> {
         std::mem::take(&mut self.events)
     }
@@ -102,3 +106,52 @@ impl<DB: Database, SK: StoreKey> TransactionalContext<DB, SK> for InitContext<'_
         StoreMut::from(self.kv_store_mut(store_key))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use key_derive::{ParamsKeys, StoreKeys};
+
+    use super::*;
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+    #[skey(params = Params, gears)]
+    #[pkey(gears)]
+    enum SubspaceKey {
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
+
+    fn event(r#type: &str) -> Event {
+        Event {
+            r#type: r#type.to_owned(),
+            attributes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn events_drain_empties_buffer() {
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            0,
+            Timestamp::UNIX_EPOCH,
+            ChainId::default(),
+            ConsensusParams::default(),
+        );
+
+        ctx.push_event(event("first"));
+        ctx.push_event(event("second"));
+        assert_eq!(ctx.events().len(), 2);
+
+        let events = ctx.events_drain();
+
+        assert_eq!(events, vec![event("first"), event("second")]);
+        assert!(ctx.events().is_empty());
+    }
+}
@@ -26,6 +26,9 @@ pub trait InfallibleContext<DB, SK>: QueryableContext<DB, SK> {
 pub trait TransactionalContext<DB, SK>: QueryableContext<DB, SK> {
     fn push_event(&mut self, event: Event);
     fn append_events(&mut self, events: Vec<Event>);
+    /// Returns the events accumulated so far.
+    fn events(&self) -> &[Event];
+    /// Takes the accumulated events leaving the context with an empty event buffer.
     fn events_drain(&mut self) -> Vec<Event>;
 
     /// Public interface for getting context timestamp. Default implementation returns `None`.
@@ -1,10 +1,11 @@
 use database::prefix::PrefixDB;
 use database::Database;
+use ics23::CommitmentProof;
 
 use kv_store::{
     error::KVStoreError, query::QueryMultiStore, store::kv::immutable::KVStore, StoreKey,
 };
-use tendermint::types::chain_id::ChainId;
+use tendermint::types::{chain_id::ChainId, time::timestamp::Timestamp};
 
 use crate::types::store::kv::Store;
 
@@ -14,18 +15,22 @@ pub struct QueryContext<DB, SK> {
     multi_store: QueryMultiStore<DB, SK>,
     pub(crate) height: u32,
     pub(crate) chain_id: ChainId,
+    pub(crate) time: Option<Timestamp>,
 }
 
 impl<DB: Database, SK: StoreKey> QueryContext<DB, SK> {
+    /// `time` is the time of the block at `version`, if it's known
     pub fn new(
         multi_store: QueryMultiStore<DB, SK>,
         version: u32,
-        // chain_id: ChainId,
+        chain_id: ChainId,
+        time: Option<Timestamp>,
     ) -> Result<Self, KVStoreError> {
         Ok(QueryContext {
             multi_store,
             height: version,
-            chain_id: ChainId::new("todo-900").expect("default should be valid"),
+            chain_id,
+            time,
         })
     }
 }
@@ -38,6 +43,18 @@ impl<DB: Database, SK: StoreKey> QueryContext<DB, SK> {
     pub fn kv_store(&self, store_key: &SK) -> KVStore<'_, PrefixDB<DB>> {
         self.multi_store.kv_store(store_key)
     }
+
+    /// Returns the time of the queried block. Only the time of the latest block is kept, so it's
+    /// `None` for older blocks.
+    pub fn get_time(&self) -> Option<Timestamp> {
+        self.time
+    }
+
+    /// Returns the ICS23 proofs of `key` in the store of `store_key` against the commit hash
+    /// of the queried height, or `None` if the key isn't in the store.
+    pub fn get_proof(&self, store_key: &SK, key: &[u8]) -> Option<[CommitmentProof; 2]> {
+        self.multi_store.get_proof(store_key, key)
+    }
 }
 
 impl<DB: Database, SK: StoreKey> QueryableContext<DB, SK> for QueryContext<DB, SK> {
@@ -17,7 +17,7 @@ use crate::{
     types::{
         gas::{
             kind::{BlockKind, TxKind},
-            GasMeter,
+            FiniteGas, GasMeter,
         },
         store::{
             gas::{
@@ -81,6 +81,17 @@ impl<'a, DB, SK> TxContext<'a, DB, SK> {
     pub fn header(&self) -> &Header {
         &self.header
     }
+
+    /// Returns the gas consumed in the current block.
+    pub fn block_gas_consumed(&self) -> FiniteGas {
+        self.block_gas_meter.consumed()
+    }
+
+    /// Zeroes the gas consumed in the block while keeping the block gas limit,
+    /// used when the context is reused for a new block.
+    pub fn reset_block_gas_meter(&mut self) {
+        self.block_gas_meter.reset()
+    }
 }
 
 impl<DB: Database, SK: StoreKey> TxContext<'_, DB, SK> {
@@ -126,6 +137,10 @@ impl<DB: Database, SK: StoreKey> TransactionalContext<DB, SK> for TxContext<'_,
         self.events.append(&mut events);
     }
 
+    fn events(&self) -> &[Event] {
+        &self.events
+    }
+
     fn events_drain(&mut self) -> Vec<Event> {
         std::mem::take(&mut self.events)
     }
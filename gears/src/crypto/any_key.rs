@@ -2,7 +2,7 @@ use keyring::key::pair::KeyPair;
 
 use super::{
     keys::{GearsPublicKey, ReadAccAddress, SigningKey},
-    ledger::{LedgerError, LedgerProxyKey},
+    ledger::{LedgerProxyKey, LedgerSigningError},
 };
 
 pub enum AnyKey {
@@ -10,6 +10,8 @@ pub enum AnyKey {
     Ledger(LedgerProxyKey),
 }
 
+/// The address is derived from the public key, for ledger keys the public key read when connecting
+/// to the device is used so the device isn't queried again.
 impl ReadAccAddress for AnyKey {
     fn get_address(&self) -> address::AccAddress {
         match self {
@@ -29,7 +31,7 @@ impl GearsPublicKey for AnyKey {
 }
 
 impl SigningKey for AnyKey {
-    type Error = LedgerError;
+    type Error = LedgerSigningError;
 
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Self::Error> {
         match self {
@@ -38,3 +40,41 @@ impl SigningKey for AnyKey {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use bip32::Mnemonic;
+    use extensions::testing::UnwrapTesting;
+    use keyring::{add_key, key_by_name, Backend, HdPath, KeyType};
+
+    use crate::types::address::AccAddress;
+
+    use super::*;
+
+    #[test]
+    fn local_key_address_is_derived_from_public_key() {
+        let path = PathBuf::from("./tmp/gears/src/crypto/any_key/local_key_address");
+        let _ = std::fs::remove_dir_all(&path);
+
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+        add_key(
+            "alice",
+            &mnemonic,
+            KeyType::Secp256k1,
+            HdPath::default(),
+            Backend::Test(&path),
+        )
+        .unwrap_test();
+
+        let key = AnyKey::Local(key_by_name("alice", Backend::Test(&path)).unwrap_test());
+        std::fs::remove_dir_all(path).expect("tmp directory should be deleted");
+
+        let expected =
+            AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux").unwrap_test();
+        assert_eq!(key.get_address(), expected);
+        assert_eq!(key.get_gears_public_key().get_address(), expected);
+    }
+}
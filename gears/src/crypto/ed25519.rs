@@ -1,5 +1,6 @@
 use address::AccAddress;
 use core_types::Protobuf;
+use ed25519_dalek::{Signature, Verifier, VerifyingKey, PUBLIC_KEY_LENGTH};
 use keyring::error::DecodeError;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -17,10 +18,23 @@ pub struct Ed25519PubKey {
 impl Ed25519PubKey {
     pub fn verify_signature(
         &self,
-        _message: impl AsRef<[u8]>,
-        _signature: impl AsRef<[u8]>,
+        message: impl AsRef<[u8]>,
+        signature: impl AsRef<[u8]>,
     ) -> Result<(), SigningError> {
-        todo!()
+        let key_bytes: &[u8; PUBLIC_KEY_LENGTH] = self
+            .key
+            .key
+            .as_slice()
+            .try_into()
+            .map_err(|_| SigningError::InvalidPublicKey)?;
+        let key =
+            VerifyingKey::from_bytes(key_bytes).map_err(|_| SigningError::InvalidPublicKey)?;
+
+        let signature = Signature::from_slice(signature.as_ref())
+            .map_err(|_| SigningError::InvalidSignature)?;
+
+        key.verify(message.as_ref(), &signature)
+            .map_err(|_| SigningError::IncorrectSignature)
     }
 
     pub fn get_address(&self) -> AccAddress {
@@ -114,3 +128,49 @@ impl<'de> serde::de::Visitor<'de> for Ed25519Visitor {
         Ok(inner::Ed25519PubKey { key })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // test vector 2 from RFC 8032 section 7.1
+    const PUBLIC_KEY: &str = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+    const MESSAGE: [u8; 1] = [0x72];
+    const SIGNATURE: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+    fn key() -> Ed25519PubKey {
+        hex::decode(PUBLIC_KEY)
+            .expect("hard coded key is valid hex")
+            .try_into()
+            .expect("hard coded key is valid")
+    }
+
+    fn signature() -> Vec<u8> {
+        hex::decode(SIGNATURE).expect("hard coded signature is valid hex")
+    }
+
+    #[test]
+    fn verify_signature_works() -> Result<(), SigningError> {
+        key().verify_signature(MESSAGE, signature())
+    }
+
+    #[test]
+    fn verify_signature_tampered_message_fails() {
+        let err = key()
+            .verify_signature([0x73], signature())
+            .expect_err("message was tampered with");
+
+        assert_eq!(err, SigningError::IncorrectSignature);
+    }
+
+    #[test]
+    fn verify_signature_wrong_key_length_fails() {
+        let key: Ed25519PubKey = vec![1; 31].try_into().expect("length is not validated");
+
+        let err = key
+            .verify_signature(MESSAGE, signature())
+            .expect_err("key has wrong length");
+
+        assert_eq!(err, SigningError::InvalidPublicKey);
+    }
+}
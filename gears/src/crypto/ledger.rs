@@ -10,49 +10,255 @@ use super::{
 
 pub type LedgerError = ledger_cosmos::Error;
 
-pub struct LedgerProxyKey {
-    app: CosmosValidatorApp,
+/// APDU status of the Cosmos app when the user rejects the transaction on the device
+const APDU_CODE_COMMAND_NOT_ALLOWED: u16 = 0x6986;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LedgerSigningError {
+    #[error("ledger device error, make sure the device is connected and unlocked and the Cosmos app is open: {0}")]
+    Device(#[from] LedgerError),
+    #[error("transaction was rejected on the ledger device")]
+    Rejected,
+    #[error("ledger device returned an invalid signature")]
+    InvalidSignature,
+}
+
+impl LedgerSigningError {
+    /// Distinguishes the user rejecting the transaction from other device errors
+    fn from_device(e: LedgerError) -> Self {
+        match &e {
+            LedgerError::Ledger(ledger::Error::Apdu(status))
+                if apdu_status(APDU_CODE_COMMAND_NOT_ALLOWED) == Some(*status) =>
+            {
+                Self::Rejected
+            }
+            _ => Self::Device(e),
+        }
+    }
+}
+
+/// The ledger transport reports APDU errors by the description of their status code
+fn apdu_status(code: u16) -> Option<&'static str> {
+    match ledger::map_apdu_error(code) {
+        ledger::Error::Apdu(status) => Some(status),
+        _ => None,
+    }
+}
+
+/// Device running the Cosmos ledger app
+pub trait LedgerDevice {
+    /// Returns the compressed secp256k1 public key of the device
+    fn public_key(&self) -> Result<Vec<u8>, LedgerError>;
+
+    /// Displays the message on the device and returns its DER encoded signature once the user
+    /// approves it
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, LedgerSigningError>;
+}
+
+impl LedgerDevice for CosmosValidatorApp {
+    fn public_key(&self) -> Result<Vec<u8>, LedgerError> {
+        Ok(self.public_key_secp256k1()?.to_vec())
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, LedgerSigningError> {
+        Ok(self
+            .sign_v2(message)
+            .map_err(LedgerSigningError::from_device)?
+            .to_vec())
+    }
+}
+
+pub struct LedgerProxyKey<D = CosmosValidatorApp> {
+    device: D,
     address: AccAddress,
     public_key: Secp256k1PubKey,
 }
 
 impl LedgerProxyKey {
     pub fn new() -> Result<Self, LedgerError> {
-        let app = CosmosValidatorApp::connect()?;
-        let pub_key_raw = app.public_key_secp256k1()?;
-        let public_key = Secp256k1PubKey::try_from(pub_key_raw.to_vec())
-            .map_err(|_| ledger_cosmos::Error::InvalidPK)?;
+        Self::with_device(CosmosValidatorApp::connect()?)
+    }
+}
+
+impl<D: LedgerDevice> LedgerProxyKey<D> {
+    pub fn with_device(device: D) -> Result<Self, LedgerError> {
+        let pub_key_raw = device.public_key()?;
+        let public_key =
+            Secp256k1PubKey::try_from(pub_key_raw).map_err(|_| ledger_cosmos::Error::InvalidPK)?;
         let address = public_key.get_address();
         Ok(Self {
-            app,
+            device,
             address,
             public_key,
         })
     }
 }
 
-impl ReadAccAddress for LedgerProxyKey {
+impl<D> ReadAccAddress for LedgerProxyKey<D> {
     fn get_address(&self) -> AccAddress {
         self.address.clone()
     }
 }
 
 // TODO: find better name without gears part
-impl GearsPublicKey for LedgerProxyKey {
+impl<D> GearsPublicKey for LedgerProxyKey<D> {
     fn get_gears_public_key(&self) -> PublicKey {
         PublicKey::Secp256k1(self.public_key.clone())
     }
 }
 
-impl SigningKey for LedgerProxyKey {
-    type Error = LedgerError;
+impl<D: LedgerDevice> SigningKey for LedgerProxyKey<D> {
+    type Error = LedgerSigningError;
 
+    /// Sends the sign bytes to the device and waits for the user to approve them
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Self::Error> {
-        let der_sig = self.app.sign_v2(message)?;
+        let der_sig = self.device.sign(message)?;
 
-        // convert signature from DER to BER
+        // convert signature from DER to compact
         let signature = secp256k1::ecdsa::Signature::from_der(&der_sig)
-            .map_err(|_| ledger_cosmos::Error::InvalidPK)?;
+            .map_err(|_| LedgerSigningError::InvalidSignature)?;
         Ok(signature.serialize_compact().to_vec())
     }
 }
+
+#[cfg(test)]
+pub(crate) mod mock {
+    use std::sync::{Arc, Mutex};
+
+    use extensions::testing::UnwrapTesting;
+    use secp256k1::{hashes::sha256, Message, Secp256k1, SecretKey};
+
+    use super::{LedgerDevice, LedgerError, LedgerSigningError, APDU_CODE_COMMAND_NOT_ALLOWED};
+
+    /// Signs with a fixed key and records every message sent to the device
+    pub struct MockDevice {
+        secret_key: SecretKey,
+        pub signed: Arc<Mutex<Vec<Vec<u8>>>>,
+        /// Rejects every transaction as the user would on the device
+        pub reject: bool,
+    }
+
+    impl MockDevice {
+        pub fn new(secret_key: [u8; 32]) -> Self {
+            Self {
+                secret_key: SecretKey::from_slice(&secret_key).unwrap_test(),
+                signed: Default::default(),
+                reject: false,
+            }
+        }
+    }
+
+    impl LedgerDevice for MockDevice {
+        fn public_key(&self) -> Result<Vec<u8>, LedgerError> {
+            Ok(self
+                .secret_key
+                .public_key(&Secp256k1::signing_only())
+                .serialize()
+                .to_vec())
+        }
+
+        fn sign(&self, message: &[u8]) -> Result<Vec<u8>, LedgerSigningError> {
+            self.signed.lock().unwrap_test().push(message.to_vec());
+
+            if self.reject {
+                return Err(LedgerSigningError::from_device(
+                    ledger::map_apdu_error(APDU_CODE_COMMAND_NOT_ALLOWED).into(),
+                ));
+            }
+
+            let message = Message::from_hashed_data::<sha256::Hash>(message);
+            Ok(Secp256k1::signing_only()
+                .sign_ecdsa(&message, &self.secret_key)
+                .serialize_der()
+                .to_vec())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core_types::{signing::SignDoc, Protobuf};
+    use extensions::testing::UnwrapTesting;
+    use prost::Message as _;
+    use tendermint::types::chain_id::ChainId;
+    use vec1::vec1;
+
+    use crate::{
+        crypto::info::{create_signed_transaction_direct, SigningInfo},
+        types::{auth::fee::Fee, msg::send::MsgSend, tx::body::TxBody},
+    };
+
+    use super::{mock::MockDevice, *};
+
+    #[test]
+    fn sign_sends_sign_doc_bytes_to_device() {
+        let key = LedgerProxyKey::with_device(MockDevice::new([0xcd; 32])).unwrap_test();
+
+        let body = TxBody::new_with_defaults(vec1![MsgSend {
+            from_address: key.get_address(),
+            to_address: key.get_address(),
+            amount: "10uatom".parse().unwrap_test(),
+        }]);
+        let fee = Fee {
+            amount: None,
+            gas_limit: 200_000_u32.into(),
+            payer: None,
+            granter: String::new(),
+        };
+        let signing_info = SigningInfo {
+            key: &key,
+            sequence: 3,
+            account_number: 7,
+        };
+
+        let tx = create_signed_transaction_direct(
+            vec![signing_info],
+            ChainId::default(),
+            fee,
+            None,
+            body,
+        )
+        .unwrap_test();
+
+        let expected = SignDoc {
+            body_bytes: tx.body.encode_vec(),
+            auth_info_bytes: tx.auth_info.encode_vec(),
+            chain_id: ChainId::default().into(),
+            account_number: 7,
+        }
+        .encode_to_vec();
+
+        assert_eq!(
+            *key.device.signed.lock().unwrap_test(),
+            vec![expected.clone()]
+        );
+        key.public_key
+            .verify_signature(&expected, &tx.signatures[0])
+            .unwrap_test();
+    }
+
+    #[test]
+    fn sign_rejected_on_device() {
+        let mut device = MockDevice::new([0xcd; 32]);
+        device.reject = true;
+        let key = LedgerProxyKey::with_device(device).unwrap_test();
+
+        let err = SigningKey::sign(&key, b"sign bytes").expect_err("user rejected the tx");
+
+        assert!(matches!(err, LedgerSigningError::Rejected));
+    }
+
+    #[test]
+    fn other_device_errors_are_not_rejections() {
+        for e in [
+            ledger::map_apdu_error(0x6A80).into(),
+            ledger::Error::DeviceNotFound.into(),
+            LedgerError::NoSignature,
+        ] {
+            assert!(matches!(
+                LedgerSigningError::from_device(e),
+                LedgerSigningError::Device(_)
+            ));
+        }
+    }
+}
@@ -127,9 +127,58 @@ impl<'de> de::Visitor<'de> for Secp256k1Visitor {
 
 #[cfg(test)]
 mod tests {
+    use secp256k1::SecretKey;
 
     use super::*;
 
+    fn sign(secret_key: &SecretKey, message: &[u8]) -> [u8; 64] {
+        let message = Message::from_hashed_data::<sha256::Hash>(message);
+        Secp256k1::signing_only()
+            .sign_ecdsa(&message, secret_key)
+            .serialize_compact()
+    }
+
+    fn key_pair() -> (SecretKey, Secp256k1PubKey) {
+        let secret_key = SecretKey::from_slice(&[0xcd; 32]).expect("hardcoded key is valid");
+        let key = secret_key.public_key(&Secp256k1::signing_only());
+
+        (secret_key, Secp256k1PubKey { key })
+    }
+
+    #[test]
+    fn verify_signature_generated_key() {
+        let (secret_key, public_key) = key_pair();
+        let signature = sign(&secret_key, b"message");
+
+        public_key
+            .verify_signature(b"message", signature)
+            .expect("signature is valid");
+    }
+
+    #[test]
+    fn verify_signature_tampered_message() {
+        let (secret_key, public_key) = key_pair();
+        let signature = sign(&secret_key, b"message");
+
+        let err = public_key
+            .verify_signature(b"tampered message", signature)
+            .expect_err("message was tampered with");
+
+        assert_eq!(err, SigningError::IncorrectSignature);
+    }
+
+    #[test]
+    fn verify_signature_malformed_signature() {
+        let (secret_key, public_key) = key_pair();
+        let signature = sign(&secret_key, b"message");
+
+        let err = public_key
+            .verify_signature(b"message", &signature[..63])
+            .expect_err("signature is too short");
+
+        assert_eq!(err, SigningError::InvalidSignature);
+    }
+
     #[test]
     fn deserialize_works() {
         let _: Secp256k1PubKey = serde_json::from_str(
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 use address::AddressError;
 use core_types::errors::CoreError;
 use cosmwasm_std::Decimal256RangeExceeded;
@@ -13,17 +15,52 @@ pub const POISONED_LOCK: &str = "poisoned lock";
 
 #[derive(Debug, thiserror::Error)]
 pub enum NumericError {
-    #[error("overflow on {0}")]
-    Overflow(MathOperation),
-    #[error("{0}")]
-    DecimalRange(#[from] Decimal256RangeExceeded),
+    #[error("overflow on {operation} of {lhs} and {rhs}")]
+    Overflow {
+        operation: MathOperation,
+        lhs: String,
+        rhs: String,
+    },
+    #[error("decimal out of range for `{field}`: {source}")]
+    DecimalRange {
+        field: String,
+        source: Decimal256RangeExceeded,
+    },
+}
+
+impl NumericError {
+    pub fn overflow(operation: MathOperation, lhs: impl Display, rhs: impl Display) -> Self {
+        Self::Overflow {
+            operation,
+            lhs: lhs.to_string(),
+            rhs: rhs.to_string(),
+        }
+    }
+
+    pub fn decimal_range(field: impl Into<String>, source: Decimal256RangeExceeded) -> Self {
+        Self::DecimalRange {
+            field: field.into(),
+            source,
+        }
+    }
 }
 
 impl Clone for NumericError {
     fn clone(&self) -> Self {
         match self {
-            Self::Overflow(arg0) => Self::Overflow(arg0.clone()),
-            Self::DecimalRange(_) => Self::DecimalRange(Decimal256RangeExceeded), // Why ZST is not clonable... Why?
+            Self::Overflow {
+                operation,
+                lhs,
+                rhs,
+            } => Self::Overflow {
+                operation: operation.clone(),
+                lhs: lhs.clone(),
+                rhs: rhs.clone(),
+            },
+            Self::DecimalRange { field, source: _ } => Self::DecimalRange {
+                field: field.clone(),
+                source: Decimal256RangeExceeded, // Why ZST is not clonable... Why?
+            },
         }
     }
 }
@@ -51,7 +88,9 @@ pub enum ProtobufError {
     #[error("{0}")]
     Denom(#[from] DenomError),
     #[error(transparent)]
-    Core(#[from] CoreError),
+    Core(CoreError),
+    #[error("decode error: {0}")]
+    Decode(#[from] prost::DecodeError),
     #[error("decode adress error: {0}")]
     Tendermint(#[from] TendermintError),
     #[error("decode adress error: {0}")]
@@ -62,6 +101,15 @@ pub enum ProtobufError {
     Custom(#[from] anyhow::Error),
 }
 
+impl From<CoreError> for ProtobufError {
+    fn from(e: CoreError) -> Self {
+        match e {
+            CoreError::Decode(e) => Self::Decode(e),
+            e => Self::Core(e),
+        }
+    }
+}
+
 impl From<ProtobufError> for tonic::Status {
     fn from(e: ProtobufError) -> Self {
         tonic::Status::invalid_argument(format!("{:?}", e))
@@ -73,3 +121,47 @@ impl From<std::convert::Infallible> for ProtobufError {
         unreachable!("who would return infallible error?")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Decimal256, Uint256};
+    use prost::Message;
+
+    use super::*;
+
+    #[test]
+    fn decimal_range_names_field() {
+        let err = Decimal256::from_atomics(Uint256::MAX, 0)
+            .map_err(|e| NumericError::decimal_range("commission_rate", e))
+            .expect_err("value is out of range");
+
+        assert!(err.to_string().contains("`commission_rate`"));
+    }
+
+    #[test]
+    fn malformed_bytes_map_to_decode() {
+        // field 1 declares a length of 5 bytes but no bytes follow
+        let err = core_types::base::coin::Coin::decode([0x0a, 0x05].as_slice())
+            .expect_err("bytes are malformed");
+
+        assert!(matches!(
+            ProtobufError::from(err.clone()),
+            ProtobufError::Decode(_)
+        ));
+        assert!(matches!(
+            ProtobufError::from(CoreError::Decode(err.clone())),
+            ProtobufError::Decode(_)
+        ));
+        assert_eq!(
+            tonic::Status::from(ProtobufError::from(err)).code(),
+            tonic::Code::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn core_errors_stay_core() {
+        let err = ProtobufError::from(CoreError::MissingField("amount".to_owned()));
+
+        assert!(matches!(err, ProtobufError::Core(_)));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_server::Service as HealthService;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_server::ServiceServer as HealthServer;
 use ibc_proto::cosmos::base::tendermint::v1beta1::{
@@ -6,9 +8,16 @@ use ibc_proto::cosmos::base::tendermint::v1beta1::{
     GetLatestValidatorSetResponse, GetNodeInfoRequest, GetNodeInfoResponse, GetSyncingRequest,
     GetSyncingResponse, GetValidatorSetByHeightRequest, GetValidatorSetByHeightResponse,
 };
+use tonic::codegen::BoxStream;
+use tonic::server::NamedService;
 use tonic::{Request, Response, Status};
+use tonic_health::pb::health_check_response::ServingStatus;
+use tonic_health::pb::health_server::{Health, HealthServer as HealthCheckServer};
+use tonic_health::pb::{HealthCheckRequest, HealthCheckResponse};
 use tracing::info;
 
+use crate::baseapp::NodeHealthHandler;
+
 pub struct GearsHealthService;
 
 #[tonic::async_trait]
@@ -74,3 +83,129 @@ impl HealthService for GearsHealthService {
 pub fn health_server() -> HealthServer<GearsHealthService> {
     HealthServer::new(GearsHealthService)
 }
+
+/// Implements the standard `grpc.health.v1.Health` service. An empty service name reports the
+/// liveness of the process, the name of a served service reports whether the node is ready to
+/// serve queries, i.e. it has committed a block and its store is readable.
+pub struct HealthCheckService<App> {
+    app: App,
+    services: HashSet<&'static str>,
+}
+
+#[tonic::async_trait]
+impl<App: NodeHealthHandler> Health for HealthCheckService<App> {
+    async fn check(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<HealthCheckResponse>, Status> {
+        info!("Received a gRPC request health::check");
+
+        let HealthCheckRequest { service } = request.into_inner();
+
+        let status = if service.is_empty() {
+            ServingStatus::Serving
+        } else if !self.services.contains(service.as_str()) {
+            return Err(Status::not_found(format!("unknown service: {service}")));
+        } else if self.app.is_ready() {
+            ServingStatus::Serving
+        } else {
+            ServingStatus::NotServing
+        };
+
+        Ok(Response::new(HealthCheckResponse {
+            status: status.into(),
+        }))
+    }
+
+    type WatchStream = BoxStream<HealthCheckResponse>;
+
+    async fn watch(
+        &self,
+        _request: Request<HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        Err(Status::unimplemented(
+            "health status watching is not supported",
+        ))
+    }
+}
+
+/// `services` are the names of the other services served by the node
+pub fn health_check_server<App: NodeHealthHandler>(
+    app: App,
+    services: impl IntoIterator<Item = &'static str>,
+) -> HealthCheckServer<HealthCheckService<App>> {
+    let mut services = services.into_iter().collect::<HashSet<_>>();
+    services.insert(HealthCheckServer::<HealthCheckService<App>>::NAME);
+
+    HealthCheckServer::new(HealthCheckService { app, services })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+
+    use crate::runtime::runtime;
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Default)]
+    struct MockApp {
+        ready: Arc<AtomicBool>,
+    }
+
+    impl NodeHealthHandler for MockApp {
+        fn is_ready(&self) -> bool {
+            self.ready.load(Ordering::Acquire)
+        }
+    }
+
+    fn check(service: &HealthCheckService<MockApp>, name: &str) -> Result<ServingStatus, Status> {
+        let res = runtime().block_on(service.check(Request::new(HealthCheckRequest {
+            service: name.to_owned(),
+        })))?;
+
+        Ok(ServingStatus::try_from(res.into_inner().status).unwrap_test())
+    }
+
+    fn service(app: MockApp) -> HealthCheckService<MockApp> {
+        HealthCheckService {
+            app,
+            services: HashSet::from(["cosmos.bank.v1beta1.Query"]),
+        }
+    }
+
+    #[test]
+    fn readiness_reported_after_first_commit() {
+        let app = MockApp::default();
+        let service = service(app.clone());
+
+        assert_eq!(check(&service, "").unwrap_test(), ServingStatus::Serving);
+        assert_eq!(
+            check(&service, "cosmos.bank.v1beta1.Query").unwrap_test(),
+            ServingStatus::NotServing
+        );
+
+        app.ready.store(true, Ordering::Release);
+
+        assert_eq!(check(&service, "").unwrap_test(), ServingStatus::Serving);
+        assert_eq!(
+            check(&service, "cosmos.bank.v1beta1.Query").unwrap_test(),
+            ServingStatus::Serving
+        );
+    }
+
+    #[test]
+    fn unknown_service_not_found() {
+        let app = MockApp::default();
+        app.ready.store(true, Ordering::Release);
+
+        let err =
+            check(&service(app), "cosmos.unknown.v1beta1.Query").expect_err("service isn't served");
+
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+}
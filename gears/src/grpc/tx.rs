@@ -1,3 +1,6 @@
+use std::marker::PhantomData;
+
+use ibc_proto::cosmos::base::abci::v1beta1::{GasInfo, Result as AbciResult};
 use ibc_proto::cosmos::tx::v1beta1::service_server::Service;
 use ibc_proto::cosmos::tx::v1beta1::service_server::ServiceServer as TxServer;
 use ibc_proto::cosmos::tx::v1beta1::{
@@ -5,22 +8,69 @@ use ibc_proto::cosmos::tx::v1beta1::{
     GetTxRequest, GetTxResponse, GetTxsEventRequest, GetTxsEventResponse, SimulateRequest,
     SimulateResponse,
 };
+use tendermint::rpc::client::HttpClientUrl;
 use tonic::{Request, Response, Status};
 use tracing::info;
 
-pub struct TxService;
+use crate::baseapp::{NodeSimulateHandler, RunTxInfo};
+use crate::types::gas::Gas;
+use crate::types::tx::TxMessage;
+
+// searching txs by events relies on the tx queries of the xmods
+#[cfg(feature = "xmods")]
+use {
+    crate::types::pagination::request::QUERY_DEFAULT_LIMIT,
+    crate::types::response::{any::AnyTx, tx::TxResponse},
+    crate::x::query::tx_query::{search_txs_by_event, txs_event_query},
+    crate::x::query::types::QueryGetTxsEventRequest,
+    ibc_proto::cosmos::base::abci::v1beta1::TxResponse as RawTxResponse,
+    ibc_proto::cosmos::tx::v1beta1::OrderBy,
+    tendermint::rpc::client::HttpClient,
+    tendermint::rpc::url::Url,
+    tendermint::types::proto::event::Event,
+};
+
+pub struct TxService<App, M> {
+    app: App,
+    tendermint_rpc_address: HttpClientUrl,
+    _phantom: PhantomData<M>,
+}
 
 #[tonic::async_trait]
-impl Service for TxService {
+impl<App: NodeSimulateHandler, M: TxMessage> Service for TxService<App, M> {
     async fn simulate(
         &self,
-        _request: Request<SimulateRequest>,
+        request: Request<SimulateRequest>,
     ) -> Result<Response<SimulateResponse>, Status> {
         info!("Received a gRPC request tx::simulate");
-        // TODO: run simulation once implemented
+
+        let SimulateRequest { tx_bytes, .. } = request.into_inner();
+        if tx_bytes.is_empty() {
+            return Err(Status::invalid_argument("empty tx_bytes is not allowed"));
+        }
+
+        let RunTxInfo {
+            events,
+            gas_wanted,
+            gas_used,
+        } = self
+            .app
+            .simulate_tx(tx_bytes.into())
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
         Ok(Response::new(SimulateResponse {
-            gas_info: None,
-            result: None,
+            gas_info: Some(GasInfo {
+                // consistent with the SDK where the limit of an infinite gas meter is `u64::MAX`
+                gas_wanted: match gas_wanted {
+                    Gas::Infinite => u64::MAX,
+                    Gas::Finite(gas) => gas.into(),
+                },
+                gas_used: gas_used.into(),
+            }),
+            result: Some(AbciResult {
+                events: events.into_iter().map(Into::into).collect(),
+                ..Default::default()
+            }),
         }))
     }
 
@@ -42,10 +92,13 @@ impl Service for TxService {
 
     async fn get_txs_event(
         &self,
-        _request: Request<GetTxsEventRequest>,
+        request: Request<GetTxsEventRequest>,
     ) -> Result<Response<GetTxsEventResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_txs_event");
+
+        search_txs::<M>(&self.tendermint_rpc_address, request.into_inner())
+            .await
+            .map(Response::new)
     }
 
     async fn get_block_with_txs(
@@ -57,6 +110,125 @@ impl Service for TxService {
     }
 }
 
-pub fn tx_server() -> TxServer<TxService> {
-    TxServer::new(TxService)
+pub fn tx_server<App: NodeSimulateHandler, M: TxMessage>(
+    app: App,
+    tendermint_rpc_address: HttpClientUrl,
+) -> TxServer<TxService<App, M>> {
+    TxServer::new(TxService {
+        app,
+        tendermint_rpc_address,
+        _phantom: PhantomData,
+    })
+}
+
+/// Searches the txs indexed by Tendermint which match all the events of the request
+#[cfg(feature = "xmods")]
+async fn search_txs<M: TxMessage>(
+    tendermint_rpc_address: &HttpClientUrl,
+    request: GetTxsEventRequest,
+) -> Result<GetTxsEventResponse, Status> {
+    let GetTxsEventRequest {
+        events,
+        order_by,
+        page,
+        limit,
+        ..
+    } = request;
+
+    if events.is_empty() {
+        return Err(Status::invalid_argument(
+            "must declare at least one event to search",
+        ));
+    }
+    txs_event_query(&events).map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+    let req = QueryGetTxsEventRequest {
+        events,
+        order_by: if order_by == OrderBy::Desc as i32 {
+            "desc".to_owned()
+        } else {
+            "asc".to_owned()
+        },
+        page: match page {
+            0 => 1,
+            page => page.try_into().unwrap_or(u32::MAX),
+        },
+        limit: match limit {
+            0 => QUERY_DEFAULT_LIMIT.into(),
+            limit => limit.try_into().unwrap_or(u32::MAX),
+        },
+    };
+
+    let client = HttpClient::new::<Url>(tendermint_rpc_address.clone().into())
+        .map_err(|e| Status::internal(e.to_string()))?;
+    let res = search_txs_by_event::<M, _>(&client, &req)
+        .await
+        .map_err(|e| {
+            tracing::error!("Error searching txs in Tendermint: {e}");
+            Status::unavailable("failed to search txs in Tendermint")
+        })?;
+
+    let mut txs = Vec::with_capacity(res.txs.len());
+    let mut tx_responses = Vec::with_capacity(res.txs.len());
+    for tx_response in res.txs {
+        let AnyTx::Tx(tx) = tx_response.tx.clone();
+        txs.push(tx.into());
+        tx_responses.push(raw_tx_response(tx_response));
+    }
+
+    Ok(GetTxsEventResponse {
+        txs,
+        tx_responses,
+        total: res.total_count,
+        ..Default::default()
+    })
+}
+
+#[cfg(not(feature = "xmods"))]
+async fn search_txs<M: TxMessage>(
+    _tendermint_rpc_address: &HttpClientUrl,
+    _request: GetTxsEventRequest,
+) -> Result<GetTxsEventResponse, Status> {
+    Err(Status::unimplemented(
+        "searching txs by events requires the `xmods` feature",
+    ))
+}
+
+#[cfg(feature = "xmods")]
+fn raw_tx_response<M: TxMessage>(
+    TxResponse {
+        height,
+        txhash,
+        codespace,
+        code,
+        data,
+        raw_log,
+        logs: _,
+        info,
+        gas_wanted,
+        gas_used,
+        tx,
+        timestamp,
+        events,
+    }: TxResponse<M>,
+) -> RawTxResponse {
+    RawTxResponse {
+        height,
+        txhash,
+        codespace,
+        code,
+        data,
+        raw_log,
+        // logs are only kept as a raw string
+        logs: Vec::new(),
+        info,
+        gas_wanted,
+        gas_used,
+        tx: Some(tx.into()),
+        timestamp,
+        events: events
+            .into_iter()
+            .map(|event| Event::from(event).into())
+            .collect(),
+    }
 }
@@ -6,7 +6,6 @@ use std::{
 };
 
 use database::{prefix::PrefixDB, Database};
-use extensions::corruption::UnwrapCorrupt;
 use kv_store::StoreKey;
 
 use crate::context::{InfallibleContext, InfallibleContextMut};
@@ -53,6 +52,16 @@ pub fn infallible_subspace_mut<
 #[error("error parsing subpsace: {0}")]
 pub struct SubspaceParseError(pub String);
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParamsError {
+    #[error("missing param `{0}`")]
+    MissingKey(&'static str),
+    #[error("param `{0}` is not valid utf-8")]
+    InvalidUtf8(&'static str),
+    #[error("failed to parse param `{key}`: {reason}")]
+    Parse { key: &'static str, reason: String },
+}
+
 pub trait ParamsSubspaceKey: Hash + Eq + Clone + Send + Sync + 'static {
     fn name(&self) -> &'static str;
 
@@ -91,19 +100,20 @@ pub enum ParamKind {
 
 impl ParamKind {
     pub fn parse_param(self, bytes: Vec<u8>) -> Params {
-        fn parse_primitive_bytes<T: FromStr>(value: Vec<u8>) -> T
-        where
-            <T as FromStr>::Err: std::fmt::Debug,
-        {
-            let value = String::from_utf8(value).expect("should be valid utf-8");
-
-            value
-                .strip_suffix('\"')
-                .and_then(|this| this.strip_prefix('\"'))
-                .map(String::from)
-                .unwrap_or(value)
-                .parse()
-                .unwrap_or_corrupt()
+        fn parse_primitive_bytes<T: FromStr>(bytes: Vec<u8>, kind: fn(T) -> Params) -> Params {
+            let parsed = std::str::from_utf8(&bytes).ok().and_then(|value| {
+                value
+                    .strip_suffix('\"')
+                    .and_then(|this| this.strip_prefix('\"'))
+                    .unwrap_or(value)
+                    .parse()
+                    .ok()
+            });
+
+            match parsed {
+                Some(var) => kind(var),
+                None => Params::InvalidCast(bytes),
+            }
         }
 
         match self {
@@ -116,14 +126,14 @@ impl ParamKind {
                 Ok(var) => Params::Bool(var),
                 Err(_) => Params::InvalidCast(bytes),
             },
-            ParamKind::U64 => Params::U64(parse_primitive_bytes(bytes)),
-            ParamKind::I64 => Params::I64(parse_primitive_bytes(bytes)),
-            ParamKind::U32 => Params::U32(parse_primitive_bytes(bytes)),
-            ParamKind::I32 => Params::I32(parse_primitive_bytes(bytes)),
-            ParamKind::U16 => Params::U16(parse_primitive_bytes(bytes)),
-            ParamKind::I16 => Params::I16(parse_primitive_bytes(bytes)),
-            ParamKind::U8 => Params::U8(parse_primitive_bytes(bytes)),
-            ParamKind::I8 => Params::I8(parse_primitive_bytes(bytes)),
+            ParamKind::U64 => parse_primitive_bytes(bytes, Params::U64),
+            ParamKind::I64 => parse_primitive_bytes(bytes, Params::I64),
+            ParamKind::U32 => parse_primitive_bytes(bytes, Params::U32),
+            ParamKind::I32 => parse_primitive_bytes(bytes, Params::I32),
+            ParamKind::U16 => parse_primitive_bytes(bytes, Params::U16),
+            ParamKind::I16 => parse_primitive_bytes(bytes, Params::I16),
+            ParamKind::U8 => parse_primitive_bytes(bytes, Params::U8),
+            ParamKind::I8 => parse_primitive_bytes(bytes, Params::I8),
         }
     }
 }
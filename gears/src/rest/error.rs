@@ -11,16 +11,13 @@ pub struct HTTPError {
     description: String, // set to (unless overridden): https://github.com/SergioBenitez/Rocket/blob/91f6288ea4aeb3d5a502b2f18b2b9677a85463ea/core/lib/src/catcher/catcher.rs#L369-L416
 }
 
-#[derive(Serialize)]
-struct PrintErrorCore {
-    pub code: u16,
-    pub reason: String,
-    pub description: String,
-}
-
+/// Error body in the same shape as the one returned by the Cosmos SDK gRPC gateway.
 #[derive(Serialize)]
 struct PrintError {
-    error: PrintErrorCore,
+    /// gRPC status code corresponding to the HTTP status
+    code: u32,
+    message: String,
+    details: Vec<serde_json::Value>,
 }
 
 impl HTTPError {
@@ -74,17 +71,23 @@ impl HTTPError {
         }
     }
 
+    /// Maps the HTTP status to a gRPC status code as done by the gRPC gateway.
+    fn grpc_code(&self) -> u32 {
+        match self.status {
+            StatusCode::BAD_REQUEST => 3,            // INVALID_ARGUMENT
+            StatusCode::GATEWAY_TIMEOUT => 4,        // DEADLINE_EXCEEDED
+            StatusCode::NOT_FOUND => 5,              // NOT_FOUND
+            StatusCode::INTERNAL_SERVER_ERROR => 13, // INTERNAL
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE => 14, // UNAVAILABLE
+            _ => 2,                                  // UNKNOWN
+        }
+    }
+
     fn to_serializable(self) -> PrintError {
         PrintError {
-            error: PrintErrorCore {
-                code: self.status.as_u16(),
-                reason: self
-                    .status
-                    .canonical_reason()
-                    .unwrap_or_default()
-                    .to_string(),
-                description: self.description,
-            },
+            code: self.grpc_code(),
+            message: self.description,
+            details: Vec::new(),
         }
     }
 }
@@ -101,7 +104,70 @@ impl From<QueryError> for HTTPError {
             QueryError::Store(_) => {
                 HTTPError::not_found_with_msg("The requested version could not be found.".into())
             }
-            _ => HTTPError::bad_request("Invalid request.".to_owned()), // TODO: Don't forget to add more info later
+            QueryError::PathNotFound
+            | QueryError::BlockEventsNotFound(_)
+            | QueryError::HeightNotAvailable(_)
+            | QueryError::NotFound(_) => HTTPError::not_found_with_msg(err.to_string()),
+            QueryError::InvalidHeight | QueryError::Proto(_) => {
+                HTTPError::bad_request(err.to_string())
+            }
+            QueryError::TODO(_) => HTTPError::internal_server_error(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response_parts(err: HTTPError) -> (StatusCode, serde_json::Value) {
+        let response = err.into_response();
+        let status = response.status();
+        let body = crate::runtime::runtime()
+            .block_on(axum::body::to_bytes(response.into_body(), usize::MAX))
+            .expect("body is in memory");
+
+        (
+            status,
+            serde_json::from_slice(&body).expect("body is valid json"),
+        )
+    }
+
+    #[test]
+    fn error_body_is_json_problem_object() {
+        let (status, body) = response_parts(HTTPError::bad_request("invalid address".to_owned()));
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body,
+            serde_json::json!({ "code": 3, "message": "invalid address", "details": [] })
+        );
+
+        let (status, body) = response_parts(HTTPError::not_found());
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], 5);
+
+        let (status, body) = response_parts(HTTPError::internal_server_error());
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(body["code"], 13);
+    }
+
+    #[test]
+    fn query_error_maps_to_status() {
+        let status = |err: QueryError| HTTPError::from(err).status;
+
+        assert_eq!(status(QueryError::PathNotFound), StatusCode::NOT_FOUND);
+        assert_eq!(status(QueryError::InvalidHeight), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            status(QueryError::HeightNotAvailable(10)),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(QueryError::NotFound("client not found".to_owned())),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            status(QueryError::TODO(anyhow::anyhow!("failure"))),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use crate::baseapp::HeightQueryRequest;
+
+/// Optional `?height=` query parameter which selects the state a query is run against
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Default, Eq, PartialEq)]
+pub struct QueryHeight {
+    /// height of the block whose state should be queried. Zero or absent means latest.
+    height: Option<u32>,
+}
+
+impl QueryHeight {
+    pub fn new(height: Option<u32>) -> Self {
+        Self { height }
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height.unwrap_or_default()
+    }
+
+    /// Pin `request` to the requested height
+    pub fn apply<QReq: HeightQueryRequest>(&self, request: QReq) -> QReq {
+        request.with_height(self.height())
+    }
+}
@@ -1,8 +1,10 @@
 pub mod error;
 mod handlers;
+mod height;
 mod pagination;
 mod rest;
 pub mod tendermint_events_handler;
 
+pub use height::*;
 pub use pagination::*;
 pub use rest::*;
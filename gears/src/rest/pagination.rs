@@ -15,11 +15,19 @@ pub struct Pagination {
     /// If left empty it will default to a value to be set by each app.
     #[serde(alias = "pagination.limit")]
     limit: Option<u8>,
+    /// count_total is set to true to indicate that the result set should include
+    /// a count of the total number of items available for pagination.
+    #[serde(alias = "pagination.count_total")]
+    count_total: Option<bool>,
 }
 
 impl Pagination {
     pub fn new(offset: Option<u32>, limit: Option<u8>) -> Pagination {
-        Pagination { offset, limit }
+        Pagination {
+            offset,
+            limit,
+            count_total: None,
+        }
     }
 }
 
@@ -31,6 +39,7 @@ impl From<Pagination> for PaginationRequest {
             limit,
             // the app page offset starts from 0
             kind: PaginationKind::Offset { offset: offset - 1 },
+            count_total: pagination.count_total.unwrap_or_default(),
         }
     }
 }
@@ -59,6 +68,7 @@ mod tests {
         let (page, limit) = parse_pagination(&Pagination {
             offset: Some(100),
             limit: Some(30),
+            count_total: None,
         });
 
         assert_eq!(page, 4);
@@ -67,6 +77,7 @@ mod tests {
         let (page, limit) = parse_pagination(&Pagination {
             offset: Some(100),
             limit: Some(0),
+            count_total: None,
         });
 
         assert_eq!(page, 2);
@@ -75,6 +86,7 @@ mod tests {
         let (page, limit) = parse_pagination(&Pagination {
             offset: None,
             limit: None,
+            count_total: None,
         });
 
         assert_eq!(page, 1);
@@ -6,9 +6,11 @@ use crate::{
     },
 };
 
-use crate::signing::renderer::value_renderer::{
-    DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
-    TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+use crate::signing::renderer::{
+    primitives::send_coins::CoinsField,
+    value_renderer::{
+        DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError, ValueRenderer,
+    },
 };
 
 impl ValueRenderer for MsgSend {
@@ -31,15 +33,14 @@ impl ValueRenderer for MsgSend {
             expert: false,
         });
 
-        screens_vec.push(Screen {
-            title: "Amount".to_string(),
-            content: DefaultPrimitiveRenderer::try_format_with_metadata(
-                self.amount.to_owned(),
-                get_metadata,
-            )?,
-            indent: Some(Indent::two()),
-            expert: false,
-        });
+        screens_vec.extend(
+            CoinsField {
+                title: "Amount",
+                coins: &self.amount,
+                indent: Some(Indent::two()),
+            }
+            .format(get_metadata)?,
+        );
 
         Ok(screens_vec)
     }
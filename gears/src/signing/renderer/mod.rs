@@ -10,10 +10,29 @@ pub(super) mod test_functions {
         signing::handler::MetadataGetter,
         types::{
             denom::Denom,
-            tx::metadata::{DenomUnit, Metadata},
+            rendering::screen::Screen,
+            tx::{
+                metadata::{DenomUnit, Metadata},
+                signer::SignerData,
+                Tx, TxMessage,
+            },
         },
     };
 
+    use super::{
+        tx::Envelope,
+        value_renderer::{RenderError, ValueRenderer},
+    };
+
+    /// Renders all the SIGN_MODE_TEXTUAL screens of a tx in the order they are signed over
+    pub fn render_tx_screens<M: TxMessage + ValueRenderer, MG: MetadataGetter>(
+        tx: &Tx<M>,
+        signer_data: SignerData,
+        get_metadata: &MG,
+    ) -> Result<Vec<Screen>, RenderError> {
+        Envelope::new(signer_data, &tx.body, &tx.auth_info).format(get_metadata)
+    }
+
     pub struct TestNoneMetadataGetter;
 
     impl MetadataGetter for TestNoneMetadataGetter {
@@ -74,3 +93,83 @@ pub(super) mod test_functions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use core_types::tx::mode_info::{ModeInfo, SignMode};
+    use extensions::testing::UnwrapTesting;
+    use tendermint::types::chain_id::ChainId;
+    use vec1::vec1;
+
+    use crate::types::{
+        address::AccAddress,
+        auth::{fee::Fee, info::AuthInfo},
+        base::coins::UnsignedCoins,
+        msg::send::MsgSend,
+        rendering::screen::Screen,
+        signing::SignerInfo,
+        tx::{body::TxBody, signer::SignerData, Tx},
+    };
+
+    use super::test_functions::{render_tx_screens, TestMetadataGetter};
+
+    const PUBLIC_KEY: &str = r#"{
+        "@type": "/cosmos.crypto.secp256k1.PubKey",
+        "key": "Auvdf+T963bciiBe9l15DNMOijdaXCUo6zqSOvH7TXlN"
+    }"#;
+
+    fn address(address: &str) -> AccAddress {
+        AccAddress::from_bech32(address).unwrap_test()
+    }
+
+    #[test]
+    fn msg_send_screens_match_fixture() {
+        let tx = Tx {
+            body: TxBody {
+                messages: vec1![MsgSend {
+                    from_address: address("cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs"),
+                    to_address: address("cosmos1ejrf4cur2wy6kfurg9f2jppp2h3afe5h6pkh5t"),
+                    amount: UnsignedCoins::new(vec!["10000000uatom".parse().unwrap_test()])
+                        .unwrap_test(),
+                }],
+                memo: String::new(),
+                timeout_height: 0,
+                extension_options: Vec::new(),
+                non_critical_extension_options: Vec::new(),
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![SignerInfo {
+                    public_key: Some(serde_json::from_str(PUBLIC_KEY).unwrap_test()),
+                    mode_info: ModeInfo::Single(SignMode::Textual),
+                    sequence: 2,
+                }],
+                fee: Fee {
+                    amount: Some(
+                        UnsignedCoins::new(vec!["2000uatom".parse().unwrap_test()]).unwrap_test(),
+                    ),
+                    gas_limit: 100_000_u32.into(),
+                    payer: None,
+                    granter: String::new(),
+                },
+                tip: None,
+            },
+            signatures: Vec::new(),
+            signatures_data: Vec::new(),
+        };
+        let signer_data = SignerData {
+            address: address("cosmos1ulav3hsenupswqfkw2y3sup5kgtqwnvqa8eyhs"),
+            chain_id: ChainId::from_str("my-chain").unwrap_test(),
+            account_number: 1,
+            sequence: 2,
+            pub_key: serde_json::from_str(PUBLIC_KEY).unwrap_test(),
+        };
+
+        let expected: Vec<Screen> =
+            serde_json::from_str(include_str!("fixtures/msg_send.json")).unwrap_test();
+        let actual = render_tx_screens(&tx, signer_data, &TestMetadataGetter).unwrap_test();
+
+        assert_eq!(expected, actual);
+    }
+}
@@ -18,12 +18,7 @@ impl TryPrimitiveValueRendererWithMetadata<UnsignedCoin> for DefaultPrimitiveRen
             RenderError::Rendering(format!("error getting metadata for {}: {e}", coin.denom))
         })?;
         let Some(metadata) = metadata else {
-            let display = coin.denom.to_string();
-            return Ok(Content::try_new(format!(
-                "{} {display}",
-                DefaultPrimitiveRenderer::format(coin.amount).into_inner()
-            ))
-            .expect("this String is not empty so it will never fail to parse"));
+            return Ok(format_base_denom(&coin));
         };
 
         let Metadata {
@@ -33,12 +28,7 @@ impl TryPrimitiveValueRendererWithMetadata<UnsignedCoin> for DefaultPrimitiveRen
         } = metadata;
 
         if display.is_empty() || coin.denom.to_string() == display {
-            let display = coin.denom.to_string();
-            return Ok(Content::try_new(format!(
-                "{} {display}",
-                DefaultPrimitiveRenderer::format(coin.amount).into_inner()
-            ))
-            .expect("this String is not empty so it will never fail to parse"));
+            return Ok(format_base_denom(&coin));
         }
 
         let coin_exp = denom_units.iter().find(|this| this.denom == coin.denom);
@@ -48,68 +38,89 @@ impl TryPrimitiveValueRendererWithMetadata<UnsignedCoin> for DefaultPrimitiveRen
 
         match (coin_exp, denom_exp) {
             (Some(coin_exp), Some(denom_exp)) => {
-                let formatted_amount = match coin_exp.exponent.cmp(&denom_exp.exponent) {
-                    std::cmp::Ordering::Less => {
-                        let power = denom_exp.exponent - coin_exp.exponent;
-
-                        let amount = Decimal256::from_atomics(coin.amount, 0).map_err(|_| {
-                            RenderError::Rendering(format!(
-                                "coin amounts greater than {} are not supported for this signing mode",
-                                Decimal256::MAX
-                            ))
-                        })?; //TODO: this is a deficiency of the Decimal256 type, it should be able to hold any Uint256 value
-                        let scaling = Uint256::from(10u32).checked_pow(power).map_err(|_| {
-                            RenderError::Rendering(format!(
-                                "{display} denom is not supported for this signing mode"
-                            ))
-                        })?;
-
-                        let disp_amount = amount / scaling; // TODO: what happens if scaling  > 10**18 causing amount to go to zero?
-
-                        DefaultPrimitiveRenderer::format(disp_amount).into_inner()
-                    }
-
-                    std::cmp::Ordering::Equal => {
-                        DefaultPrimitiveRenderer::format(coin.amount).into_inner()
-                    }
-                    std::cmp::Ordering::Greater => {
-                        // TODO: write test for this case
-
-                        let power = coin_exp.exponent - denom_exp.exponent;
-
-                        let scaling = Uint256::from(10u32).checked_pow(power).map_err(|_| {
-                            RenderError::Rendering(format!(
-                                "{display} denom is not supported for this signing mode"
-                            ))
-                        })?;
-
-                        let disp_amount = coin.amount.checked_mul(scaling).map_err(|_| {
-                            RenderError::Rendering(format!(
-                                "coin amounts greater than {} are not supported for this signing mode and denom {}",
-                                Uint256::MAX / scaling,
-                                display
-                            ))
-                        })?;
-
-                        DefaultPrimitiveRenderer::format(disp_amount).into_inner()
-                    }
-                };
+                let formatted_amount = format_display_amount(
+                    coin.amount,
+                    coin_exp.exponent,
+                    denom_exp.exponent,
+                    &display,
+                )?;
 
                 Ok(Content::try_new(format!("{formatted_amount} {display}"))
                     .expect("this String is not empty so it will never fail to parse"))
             }
-            _ => {
-                let display = coin.denom.to_string();
-                Ok(Content::try_new(format!(
-                    "{} {display}",
-                    DefaultPrimitiveRenderer::format(coin.amount).into_inner()
-                ))
-                .expect("this String is not empty so it will never fail to parse"))
-            }
+            _ => Ok(format_base_denom(&coin)),
         }
     }
 }
 
+/// Renders the coin in its base denom, used when there is no display denom to convert to.
+/// This also covers denoms without any registered metadata, which SIGN_MODE_TEXTUAL renders
+/// as `<amount> <base denom>` rather than rejecting the tx.
+fn format_base_denom(coin: &UnsignedCoin) -> Content {
+    Content::try_new(format!(
+        "{} {}",
+        DefaultPrimitiveRenderer::format(coin.amount).into_inner(),
+        coin.denom
+    ))
+    .expect("this String is not empty so it will never fail to parse")
+}
+
+/// Converts an amount of a denom with exponent `coin_exp` into an amount of the display denom
+/// with exponent `display_exp`
+fn format_display_amount(
+    amount: Uint256,
+    coin_exp: u32,
+    display_exp: u32,
+    display: &str,
+) -> Result<String, RenderError> {
+    let formatted_amount = match coin_exp.cmp(&display_exp) {
+        std::cmp::Ordering::Less => {
+            let power = display_exp - coin_exp;
+
+            let amount = Decimal256::from_atomics(amount, 0).map_err(|_| {
+                RenderError::Rendering(format!(
+                    "coin amounts greater than {} are not supported for this signing mode",
+                    Decimal256::MAX
+                ))
+            })?; //TODO: this is a deficiency of the Decimal256 type, it should be able to hold any Uint256 value
+            let scaling = Uint256::from(10u32).checked_pow(power).map_err(|_| {
+                RenderError::Rendering(format!(
+                    "{display} denom is not supported for this signing mode"
+                ))
+            })?;
+
+            let disp_amount = amount / scaling; // TODO: what happens if scaling  > 10**18 causing amount to go to zero?
+
+            DefaultPrimitiveRenderer::format(disp_amount).into_inner()
+        }
+
+        std::cmp::Ordering::Equal => DefaultPrimitiveRenderer::format(amount).into_inner(),
+        std::cmp::Ordering::Greater => {
+            // TODO: write test for this case
+
+            let power = coin_exp - display_exp;
+
+            let scaling = Uint256::from(10u32).checked_pow(power).map_err(|_| {
+                RenderError::Rendering(format!(
+                    "{display} denom is not supported for this signing mode"
+                ))
+            })?;
+
+            let disp_amount = amount.checked_mul(scaling).map_err(|_| {
+                RenderError::Rendering(format!(
+                    "coin amounts greater than {} are not supported for this signing mode and denom {}",
+                    Uint256::MAX / scaling,
+                    display
+                ))
+            })?;
+
+            DefaultPrimitiveRenderer::format(disp_amount).into_inner()
+        }
+    };
+
+    Ok(formatted_amount)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::signing::renderer::test_functions::TestMetadataGetter;
@@ -172,6 +183,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn coin_formatting_without_metadata_uses_base_denom() -> anyhow::Result<()> {
+        let coin = UnsignedCoin {
+            denom: "ustake".try_into()?,
+            amount: Uint256::from(1_000_000_u64),
+        };
+
+        let expected_content = Content::try_new("1'000'000 ustake".to_string()).unwrap_test();
+
+        let actual_content =
+            DefaultPrimitiveRenderer::try_format_with_metadata(coin, &TestMetadataGetter);
+
+        assert_eq!(expected_content, actual_content.unwrap_test());
+
+        Ok(())
+    }
+
     #[test]
     fn coin_formatting_large_amount_works() -> anyhow::Result<()> {
         let coin = UnsignedCoin {
@@ -9,6 +9,7 @@ pub mod decimal256;
 pub mod i64;
 pub mod send_coins;
 pub mod string;
+pub mod timestamp;
 pub mod u32;
 pub mod u64;
 pub mod uint256;
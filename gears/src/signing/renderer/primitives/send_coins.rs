@@ -1,8 +1,33 @@
 use crate::signing::handler::MetadataGetter;
 use crate::signing::renderer::value_renderer::{
-    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRendererWithMetadata,
+    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
 };
-use crate::types::{base::coins::UnsignedCoins, rendering::screen::Content};
+use crate::types::{
+    base::coins::UnsignedCoins,
+    rendering::screen::{Content, Indent, Screen},
+};
+
+/// Coins field of a message. The coins are rendered on a single screen titled by the field.
+#[derive(Debug, Clone)]
+pub struct CoinsField<'a> {
+    pub title: &'a str,
+    pub coins: &'a UnsignedCoins,
+    pub indent: Option<Indent>,
+}
+
+impl ValueRenderer for CoinsField<'_> {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![Screen {
+            title: self.title.to_owned(),
+            content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                self.coins.to_owned(),
+                get_metadata,
+            )?,
+            indent: self.indent,
+            expert: false,
+        }])
+    }
+}
 
 impl TryPrimitiveValueRendererWithMetadata<UnsignedCoins> for DefaultPrimitiveRenderer {
     fn try_format_with_metadata<MG: MetadataGetter>(
@@ -40,13 +65,55 @@ mod tests {
 
     use crate::signing::renderer::test_functions::TestMetadataGetter;
     use crate::signing::renderer::value_renderer::{
-        DefaultPrimitiveRenderer, TryPrimitiveValueRendererWithMetadata,
+        DefaultPrimitiveRenderer, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
     };
     use crate::types::{
         base::{coin::UnsignedCoin, coins::UnsignedCoins},
-        rendering::screen::Content,
+        rendering::screen::{Content, Indent, Screen},
     };
 
+    use super::CoinsField;
+
+    #[test]
+    fn coins_field_screen_is_titled_by_field() -> anyhow::Result<()> {
+        let coins = UnsignedCoins::new(vec!["10uatom".parse()?]).unwrap_test();
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[{ "title": "Amount", "content": "0.00001 ATOM", "indent": 2 }]"#,
+        )?;
+
+        let actual_screens = CoinsField {
+            title: "Amount",
+            coins: &coins,
+            indent: Some(Indent::two()),
+        }
+        .format(&TestMetadataGetter);
+
+        assert_eq!(expected_screens, actual_screens.unwrap_test());
+
+        Ok(())
+    }
+
+    #[test]
+    fn coins_field_joins_multiple_coins() -> anyhow::Result<()> {
+        let coins = UnsignedCoins::new(vec!["2000uon".parse()?, "10uatom".parse()?]).unwrap_test();
+
+        let expected_screens: Vec<Screen> = serde_json::from_str(
+            r#"[{ "title": "Deposit", "content": "0.00001 ATOM, 0.002 AAUON" }]"#,
+        )?;
+
+        let actual_screens = CoinsField {
+            title: "Deposit",
+            coins: &coins,
+            indent: None,
+        }
+        .format(&TestMetadataGetter);
+
+        assert_eq!(expected_screens, actual_screens.unwrap_test());
+
+        Ok(())
+    }
+
     #[test]
     fn send_coins_check_format() -> anyhow::Result<()> {
         let coin = UnsignedCoin {
@@ -66,6 +133,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn send_coins_check_format_converts_to_display_denom() -> anyhow::Result<()> {
+        let expected_content = Content::try_new("0.00001 ATOM".to_string()).unwrap_test();
+
+        let actual_content = DefaultPrimitiveRenderer::try_format_with_metadata(
+            UnsignedCoins::new(vec!["10uatom".parse()?]).unwrap_test(),
+            &TestMetadataGetter,
+        );
+
+        assert_eq!(expected_content, actual_content.unwrap_test());
+
+        Ok(())
+    }
+
     #[test]
     fn send_coins_check_format_multi_denom_alphabetical() -> anyhow::Result<()> {
         let coin1 = UnsignedCoin {
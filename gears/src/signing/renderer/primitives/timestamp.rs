@@ -0,0 +1,51 @@
+//! Default formatting implementation for `Timestamp`
+
+use tendermint::types::time::timestamp::Timestamp;
+
+use crate::signing::renderer::value_renderer::{DefaultPrimitiveRenderer, PrimitiveValueRenderer};
+use crate::types::rendering::screen::Content;
+
+impl PrimitiveValueRenderer<Timestamp> for DefaultPrimitiveRenderer {
+    fn format(value: Timestamp) -> Content {
+        Content::try_new(value.format_rfc3339()).expect("String will never be empty")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+    use tendermint::types::time::timestamp::Timestamp;
+
+    use crate::signing::renderer::value_renderer::{
+        DefaultPrimitiveRenderer, PrimitiveValueRenderer,
+    };
+
+    #[test]
+    fn test_formatting() {
+        let test_data = [
+            (Timestamp::UNIX_EPOCH, "1970-01-01T00:00:00Z"),
+            (
+                Timestamp::try_new(1136214245, 0).unwrap_test(),
+                "2006-01-02T15:04:05Z",
+            ),
+            (
+                Timestamp::try_new(1136214245, 500_000_000).unwrap_test(),
+                "2006-01-02T15:04:05.5Z",
+            ),
+            (
+                Timestamp::try_new(1136214245, 1).unwrap_test(),
+                "2006-01-02T15:04:05.000000001Z",
+            ),
+            (
+                Timestamp::try_new(-62135596800, 0).unwrap_test(),
+                "0001-01-01T00:00:00Z",
+            ),
+        ];
+
+        for (timestamp, expected) in test_data {
+            let actual = DefaultPrimitiveRenderer::format(timestamp);
+
+            assert_eq!(expected, &actual.into_inner());
+        }
+    }
+}
@@ -7,11 +7,13 @@ use serde_aux::prelude::deserialize_number_from_string;
 
 use crate::crypto::public::{DecodeError, PublicKey};
 
-use super::address::AccAddress;
+use super::{address::AccAddress, base::coin::UnsignedCoin};
 
 pub mod inner {
     pub use core_types::account::BaseAccount;
+    pub use core_types::account::BaseVestingAccount;
     pub use core_types::account::ModuleAccount;
+    pub use core_types::account::PermanentLockedAccount;
 }
 
 /// BaseAccount defines a base account type. It contains all the necessary fields
@@ -112,6 +114,119 @@ impl From<ModuleAccount> for inner::ModuleAccount {
 
 impl Protobuf<inner::ModuleAccount> for ModuleAccount {}
 
+/// BaseVestingAccount implements the VestingAccount interface. It contains all
+/// the necessary fields needed for any vesting account implementation.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BaseVestingAccount {
+    pub base_account: BaseAccount,
+    pub original_vesting: Vec<UnsignedCoin>,
+    pub delegated_free: Vec<UnsignedCoin>,
+    pub delegated_vesting: Vec<UnsignedCoin>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(serialize_with = "serialize_number_to_string")]
+    pub end_time: i64,
+}
+
+impl TryFrom<inner::BaseVestingAccount> for BaseVestingAccount {
+    type Error = IbcError;
+
+    fn try_from(raw: inner::BaseVestingAccount) -> Result<Self, Self::Error> {
+        let coins = |coins: Vec<core_types::base::coin::Coin>| {
+            coins
+                .into_iter()
+                .map(UnsignedCoin::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| IbcError::Coins(e.to_string()))
+        };
+
+        Ok(BaseVestingAccount {
+            base_account: raw
+                .base_account
+                .ok_or(IbcError::DecodeGeneral("missing base account field".into()))?
+                .try_into()?,
+            original_vesting: coins(raw.original_vesting)?,
+            delegated_free: coins(raw.delegated_free)?,
+            delegated_vesting: coins(raw.delegated_vesting)?,
+            end_time: raw.end_time,
+        })
+    }
+}
+
+impl From<BaseVestingAccount> for inner::BaseVestingAccount {
+    fn from(acct: BaseVestingAccount) -> inner::BaseVestingAccount {
+        Self {
+            base_account: Some(acct.base_account.into()),
+            original_vesting: acct.original_vesting.into_iter().map(Into::into).collect(),
+            delegated_free: acct.delegated_free.into_iter().map(Into::into).collect(),
+            delegated_vesting: acct.delegated_vesting.into_iter().map(Into::into).collect(),
+            end_time: acct.end_time,
+        }
+    }
+}
+
+impl Protobuf<inner::BaseVestingAccount> for BaseVestingAccount {}
+
+/// PermanentLockedAccount implements the VestingAccount interface. It does
+/// not ever release coins, locking them indefinitely. Coins in this account can
+/// still be used for delegating and for governance votes even while locked.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PermanentLockedAccount {
+    pub base_vesting_account: BaseVestingAccount,
+}
+
+impl PermanentLockedAccount {
+    /// locked_coins returns the original vesting coins which are not delegated
+    pub fn locked_coins(&self) -> Vec<UnsignedCoin> {
+        let BaseVestingAccount {
+            original_vesting,
+            delegated_vesting,
+            ..
+        } = &self.base_vesting_account;
+
+        original_vesting
+            .iter()
+            .filter_map(|coin| {
+                let delegated = delegated_vesting
+                    .iter()
+                    .find(|this| this.denom == coin.denom)
+                    .map(|this| this.amount)
+                    .unwrap_or_default();
+                let amount = coin.amount.saturating_sub(delegated);
+
+                (!amount.is_zero()).then(|| UnsignedCoin {
+                    denom: coin.denom.clone(),
+                    amount,
+                })
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<inner::PermanentLockedAccount> for PermanentLockedAccount {
+    type Error = IbcError;
+
+    fn try_from(raw: inner::PermanentLockedAccount) -> Result<Self, Self::Error> {
+        Ok(PermanentLockedAccount {
+            base_vesting_account: raw
+                .base_vesting_account
+                .ok_or(IbcError::DecodeGeneral(
+                    "missing base vesting account field".into(),
+                ))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<PermanentLockedAccount> for inner::PermanentLockedAccount {
+    fn from(acct: PermanentLockedAccount) -> inner::PermanentLockedAccount {
+        Self {
+            base_vesting_account: Some(acct.base_vesting_account.into()),
+        }
+    }
+}
+
+impl Protobuf<inner::PermanentLockedAccount> for PermanentLockedAccount {}
+
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum Account {
@@ -119,6 +234,8 @@ pub enum Account {
     Base(BaseAccount),
     #[serde(rename = "/cosmos.auth.v1beta1.ModuleAccount")]
     Module(ModuleAccount),
+    #[serde(rename = "/cosmos.vesting.v1beta1.PermanentLockedAccount")]
+    PermanentLocked(PermanentLockedAccount),
 }
 
 impl Account {
@@ -126,6 +243,9 @@ impl Account {
         match self {
             Account::Base(acct) => acct.pub_key.as_ref(),
             Account::Module(acct) => acct.base_account.pub_key.as_ref(),
+            Account::PermanentLocked(acct) => {
+                acct.base_vesting_account.base_account.pub_key.as_ref()
+            }
         }
     }
 
@@ -133,6 +253,7 @@ impl Account {
         match self {
             Account::Base(base) => &base.address,
             Account::Module(module) => &module.base_account.address,
+            Account::PermanentLocked(acct) => &acct.base_vesting_account.base_account.address,
         }
     }
 
@@ -140,6 +261,9 @@ impl Account {
         match self {
             Account::Base(acct) => acct.pub_key = Some(key),
             Account::Module(acct) => acct.base_account.pub_key = Some(key),
+            Account::PermanentLocked(acct) => {
+                acct.base_vesting_account.base_account.pub_key = Some(key)
+            }
         }
     }
 
@@ -147,6 +271,19 @@ impl Account {
         match self {
             Account::Base(acct) => acct.account_number = number,
             Account::Module(acct) => acct.base_account.account_number = number,
+            Account::PermanentLocked(acct) => {
+                acct.base_vesting_account.base_account.account_number = number
+            }
+        }
+    }
+
+    pub fn set_sequence(&mut self, sequence: u64) {
+        match self {
+            Account::Base(acct) => acct.sequence = sequence,
+            Account::Module(acct) => acct.base_account.sequence = sequence,
+            Account::PermanentLocked(acct) => {
+                acct.base_vesting_account.base_account.sequence = sequence
+            }
         }
     }
 
@@ -154,6 +291,7 @@ impl Account {
         match self {
             Account::Base(acct) => acct.sequence += 1,
             Account::Module(acct) => acct.base_account.sequence += 1,
+            Account::PermanentLocked(acct) => acct.base_vesting_account.base_account.sequence += 1,
         }
     }
 
@@ -161,6 +299,7 @@ impl Account {
         match self {
             Account::Base(acct) => acct.sequence,
             Account::Module(acct) => acct.base_account.sequence,
+            Account::PermanentLocked(acct) => acct.base_vesting_account.base_account.sequence,
         }
     }
 
@@ -168,6 +307,7 @@ impl Account {
         match self {
             Account::Base(acct) => acct.account_number,
             Account::Module(acct) => acct.base_account.account_number,
+            Account::PermanentLocked(acct) => acct.base_vesting_account.base_account.account_number,
         }
     }
 
@@ -175,6 +315,15 @@ impl Account {
         match self {
             Account::Base(_) => false, // TODO:NOW
             Account::Module(var) => var.permissions.iter().any(|this| this == perm.as_ref()),
+            Account::PermanentLocked(_) => false,
+        }
+    }
+
+    /// locked_coins returns the coins which are not spendable. Only vesting accounts lock coins.
+    pub fn locked_coins(&self) -> Vec<UnsignedCoin> {
+        match self {
+            Account::Base(_) | Account::Module(_) => vec![],
+            Account::PermanentLocked(acct) => acct.locked_coins(),
         }
     }
 }
@@ -194,6 +343,11 @@ impl TryFrom<Any> for Account {
                     .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
                 Ok(Account::Module(module))
             }
+            "/cosmos.vesting.v1beta1.PermanentLockedAccount" => {
+                let account = PermanentLockedAccount::decode::<Bytes>(any.value.into())
+                    .map_err(|e| core_types::errors::CoreError::DecodeGeneral(e.to_string()))?;
+                Ok(Account::PermanentLocked(account))
+            }
             _ => Err(core_types::errors::CoreError::DecodeAny(format!(
                 "account type not recognized: {}",
                 any.type_url
@@ -213,6 +367,10 @@ impl From<Account> for Any {
                 type_url: "/cosmos.auth.v1beta1.ModuleAccount".to_string(),
                 value: module.encode_vec(),
             },
+            Account::PermanentLocked(account) => Any {
+                type_url: "/cosmos.vesting.v1beta1.PermanentLockedAccount".to_string(),
+                value: account.encode_vec(),
+            },
         }
     }
 }
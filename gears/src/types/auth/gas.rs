@@ -3,6 +3,8 @@ use derive_more::derive::{Add, Deref, Display, From, Into, Mul, Sub};
 use std::{num::ParseIntError, str::FromStr};
 use ux::u63;
 
+use crate::types::gas::GasMeteringErrors;
+
 pub mod inner {
     pub use core_types::auth::fee::Fee;
     pub use core_types::base::coin::Coin;
@@ -42,7 +44,6 @@ impl Gas {
         Self(val)
     }
 
-    // TODO: write a test for this
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         if (Self::MAX - self) >= rhs {
             Some(self + rhs)
@@ -51,6 +52,12 @@ impl Gas {
         }
     }
 
+    /// Adds gas consumed by `descriptor`. Unlike the derived `Add` it errors rather than overflows.
+    pub fn try_add(self, rhs: Self, descriptor: &str) -> Result<Self, GasMeteringErrors> {
+        self.checked_add(rhs)
+            .ok_or_else(|| GasMeteringErrors::ErrorGasOverflow(descriptor.to_owned()))
+    }
+
     // TODO: write a test for this
     pub fn checked_sub(self, rhs: Self) -> Option<Self> {
         let self_inner: u64 = self.0.into();
@@ -210,9 +217,52 @@ mod tests {
         assert!(gas.is_err());
     }
 
+    #[test]
+    fn test_gas_checked_add() {
+        assert_eq!(
+            Gas::from(100_u8).checked_add(Gas::from(28_u8)),
+            Some(Gas::from(128_u8))
+        );
+        assert_eq!(Gas::MAX.checked_add(Gas::ZERO), Some(Gas::MAX));
+    }
+
+    #[test]
+    fn test_gas_checked_add_overflow() {
+        assert_eq!(Gas::MAX.checked_add(Gas::from(1_u8)), None);
+    }
+
+    #[test]
+    fn test_gas_try_add() {
+        assert_eq!(
+            Gas::from(100_u8).try_add(Gas::from(28_u8), "add"),
+            Ok(Gas::from(128_u8))
+        );
+        assert_eq!(
+            Gas::MAX.try_add(Gas::from(1_u8), "overflow"),
+            Err(GasMeteringErrors::ErrorGasOverflow("overflow".to_owned()))
+        );
+    }
+
     #[test]
     fn test_gas_into_i64() {
         let gas: Gas = u63::new(100).into();
         assert_eq!(i64::from(gas), 100);
     }
+
+    #[test]
+    fn test_gas_try_from_negative_i64_error() {
+        assert!(Gas::try_from(-1_i64).is_err());
+    }
+
+    #[test]
+    fn test_gas_i64_round_trip_zero() {
+        assert_eq!(i64::from(Gas::ZERO), 0);
+        assert_eq!(Gas::try_from(0_i64).unwrap_test(), Gas::ZERO);
+    }
+
+    #[test]
+    fn test_gas_i64_round_trip_max() {
+        assert_eq!(i64::from(Gas::MAX), i64::MAX);
+        assert_eq!(Gas::try_from(i64::MAX).unwrap_test(), Gas::MAX);
+    }
 }
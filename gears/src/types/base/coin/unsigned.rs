@@ -13,9 +13,46 @@ pub mod inner {
     pub use core_types::base::coin::IntProto;
 }
 
+/// JSON representation of [`UnsignedCoin`]. Like in the Cosmos SDK the amount is a decimal
+/// string, numeric amounts are accepted on input as well.
+#[derive(Serialize, Deserialize)]
+struct UnsignedCoinRaw {
+    denom: String,
+    amount: AmountRaw,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum AmountRaw {
+    String(String),
+    Number(u64),
+}
+
+impl From<UnsignedCoin> for UnsignedCoinRaw {
+    fn from(UnsignedCoin { denom, amount }: UnsignedCoin) -> Self {
+        Self {
+            denom: denom.to_string(),
+            amount: AmountRaw::String(amount.to_string()),
+        }
+    }
+}
+
+impl TryFrom<UnsignedCoinRaw> for UnsignedCoin {
+    type Error = CoinError;
+
+    fn try_from(UnsignedCoinRaw { denom, amount }: UnsignedCoinRaw) -> Result<Self, Self::Error> {
+        let amount = match amount {
+            AmountRaw::String(amount) => amount,
+            AmountRaw::Number(amount) => amount.to_string(),
+        };
+
+        inner::Coin { denom, amount }.try_into()
+    }
+}
+
 /// Coin defines a token with a denomination and an amount.
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
-#[serde(try_from = "inner::Coin", into = "inner::Coin")]
+#[serde(try_from = "UnsignedCoinRaw", into = "UnsignedCoinRaw")]
 pub struct UnsignedCoin {
     pub denom: Denom,
     pub amount: Uint256,
@@ -122,3 +159,39 @@ impl PaginationKey for UnsignedCoin {
         Cow::Borrowed(self.denom.as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+    use crate::types::base::coins::UnsignedCoins;
+
+    #[test]
+    fn json_amount_is_a_string() {
+        let coin: UnsignedCoin =
+            serde_json::from_str(r#"{"denom":"uatom","amount":"34"}"#).unwrap_test();
+
+        assert_eq!(coin, "34uatom".parse().unwrap_test());
+        assert_eq!(
+            serde_json::to_string(&coin).unwrap_test(),
+            r#"{"denom":"uatom","amount":"34"}"#
+        );
+    }
+
+    #[test]
+    fn json_numeric_amount_deserializes() {
+        let coin: UnsignedCoin =
+            serde_json::from_str(r#"{"denom":"uatom","amount":34}"#).unwrap_test();
+
+        assert_eq!(coin, "34uatom".parse().unwrap_test());
+    }
+
+    #[test]
+    fn coins_json_matches_sdk() {
+        let json = r#"[{"denom":"stake","amount":"1"},{"denom":"uatom","amount":"34"}]"#;
+        let coins: UnsignedCoins = serde_json::from_str(json).unwrap_test();
+
+        assert_eq!(serde_json::to_string(&coins).unwrap_test(), json);
+    }
+}
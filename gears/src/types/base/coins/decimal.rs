@@ -103,17 +103,18 @@ impl DecimalCoins {
     }
 
     /// Multiplies each coin by a number and truncates decimal part from the result.
+    /// Coins truncated to zero are removed, if no coins are left [`CoinsError::EmptyList`] is returned.
     pub fn checked_mul_dec_truncate(&self, multiplier: Decimal256) -> Result<Self, CoinsError> {
         let mut coins = vec![];
         for coin in self.inner().iter() {
-            coins.push(DecimalCoin::new(
-                coin.amount
-                    .checked_mul(multiplier)
-                    // TODO: extend error
-                    .map_err(|_| CoinsError::InvalidAmount)?
-                    .floor(),
-                coin.denom.clone(),
-            ));
+            let amount = coin
+                .amount
+                .checked_mul(multiplier)
+                .map_err(|_| CoinsError::Overflow(coin.denom.clone()))?
+                .floor();
+            if !amount.is_zero() {
+                coins.push(DecimalCoin::new(amount, coin.denom.clone()));
+            }
         }
 
         Self::new(coins)
@@ -399,7 +400,35 @@ mod tests {
         let dec_coins = DecimalCoins::new(dec_coins_inner.clone()).unwrap_test();
         let dec_coins_mul_truncated =
             dec_coins.checked_mul_dec_truncate(Decimal256::new(Uint256::MAX));
-        assert!(dec_coins_mul_truncated.is_err());
+        assert_eq!(
+            dec_coins_mul_truncated,
+            Err(CoinsError::Overflow(
+                DENOMS.get().expect("cannot fail initialized variable")[0].clone()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn checked_mul_dec_truncate_removes_zero_coins() -> anyhow::Result<()> {
+        let dec_coins = generate_coins(vec![100, 9]);
+        let dec_coins_mul_truncated = dec_coins.checked_mul_dec_truncate(
+            Decimal256::from_atomics(1u64, 1).expect("hardcoded value can't fail"),
+        )?;
+        assert_eq!(
+            dec_coins_mul_truncated.inner(),
+            &vec![DecimalCoin {
+                denom: DENOMS.get().expect("cannot fail initialized variable")[0].clone(),
+                amount: Decimal256::from_atomics(10u64, 0).unwrap_test(),
+            }]
+        );
+
+        assert_eq!(
+            generate_coins(vec![9])
+                .checked_mul_dec_truncate(Decimal256::from_atomics(1u64, 1).unwrap_test()),
+            Err(CoinsError::EmptyList)
+        );
 
         Ok(())
     }
@@ -93,15 +93,16 @@ pub struct Coins<T: ZeroNumeric, U: Coin<Amount = T>> {
 }
 
 impl<T: ZeroNumeric, U: Coin<Amount = T>> Coins<T, U> {
-    // Checks that the Coins are sorted, have positive amount, with a valid and unique
-    // denomination (i.e no duplicates). Otherwise, it returns an error.
+    // Sorts the Coins by denomination and checks that they have positive amount, with a valid
+    // and unique denomination (i.e no duplicates). Otherwise, it returns an error.
     // A valid list of coins satisfies:
     // - Contains at least one coin
     // - All amounts are positive
     // - No duplicate denominations
-    // - Sorted lexicographically
+    // The stored coins are always sorted lexicographically by denomination, so the order
+    // doesn't depend on the order the coins were passed in.
     pub fn new(coins: impl IntoIterator<Item = U>) -> Result<Self, CoinsError> {
-        let coins = coins.into_iter().collect::<Vec<_>>();
+        let mut coins = coins.into_iter().collect::<Vec<_>>();
 
         if coins.is_empty() {
             Err(CoinsError::EmptyList)?
@@ -111,20 +112,13 @@ impl<T: ZeroNumeric, U: Coin<Amount = T>> Coins<T, U> {
             Err(CoinsError::InvalidAmount)?
         }
 
+        coins.sort_by(|a, b| a.denom().cmp(b.denom()));
+
+        if let Some(pair) = coins
+            .windows(2)
+            .find(|pair| pair[0].denom() == pair[1].denom())
         {
-            let mut previous_denom = coins[0].denom();
-
-            for coin in &coins[1..] {
-                // Less than to ensure lexicographical ordering
-                // Equality to ensure that there are no duplications
-                match coin.denom().cmp(previous_denom) {
-                    std::cmp::Ordering::Less => Err(CoinsError::Unsorted),
-                    std::cmp::Ordering::Equal => Err(CoinsError::Duplicates),
-                    std::cmp::Ordering::Greater => Ok(()),
-                }?;
-
-                previous_denom = coin.denom();
-            }
+            Err(CoinsError::Duplicates(pair[0].denom().clone()))?
         }
 
         Ok(Self {
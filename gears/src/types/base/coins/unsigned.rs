@@ -13,8 +13,9 @@ pub type UnsignedCoinsRaw = ProtoCoinsRaw<IbcCoin>;
 pub type UnsignedCoins = Coins<Uint256, UnsignedCoin>;
 
 impl UnsignedCoins {
+    /// Adds matching coins and merges the rest. Returns an error if any amount overflows.
     pub fn checked_add(&self, other: &Self) -> Result<Self, CoinsError> {
-        Self::new(self.storage.iter().chain(other.storage.iter()).cloned())
+        Self::new(self.checked_calculate_iterate(other.inner(), Uint256::checked_add)?)
     }
 
     pub fn is_all_gte<'a>(&self, other: impl IntoIterator<Item = &'a UnsignedCoin>) -> bool {
@@ -35,20 +36,24 @@ impl UnsignedCoins {
 
     // TODO: Move this to generic declaration
     /// Substracts matching coins. If the other coins have bigger values or the coins that don't
-    /// exists in original set, method returns error. If all coins are identical method returns
-    /// error.
+    /// exists in original set, method returns insufficient funds error. If all coins are
+    /// identical method returns error.
     pub fn checked_sub(&self, other: &UnsignedCoins) -> Result<Self, CoinsError> {
-        if self.is_all_gte(other.inner()) {
-            let coins: Vec<UnsignedCoin> = self
-                .checked_calculate_iterate(other.inner(), Uint256::checked_sub)?
-                .into_iter()
-                // filter zeros after sub
-                .filter(|c| !c.amount.is_zero())
-                .collect();
-            Self::new(coins)
-        } else {
-            Err(CoinsError::InvalidAmount)
+        if let Some(coin) = other
+            .inner()
+            .iter()
+            .find(|coin| coin.amount > self.amount_of(&coin.denom))
+        {
+            return Err(CoinsError::InsufficientFunds(coin.denom.clone()));
         }
+
+        let coins: Vec<UnsignedCoin> = self
+            .checked_calculate_iterate(other.inner(), Uint256::checked_sub)?
+            .into_iter()
+            // filter zeros after sub
+            .filter(|c| !c.amount.is_zero())
+            .collect();
+        Self::new(coins)
     }
 
     fn checked_calculate_iterate(
@@ -80,7 +85,7 @@ impl UnsignedCoins {
                     result.push(UnsignedCoin {
                         denom: self_coins[i].denom.clone(),
                         amount: operation(self_coins[i].amount, other_coins[j].amount)
-                            .map_err(|_| CoinsError::InvalidAmount)?,
+                            .map_err(|_| CoinsError::Overflow(self_coins[i].denom.clone()))?,
                     });
                     i += 1;
                     j += 1;
@@ -266,12 +271,17 @@ mod tests {
             },
         ];
         let err = UnsignedCoins::new(coins);
-        assert_eq!(err, Err(CoinsError::Duplicates));
+        assert_eq!(
+            err,
+            Err(CoinsError::Duplicates(
+                String::from("truer").try_into().unwrap_test()
+            ))
+        );
 
-        // not sorted
+        // unsorted duplicate denomination
         let coins = vec![
             UnsignedCoin {
-                denom: String::from("tree").try_into().unwrap_test(),
+                denom: String::from("truer").try_into().unwrap_test(),
                 amount: Uint256::one(),
             },
             UnsignedCoin {
@@ -279,30 +289,111 @@ mod tests {
                 amount: Uint256::from_str("3").unwrap_test(),
             },
             UnsignedCoin {
-                denom: String::from("mineral").try_into().unwrap_test(),
-                amount: Uint256::from_str("3").unwrap_test(),
+                denom: String::from("truer").try_into().unwrap_test(),
+                amount: Uint256::one(),
             },
         ];
         let err = UnsignedCoins::new(coins);
-        assert_eq!(err, Err(CoinsError::Unsorted));
+        assert_eq!(
+            err,
+            Err(CoinsError::Duplicates(
+                String::from("truer").try_into().unwrap_test()
+            ))
+        );
+    }
 
-        // not sorted 2
+    #[test]
+    fn new_sorts_coins_by_denom() {
         let coins = vec![
             UnsignedCoin {
-                denom: String::from("gas").try_into().unwrap_test(),
+                denom: String::from("tree").try_into().unwrap_test(),
                 amount: Uint256::one(),
             },
             UnsignedCoin {
-                denom: String::from("true").try_into().unwrap_test(),
+                denom: String::from("gas").try_into().unwrap_test(),
                 amount: Uint256::from_str("3").unwrap_test(),
             },
             UnsignedCoin {
                 denom: String::from("mineral").try_into().unwrap_test(),
-                amount: Uint256::from_str("3").unwrap_test(),
+                amount: Uint256::from_str("5").unwrap_test(),
             },
         ];
-        let err = UnsignedCoins::new(coins);
-        assert_eq!(err, Err(CoinsError::Unsorted));
+        let coins = UnsignedCoins::new(coins).unwrap_test();
+
+        let denoms = coins
+            .inner()
+            .iter()
+            .map(|coin| coin.denom.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(denoms, ["gas", "mineral", "tree"]);
+        assert_eq!(
+            coins.amount_of(&String::from("gas").try_into().unwrap_test()),
+            Uint256::from_str("3").unwrap_test()
+        );
+
+        let parsed = UnsignedCoins::from_str("30uatom,100atom").unwrap_test();
+        assert_eq!(
+            parsed,
+            UnsignedCoins::from_str("100atom,30uatom").unwrap_test()
+        );
+    }
+
+    #[test]
+    fn checked_add_merges_disjoint_denoms() {
+        let coins = UnsignedCoins::from_str("10atom,5uatom").unwrap_test();
+        let other = UnsignedCoins::from_str("3gas,7stake").unwrap_test();
+
+        assert_eq!(
+            coins.checked_add(&other).unwrap_test(),
+            UnsignedCoins::from_str("10atom,3gas,7stake,5uatom").unwrap_test()
+        );
+    }
+
+    #[test]
+    fn checked_add_merges_overlapping_denoms() {
+        let coins = UnsignedCoins::from_str("10atom,5uatom").unwrap_test();
+        let other = UnsignedCoins::from_str("3gas,7uatom").unwrap_test();
+
+        assert_eq!(
+            coins.checked_add(&other).unwrap_test(),
+            UnsignedCoins::from_str("10atom,3gas,12uatom").unwrap_test()
+        );
+
+        let max = UnsignedCoins::new([UnsignedCoin {
+            denom: String::from("uatom").try_into().unwrap_test(),
+            amount: Uint256::MAX,
+        }])
+        .unwrap_test();
+        assert_eq!(
+            max.checked_add(&coins),
+            Err(CoinsError::Overflow(
+                String::from("uatom").try_into().unwrap_test()
+            ))
+        );
+    }
+
+    #[test]
+    fn checked_sub_underflow_errors() {
+        let coins = UnsignedCoins::from_str("10atom,5uatom").unwrap_test();
+
+        assert_eq!(
+            coins
+                .checked_sub(&UnsignedCoins::from_str("4atom,5uatom").unwrap_test())
+                .unwrap_test(),
+            UnsignedCoins::from_str("6atom").unwrap_test()
+        );
+        assert_eq!(
+            coins.checked_sub(&UnsignedCoins::from_str("6uatom").unwrap_test()),
+            Err(CoinsError::InsufficientFunds(
+                String::from("uatom").try_into().unwrap_test()
+            ))
+        );
+        assert_eq!(
+            coins.checked_sub(&UnsignedCoins::from_str("1gas").unwrap_test()),
+            Err(CoinsError::InsufficientFunds(
+                String::from("gas").try_into().unwrap_test()
+            ))
+        );
     }
 
     #[test]
@@ -1,13 +1,19 @@
+use crate::types::denom::Denom;
+
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum CoinsError {
     #[error("list of coins is empty")]
     EmptyList,
     #[error("coin amount must be positive")]
     InvalidAmount,
-    #[error("coins contain duplicates")]
-    Duplicates,
+    #[error("coins contain duplicate denomination: {0}")]
+    Duplicates(Denom),
     #[error("coins are not sorted")]
     Unsorted,
+    #[error("overflow on {0} amount")]
+    Overflow(Denom),
+    #[error("insufficient funds: not enough {0}")]
+    InsufficientFunds(Denom),
     #[error("coin error: {0}")]
     Coin(String),
 }
@@ -66,7 +66,7 @@ impl MinGasPrices {
                 // Equality to ensure that there are no duplications
                 match coin.denom.cmp(previous_denom) {
                     std::cmp::Ordering::Less => Err(CoinsError::Unsorted),
-                    std::cmp::Ordering::Equal => Err(CoinsError::Duplicates),
+                    std::cmp::Ordering::Equal => Err(CoinsError::Duplicates(coin.denom.clone())),
                     std::cmp::Ordering::Greater => Ok(()),
                 }?;
 
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::{FiniteGas, Gas, GasMeteringErrors, PlainGasMeter};
+use super::{ErrorNegativeGasConsumed, FiniteGas, Gas, GasMeteringErrors, PlainGasMeter};
 
 /// Basic gas meter.
 #[derive(Debug, Clone)]
@@ -53,33 +53,35 @@ impl PlainGasMeter for BasicGasMeter {
         amount: FiniteGas,
         descriptor: &str,
     ) -> Result<(), GasMeteringErrors> {
-        if let Some(sum) = self.consumed.checked_add(amount) {
-            if self.consumed > self.limit {
-                //TODO: This does not seem right - self.consumed hasn't been updated yet
-                Err(GasMeteringErrors::ErrorOutOfGas(descriptor.to_owned()))
-            } else {
+        match self.consumed.try_add(amount, descriptor) {
+            Ok(sum) => {
                 self.consumed = sum;
-                Ok(())
+
+                if self.is_past_limit() {
+                    Err(GasMeteringErrors::ErrorOutOfGas(descriptor.to_owned()))
+                } else {
+                    Ok(())
+                }
+            }
+            Err(e) => {
+                self.consumed = FiniteGas::MAX; // TODO: it must be the case that we are out of gas
+                Err(e)
             }
-        } else {
-            self.consumed = FiniteGas::MAX; // TODO: it must be the case that we are out of gas
-            Err(GasMeteringErrors::ErrorGasOverflow(descriptor.to_owned()))
         }
     }
 
-    // fn refund_gas(
-    //     &mut self,
-    //     amount: FiniteGas,
-    //     descriptor: &str,
-    // ) -> Result<(), ErrorNegativeGasConsumed> {
-    //     if self.consumed < amount {
-    //         Err(ErrorNegativeGasConsumed(descriptor.to_owned()))
-    //     } else {
-    //         self.consumed.0 -= amount.0;
+    fn refund_gas(
+        &mut self,
+        amount: FiniteGas,
+        descriptor: &str,
+    ) -> Result<(), ErrorNegativeGasConsumed> {
+        self.consumed = self
+            .consumed
+            .checked_sub(amount)
+            .ok_or_else(|| ErrorNegativeGasConsumed(descriptor.to_owned()))?;
 
-    //         Ok(())
-    //     }
-    // }
+        Ok(())
+    }
 
     fn is_past_limit(&self) -> bool {
         self.consumed > self.limit
@@ -103,3 +105,40 @@ impl Display for BasicGasMeter {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn consuming_exactly_the_limit_is_out_of_gas_but_not_past_limit() {
+        let mut meter = BasicGasMeter::new(FiniteGas::from(100_u8));
+
+        meter
+            .consume_gas(FiniteGas::from(100_u8), "consume")
+            .unwrap_test();
+
+        assert!(meter.is_out_of_gas());
+        assert!(!meter.is_past_limit());
+        assert_eq!(meter.gas_consumed(), FiniteGas::from(100_u8));
+    }
+
+    #[test]
+    fn consuming_above_the_limit_errors() {
+        let mut meter = BasicGasMeter::new(FiniteGas::from(100_u8));
+        meter
+            .consume_gas(FiniteGas::from(100_u8), "consume")
+            .unwrap_test();
+
+        let err = meter
+            .consume_gas(FiniteGas::from(1_u8), "one more")
+            .expect_err("limit is exceeded");
+
+        assert_eq!(err, GasMeteringErrors::ErrorOutOfGas("one more".to_owned()));
+        assert!(meter.is_out_of_gas());
+        assert!(meter.is_past_limit());
+        assert_eq!(meter.gas_consumed_or_limit(), FiniteGas::from(100_u8));
+    }
+}
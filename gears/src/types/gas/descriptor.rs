@@ -1,3 +1,4 @@
 pub const BLOCK_GAS_DESCRIPTOR: &str = "block gas meter";
 pub const ANTE_SECKP251K1_DESCRIPTOR: &str = "ante verify: secp256k1";
+pub const ANTE_ED25519_DESCRIPTOR: &str = "ante verify: ed25519";
 pub const TX_SIZE_DESCRIPTOR: &str = "txSize";
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use super::{FiniteGas, Gas, GasMeteringErrors, PlainGasMeter};
+use super::{ErrorNegativeGasConsumed, FiniteGas, Gas, GasMeteringErrors, PlainGasMeter};
 
 /// Gas meter without consumption limit
 #[derive(Debug, Clone)]
@@ -45,27 +45,23 @@ impl PlainGasMeter for InfiniteGasMeter {
         amount: FiniteGas,
         descriptor: &str,
     ) -> Result<(), GasMeteringErrors> {
-        if let Some(sum) = self.consumed.checked_add(amount) {
-            self.consumed = sum;
-            Ok(())
-        } else {
-            Err(GasMeteringErrors::ErrorGasOverflow(descriptor.to_owned()))
-        }
+        self.consumed = self.consumed.try_add(amount, descriptor)?;
+
+        Ok(())
     }
 
-    // fn refund_gas(
-    //     &mut self,
-    //     amount: FiniteGas,
-    //     descriptor: &str,
-    // ) -> Result<(), ErrorNegativeGasConsumed> {
-    //     if self.consumed < amount {
-    //         Err(ErrorNegativeGasConsumed(descriptor.to_owned()))
-    //     } else {
-    //         self.consumed.0 -= amount.0;
-
-    //         Ok(())
-    //     }
-    // }
+    fn refund_gas(
+        &mut self,
+        amount: FiniteGas,
+        descriptor: &str,
+    ) -> Result<(), ErrorNegativeGasConsumed> {
+        self.consumed = self
+            .consumed
+            .checked_sub(amount)
+            .ok_or_else(|| ErrorNegativeGasConsumed(descriptor.to_owned()))?;
+
+        Ok(())
+    }
 
     fn is_past_limit(&self) -> bool {
         false
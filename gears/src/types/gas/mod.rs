@@ -12,14 +12,19 @@ pub mod kind;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use self::kind::MeterKind;
+use self::{config::GasConfig, kind::MeterKind};
 
 #[no_link]
 extern crate derive_more;
 
+use basic_meter::BasicGasMeter;
 use infinite_meter::InfiniteGasMeter;
 use tracing::debug;
 
+use crate::types::store::gas::constants::{
+    READ_COST_FLAT_DESC, READ_PER_BYTE_DESC, WRITE_COST_FLAT_DESC, WRITE_PER_BYTE_DESC,
+};
+
 pub type FiniteGas = super::auth::gas::Gas;
 
 #[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
@@ -30,7 +35,8 @@ pub enum GasMeteringErrors {
     ErrorGasOverflow(String),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Eq, PartialEq, thiserror::Error)]
+#[error("negative gas consumed: {0}")]
 pub struct ErrorNegativeGasConsumed(pub String);
 
 #[derive(Debug, Clone, Copy)]
@@ -39,6 +45,19 @@ pub enum Gas {
     Finite(FiniteGas),
 }
 
+/// Gas consumed by a meter together with its limit, so that meters without a limit still
+/// report how much gas was used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasUsage {
+    NoLimit {
+        consumed: FiniteGas,
+    },
+    Limited {
+        consumed: FiniteGas,
+        limit: FiniteGas,
+    },
+}
+
 /// This is needed to convert block gas limit from i64 to Gas
 impl From<i64> for Gas {
     fn from(val: i64) -> Self {
@@ -78,15 +97,14 @@ pub trait PlainGasMeter: Send + Sync + Debug {
     /// If the gas meter is not infinite, it returns error  if gas consumed goes above the limit.
     fn consume_gas(&mut self, amount: FiniteGas, descriptor: &str)
         -> Result<(), GasMeteringErrors>;
-    // TODO: add refund_gas back in
     /// Deducts the given amount from the gas consumed.
     /// This functionality enables refunding gas to the transaction
     /// or block gas pools so that EVM-compatible chains can fully support the go-ethereum StateDB interface.
-    // fn refund_gas(
-    //     &mut self,
-    //     amount: FiniteGas,
-    //     descriptor: &str,
-    // ) -> Result<(), ErrorNegativeGasConsumed>;
+    fn refund_gas(
+        &mut self,
+        amount: FiniteGas,
+        descriptor: &str,
+    ) -> Result<(), ErrorNegativeGasConsumed>;
     /// Returns true if the amount of gas consumed by the gas meter instance is strictly above the limit, false otherwise.
     fn is_past_limit(&self) -> bool;
     /// Returns true if the amount of gas consumed by the gas meter instance is above or equal to the limit, false otherwise.
@@ -121,10 +139,24 @@ impl<DS: MeterKind> GasMeter<DS> {
         let _ = std::mem::replace(&mut self.meter, meter);
     }
 
+    pub fn consumed(&self) -> FiniteGas {
+        self.meter.gas_consumed()
+    }
+
     pub fn consumed_or_limit(&self) -> FiniteGas {
         self.meter.gas_consumed_or_limit()
     }
 
+    /// Zeroes the consumed gas while keeping the limit of the meter.
+    pub fn reset(&mut self) {
+        let meter: Box<dyn PlainGasMeter> = match self.meter.limit() {
+            Gas::Infinite => Box::<InfiniteGasMeter>::default(),
+            Gas::Finite(limit) => Box::new(BasicGasMeter::new(limit)),
+        };
+
+        self.replace_meter(meter);
+    }
+
     pub fn consume_gas(
         &mut self,
         amount: FiniteGas,
@@ -139,6 +171,50 @@ impl<DS: MeterKind> GasMeter<DS> {
         self.meter.consume_gas(amount, descriptor)
     }
 
+    pub fn refund_gas(
+        &mut self,
+        amount: FiniteGas,
+        descriptor: &str,
+    ) -> Result<(), ErrorNegativeGasConsumed> {
+        debug!(
+            "Refunded {} gas for {} with {}",
+            amount,
+            self.meter.name(),
+            descriptor
+        );
+        self.meter.refund_gas(amount, descriptor)
+    }
+
+    /// Consumes the flat and per byte read cost of `config` for reading `bytes_read` bytes.
+    pub fn consume_read(
+        &mut self,
+        config: &GasConfig,
+        bytes_read: usize,
+    ) -> Result<(), GasMeteringErrors> {
+        self.consume_gas(config.read_cost_flat, READ_COST_FLAT_DESC)?;
+        self.consume_gas(
+            per_byte_cost(config.read_cost_per_byte, bytes_read, READ_PER_BYTE_DESC)?,
+            READ_PER_BYTE_DESC,
+        )
+    }
+
+    /// Consumes the flat and per byte write cost of `config` for writing `bytes_written` bytes.
+    pub fn consume_write(
+        &mut self,
+        config: &GasConfig,
+        bytes_written: usize,
+    ) -> Result<(), GasMeteringErrors> {
+        self.consume_gas(config.write_cost_flat, WRITE_COST_FLAT_DESC)?;
+        self.consume_gas(
+            per_byte_cost(
+                config.write_cost_per_byte,
+                bytes_written,
+                WRITE_PER_BYTE_DESC,
+            )?,
+            WRITE_PER_BYTE_DESC,
+        )
+    }
+
     pub fn is_out_of_gas(&self) -> bool {
         self.meter.is_out_of_gas()
     }
@@ -150,4 +226,176 @@ impl<DS: MeterKind> GasMeter<DS> {
     pub fn gas_remaining(&self) -> Gas {
         self.meter.gas_remaining()
     }
+
+    pub fn usage(&self) -> GasUsage {
+        let consumed = self.meter.gas_consumed();
+        match self.meter.limit() {
+            Gas::Infinite => GasUsage::NoLimit { consumed },
+            Gas::Finite(limit) => GasUsage::Limited { consumed, limit },
+        }
+    }
+}
+
+fn per_byte_cost(
+    cost_per_byte: FiniteGas,
+    bytes: usize,
+    descriptor: &str,
+) -> Result<FiniteGas, GasMeteringErrors> {
+    FiniteGas::try_from(bytes as u64)
+        .ok()
+        .and_then(|bytes| cost_per_byte.checked_mul(bytes))
+        .ok_or_else(|| GasMeteringErrors::ErrorGasOverflow(descriptor.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::{
+        kind::{BlockKind, TxKind},
+        *,
+    };
+
+    fn meter(limit: u32) -> GasMeter<TxKind> {
+        GasMeter::new(Box::new(BasicGasMeter::new(FiniteGas::from(limit))))
+    }
+
+    #[test]
+    fn infinite_gas_round_trips_through_i64() {
+        assert_eq!(i64::from(Gas::Infinite), -1);
+        assert!(matches!(Gas::from(-1_i64), Gas::Infinite));
+        assert_eq!(i64::from(Gas::Finite(FiniteGas::from(100_u8))), 100);
+    }
+
+    #[test]
+    fn consume_read_charges_flat_and_per_byte() {
+        let mut meter = meter(1_000_000);
+
+        meter
+            .consume_read(GasConfig::kv(), 10)
+            .expect("limit is not reached");
+
+        assert_eq!(
+            meter.consumed_or_limit(),
+            FiniteGas::from(1000_u32 + 10 * 3)
+        );
+    }
+
+    #[test]
+    fn consume_write_charges_flat_and_per_byte() {
+        let mut meter = meter(1_000_000);
+
+        meter
+            .consume_write(GasConfig::kv(), 10)
+            .expect("limit is not reached");
+
+        assert_eq!(
+            meter.consumed_or_limit(),
+            FiniteGas::from(2000_u32 + 10 * 30)
+        );
+    }
+
+    #[test]
+    fn refund_gas_reduces_consumed() {
+        let mut meter = meter(1000);
+
+        meter
+            .consume_gas(FiniteGas::from(100_u8), "consume")
+            .unwrap_test();
+        meter
+            .refund_gas(FiniteGas::from(40_u8), "refund")
+            .unwrap_test();
+
+        assert_eq!(meter.consumed_or_limit(), FiniteGas::from(60_u8));
+    }
+
+    #[test]
+    fn refund_gas_more_than_consumed_errors() {
+        let mut meter = meter(1000);
+
+        meter
+            .consume_gas(FiniteGas::from(100_u8), "consume")
+            .unwrap_test();
+        let err = meter
+            .refund_gas(FiniteGas::from(101_u8), "refund")
+            .expect_err("refund is greater than consumed gas");
+
+        assert_eq!(err, ErrorNegativeGasConsumed("refund".to_owned()));
+        assert_eq!(meter.consumed_or_limit(), FiniteGas::from(100_u8));
+    }
+
+    #[test]
+    fn reset_zeroes_consumed_and_keeps_limit() {
+        let mut meter =
+            GasMeter::<BlockKind>::new(Box::new(BasicGasMeter::new(FiniteGas::from(1000_u16))));
+        meter
+            .consume_gas(FiniteGas::from(100_u8), "consume")
+            .unwrap_test();
+
+        meter.reset();
+
+        assert_eq!(meter.consumed(), FiniteGas::ZERO);
+        assert!(matches!(meter.limit(), Gas::Finite(limit) if limit == FiniteGas::from(1000_u16)));
+    }
+
+    #[test]
+    fn reset_infinite_meter() {
+        let mut meter = GasMeter::<BlockKind>::infinite();
+        meter
+            .consume_gas(FiniteGas::from(100_u8), "consume")
+            .unwrap_test();
+
+        meter.reset();
+
+        assert_eq!(meter.consumed(), FiniteGas::ZERO);
+        assert!(matches!(meter.limit(), Gas::Infinite));
+    }
+
+    #[test]
+    fn infinite_meter_tracks_consumed_gas() {
+        let mut meter = GasMeter::<TxKind>::infinite();
+        meter
+            .consume_gas(FiniteGas::from(500_u16), "consume")
+            .unwrap_test();
+
+        assert_eq!(meter.consumed(), FiniteGas::from(500_u16));
+        assert_eq!(meter.consumed_or_limit(), FiniteGas::from(500_u16));
+        assert!(matches!(meter.gas_remaining(), Gas::Infinite));
+        assert_eq!(
+            meter.usage(),
+            GasUsage::NoLimit {
+                consumed: FiniteGas::from(500_u16)
+            }
+        );
+    }
+
+    #[test]
+    fn consume_gas_overflow_errors() {
+        let mut meter = GasMeter::<TxKind>::infinite();
+        meter.consume_gas(FiniteGas::MAX, "consume").unwrap_test();
+
+        let err = meter
+            .consume_gas(FiniteGas::from(1_u8), "overflow")
+            .expect_err("consumed gas overflows");
+
+        assert_eq!(
+            err,
+            GasMeteringErrors::ErrorGasOverflow("overflow".to_owned())
+        );
+        assert_eq!(meter.consumed(), FiniteGas::MAX);
+    }
+
+    #[test]
+    fn consume_read_past_limit_errors() {
+        let mut meter = meter(500);
+
+        let err = meter
+            .consume_read(GasConfig::kv(), 10)
+            .expect_err("limit is exceeded");
+
+        assert_eq!(
+            err,
+            GasMeteringErrors::ErrorOutOfGas(READ_COST_FLAT_DESC.to_owned())
+        );
+    }
 }
@@ -24,11 +24,22 @@ pub struct PaginationRequest {
     /// limit is the total number of results to be returned in the result page.
     /// If left empty it will default to a value to be set by each app.
     pub limit: u8,
+    /// count_total is set to true to indicate that the result set should include
+    /// a count of the total number of items available for pagination.
+    /// It requires a full scan of the items, so it is disabled by default.
+    #[serde(default)]
+    pub count_total: bool,
 }
 
 impl From<PaginationRequest> for Pagination {
-    fn from(PaginationRequest { kind, limit }: PaginationRequest) -> Self {
-        match kind {
+    fn from(
+        PaginationRequest {
+            kind,
+            limit,
+            count_total,
+        }: PaginationRequest,
+    ) -> Self {
+        let pagination = match kind {
             PaginationKind::Key { key } => Self::from(PaginationByKey {
                 key,
                 limit: limit as usize,
@@ -40,7 +51,9 @@ impl From<PaginationRequest> for Pagination {
                     .unwrap_or(usize::MAX),
                 limit: limit as usize,
             }),
-        }
+        };
+
+        pagination.count_total(count_total)
     }
 }
 
@@ -50,7 +63,7 @@ impl From<core_types::query::request::PageRequest> for PaginationRequest {
             key,
             offset,
             limit,
-            count_total: _,
+            count_total,
             reverse: _,
         }: core_types::query::request::PageRequest,
     ) -> Self {
@@ -62,12 +75,19 @@ impl From<core_types::query::request::PageRequest> for PaginationRequest {
                 },
             },
             limit: limit.try_into().unwrap_or(u8::MAX),
+            count_total,
         }
     }
 }
 
 impl From<PaginationRequest> for core_types::query::request::PageRequest {
-    fn from(PaginationRequest { kind, limit }: PaginationRequest) -> Self {
+    fn from(
+        PaginationRequest {
+            kind,
+            limit,
+            count_total,
+        }: PaginationRequest,
+    ) -> Self {
         let (key, offset) = match kind {
             PaginationKind::Key { key } => (key.into_vec(), 0),
             PaginationKind::Offset { offset } => (Vec::new(), offset),
@@ -76,7 +96,7 @@ impl From<PaginationRequest> for core_types::query::request::PageRequest {
             key,
             offset: offset as u64,
             limit: limit as u64,
-            count_total: false,
+            count_total,
             reverse: false,
         }
     }
@@ -1,4 +1,4 @@
-mod constants;
+pub(crate) mod constants;
 pub mod errors;
 pub mod guard;
 pub mod kv;
@@ -53,14 +53,16 @@ impl<M> TxBody<M> {
     }
 }
 
-impl<M: TxMessage> TryFrom<inner::TxBody> for TxBody<M> {
-    type Error = CoreError;
-
-    fn try_from(raw: inner::TxBody) -> Result<Self, Self::Error> {
+impl<M> TxBody<M> {
+    /// Decodes the raw body, decoding its messages with `decode_msg`
+    pub fn try_from_raw_with(
+        raw: inner::TxBody,
+        decode_msg: impl Fn(Any) -> Result<M, CoreError>,
+    ) -> Result<Self, CoreError> {
         let mut messages: Vec<M> = vec![];
 
         for msg in raw.messages {
-            messages.push(msg.try_into()?);
+            messages.push(decode_msg(Any::from(msg))?);
         }
 
         Ok(TxBody {
@@ -84,6 +86,14 @@ impl<M: TxMessage> TryFrom<inner::TxBody> for TxBody<M> {
     }
 }
 
+impl<M: TxMessage> TryFrom<inner::TxBody> for TxBody<M> {
+    type Error = CoreError;
+
+    fn try_from(raw: inner::TxBody) -> Result<Self, Self::Error> {
+        Self::try_from_raw_with(raw, M::try_from)
+    }
+}
+
 impl<M: TxMessage> From<TxBody<M>> for inner::TxBody {
     fn from(tx_body: TxBody<M>) -> inner::TxBody {
         Self {
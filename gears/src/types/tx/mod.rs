@@ -18,6 +18,7 @@ use super::{address::AccAddress, auth::info::AuthInfo, base::coins::UnsignedCoin
 
 pub mod body;
 pub mod raw;
+pub mod registry;
 
 pub trait TxMessage:
     serde::Serialize + Clone + Send + Sync + 'static + Into<Any> + TryFrom<Any, Error = CoreError>
@@ -105,7 +106,7 @@ impl<T: TxMessage> TryFrom<Vec<T>> for Messages<T> {
     }
 }
 
-mod inner {
+pub(crate) mod inner {
     pub use core_types::tx::inner::Tx;
 }
 
@@ -217,10 +218,12 @@ impl<M: TxMessage> Tx<M> {
     }
 }
 
-impl<M: TxMessage> TryFrom<inner::Tx> for Tx<M> {
-    type Error = TxError;
-
-    fn try_from(raw: inner::Tx) -> Result<Self, Self::Error> {
+impl<M> Tx<M> {
+    /// Decodes the raw tx, decoding its messages with `decode_msg`
+    pub fn try_from_raw_with(
+        raw: inner::Tx,
+        decode_msg: impl Fn(Any) -> Result<M, CoreError>,
+    ) -> Result<Self, TxError> {
         let body = raw.body.ok_or(TxError::MissingField("body".to_owned()))?;
 
         // This covers the SDK RejectExtensionOptions ante handler
@@ -253,7 +256,7 @@ impl<M: TxMessage> TryFrom<inner::Tx> for Tx<M> {
         }
 
         Ok(Tx {
-            body: body.try_into()?,
+            body: TxBody::try_from_raw_with(body, decode_msg)?,
             auth_info,
             signatures: raw.signatures,
             signatures_data,
@@ -261,6 +264,14 @@ impl<M: TxMessage> TryFrom<inner::Tx> for Tx<M> {
     }
 }
 
+impl<M: TxMessage> TryFrom<inner::Tx> for Tx<M> {
+    type Error = TxError;
+
+    fn try_from(raw: inner::Tx) -> Result<Self, Self::Error> {
+        Self::try_from_raw_with(raw, M::try_from)
+    }
+}
+
 impl<M: TxMessage> Protobuf<inner::Tx> for Tx<M> {}
 
 impl<M: TxMessage> From<Tx<M>> for inner::Tx {
@@ -1,9 +1,9 @@
-use core_types::{errors::CoreError, Protobuf};
+use core_types::{any::google::Any, errors::CoreError, Protobuf};
 use prost::{bytes::Bytes, Message as ProstMessage};
 
 use serde::{Deserialize, Serialize};
 
-use super::{Tx, TxMessage};
+use super::{registry::TypeUrlRegistry, Tx, TxMessage};
 
 mod inner {
     pub use core_types::tx::raw::TxRaw;
@@ -75,9 +75,29 @@ pub struct TxWithRaw<M> {
 
 impl<M: TxMessage> TxWithRaw<M> {
     pub fn from_bytes(raw: Bytes) -> Result<Self, CoreError> {
+        Self::from_bytes_with(raw, M::try_from)
+    }
+
+    /// Decodes the tx, decoding its messages with the decoders registered in `registry`
+    pub fn from_bytes_with_registry(
+        raw: Bytes,
+        registry: &TypeUrlRegistry<M>,
+    ) -> Result<Self, CoreError> {
+        Self::from_bytes_with(raw, |any| registry.decode_any(any))
+    }
+
+    fn from_bytes_with(
+        raw: Bytes,
+        decode_msg: impl Fn(Any) -> Result<M, CoreError>,
+    ) -> Result<Self, CoreError> {
         let tx_len = raw.len();
 
-        let tx = Tx::decode(raw.clone()).map_err(|e| CoreError::DecodeGeneral(format!("{}", e)))?;
+        let tx = super::inner::Tx::decode(raw.clone())
+            .map_err(|e| CoreError::DecodeGeneral(format!("{}", e)))
+            .and_then(|tx| {
+                Tx::try_from_raw_with(tx, decode_msg)
+                    .map_err(|e| CoreError::DecodeGeneral(format!("{}", e)))
+            })?;
 
         let raw =
             inner::TxRaw::decode(raw).map_err(|e| CoreError::DecodeGeneral(format!("{}", e)))?;
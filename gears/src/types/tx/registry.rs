@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use core_types::{any::google::Any, errors::CoreError};
+
+/// Function decoding an `Any` into a message.
+pub type DecodeFn<M> = fn(Any) -> Result<M, CoreError>;
+
+/// Registry mapping protobuf `Any` type urls to the decoder of the corresponding message.
+/// Modules register their messages so that transactions can be decoded without a
+/// hard coded match over every known type url.
+#[derive(Debug, Clone)]
+pub struct TypeUrlRegistry<M> {
+    decoders: HashMap<&'static str, DecodeFn<M>>,
+}
+
+impl<M> Default for TypeUrlRegistry<M> {
+    fn default() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+}
+
+impl<M> TypeUrlRegistry<M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `decode_fn` as the decoder for `type_url`.
+    /// Returns an error if a decoder is already registered for the type url.
+    pub fn register(
+        &mut self,
+        type_url: &'static str,
+        decode_fn: DecodeFn<M>,
+    ) -> Result<(), CoreError> {
+        if self.decoders.contains_key(type_url) {
+            return Err(CoreError::Custom(format!(
+                "type url already registered: {type_url}"
+            )));
+        }
+
+        self.decoders.insert(type_url, decode_fn);
+
+        Ok(())
+    }
+
+    pub fn contains(&self, type_url: &str) -> bool {
+        self.decoders.contains_key(type_url)
+    }
+
+    /// Decodes `any` using the decoder registered for its type url.
+    pub fn decode_any(&self, any: Any) -> Result<M, CoreError> {
+        let decode = self
+            .decoders
+            .get(any.type_url.as_str())
+            .ok_or_else(|| CoreError::DecodeAny(format!("unknown type url: {}", any.type_url)))?;
+
+        decode(any)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use extensions::testing::UnwrapTesting;
+
+    use core_types::Protobuf;
+    use prost::bytes::Bytes;
+
+    use crate::types::{
+        address::AccAddress,
+        auth::{fee::Fee, gas::Gas, info::AuthInfo},
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        msg::send::MsgSend,
+        tx::{body::TxBody, raw::TxWithRaw, Tx},
+    };
+
+    use super::*;
+
+    fn msg_send() -> MsgSend {
+        MsgSend {
+            from_address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+                .unwrap_test(),
+            to_address: AccAddress::from_bech32("cosmos199e4sxk8sjlecwhafl9e4nuznvuaaxmn5y7cdv")
+                .unwrap_test(),
+            amount: UnsignedCoins::new(vec![UnsignedCoin::from_str("10uatom").unwrap_test()])
+                .unwrap_test(),
+        }
+    }
+
+    #[test]
+    fn decode_any_registered_msg() {
+        let mut registry = TypeUrlRegistry::<MsgSend>::new();
+        registry
+            .register(MsgSend::TYPE_URL, MsgSend::try_from)
+            .unwrap_test();
+
+        let decoded = registry.decode_any(msg_send().into()).unwrap_test();
+
+        assert_eq!(decoded, msg_send());
+    }
+
+    #[test]
+    fn decode_tx_round_trip() {
+        let mut registry = TypeUrlRegistry::<MsgSend>::new();
+        registry
+            .register(MsgSend::TYPE_URL, MsgSend::try_from)
+            .unwrap_test();
+
+        let tx = Tx {
+            body: TxBody::new_with_defaults(vec1::vec1![msg_send()]),
+            auth_info: AuthInfo {
+                signer_infos: vec![],
+                fee: Fee {
+                    amount: None,
+                    gas_limit: Gas::default(),
+                    payer: None,
+                    granter: "".to_owned(),
+                },
+                tip: None,
+            },
+            signatures: vec![],
+            signatures_data: vec![],
+        };
+        let raw = Bytes::from(tx.encode_vec());
+
+        let decoded = TxWithRaw::from_bytes_with_registry(raw, &registry).unwrap_test();
+
+        assert_eq!(decoded.tx, tx);
+    }
+
+    #[test]
+    fn decode_any_unknown_type_url() {
+        let registry = TypeUrlRegistry::<MsgSend>::new();
+
+        let err = registry
+            .decode_any(msg_send().into())
+            .expect_err("type url is not registered");
+
+        assert!(matches!(err, CoreError::DecodeAny(_)));
+    }
+
+    #[test]
+    fn register_duplicate_type_url() {
+        let mut registry = TypeUrlRegistry::<MsgSend>::new();
+        registry
+            .register(MsgSend::TYPE_URL, MsgSend::try_from)
+            .unwrap_test();
+
+        registry
+            .register(MsgSend::TYPE_URL, MsgSend::try_from)
+            .expect_err("type url is already registered");
+    }
+}
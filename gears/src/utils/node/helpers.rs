@@ -9,8 +9,8 @@ use tendermint::types::chain_id::ChainId;
 use crate::{
     crypto::info::SigningInfo,
     types::{
-        auth::fee::Fee,
-        base::coins::Coins,
+        auth::{fee::Fee, gas::Gas},
+        base::coins::{Coins, UnsignedCoins},
         tx::{body::TxBody, Tx, TxMessage},
     },
 };
@@ -23,12 +23,53 @@ pub fn acc_address() -> AccAddress {
     AccAddress::from_bech32(ACC_ADDRESS).expect("Default Address should be valid")
 }
 
+/// Signs a tx for each of `msgs` paying a `1uatom` fee with a `200_000` gas limit.
 pub fn generate_txs<M: TxMessage>(
     msgs: impl IntoIterator<Item = (u64, M)>,
     user: &User,
     chain_id: ChainId,
+) -> Vec<Bytes> {
+    let Fee {
+        amount, gas_limit, ..
+    } = default_fee();
+
+    generate_txs_with(msgs, user, chain_id, amount, gas_limit)
+}
+
+/// Same as [`generate_txs`] but with the given fee amount and gas limit.
+pub fn generate_txs_with<M: TxMessage>(
+    msgs: impl IntoIterator<Item = (u64, M)>,
+    user: &User,
+    chain_id: ChainId,
+    fee_amount: Option<UnsignedCoins>,
+    gas_limit: Gas,
 ) -> Vec<Bytes> {
     let fee = Fee {
+        amount: fee_amount,
+        gas_limit,
+        payer: None,
+        granter: "".into(),
+    };
+
+    sign_txs(
+        msgs.into_iter()
+            .map(|(sequence, msg)| (sequence, msg, user)),
+        chain_id,
+        fee,
+    )
+}
+
+/// Same as [`generate_txs`] but each tx is signed by the user it's paired with, so a block
+/// can contain txs from several accounts. Sequences are tracked by the caller per user.
+pub fn generate_txs_multi<'a, M: TxMessage>(
+    msgs: impl IntoIterator<Item = (u64, M, &'a User)>,
+    chain_id: ChainId,
+) -> Vec<Bytes> {
+    sign_txs(msgs, chain_id, default_fee())
+}
+
+fn default_fee() -> Fee {
+    Fee {
         amount: Some(
             Coins::new(vec!["1uatom".parse().expect("hard coded coin is valid")])
                 .expect("hard coded coins are valid"),
@@ -38,11 +79,17 @@ pub fn generate_txs<M: TxMessage>(
             .expect("hard coded gas limit is valid"),
         payer: None,
         granter: "".into(),
-    };
+    }
+}
 
+fn sign_txs<'a, M: TxMessage>(
+    msgs: impl IntoIterator<Item = (u64, M, &'a User)>,
+    chain_id: ChainId,
+    fee: Fee,
+) -> Vec<Bytes> {
     let mut result = Vec::new();
 
-    for (sequence, msg) in msgs {
+    for (sequence, msg, user) in msgs {
         let signing_info = SigningInfo {
             key: &user.key_pair,
             sequence,
@@ -1,4 +1,7 @@
+use address::AccAddress;
 use bytes::Bytes;
+use core_types::query::{request::bank::QueryBalanceRequest, response::bank::QueryBalanceResponse};
+use prost::Message;
 
 use tendermint::{
     application::ABCIApplication,
@@ -16,9 +19,11 @@ use tendermint::{
             end_block::RequestEndBlock, init_chain::RequestInitChain, query::RequestQuery,
         },
         response::query::ResponseQuery,
-        time::timestamp::Timestamp,
+        time::{duration::Duration, timestamp::Timestamp},
     },
 };
+
+use crate::types::{base::coin::UnsignedCoin, denom::Denom};
 #[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
 pub struct InitState<G> {
     pub time: Timestamp,
@@ -49,6 +54,8 @@ pub struct MockNode<App, G> {
     chain_id: ChainId,
     time: Timestamp,
     last_block_id: BlockId,
+    validators: Vec<ValidatorUpdate>,
+    last_validator_updates: Vec<ValidatorUpdate>,
     // last_header: Header,
     _phantom: std::marker::PhantomData<G>,
 }
@@ -63,13 +70,21 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         // 1 then the app subtracts 1 from the height in the header.
         // On the first begin block the header height passed by TM is also 1. The app uses this without modification.
         // To achieve this we initialise the height in this mock state to 0 such that on the first step this gets incremented to the correct height of 1.
+        // More generally the height is initialised to one less than the initial height so that the first block is at the initial height.
 
         let res = app.init_chain(init_state.clone().into());
 
+        // Like TM, the validators returned by the app (e.g. from genesis gentxs) replace the
+        // validators of the init state. Otherwise the init state validators are used.
+        let validators = match res.validators.is_empty() {
+            true => init_state.validators,
+            false => res.validators,
+        };
+
         Self {
             app,
             app_hash: res.app_hash,
-            height: 0,
+            height: init_state.initial_height.saturating_sub(1),
             chain_id: init_state.chain_id,
             time: init_state.time,
             last_block_id: BlockId {
@@ -79,6 +94,8 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
                     hash: vec![],
                 }),
             },
+            validators,
+            last_validator_updates: Vec::new(),
             _phantom: Default::default(),
         }
     }
@@ -105,9 +122,11 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
             self.app.deliver_tx(RequestDeliverTx { tx });
         }
 
-        self.app.end_block(RequestEndBlock {
+        let res_end_block = self.app.end_block(RequestEndBlock {
             height: self.height as i64,
         });
+        self.apply_validator_updates(&res_end_block.validator_updates);
+        self.last_validator_updates = res_end_block.validator_updates;
 
         let res_commit = self.app.commit();
 
@@ -116,10 +135,66 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         &self.app_hash
     }
 
+    /// Updates with zero power remove the validator, other updates add it or change its power
+    fn apply_validator_updates(&mut self, updates: &[ValidatorUpdate]) {
+        for update in updates {
+            self.validators
+                .retain(|validator| validator.pub_key != update.pub_key);
+
+            if update.power.power() > 0 {
+                self.validators.push(update.clone());
+            }
+        }
+    }
+
+    /// Steps an empty block `duration` after the previous block
+    pub fn advance_time(&mut self, duration: Duration) -> &Bytes {
+        let time = self
+            .time
+            .checked_add(duration)
+            .expect("block time should stay in the valid range");
+
+        self.step([], time)
+    }
+
     pub fn query(&self, req: RequestQuery) -> ResponseQuery {
         self.app.query(req)
     }
 
+    /// Runs the query at `path` against the latest committed state and returns the encoded
+    /// response. Panics if the query fails.
+    pub fn query_path(&self, path: impl Into<String>, data: impl Into<Bytes>) -> Vec<u8> {
+        let res = self.query(RequestQuery {
+            data: data.into(),
+            path: path.into(),
+            height: 0,
+            prove: false,
+        });
+
+        assert_eq!(res.code, 0, "query failed: {}", res.log);
+
+        res.value.to_vec()
+    }
+
+    /// Returns the balance of `address` in `denom` from the latest committed state.
+    pub fn query_balance(&self, address: &AccAddress, denom: &Denom) -> UnsignedCoin {
+        let res = self.query_path(
+            "/cosmos.bank.v1beta1.Query/Balance",
+            QueryBalanceRequest {
+                address: address.to_string(),
+                denom: denom.to_string(),
+            }
+            .encode_to_vec(),
+        );
+
+        QueryBalanceResponse::decode(res.as_slice())
+            .expect("bank returns a valid response")
+            .balance
+            .expect("bank returns a balance for any denom")
+            .try_into()
+            .expect("bank returns a valid coin")
+    }
+
     fn calculate_header(&self) -> Header {
         Header {
             version: Consensus { block: 11, app: 10 },
@@ -171,13 +246,79 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
         }
     }
 
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    /// Returns the current validator set
+    pub fn validator_set(&self) -> &[ValidatorUpdate] {
+        &self.validators
+    }
+
+    /// Returns the validator updates of the `EndBlock` response of the last block
+    pub fn last_validator_updates(&self) -> &[ValidatorUpdate] {
+        &self.last_validator_updates
+    }
+
     pub fn chain_id(&self) -> &ChainId {
         &self.chain_id
     }
 
+    /// Returns the height of the last block, or one less than the initial height before the first step
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the time of the last block, or the genesis time before the first step
+    pub fn time(&self) -> Timestamp {
+        self.time
+    }
+
     pub fn skip_steps(&mut self, steps: usize) {
         for _ in 0..steps {
             let _ = self.step([], Timestamp::UNIX_EPOCH);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+    use tendermint::types::time::{duration::Duration, timestamp::Timestamp};
+
+    use crate::{
+        baseapp::block_events::tests::{
+            block_event, query_block_events, EventsHandler, SubspaceKey,
+        },
+        utils::node::{init_node, GenesisSource, MockOptions},
+    };
+
+    #[test]
+    fn mock_node_starts_at_initial_height() {
+        let (mut node, _) = init_node(MockOptions {
+            baseapp_sbs_key: SubspaceKey::Params,
+            node_opt: None,
+            genesis: GenesisSource::Default,
+            abci_handler: EventsHandler,
+            initial_height: Some(1000),
+            genesis_time: None,
+        });
+
+        node.step(vec![], Timestamp::UNIX_EPOCH);
+
+        assert_eq!(node.height(), 1000);
+        assert_eq!(
+            query_block_events(node.app(), 0).unwrap_test().begin_block,
+            vec![block_event("begin", 1000)]
+        );
+
+        node.advance_time(Duration::try_new(5, 0).unwrap_test());
+
+        assert_eq!(node.height(), 1001);
+        assert_eq!(node.time(), Timestamp::try_new(5, 0).unwrap_test());
+        assert_eq!(
+            query_block_events(node.app(), 0).unwrap_test().begin_block,
+            vec![block_event("begin", 1001)]
+        );
+    }
+}
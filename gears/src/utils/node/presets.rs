@@ -34,6 +34,10 @@ pub struct MockOptions<PSK: ParamsSubspaceKey, H: ABCIHandler, GS: Genesis> {
     pub node_opt: Option<NodeOptions>,
     pub genesis: GenesisSource<GS>,
     pub abci_handler: H,
+    /// Height of the first block, defaults to 1
+    pub initial_height: Option<u32>,
+    /// Genesis time, defaults to [`Timestamp::UNIX_EPOCH`]
+    pub genesis_time: Option<Timestamp>,
 }
 
 impl<PSK: ParamsSubspaceKey, H: ABCIHandler, GS: Genesis> From<MockOptionsFormer<PSK, H, GS>>
@@ -52,6 +56,8 @@ pub fn init_node<PSK: ParamsSubspaceKey, H: ABCIHandler<Genesis = GS>, GS: Genes
         node_opt,
         genesis,
         abci_handler,
+        initial_height,
+        genesis_time,
     } = opt.into();
 
     let db = MemDB::new();
@@ -92,7 +98,7 @@ pub fn init_node<PSK: ParamsSubspaceKey, H: ABCIHandler<Genesis = GS>, GS: Genes
     };
 
     let init_state = InitState {
-        time: Timestamp::UNIX_EPOCH,
+        time: genesis_time.unwrap_or(Timestamp::UNIX_EPOCH),
         chain_id: chain_id.clone(),
         consensus_params: ConsensusParams::default(),
         validators: vec![ValidatorUpdate {
@@ -102,7 +108,7 @@ pub fn init_node<PSK: ParamsSubspaceKey, H: ABCIHandler<Genesis = GS>, GS: Genes
             power: VotingPower::new(10).expect("hardcoded power is less the max voting power"),
         }],
         app_genesis,
-        initial_height: 1,
+        initial_height: initial_height.unwrap_or(1),
     };
 
     (
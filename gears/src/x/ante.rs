@@ -6,11 +6,15 @@ use crate::signing::handler::MetadataGetter;
 use crate::signing::renderer::amino_renderer::{AminoRenderer, RenderError as AminoRendererError};
 use crate::signing::std_sign_doc;
 use crate::signing::{handler::SignModeHandler, renderer::value_renderer::ValueRenderer};
+use crate::types::auth::fee::Fee;
 use crate::types::auth::gas::Gas;
 use crate::types::base::coin::UnsignedCoin;
 use crate::types::base::coins::UnsignedCoins;
+use crate::types::base::min_gas::MinGasPrices;
 use crate::types::denom::Denom;
-use crate::types::gas::descriptor::{ANTE_SECKP251K1_DESCRIPTOR, TX_SIZE_DESCRIPTOR};
+use crate::types::gas::descriptor::{
+    ANTE_ED25519_DESCRIPTOR, ANTE_SECKP251K1_DESCRIPTOR, TX_SIZE_DESCRIPTOR,
+};
 use crate::types::gas::kind::TxKind;
 use crate::types::gas::GasMeter;
 use crate::types::store::gas::errors::GasStoreErrors;
@@ -70,7 +74,15 @@ impl SignGasConsumer for DefaultSignGasConsumer {
                     .consume_gas(amount, ANTE_SECKP251K1_DESCRIPTOR)
                     .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
             }
-            PublicKey::Ed25519(_) => todo!(), //TODO: implement
+            PublicKey::Ed25519(_key) => {
+                let amount = params
+                    .sig_verify_cost_ed25519()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?;
+                gas_meter
+                    .consume_gas(amount, ANTE_ED25519_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?;
+            }
         }
 
         Ok(())
@@ -135,7 +147,7 @@ impl<
         self.consume_gas_for_tx_size(ctx, tx, gas_meter.clone())?;
         self.deduct_fee_ante_handler(ctx, &tx.tx)?;
         self.set_pub_key_ante_handler(ctx, &tx.tx)?;
-        //  ** ante.NewValidateSigCountDecorator(opts.AccountKeeper),
+        self.validate_sig_count_ante_handler(ctx, &tx.tx)?;
         self.sign_gas_consume(ctx, &tx.tx, gas_meter.clone())?;
         self.sig_verification_handler(ctx, tx)?;
         self.increment_sequence_ante_handler(ctx, &tx.tx)?;
@@ -150,7 +162,7 @@ impl<
         //  - ante.NewDeductFeeDecorator(opts.AccountKeeper, opts.BankKeeper, opts.FeegrantKeeper),
         // // SetPubKeyDecorator must be called before all signature verification decorators
         //  - ante.NewSetPubKeyDecorator(opts.AccountKeeper),
-        //  ** ante.NewValidateSigCountDecorator(opts.AccountKeeper),
+        //  - ante.NewValidateSigCountDecorator(opts.AccountKeeper),
         //  ante.NewSigGasConsumeDecorator(opts.AccountKeeper, sigGasConsumer),
         //  - ante.NewSigVerificationDecorator(opts.AccountKeeper, opts.SignModeHandler),
         //  - ante.NewIncrementSequenceDecorator(opts.AccountKeeper),
@@ -173,60 +185,7 @@ impl<
             return Ok(());
         }
 
-        let fee = tx.auth_info.fee.amount.as_ref();
-        let gas = tx.auth_info.fee.gas_limit;
-
-        let min_gas_prices = node_opt.min_gas_prices();
-
-        if min_gas_prices.is_empty() || min_gas_prices.is_zero() {
-            return Ok(());
-        }
-
-        if let Some(fee_coins) = fee {
-            let mut required_fees = Vec::with_capacity(min_gas_prices.len());
-
-            for gp in min_gas_prices {
-                required_fees.push(UnsignedCoin {
-                    denom: gp.denom,
-                    amount: gp
-                        .amount
-                        .checked_mul(Into::<Decimal256>::into(gas))
-                        .map_err(|_| {
-                            AnteGasError::Overflow("overflow calculating required fees".into())
-                        })?
-                        .to_uint_ceil(),
-                });
-            }
-
-            let required_fees = UnsignedCoins::new(required_fees)
-                .expect("we know by now that required_fees: contains at least one coin, all amounts are positive, no duplicate denominations and sorted lexicographically");
-
-            if !is_any_gte(fee_coins.inner(), &required_fees) {
-                Err(AnteError::InsufficientFees {
-                    got: format!("{fee_coins:?}"),
-                    required: format!("{required_fees:?}"),
-                })?
-            }
-        } else {
-            Err(AnteError::MissingFee)?
-        }
-
-        fn is_any_gte(coins_a: &Vec<UnsignedCoin>, coins_b: &UnsignedCoins) -> bool {
-            if coins_b.is_empty() {
-                return false;
-            }
-
-            for coin in coins_a {
-                let amount = coins_b.amount_of(&coin.denom);
-                if coin.amount >= amount && !amount.is_zero() {
-                    return true;
-                }
-            }
-
-            false
-        }
-
-        Ok(())
+        check_min_gas_prices(&tx.auth_info.fee, node_opt.min_gas_prices())
     }
 
     fn consume_gas_for_tx_size<M: TxMessage, DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -240,22 +199,8 @@ impl<
         gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
     ) -> Result<(), AnteError> {
         let params = self.auth_keeper.get_auth_params(ctx)?;
-        let tx_len: Gas = (*tx_len as u64).try_into().map_err(|_| AnteError::TxLen)?;
-        let cost_per_byte: Gas = params.tx_cost_per_byte().try_into().map_err(|_| {
-            AnteGasError::Overflow("overflow converting tx cost per byte to gas".to_string())
-        })?;
-        let gas_required = tx_len
-            .checked_mul(cost_per_byte)
-            .ok_or(AnteGasError::Overflow(
-                "overflow calculating gas required for tx size".to_string(),
-            ))?;
-
-        gas_meter
-            .borrow_mut()
-            .consume_gas(gas_required, TX_SIZE_DESCRIPTOR)
-            .map_err(Into::<AnteGasError>::into)?;
 
-        Ok(())
+        consume_gas_for_tx_size(&mut gas_meter.borrow_mut(), *tx_len, &params)
     }
 
     fn sign_gas_consume<M: TxMessage, DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -338,17 +283,23 @@ impl<
         ctx: &CTX,
         tx: &Tx<M>,
     ) -> Result<(), AnteError> {
-        let max_memo_chars = self.auth_keeper.get_auth_params(ctx)?.max_memo_characters();
-        let memo_length: u64 = tx
-            .get_memo()
-            .len()
-            .try_into()
-            .map_err(|_| AnteError::Memo(max_memo_chars))?;
-
-        if memo_length > max_memo_chars {
-            return Err(AnteError::Memo(max_memo_chars));
-        };
-        Ok(())
+        let params = self.auth_keeper.get_auth_params(ctx)?;
+
+        validate_memo(tx, &params)
+    }
+
+    fn validate_sig_count_ante_handler<
+        DB: Database,
+        M: TxMessage,
+        CTX: QueryableContext<DB, SK>,
+    >(
+        &self,
+        ctx: &CTX,
+        tx: &Tx<M>,
+    ) -> Result<(), AnteError> {
+        let params = self.auth_keeper.get_auth_params(ctx)?;
+
+        validate_sig_count(tx, &params)
     }
 
     fn deduct_fee_ante_handler<DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
@@ -587,6 +538,265 @@ impl<
     }
 }
 
+/// Rejects txs whose fee is below the node's minimum gas prices for every provided fee denom.
+/// Only applied in `CheckTx` so validators can set their own floor for the mempool.
+fn check_min_gas_prices(fee: &Fee, min_gas_prices: MinGasPrices) -> Result<(), AnteError> {
+    let fee_coins = fee.amount.as_ref();
+    let gas = fee.gas_limit;
+
+    if min_gas_prices.is_empty() || min_gas_prices.is_zero() {
+        return Ok(());
+    }
+
+    if let Some(fee_coins) = fee_coins {
+        let mut required_fees = Vec::with_capacity(min_gas_prices.len());
+
+        for gp in min_gas_prices {
+            let amount = gp
+                .amount
+                .checked_mul(Into::<Decimal256>::into(gas))
+                .map_err(|_| AnteGasError::Overflow("overflow calculating required fees".into()))?
+                .to_uint_ceil();
+
+            // a zero price for a denom doesn't impose a floor for it
+            if !amount.is_zero() {
+                required_fees.push(UnsignedCoin {
+                    denom: gp.denom,
+                    amount,
+                });
+            }
+        }
+
+        if required_fees.is_empty() {
+            return Ok(());
+        }
+
+        let required_fees = UnsignedCoins::new(required_fees)
+            .expect("we know by now that required_fees: contains at least one coin, all amounts are positive, no duplicate denominations and sorted lexicographically");
+
+        if !is_any_gte(fee_coins.inner(), &required_fees) {
+            Err(AnteError::InsufficientFees {
+                got: format!("{fee_coins:?}"),
+                required: format!("{required_fees:?}"),
+            })?
+        }
+    } else {
+        Err(AnteError::MissingFee)?
+    }
+
+    fn is_any_gte(coins_a: &Vec<UnsignedCoin>, coins_b: &UnsignedCoins) -> bool {
+        if coins_b.is_empty() {
+            return false;
+        }
+
+        for coin in coins_a {
+            let amount = coins_b.amount_of(&coin.denom);
+            if coin.amount >= amount && !amount.is_zero() {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    Ok(())
+}
+
+fn validate_memo<M: TxMessage, AP: AuthParams>(tx: &Tx<M>, params: &AP) -> Result<(), AnteError> {
+    let max_memo_chars = params.max_memo_characters();
+    let memo_length: u64 = tx
+        .get_memo()
+        .len()
+        .try_into()
+        .map_err(|_| AnteError::Memo(max_memo_chars))?;
+
+    if memo_length > max_memo_chars {
+        return Err(AnteError::Memo(max_memo_chars));
+    };
+    Ok(())
+}
+
+/// Rejects txs with more signer infos than allowed by the `tx_sig_limit` param. Multisig keys
+/// aren't supported yet, so every signer info counts as a single signature.
+fn validate_sig_count<M: TxMessage, AP: AuthParams>(
+    tx: &Tx<M>,
+    params: &AP,
+) -> Result<(), AnteError> {
+    let limit = params.tx_sig_limit();
+    let got = tx.auth_info.signer_infos.len() as u64;
+
+    if got > limit {
+        return Err(AnteError::TooManySignatures { limit, got });
+    }
+    Ok(())
+}
+
+fn consume_gas_for_tx_size<AP: AuthParams>(
+    gas_meter: &mut GasMeter<TxKind>,
+    tx_len: usize,
+    params: &AP,
+) -> Result<(), AnteError> {
+    let tx_len: Gas = (tx_len as u64).try_into().map_err(|_| AnteError::TxLen)?;
+    let cost_per_byte: Gas = params.tx_cost_per_byte().try_into().map_err(|_| {
+        AnteGasError::Overflow("overflow converting tx cost per byte to gas".to_string())
+    })?;
+    let gas_required = tx_len
+        .checked_mul(cost_per_byte)
+        .ok_or(AnteGasError::Overflow(
+            "overflow calculating gas required for tx size".to_string(),
+        ))?;
+
+    gas_meter
+        .consume_gas(gas_required, TX_SIZE_DESCRIPTOR)
+        .map_err(Into::<AnteGasError>::into)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+    use vec1::vec1;
+
+    use crate::types::{
+        address::AccAddress,
+        auth::info::AuthInfo,
+        base::coins::UnsignedCoins,
+        gas::{basic_meter::BasicGasMeter, FiniteGas},
+        msg::send::MsgSend,
+        signing::SignerInfo,
+        tx::body::TxBody,
+    };
+
+    use super::*;
+
+    struct TestParams {
+        max_memo_characters: u64,
+        tx_sig_limit: u64,
+    }
+
+    impl AuthParams for TestParams {
+        fn max_memo_characters(&self) -> u64 {
+            self.max_memo_characters
+        }
+
+        fn tx_sig_limit(&self) -> u64 {
+            self.tx_sig_limit
+        }
+
+        fn sig_verify_cost_ed25519(&self) -> u64 {
+            590
+        }
+
+        fn sig_verify_cost_secp256k1(&self) -> u64 {
+            1000
+        }
+
+        fn tx_cost_per_byte(&self) -> u64 {
+            10
+        }
+    }
+
+    const PARAMS: TestParams = TestParams {
+        max_memo_characters: 10,
+        tx_sig_limit: 2,
+    };
+
+    fn tx(memo: &str, signers: usize) -> Tx<MsgSend> {
+        let address = AccAddress::try_from(vec![1; 20]).unwrap_test();
+        let signer_info = SignerInfo {
+            public_key: None,
+            mode_info: ModeInfo::Single(SignMode::Direct),
+            sequence: 0,
+        };
+
+        Tx {
+            body: TxBody {
+                messages: vec1![MsgSend {
+                    from_address: address.clone(),
+                    to_address: address,
+                    amount: UnsignedCoins::new(vec!["10uatom".parse().unwrap_test()]).unwrap_test(),
+                }],
+                memo: memo.to_owned(),
+                timeout_height: 0,
+                extension_options: Vec::new(),
+                non_critical_extension_options: Vec::new(),
+            },
+            auth_info: AuthInfo {
+                signer_infos: vec![signer_info; signers],
+                fee: Fee {
+                    amount: None,
+                    gas_limit: 200_000_u32.into(),
+                    payer: None,
+                    granter: String::new(),
+                },
+                tip: None,
+            },
+            signatures: vec![vec![]; signers],
+            signatures_data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn validate_memo_rejects_too_long_memo() {
+        validate_memo(&tx("short memo", 1), &PARAMS).unwrap_test();
+
+        let err = validate_memo(&tx("a bit too long", 1), &PARAMS).unwrap_err();
+        assert!(matches!(err, AnteError::Memo(10)));
+    }
+
+    #[test]
+    fn validate_sig_count_rejects_too_many_signatures() {
+        validate_sig_count(&tx("", 2), &PARAMS).unwrap_test();
+
+        let err = validate_sig_count(&tx("", 3), &PARAMS).unwrap_err();
+        assert!(matches!(
+            err,
+            AnteError::TooManySignatures { limit: 2, got: 3 }
+        ));
+    }
+
+    fn fee(amount: &str) -> Fee {
+        Fee {
+            amount: Some(UnsignedCoins::new(vec![amount.parse().unwrap_test()]).unwrap_test()),
+            gas_limit: 200_000_u32.into(),
+            payer: None,
+            granter: String::new(),
+        }
+    }
+
+    #[test]
+    fn check_min_gas_prices_rejects_fee_below_minimum() {
+        let min_gas_prices: MinGasPrices = "0.01uatom".parse().unwrap_test();
+
+        let err = check_min_gas_prices(&fee("1999uatom"), min_gas_prices.clone()).unwrap_err();
+        assert!(matches!(err, AnteError::InsufficientFees { .. }));
+
+        // fees in a denom without a minimum price don't count
+        let err = check_min_gas_prices(&fee("5000stake"), min_gas_prices).unwrap_err();
+        assert!(matches!(err, AnteError::InsufficientFees { .. }));
+    }
+
+    #[test]
+    fn check_min_gas_prices_accepts_fee_at_or_above_minimum() {
+        let min_gas_prices: MinGasPrices = "0.01uatom".parse().unwrap_test();
+
+        check_min_gas_prices(&fee("2000uatom"), min_gas_prices.clone()).unwrap_test();
+        check_min_gas_prices(&fee("2001uatom"), min_gas_prices).unwrap_test();
+        check_min_gas_prices(&fee("1uatom"), MinGasPrices::default()).unwrap_test();
+    }
+
+    #[test]
+    fn consume_gas_for_tx_size_charges_per_byte() {
+        let mut gas_meter: GasMeter<TxKind> =
+            GasMeter::new(Box::new(BasicGasMeter::new(FiniteGas::from(1_000_000_u32))));
+
+        consume_gas_for_tx_size(&mut gas_meter, 250, &PARAMS).unwrap_test();
+
+        assert_eq!(gas_meter.consumed(), FiniteGas::from(2500_u32));
+    }
+}
+
 // TODO: uncomment tests
 // #[cfg(test)]
 // mod tests {
@@ -96,6 +96,8 @@ pub(crate) enum AnteError {
     Memo(u64),
     #[error("tx is too long")]
     TxLen,
+    #[error("signatures: {got}, limit: {limit}: too many signatures")]
+    TooManySignatures { limit: u64, got: u64 },
     #[error("account not found {0}")]
     AccountNotFound(#[from] AccountNotFound),
     #[error("{0}")]
@@ -130,6 +132,7 @@ impl From<AnteError> for TxError {
             AnteError::Gas(_) => 10,
             AnteError::LegacyAminoJson(_) => 11,
             AnteError::Signing(_) => 12,
+            AnteError::TooManySignatures { limit: _, got: _ } => 13,
         };
 
         TxError {
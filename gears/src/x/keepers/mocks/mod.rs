@@ -1,4 +1,5 @@
 pub mod auth;
 pub mod bank;
 pub mod gov;
+pub mod module;
 pub mod staking;
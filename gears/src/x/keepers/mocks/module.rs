@@ -0,0 +1,69 @@
+use key_derive::{ParamsKeys, StoreKeys};
+use sha2::Digest;
+
+use crate::{types::address::AccAddress, x::module::Module};
+
+/// Module accounts of the xmods, for tests of keepers which hold module accounts
+#[derive(strum::EnumIter, Debug, Clone, PartialEq, Eq)]
+pub enum MockModules {
+    FeeCollector,
+    BondedPool,
+    NotBondedPool,
+    Distribution,
+    Slashing,
+}
+
+impl Module for MockModules {
+    fn get_name(&self) -> String {
+        match self {
+            MockModules::FeeCollector => "fee_collector".into(),
+            MockModules::BondedPool => "bonded_tokens_pool".into(),
+            MockModules::NotBondedPool => "not_bonded_tokens_pool".into(),
+            MockModules::Distribution => "distribution".into(),
+            MockModules::Slashing => "slashing".into(),
+        }
+    }
+
+    fn get_address(&self) -> AccAddress {
+        // same derivation as module accounts of the cosmos sdk
+        let hash = sha2::Sha256::digest(self.get_name());
+
+        AccAddress::try_from(hash[..20].to_vec()).expect("20 bytes are a valid address")
+    }
+
+    fn get_permissions(&self) -> Vec<String> {
+        match self {
+            MockModules::BondedPool | MockModules::NotBondedPool => {
+                vec!["burner".into(), "staking".into()]
+            }
+            MockModules::FeeCollector | MockModules::Distribution | MockModules::Slashing => {
+                vec![]
+            }
+        }
+    }
+}
+
+/// Store and params keys of the xmods, for tests of keepers over a multi store
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+#[skey(params = Params, gears)]
+#[pkey(gears)]
+pub enum SubspaceKey {
+    #[skey(to_string = "acc")]
+    #[pkey(to_string = "auth/")]
+    Auth,
+    #[skey(to_string = "bank")]
+    #[pkey(to_string = "bank/")]
+    Bank,
+    #[skey(to_string = "staking")]
+    #[pkey(to_string = "staking/")]
+    Staking,
+    #[skey(to_string = "distribution")]
+    #[pkey(to_string = "distribution/")]
+    Distribution,
+    #[skey(to_string = "slashing")]
+    #[pkey(to_string = "slashing/")]
+    Slashing,
+    #[skey(to_string = "params")]
+    #[pkey(to_string = "params/")]
+    Params,
+}
@@ -8,10 +8,7 @@ use crate::{
         informal::Hash,
         rpc::{
             client::{Client, HttpClient},
-            response::{
-                block::Response as BlockResponse,
-                tx::{search::Response as SearchResponse, Response as CosmosTxResponse},
-            },
+            response::{block::Response as BlockResponse, tx::Response as CosmosTxResponse},
         },
         types::proto::block::Height,
     },
@@ -261,27 +258,38 @@ fn query_txs_by_event<M: TxMessage>(
     runtime: tokio::runtime::Runtime,
     req: &QueryGetTxsEventRequest,
 ) -> anyhow::Result<Vec<u8>> {
-    let query_str_events = req.events.join(" AND ");
-    let query = crate::tendermint::rpc::query::Query::from_str(&query_str_events)?;
-    let res: anyhow::Result<(SearchResponse, HashMap<Height, BlockResponse>)> =
-        runtime.block_on(async {
-            let txs = client
-                .tx_search(
-                    query,
-                    true,
-                    req.page,
-                    req.limit.try_into().unwrap_or(u8::MAX),
-                    crate::tendermint::rpc::Order::from_str(&req.order_by)?,
-                )
-                .await?;
-            let mut blocks: HashMap<Height, BlockResponse> = HashMap::with_capacity(txs.txs.len());
-            for tx in &txs.txs {
-                blocks.insert(tx.height, client.block(tx.height).await?);
-            }
+    let res = runtime.block_on(search_txs_by_event::<M, _>(&client, req))?;
+    Ok(res.encode_vec())
+}
+
+/// Joins event filters, e.g. `transfer.recipient='cosmos1...'`, into a tendermint `tx_search` query.
+pub fn txs_event_query(events: &[String]) -> anyhow::Result<crate::tendermint::rpc::query::Query> {
+    Ok(crate::tendermint::rpc::query::Query::from_str(
+        &events.join(" AND "),
+    )?)
+}
+
+/// Searches txs which match all event filters of the request and decodes them.
+pub async fn search_txs_by_event<M: TxMessage, C: Client + Sync>(
+    client: &C,
+    req: &QueryGetTxsEventRequest,
+) -> anyhow::Result<SearchTxsResult<M>> {
+    let search_res = client
+        .tx_search(
+            txs_event_query(&req.events)?,
+            true,
+            req.page,
+            req.limit.try_into().unwrap_or(u8::MAX),
+            crate::tendermint::rpc::Order::from_str(&req.order_by)?,
+        )
+        .await?;
+
+    let mut blocks_res: HashMap<Height, BlockResponse> =
+        HashMap::with_capacity(search_res.txs.len());
+    for tx in &search_res.txs {
+        blocks_res.insert(tx.height, client.block(tx.height).await?);
+    }
 
-            Ok((txs, blocks))
-        });
-    let (search_res, blocks_res) = res?;
     let mut tx_responses = vec![];
     for cosmos_tx in &search_res.txs {
         tx_responses.push(make_tx_response::<M>(
@@ -299,7 +307,7 @@ fn query_txs_by_event<M: TxMessage>(
         .try_into()
         .map_err(|e| anyhow!("{e}"))?;
 
-    let res = SearchTxsResult {
+    Ok(SearchTxsResult {
         total_count: search_res.total_count as u64,
         count,
         page_number: req.page as u64,
@@ -307,8 +315,7 @@ fn query_txs_by_event<M: TxMessage>(
         page_total: (search_res.total_count as f64 / req.limit as f64).ceil() as u64,
         limit: req.limit as u64,
         txs: tx_responses,
-    };
-    Ok(res.encode_vec())
+    })
 }
 
 fn make_tx_response<M: TxMessage>(
@@ -320,3 +327,89 @@ fn make_tx_response<M: TxMessage>(
         block_response.block.header.time.to_string(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use crate::{
+        tendermint::rpc::client::{Request as _, Response as _, SimpleRequest},
+        types::msg::send::MsgSend,
+    };
+
+    use super::*;
+
+    const TX_SEARCH_RESPONSE: &str = r#"{
+        "jsonrpc": "2.0",
+        "id": "",
+        "result": {
+            "txs": [],
+            "total_count": "0"
+        }
+    }"#;
+
+    /// Client recording the JSON of every request and responding with an empty tx search result
+    #[derive(Debug, Default)]
+    struct MockClient {
+        requests: std::sync::Mutex<Vec<serde_json::Value>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Client for MockClient {
+        async fn perform<R>(&self, request: R) -> Result<R::Response, tendermint::rpc::error::Error>
+        where
+            R: SimpleRequest,
+        {
+            self.requests
+                .lock()
+                .unwrap_test()
+                .push(serde_json::from_str(&request.into_json()).unwrap_test());
+
+            R::Response::from_string(TX_SEARCH_RESPONSE)
+        }
+    }
+
+    #[test]
+    fn txs_event_query_joins_events() {
+        let query = txs_event_query(&[
+            "transfer.recipient='cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux'".to_owned(),
+            "message.action='send'".to_owned(),
+        ])
+        .unwrap_test();
+
+        assert_eq!(
+            query.to_string(),
+            "transfer.recipient = 'cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux' AND message.action = 'send'"
+        );
+    }
+
+    #[test]
+    fn search_txs_by_event_sends_search_query() {
+        let client = MockClient::default();
+        let req = QueryGetTxsEventRequest {
+            events: vec![
+                "transfer.recipient='cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux'".to_owned(),
+            ],
+            order_by: "asc".to_owned(),
+            page: 1,
+            limit: 10,
+        };
+
+        let res = tokio::runtime::Runtime::new()
+            .unwrap_test()
+            .block_on(search_txs_by_event::<MsgSend, _>(&client, &req))
+            .unwrap_test();
+
+        assert!(res.txs.is_empty());
+        assert_eq!(res.total_count, 0);
+
+        let requests = client.requests.lock().unwrap_test();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["method"], "tx_search");
+        assert_eq!(
+            requests[0]["params"]["query"],
+            "transfer.recipient = 'cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux'"
+        );
+        assert_eq!(requests[0]["params"]["order_by"], "asc");
+    }
+}
@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, gears::derive::ParamsKeys)]
+#[pkey(from_str)]
+pub enum GaiaParamsStoreKey {
+    #[pkey(to_string = "bank/")]
+    Bank,
+    #[pkey(to_string = "auth/")]
+    Auth,
+    #[pkey(to_string = "baseapp/")]
+    BaseApp,
+}
+
+fn main() {
+    for key in GaiaParamsStoreKey::iter() {
+        assert_eq!(GaiaParamsStoreKey::from_str(&key.to_string()), Ok(key));
+    }
+
+    assert_eq!(GaiaParamsStoreKey::BaseApp.to_string(), "baseapp/");
+    assert!(GaiaParamsStoreKey::from_str("staking/").is_err());
+}
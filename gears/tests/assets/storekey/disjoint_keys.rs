@@ -0,0 +1,22 @@
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, gears::derive::StoreKeys)]
+#[skey(params = Params)]
+pub enum GaiaStoreKey {
+    #[skey(to_string = "bank")]
+    Bank,
+    #[skey(to_string = "params")]
+    Params,
+}
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, gears::derive::ParamsKeys)]
+pub enum GaiaParamsStoreKey {
+    #[pkey(to_string = "bank/")]
+    Bank,
+    #[pkey(to_string = "staking/")]
+    Staking,
+}
+
+// a store name may prefix a params subspace since they live in different keyspaces
+gears::store::assert_disjoint_keys!(GaiaStoreKey::STORE_KEYS);
+gears::store::assert_disjoint_keys!(GaiaParamsStoreKey::PARAMS_KEYS);
+
+fn main() {}
@@ -0,0 +1,23 @@
+use std::str::FromStr;
+
+use strum::IntoEnumIterator;
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, gears::derive::StoreKeys)]
+#[skey(params = Params, from_str)]
+pub enum GaiaStoreKey {
+    #[skey(to_string = "bank")]
+    Bank,
+    #[skey(to_string = "acc")]
+    Auth,
+    #[skey(to_string = "params")]
+    Params,
+}
+
+fn main() {
+    for key in GaiaStoreKey::iter() {
+        assert_eq!(GaiaStoreKey::from_str(&key.to_string()), Ok(key));
+    }
+
+    assert_eq!(GaiaStoreKey::Auth.to_string(), "acc");
+    assert!(GaiaStoreKey::from_str("staking").is_err());
+}
@@ -6,6 +6,12 @@ fn simple() {
     t.pass("tests/assets/paramskey/simple.rs");
 }
 
+#[test]
+fn from_str() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/assets/paramskey/from_str.rs");
+}
+
 #[test]
 #[should_panic]
 fn empty_key() {
@@ -6,6 +6,12 @@ fn simple() {
     t.pass("tests/assets/storekey/simple.rs");
 }
 
+#[test]
+fn from_str() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/assets/storekey/from_str.rs");
+}
+
 #[test]
 #[should_panic]
 fn empty_key() {
@@ -26,3 +32,9 @@ fn no_params() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/assets/storekey/no_params.rs");
 }
+
+#[test]
+fn disjoint_keys() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/assets/storekey/disjoint_keys.rs");
+}
@@ -57,6 +57,12 @@ pub enum Error {
         msg: String,
     },
 
+    #[error("the armored key is invalid: {msg}")]
+    Armor {
+        source: k256::pkcs8::Error,
+        msg: String,
+    },
+
     #[error("incorrect password")]
     IncorrectPassword,
 
@@ -2,7 +2,7 @@ pub mod secp256k1_key_pair;
 
 use serde::{Deserialize, Serialize};
 
-use self::secp256k1_key_pair::Secp256k1KeyPair;
+use self::secp256k1_key_pair::{HdPath, Secp256k1KeyPair};
 
 /// A key pair.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -56,6 +56,10 @@ impl KeyPair {
     pub fn from_mnemonic(mnemonic: &bip32::Mnemonic) -> Self {
         Self::Secp256k1(Secp256k1KeyPair::from_mnemonic(mnemonic))
     }
+
+    pub fn from_mnemonic_with_path(mnemonic: &bip32::Mnemonic, hd_path: HdPath) -> Self {
+        Self::Secp256k1(Secp256k1KeyPair::from_mnemonic_with_path(mnemonic, hd_path))
+    }
 }
 
 #[cfg(test)]
@@ -10,10 +10,29 @@ use pkcs8::{
     DecodePrivateKey, EncodePrivateKey, EncryptedPrivateKeyInfo, LineEnding, PrivateKeyInfo,
 };
 use std::error::Error;
-const HDPATH: &str = "m/44'/118'/0'/0/0";
+use std::fmt::Display;
 
 type SignatureBytes = [u8; 64];
 
+/// BIP44 derivation path `m/44'/118'/<account>'/0/<index>` used for Cosmos keys.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HdPath {
+    pub account: u32,
+    pub index: u32,
+}
+
+impl HdPath {
+    pub fn new(account: u32, index: u32) -> Self {
+        Self { account, index }
+    }
+}
+
+impl Display for HdPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "m/44'/118'/{}'/0/{}", self.account, self.index)
+    }
+}
+
 /// A secp256k1 key pair.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Secp256k1KeyPair(SecretKey);
@@ -95,10 +114,18 @@ impl Secp256k1KeyPair {
         Ok(Self(SecretKey::from_pkcs8_encrypted_pem(s, password)?))
     }
 
-    /// Returns a key pair from a mnemonic.
+    /// Returns a key pair from a mnemonic using the default derivation path.
     pub fn from_mnemonic(mnemonic: &Mnemonic) -> Self {
+        Self::from_mnemonic_with_path(mnemonic, HdPath::default())
+    }
+
+    /// Returns a key pair from a mnemonic using the given derivation path.
+    pub fn from_mnemonic_with_path(mnemonic: &Mnemonic, hd_path: HdPath) -> Self {
         let seed = mnemonic.to_seed("");
-        let child_path: DerivationPath = HDPATH.parse().expect("hard coded path will never fail");
+        let child_path: DerivationPath = hd_path
+            .to_string()
+            .parse()
+            .expect("path is built from valid components so this will never fail");
         let child_xprv = XPrv::derive_from_path(&seed, &child_path)
             .expect("seed has length 64 so this will never return an error");
         let signing_key = child_xprv.private_key();
@@ -181,6 +208,27 @@ mod tests {
         assert_eq!(key_pair, key_pair_from_pem);
     }
 
+    #[test]
+    fn hd_path_display() {
+        assert_eq!(HdPath::default().to_string(), "m/44'/118'/0'/0/0");
+        assert_eq!(HdPath::new(1, 2).to_string(), "m/44'/118'/1'/0/2");
+    }
+
+    #[test]
+    fn from_mnemonic_with_path_derives_distinct_keys() {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+
+        let default_key_pair = Secp256k1KeyPair::from_mnemonic(&mnemonic);
+        let first_key_pair =
+            Secp256k1KeyPair::from_mnemonic_with_path(&mnemonic, HdPath::new(0, 0));
+        let second_key_pair =
+            Secp256k1KeyPair::from_mnemonic_with_path(&mnemonic, HdPath::new(1, 0));
+
+        assert_eq!(default_key_pair, first_key_pair);
+        assert_ne!(first_key_pair, second_key_pair);
+    }
+
     #[test]
     fn sandpit() {
         let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
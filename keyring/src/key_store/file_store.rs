@@ -19,6 +19,24 @@ use std::os::unix::fs::PermissionsExt;
 const JSON_EXTENSION: &str = "json";
 const KEY_HASH_FILE: &str = "key_hash";
 
+/// Environment variable read for the keyring passphrase instead of prompting for it
+pub const KEYRING_PASSPHRASE_ENV: &str = "KEYRING_PASSPHRASE";
+
+/// Returns the `given` passphrase or reads it from [`KEYRING_PASSPHRASE_ENV`] if set, otherwise
+/// prompts the user.
+fn passphrase(given: Option<String>) -> Result<String, Error> {
+    match given.or_else(|| std::env::var(KEYRING_PASSPHRASE_ENV).ok()) {
+        Some(password) => Ok(password),
+        None => {
+            //TODO: wrap password in secret
+            rpassword::prompt_password("Enter keyring passphrase: ").map_err(|e| Error::IO {
+                msg: e.to_string(),
+                source: e,
+            })
+        }
+    }
+}
+
 fn verify_password(
     password: Option<impl AsRef<str>>,
     password_hash: &str,
@@ -99,6 +117,10 @@ fn open<'a>(
     backend: Backend,
 ) -> Result<Option<String>, Error> {
     let key_hash_path = path.as_ref().join(KEY_HASH_FILE);
+    let given_passphrase = match &backend {
+        Backend::Test => None,
+        Backend::Encrypted(passphrase) => passphrase.clone(),
+    };
 
     match fs::read_to_string(&key_hash_path) {
         Ok(password_hash) => {
@@ -109,7 +131,7 @@ fn open<'a>(
                     Err(Error::IncorrectBackend {
                         path: path.as_ref().display().to_string(),
                         expected: backend.into(),
-                        found: Backend::Encrypted.into(),
+                        found: Backend::Encrypted(None).into(),
                     })
                 }
             } else if password_hash.is_empty() {
@@ -119,15 +141,7 @@ fn open<'a>(
                     found: Backend::Test.into(),
                 })
             } else {
-                let password = Some(
-                    //TODO: wrap password in secret
-                    rpassword::prompt_password("Enter keyring passphrase: ").map_err(|e| {
-                        Error::IO {
-                            msg: e.to_string(),
-                            source: e,
-                        }
-                    })?,
-                );
+                let password = Some(passphrase(given_passphrase)?);
                 verify_password(password.as_deref(), &password_hash, &key_hash_path)?;
                 Ok(password)
             }
@@ -144,12 +158,7 @@ fn open<'a>(
                     let password = if backend == Backend::Test {
                         None
                     } else {
-                        let password = rpassword::prompt_password("Enter keyring passphrase: ")
-                            .map_err(|e| Error::IO {
-                                msg: e.to_string(),
-                                source: e,
-                            })?;
-                        Some(password)
+                        Some(passphrase(given_passphrase)?)
                     };
 
                     let password_hash = calculate_password_hash(password.as_deref())?;
@@ -201,6 +210,55 @@ where
     S: AsRef<str> + ?Sized,
 {
     let password = open(&path, false, backend)?;
+
+    read_key(name, path, password.as_deref())
+}
+
+/// Gets all entries in the keyring sorted by name.
+pub fn list_keys(
+    path: impl AsRef<Path>,
+    backend: Backend,
+) -> Result<Vec<(String, KeyPair)>, Error> {
+    let password = open(&path, false, backend)?;
+
+    let entries = fs::read_dir(&path).map_err(|e| Error::FileIO {
+        msg: e.to_string(),
+        source: e,
+        path: path.as_ref().display().to_string(),
+    })?;
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| Error::FileIO {
+            msg: e.to_string(),
+            source: e,
+            path: path.as_ref().display().to_string(),
+        })?;
+
+        let entry_path = entry.path();
+        if entry_path.extension().and_then(|ext| ext.to_str()) != Some(JSON_EXTENSION) {
+            continue;
+        }
+
+        if let Some(name) = entry_path.file_stem().and_then(|name| name.to_str()) {
+            names.push(name.to_owned());
+        }
+    }
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let key_pair = read_key(&name, &path, password.as_deref())?;
+            Ok((name, key_pair))
+        })
+        .collect()
+}
+
+fn read_key<S>(name: &S, path: impl AsRef<Path>, password: Option<&str>) -> Result<KeyPair, Error>
+where
+    S: AsRef<str> + ?Sized,
+{
     let mut path = path.as_ref().join(name.as_ref());
     path.set_extension(JSON_EXTENSION);
 
@@ -327,14 +385,16 @@ where
 #[derive(PartialEq)]
 pub enum Backend {
     Test,
-    Encrypted,
+    /// Keys are encrypted with the given passphrase. If it's `None` the passphrase is read from
+    /// [`KEYRING_PASSPHRASE_ENV`] or prompted for.
+    Encrypted(Option<String>),
 }
 
 impl From<Backend> for String {
     fn from(backend: Backend) -> Self {
         match backend {
             Backend::Test => "test".into(),
-            Backend::Encrypted => "encrypted".into(),
+            Backend::Encrypted(_) => "encrypted".into(),
         }
     }
 }
@@ -343,6 +403,8 @@ impl From<Backend> for String {
 mod tests {
 
     use super::*;
+    use crate::key::pair::secp256k1_key_pair::{HdPath, Secp256k1KeyPair};
+    use bip32::Mnemonic;
     use std::path;
 
     #[test]
@@ -405,4 +467,35 @@ mod tests {
         let password_hash = calculate_password_hash(Some(password)).expect("password is valid");
         verify_password(Some(password), &password_hash, &path).expect("password matches hash");
     }
+
+    #[test]
+    fn encrypted_backend_encrypts_keys_with_passphrase() {
+        let path = path::PathBuf::from("./tmp/keyring/src/key_store/file_store/encrypted_backend");
+        let _ = fs::remove_dir_all(&path);
+
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let key_pair = KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic_with_path(
+            &Mnemonic::new(mnemonic, bip32::Language::English).expect("mnemonic is valid"),
+            HdPath::default(),
+        ));
+        let encrypted = |passphrase: &str| Backend::Encrypted(Some(passphrase.to_owned()));
+
+        set_key_pair("bob", &key_pair, &path, encrypted("passphrase")).expect("key should be set");
+
+        // the key isn't stored in plaintext
+        let stored = fs::read_to_string(path.join("bob.json")).expect("key file should be read");
+        assert!(serde_json::from_str::<KeyPair>(&stored).is_err());
+
+        let error = get_key_by_name("bob", &path, encrypted("wrong"))
+            .expect_err("key should not be retrieved");
+        assert!(matches!(error, Error::IncorrectPassword));
+
+        let stored = get_key_by_name("bob", &path, encrypted("passphrase"));
+        fs::remove_dir_all(path).expect("tmp directory should be deleted");
+
+        assert_eq!(
+            stored.expect("key should be retrieved").to_pkcs8_pem(),
+            key_pair.to_pkcs8_pem()
+        );
+    }
 }
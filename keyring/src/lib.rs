@@ -3,4 +3,5 @@ pub mod key;
 pub mod key_store;
 mod keyring;
 
+pub use crate::key::pair::secp256k1_key_pair::HdPath;
 pub use crate::keyring::*;
@@ -169,6 +169,26 @@ impl<DB: Database> ApplicationKVBank<DB> {
         //TODO: is it safe to assume this won't ever error?
         persistent.save_version().ok().unwrap_or_default().0
     }
+
+    /// Delete all persisted versions older than `retain_from`. The latest version is never deleted.
+    /// Versions which are still being queried are skipped and deleted by a later call.
+    pub fn prune(&mut self, retain_from: u32) {
+        let mut persistent = self.persistent.write().expect(POISONED_LOCK);
+
+        let retain_from = retain_from.min(persistent.loaded_version());
+        let versions = persistent
+            .versions()
+            .range(..retain_from)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for version in versions {
+            match persistent.delete_version(version) {
+                Ok(()) | Err(trees::Error::VersionInUse(_)) => (),
+                Err(e) => unreachable!("version exists and isn't the latest: {e}"),
+            }
+        }
+    }
 }
 
 #[cfg(test)]
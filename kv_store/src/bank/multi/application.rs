@@ -99,6 +99,13 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         hash
     }
 
+    /// Delete all persisted versions older than `retain_from` from every store
+    pub fn prune(&mut self, retain_from: u32) {
+        for store in self.backend.0.values_mut() {
+            store.prune(retain_from);
+        }
+    }
+
     pub fn clear_cache(&mut self) {
         for store in self.backend.0.values_mut() {
             store.cache_clear();
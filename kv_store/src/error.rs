@@ -26,5 +26,9 @@ impl<SK: StoreKey> std::fmt::Display for MultiStoreError<SK> {
     }
 }
 
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("error parsing store key: {0}")]
+pub struct StoreKeyParseError(pub String);
+
 pub const KEY_EXISTS_MSG: &str = "a store for every key is guaranteed to exist";
 pub const POISONED_LOCK: &str = "poisoned lock";
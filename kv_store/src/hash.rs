@@ -1,3 +1,4 @@
+use ics23::ExistenceProof;
 use integer_encoding::VarInt;
 use sha2::{Digest, Sha256};
 
@@ -49,10 +50,32 @@ pub fn hash_store_infos(store_infos: Vec<StoreInfo>) -> [u8; 32] {
     trees::merkle::root_hash(&byte_pairs)
 }
 
+/// Returns an ICS23 proof that the store called `name` has its hash in the commit hash of
+/// `store_infos`, or `None` if there is no such store.
+pub fn store_info_proof(store_infos: Vec<StoreInfo>, name: &str) -> Option<ExistenceProof> {
+    let hash = store_infos.iter().find(|info| info.name == name)?.hash;
+
+    let mut pairs: Vec<Pair> = store_infos.into_iter().map(|info| info.into()).collect();
+    pairs.sort();
+    let index = pairs.iter().position(|pair| pair.key == name.as_bytes())?;
+    let byte_pairs: Vec<Vec<u8>> = pairs.into_iter().map(|pair| pair.to_bytes()).collect();
+
+    Some(ExistenceProof {
+        key: name.into(),
+        value: hash.to_vec(),
+        leaf: Some(trees::merkle::leaf_op()),
+        path: trees::merkle::inner_ops(&byte_pairs, index),
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
     use extensions::testing::UnwrapTesting;
+    use ics23::{
+        commitment_proof::Proof, tendermint_spec, verify_membership, CommitmentProof,
+        HostFunctionsManager,
+    };
 
     use super::*;
 
@@ -95,4 +118,40 @@ mod tests {
             "9328960b097a043bd62b6d22075084251688dff84d004743d0666f4ecdd5b86d"
         );
     }
+
+    #[test]
+    fn store_info_proof_verifies_against_commit_hash() {
+        let store_infos = || {
+            ["acc", "bank", "ibc", "params", "staking"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| StoreInfo {
+                    name: name.to_owned(),
+                    hash: [i as u8; 32],
+                })
+                .collect::<Vec<_>>()
+        };
+        let commit_hash = hash_store_infos(store_infos()).to_vec();
+
+        for (i, name) in ["acc", "bank", "ibc", "params", "staking"]
+            .into_iter()
+            .enumerate()
+        {
+            let proof = CommitmentProof {
+                proof: Some(Proof::Exist(
+                    store_info_proof(store_infos(), name).unwrap_test(),
+                )),
+            };
+
+            assert!(verify_membership::<HostFunctionsManager>(
+                &proof,
+                &tendermint_spec(),
+                &commit_hash,
+                name.as_bytes(),
+                &[i as u8; 32]
+            ));
+        }
+
+        assert_eq!(store_info_proof(store_infos(), "gov"), None);
+    }
 }
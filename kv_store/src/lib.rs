@@ -32,8 +32,7 @@ fn build_prefixed_stores<DB: Database, SK: StoreKey>(db: Arc<DB>) -> HashMap<SK,
     let mut stores = HashMap::new();
 
     for store in SK::iter() {
-        let prefix = store.name().as_bytes().to_vec();
-        let prefixed_db = PrefixDB::new(Arc::clone(&db), prefix);
+        let prefixed_db = DB::store(&db, store.name());
 
         stores.insert(store, prefixed_db);
     }
@@ -41,6 +40,74 @@ fn build_prefixed_stores<DB: Database, SK: StoreKey>(db: Arc<DB>) -> HashMap<SK,
     stores
 }
 
+/// Returns `false` if any key of `keys` is a prefix of another one. Stores sharing a database
+/// are separated by prefixing their keys with the store name, so such stores would read and
+/// write each other's state. The same holds for the subspaces of a params store.
+pub const fn keys_prefix_free(keys: &[&[&str]]) -> bool {
+    const fn is_prefix(prefix: &[u8], bytes: &[u8]) -> bool {
+        if prefix.len() > bytes.len() {
+            return false;
+        }
+
+        let mut i = 0;
+        while i < prefix.len() {
+            if prefix[i] != bytes[i] {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    // compare every pair of names, including names of the same enum
+    let mut i = 0;
+    while i < keys.len() {
+        let mut a = 0;
+        while a < keys[i].len() {
+            let mut j = i;
+            while j < keys.len() {
+                let mut b = if j == i { a + 1 } else { 0 };
+                while b < keys[j].len() {
+                    let (first, second) = (keys[i][a].as_bytes(), keys[j][b].as_bytes());
+                    if is_prefix(first, second) || is_prefix(second, first) {
+                        return false;
+                    }
+                    b += 1;
+                }
+                j += 1;
+            }
+            a += 1;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Fails compilation if a key is a prefix of another one. Takes the `STORE_KEYS` consts
+/// generated by the `StoreKeys` derive of every enum whose stores share a database, or the
+/// `PARAMS_KEYS` consts of the subspaces sharing a params store. Store and params keys prefix
+/// different keyspaces so they mustn't be mixed.
+///
+/// ```
+/// kv_store::assert_disjoint_keys!(&["bank", "acc", "params"]);
+/// ```
+///
+/// ```compile_fail
+/// kv_store::assert_disjoint_keys!(&["ibc", "ibc_transfer", "params"]);
+/// ```
+#[macro_export]
+macro_rules! assert_disjoint_keys {
+    ($($keys:expr),+ $(,)?) => {
+        const _: () = {
+            if !$crate::keys_prefix_free(&[$($keys),+]) {
+                panic!("a key is a prefix of another key of the same keyspace");
+            }
+        };
+    };
+}
+
 // pub trait ReadPrefixStore {
 //     type Err;
 
@@ -87,3 +154,24 @@ fn build_prefixed_stores<DB: Database, SK: StoreKey>(db: Arc<DB>) -> HashMap<SK,
 //     /// Clears the tx caches
 //     fn caches_clear(&mut self);
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_prefix_free_checks_names_of_the_same_enum() {
+        assert!(keys_prefix_free(&[&["bank", "acc", "params"]]));
+        assert!(!keys_prefix_free(&[&["ibc", "params", "ibc_transfer"]]));
+        assert!(!keys_prefix_free(&[&["bank", "bank"]]));
+    }
+
+    #[test]
+    fn keys_prefix_free_checks_names_across_enums() {
+        assert!(keys_prefix_free(&[&["bank", "params"], &["acc"]]));
+        assert!(!keys_prefix_free(&[
+            &["bank", "params"],
+            &["acc", "bank_v2"]
+        ]));
+    }
+}
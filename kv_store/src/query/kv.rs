@@ -1,6 +1,7 @@
 use std::ops::RangeBounds;
 
 use database::Database;
+use ics23::ExistenceProof;
 use trees::iavl::QueryTree;
 
 use crate::store::prefix::immutable::ImmutablePrefixStore;
@@ -23,6 +24,15 @@ impl<DB: Database> QueryKVStore<DB> {
         self.0.get(k.as_ref())
     }
 
+    /// Returns an ICS23 proof that `k` is in the store, or `None` if it isn't
+    pub fn get_proof<R: AsRef<[u8]> + ?Sized>(&self, k: &R) -> Option<ExistenceProof> {
+        self.0.get_proof(k.as_ref())
+    }
+
+    pub fn root_hash(&self) -> [u8; 32] {
+        self.0.root_hash()
+    }
+
     pub fn prefix_store<I: IntoIterator<Item = u8>>(
         &self,
         prefix: I,
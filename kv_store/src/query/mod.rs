@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 
 use database::{prefix::PrefixDB, Database};
+use ics23::{commitment_proof::Proof, CommitmentProof};
 use trees::iavl::QueryTree;
 
 use crate::{
     bank::kv::application::ApplicationKVBank,
     bank::multi::{ApplicationMultiBank, MultiBankBackend},
     error::{KVStoreError, KEY_EXISTS_MSG, POISONED_LOCK},
+    hash::{store_info_proof, StoreInfo},
     StoreKey,
 };
 
@@ -74,6 +76,33 @@ impl<DB: Database, SK: StoreKey> QueryMultiStore<DB, SK> {
         ))
     }
 
+    /// Returns the ICS23 proofs that `key` is in the store of `store_key`, or `None` if it isn't.
+    /// The first proves the key against the store's root hash and the second proves the
+    /// store's root hash against the commit hash of the queried version.
+    pub fn get_proof(&self, store_key: &SK, key: &[u8]) -> Option<[CommitmentProof; 2]> {
+        let store_proof = self
+            .inner
+            .get(store_key)
+            .expect(KEY_EXISTS_MSG)
+            .get_proof(key)?;
+
+        let store_infos = self
+            .inner
+            .iter()
+            .map(|(store_key, store)| StoreInfo {
+                name: store_key.name().into(),
+                hash: store.root_hash(),
+            })
+            .collect();
+        let multi_store_proof = store_info_proof(store_infos, store_key.name())?;
+
+        Some(
+            [store_proof, multi_store_proof].map(|proof| CommitmentProof {
+                proof: Some(Proof::Exist(proof)),
+            }),
+        )
+    }
+
     pub fn head_version(&self) -> u32 {
         self.head_version
     }
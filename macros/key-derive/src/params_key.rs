@@ -10,6 +10,8 @@ use syn::{spanned::Spanned, DataEnum, DeriveInput, Variant};
 struct KeysArg {
     #[darling(default)]
     pub gears: Flag,
+    #[darling(default)]
+    pub from_str: Flag,
 }
 
 #[derive(FromAttributes, Default)]
@@ -30,7 +32,7 @@ impl KeysAttr {
 }
 
 pub fn expand_params(input: DeriveInput) -> syn::Result<TokenStream> {
-    let KeysArg { gears } = KeysArg::from_derive_input(&input)?;
+    let KeysArg { gears, from_str } = KeysArg::from_derive_input(&input)?;
     let DeriveInput { ident, data, .. } = input;
 
     match data {
@@ -41,6 +43,7 @@ pub fn expand_params(input: DeriveInput) -> syn::Result<TokenStream> {
             };
 
             let mut enum_variants = Vec::<TokenStream>::new();
+            let mut keys = Vec::<String>::new();
             let mut from_str_impls = Vec::<TokenStream>::new();
             let mut set = HashSet::<String>::with_capacity(enum_variants.len());
 
@@ -62,11 +65,31 @@ pub fn expand_params(input: DeriveInput) -> syn::Result<TokenStream> {
                 }
 
                 let _ = set.insert(to_string.clone());
+                keys.push(to_string.clone());
 
                 enum_variants.push(quote! { Self::#ident => #to_string });
                 from_str_impls.push(quote! { #to_string => Self::#ident });
             }
 
+            let from_str_impl = match from_str.is_present() {
+                true => quote! {
+                    impl ::std::str::FromStr for #ident {
+                        type Err = #crate_prefix::params::SubspaceParseError;
+
+                        fn from_str(val: &str) -> ::std::result::Result<Self, Self::Err> {
+                            #crate_prefix::params::ParamsSubspaceKey::from_subspace_str(val)
+                        }
+                    }
+
+                    impl ::std::fmt::Display for #ident {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            f.write_str(#crate_prefix::params::ParamsSubspaceKey::name(self))
+                        }
+                    }
+                },
+                false => quote! {},
+            };
+
             let result = quote! {
                 impl #crate_prefix ::params::ParamsSubspaceKey for #ident
                 {
@@ -87,6 +110,13 @@ pub fn expand_params(input: DeriveInput) -> syn::Result<TokenStream> {
                         ::std::result::Result::Ok(result)
                     }
                 }
+
+                impl #ident {
+                    /// All params subspace key prefixes of this enum
+                    pub const PARAMS_KEYS: &'static [&'static str] = &[#(#keys),*];
+                }
+
+                #from_str_impl
             };
 
             Ok(result)
@@ -10,6 +10,8 @@ use syn::{spanned::Spanned, DataEnum, DeriveInput, Ident, Variant};
 struct KeysArg {
     #[darling(default)]
     pub gears: Flag,
+    #[darling(default)]
+    pub from_str: Flag,
     pub params: Ident,
 }
 
@@ -31,7 +33,11 @@ impl KeysAttr {
 }
 
 pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
-    let KeysArg { gears, params } = KeysArg::from_derive_input(&input)?;
+    let KeysArg {
+        gears,
+        from_str,
+        params,
+    } = KeysArg::from_derive_input(&input)?;
     let DeriveInput { ident, data, .. } = input;
 
     match data {
@@ -42,6 +48,8 @@ pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
             };
 
             let mut enum_variants = Vec::<TokenStream>::new();
+            let mut keys = Vec::<String>::new();
+            let mut from_str_impls = Vec::<TokenStream>::new();
             let mut set = HashSet::<String>::with_capacity(enum_variants.len());
 
             for Variant { attrs, ident, .. } in variants {
@@ -62,10 +70,37 @@ pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
                 }
 
                 let _ = set.insert(to_string.clone());
+                keys.push(to_string.clone());
 
                 enum_variants.push(quote! { Self::#ident => #to_string });
+                from_str_impls.push(quote! { #to_string => Self::#ident });
             }
 
+            let from_str_impl = match from_str.is_present() {
+                true => quote! {
+                    impl ::std::str::FromStr for #ident {
+                        type Err = #crate_prefix::store::error::StoreKeyParseError;
+
+                        fn from_str(val: &str) -> ::std::result::Result<Self, Self::Err> {
+                            let result = match val
+                            {
+                                #(#from_str_impls),*
+                                , _ => ::std::result::Result::Err(#crate_prefix::store::error::StoreKeyParseError(::std::format!("missing valid key: {val} not found")))?,
+                            };
+
+                            ::std::result::Result::Ok(result)
+                        }
+                    }
+
+                    impl ::std::fmt::Display for #ident {
+                        fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                            f.write_str(#crate_prefix::store::StoreKey::name(self))
+                        }
+                    }
+                },
+                false => quote! {},
+            };
+
             let result = quote! {
                 impl #crate_prefix ::store::StoreKey for #ident
                 {
@@ -82,6 +117,13 @@ pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
                         &PARAM_KEY
                     }
                 }
+
+                impl #ident {
+                    /// All store key prefixes of this enum
+                    pub const STORE_KEYS: &'static [&'static str] = &[#(#keys),*];
+                }
+
+                #from_str_impl
             };
 
             Ok(result)
@@ -8,3 +8,4 @@ pub mod hash {
 pub use tendermint_informal::Block;
 pub use tendermint_informal::Hash;
 pub use tendermint_informal::PublicKey;
+pub use tendermint_informal::Time;
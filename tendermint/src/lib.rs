@@ -10,6 +10,9 @@ use types::{chain_id::ChainId, proto::crypto::PublicKey};
 
 pub mod abci;
 pub mod application;
+pub mod config {
+    pub use tendermint_config::net::Address;
+}
 pub mod crypto;
 pub mod error;
 pub(crate) mod ext;
@@ -38,6 +41,8 @@ pub fn write_keys_and_genesis(
     mut genesis_file: File,
     app_state: serde_json::Value, //TODO: make this a generic
     chain_id: ChainId,
+    genesis_time: tendermint_informal::Time,
+    initial_height: i64,
 ) -> Result<(), Error> {
     // write node key
     let priv_key = crypto::new_private_key();
@@ -65,9 +70,9 @@ pub fn write_keys_and_genesis(
     // write genesis file
     // TODO: create a Genesis struct in this crate and define a default
     let genesis = tendermint_informal::Genesis {
-        genesis_time: tendermint_informal::Time::now(),
+        genesis_time,
         chain_id: chain_id.into(),
-        initial_height: 1,
+        initial_height,
         consensus_params: tendermint_informal::consensus::Params {
             block: tendermint_informal::block::Size {
                 max_bytes: 22020096,
@@ -127,13 +132,20 @@ pub fn write_priv_validator_state(mut priv_validator_state_key_file: File) -> Re
         .map_err(|e| e.into())
 }
 
+/// Values of the Tendermint config which replace the defaults
+#[derive(Debug, Clone, Default)]
+pub struct TmConfigOverrides {
+    pub p2p_laddr: Option<config::Address>,
+    pub rpc_laddr: Option<config::Address>,
+    pub seeds: Vec<config::Address>,
+    pub persistent_peers: Vec<config::Address>,
+}
+
 pub fn write_tm_config(
     mut file: File,
     node_name: &str,
-    // peers: Vec<Address>,
+    overrides: TmConfigOverrides,
     // external_address: Option<Address>,
-    // tm_rpc_bind: Option<SocketAddr>,
-    // tm_p2p_bind: Option<SocketAddr>,
 ) -> Result<(), Error> {
     let mut handlebars = handlebars::Handlebars::new();
     handlebars
@@ -145,6 +157,21 @@ pub fn write_tm_config(
         .parse()
         .expect("the Moniker::from_str method never fails");
 
+    let TmConfigOverrides {
+        p2p_laddr,
+        rpc_laddr,
+        seeds,
+        persistent_peers,
+    } = overrides;
+    if let Some(p2p_laddr) = p2p_laddr {
+        tm_config.p2p.laddr = p2p_laddr;
+    }
+    if let Some(rpc_laddr) = rpc_laddr {
+        tm_config.rpc.laddr = rpc_laddr;
+    }
+    tm_config.p2p.seeds = seeds;
+    tm_config.p2p.persistent_peers = persistent_peers;
+
     let tm_config = handlebars
         .render("config", &tm_config)
         .expect("TendermintConfig will always work with the TM_CONFIG_TEMPLATE");
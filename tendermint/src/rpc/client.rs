@@ -1,9 +1,13 @@
 use tendermint_rpc::endpoint::validators::DEFAULT_VALIDATORS_PER_PAGE;
-use tendermint_rpc::{Error, HttpClient as TendermintHttpClient, SimpleRequest};
+use tendermint_rpc::{Error, HttpClient as TendermintHttpClient};
 
 pub use tendermint_rpc::Client;
 pub use tendermint_rpc::HttpClientUrl;
+pub use tendermint_rpc::Method;
 pub use tendermint_rpc::Paging;
+pub use tendermint_rpc::Request;
+pub use tendermint_rpc::Response;
+pub use tendermint_rpc::SimpleRequest;
 
 #[derive(Debug, Clone)]
 pub struct HttpClient {
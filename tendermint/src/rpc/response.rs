@@ -6,6 +6,7 @@ pub mod tx {
     pub use tendermint_rpc::endpoint::tx::Response;
 
     pub mod broadcast {
+        pub use tendermint_rpc::endpoint::broadcast::tx_async::Response as AsyncResponse;
         pub use tendermint_rpc::endpoint::broadcast::tx_commit::Response;
         pub use tendermint_rpc::endpoint::broadcast::tx_sync::Response as SyncResponse;
     }
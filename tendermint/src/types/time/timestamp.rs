@@ -282,6 +282,16 @@ impl Timestamp {
             .to_string()
     }
 
+    /// Formats a `Timestamp` as an RFC 3339 UTC string in the same way as Go's `time.RFC3339Nano`,
+    /// i.e. "YYYY-MM-DDTHH:MM:SS[.fraction]Z" where trailing zeros of the fraction are dropped.
+    pub fn format_rfc3339(&self) -> String {
+        let datetime =
+            time::OffsetDateTime::from_unix_timestamp_nanos(self.timestamp_nanoseconds().into())
+                .expect("`Self` is within `time::OffsetDateTime` range");
+
+        super::serializers::to_rfc3339_nanos(datetime)
+    }
+
     /// Returns a `Timestamp` from a byte slice formatted as in `format_bytes`.
     pub fn try_from_formatted_bytes(bytes: &[u8]) -> Result<Timestamp, TimestampParseError> {
         let s = std::str::from_utf8(bytes)?;
@@ -7,6 +7,10 @@ pub enum Error {
     VersionNotFound(u32),
     #[error("cannot overwrite existing version")]
     Overwrite,
+    #[error("cannot delete the latest version")]
+    DeleteLatest,
+    #[error("cannot delete version {0} while it's being queried")]
+    VersionInUse(u32),
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
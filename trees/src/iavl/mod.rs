@@ -4,5 +4,6 @@ mod tree;
 #[allow(dead_code)]
 pub mod tree_v3;
 
+pub use node_db::NodeDB;
 pub use query_tree::*;
 pub use tree::*;
@@ -1,6 +1,6 @@
 use std::{
-    collections::BTreeSet,
-    sync::{Arc, Mutex},
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, MutexGuard},
 };
 
 use caches::{Cache, DefaultHashBuilder, LRUCache};
@@ -43,6 +43,19 @@ where
             .collect()
     }
 
+    /// Returns the root hash of every stored version.
+    pub fn get_version_roots(&self) -> BTreeMap<u32, [u8; 32]> {
+        self.db
+            .prefix_iterator(ROOTS_PREFIX.into())
+            .map(|(k, v)| {
+                (
+                    u32::decode_var(&k).unwrap_or_corrupt().0,
+                    v.as_ref().try_into().ok().unwrap_or_corrupt(),
+                )
+            })
+            .collect()
+    }
+
     pub(crate) fn get_root_hash(&self, version: u32) -> Result<[u8; 32], Error> {
         self.db
             .get(&Self::get_root_key(version))
@@ -70,8 +83,18 @@ where
         [NODES_PREFIX.to_vec(), hash.to_vec()].concat()
     }
 
+    /// Locks the node cache. A poisoned lock is recovered from since the cache only holds
+    /// copies of nodes stored in the DB.
+    fn cache(&self) -> MutexGuard<'_, LRUCache<[u8; 32], Node, DefaultHashBuilder>> {
+        self.cache.lock().unwrap_or_else(|poisoned| {
+            tracing::warn!("node cache lock was poisoned, recovering");
+            self.cache.clear_poison();
+            poisoned.into_inner()
+        })
+    }
+
     pub(crate) fn get_node(&self, hash: &[u8; 32]) -> Option<Box<Node>> {
-        let cache = &mut self.cache.lock().expect("Lock will not be poisoned");
+        let cache = &mut self.cache();
         let cache_node = cache.get(hash);
 
         if cache_node.is_some() {
@@ -87,10 +110,7 @@ where
 
     fn save_node(&mut self, node: &Node, hash: &[u8; 32]) {
         self.db.put(Self::get_node_key(hash), node.serialize());
-        self.cache
-            .lock()
-            .expect("Lock will not be poisoned")
-            .put(*hash, node.shallow_clone());
+        self.cache().put(*hash, node.shallow_clone());
     }
 
     fn recursive_tree_save(&mut self, node: &Node, hash: &[u8; 32]) {
@@ -124,6 +144,97 @@ where
         let key = Self::get_root_key(version);
         self.db.put(key, hash.to_vec());
     }
+
+    /// Deletes the root of a version and the nodes orphaned by it. `previous` and `next` are
+    /// the closest remaining versions on either side of the deleted one.
+    pub(crate) fn delete_version(
+        &mut self,
+        version: u32,
+        previous: Option<u32>,
+        next: Option<u32>,
+    ) {
+        let root = self.get_root_node(version).ok().unwrap_or_corrupt();
+        let next_root = next
+            .map(|next| self.get_root_hash(next).ok().unwrap_or_corrupt())
+            .filter(|hash| *hash != EMPTY_HASH);
+
+        if let Some(root) = root {
+            let mut orphans = Vec::new();
+            self.collect_orphans(&root, previous.unwrap_or(0), next_root, &mut orphans);
+
+            for hash in orphans {
+                self.db.delete(&Self::get_node_key(&hash));
+                self.cache().remove(&hash);
+            }
+        }
+
+        self.db.delete(&Self::get_root_key(version));
+    }
+
+    /// Collects the hashes of the nodes in the subtree rooted at `node` which belong to
+    /// neither the previous nor the next version.
+    ///
+    /// A node is part of every version from the one which created it until the one which
+    /// replaced it. So a node created at or before the previous version is still part of it,
+    /// and so are all of its descendants. Any other node is only kept if the next version
+    /// still references it.
+    fn collect_orphans(
+        &self,
+        node: &Node,
+        previous: u32,
+        next_root: Option<[u8; 32]>,
+        orphans: &mut Vec<[u8; 32]>,
+    ) {
+        if node.get_version() <= previous {
+            return;
+        }
+
+        let hash = node.hash();
+        if let Some(next_root) = next_root {
+            if self.path_contains(next_root, node, &hash) {
+                return;
+            }
+        }
+
+        orphans.push(hash);
+
+        if let Node::Inner(inner) = node {
+            for child in [&inner.left_hash, &inner.right_hash] {
+                let child = self.get_node(child).unwrap_or_corrupt();
+                self.collect_orphans(&child, previous, next_root, orphans);
+            }
+        }
+    }
+
+    /// Returns true if the node with the given hash is in the tree rooted at `root`. A node
+    /// is always on the search path of its own key, so only that path is checked.
+    fn path_contains(&self, root: [u8; 32], node: &Node, hash: &[u8; 32]) -> bool {
+        let mut current = root;
+
+        loop {
+            if current == *hash {
+                return true;
+            }
+
+            let current_node = self.get_node(&current).unwrap_or_corrupt();
+
+            // descendants are never newer than their ancestors
+            if current_node.get_version() < node.get_version() {
+                return false;
+            }
+
+            match *current_node {
+                Node::Leaf(_) => return false,
+                Node::Inner(inner) => {
+                    current = if node.get_key() < inner.key.as_slice() {
+                        inner.left_hash
+                    } else {
+                        inner.right_hash
+                    }
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -186,4 +297,28 @@ mod tests {
 
         assert_eq!(root_hash, got_root_hash);
     }
+
+    #[test]
+    fn get_node_recovers_from_poisoned_lock() {
+        let mut node_db = NodeDB {
+            db: MemDB::new(),
+            cache: Arc::new(Mutex::new(LRUCache::new(2).unwrap_test())),
+        };
+        let node = Node::default();
+        let hash = node.hash();
+        node_db.save_node(&node, &hash);
+
+        let cache = Arc::clone(&node_db.cache);
+        let _ = std::thread::spawn(move || {
+            let _guard = cache.lock().unwrap_test();
+            panic!("poison the cache lock");
+        })
+        .join();
+        assert!(node_db.cache.is_poisoned());
+
+        let got_node = node_db.get_node(&hash).expect("node was saved");
+
+        assert_eq!(*got_node, node);
+        assert!(!node_db.cache.is_poisoned());
+    }
 }
@@ -1,8 +1,13 @@
-use std::ops::RangeBounds;
+use std::{
+    collections::BTreeMap,
+    ops::RangeBounds,
+    sync::{Arc, Mutex, MutexGuard},
+};
 
 use database::Database;
+use ics23::ExistenceProof;
 
-use crate::Error;
+use crate::{merkle::EMPTY_HASH, Error};
 
 use super::{node_db::NodeDB, Node, Range, Tree};
 
@@ -12,6 +17,55 @@ use super::{node_db::NodeDB, Node, Range, Tree};
 pub struct QueryTree<DB> {
     pub(crate) root: Option<Box<Node>>,
     pub(crate) node_db: NodeDB<DB>,
+    _reader: Reader,
+}
+
+/// Counts the live query trees of each version. A version which is being read
+/// mustn't be deleted since its nodes are loaded lazily.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Readers(Arc<Mutex<BTreeMap<u32, usize>>>);
+
+impl Readers {
+    /// Locks the counts. A poisoned lock is recovered from since the counts are only
+    /// updated by single map operations.
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<u32, usize>> {
+        self.0.lock().unwrap_or_else(|poisoned| {
+            self.0.clear_poison();
+            poisoned.into_inner()
+        })
+    }
+
+    fn acquire(&self, version: u32) -> Reader {
+        *self.lock().entry(version).or_default() += 1;
+
+        Reader {
+            version,
+            readers: self.clone(),
+        }
+    }
+
+    pub(crate) fn is_read(&self, version: u32) -> bool {
+        self.lock().contains_key(&version)
+    }
+}
+
+/// Registers a query tree as a reader of its version until dropped
+#[derive(Debug)]
+struct Reader {
+    version: u32,
+    readers: Readers,
+}
+
+impl Drop for Reader {
+    fn drop(&mut self) {
+        let mut readers = self.readers.lock();
+        if let Some(count) = readers.get_mut(&self.version) {
+            *count -= 1;
+            if *count == 0 {
+                readers.remove(&self.version);
+            }
+        }
+    }
 }
 
 impl<DB: Database> QueryTree<DB> {
@@ -28,6 +82,7 @@ impl<DB: Database> QueryTree<DB> {
             Ok(QueryTree {
                 root,
                 node_db: tree.node_db.clone(),
+                _reader: tree.readers.acquire(version),
             })
         } else {
             Err(Error::VersionNotFound(version))
@@ -90,6 +145,50 @@ impl<DB: Database> QueryTree<DB> {
         }
     }
 
+    pub fn root_hash(&self) -> [u8; 32] {
+        match &self.root {
+            Some(root) => root.hash(),
+            None => EMPTY_HASH,
+        }
+    }
+
+    /// Returns an ICS23 proof that `key` is in the tree, or `None` if it isn't or if a node on its
+    /// path is missing from the node db.
+    pub fn get_proof(&self, key: &[u8]) -> Option<ExistenceProof> {
+        let mut path = Vec::new();
+        let mut node = self.root.as_ref()?.shallow_clone();
+
+        loop {
+            match node {
+                Node::Leaf(leaf) => {
+                    if leaf.key != key {
+                        return None;
+                    }
+
+                    // the path was collected from the root down but is applied from the leaf up
+                    path.reverse();
+
+                    return Some(ExistenceProof {
+                        leaf: Some(leaf.leaf_op()),
+                        key: leaf.key,
+                        value: leaf.value,
+                        path,
+                    });
+                }
+                Node::Inner(inner) => {
+                    let left_child = key < inner.key.as_slice();
+                    let child_hash = match left_child {
+                        true => &inner.left_hash,
+                        false => &inner.right_hash,
+                    };
+
+                    path.push(inner.inner_op(left_child));
+                    node = *self.node_db.get_node(child_hash)?;
+                }
+            }
+        }
+    }
+
     pub fn range<R>(&self, range: R) -> Range<'_, DB>
     where
         R: RangeBounds<Vec<u8>>,
@@ -110,6 +209,10 @@ mod tests {
     use super::*;
     use database::MemDB;
     use extensions::testing::UnwrapTesting;
+    use ics23::{
+        commitment_proof::Proof, iavl_spec, verify_membership, CommitmentProof,
+        HostFunctionsManager,
+    };
 
     #[test]
     fn new_query_tree_works() {
@@ -130,6 +233,45 @@ mod tests {
         assert_eq!(expected, result);
     }
 
+    #[test]
+    fn get_proof_verifies_against_root_hash() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for i in 0..20u8 {
+            tree.set(vec![i], vec![i, i]);
+        }
+        tree.save_version().unwrap_test();
+        // later versions don't affect proofs of earlier ones
+        tree.set(vec![5], b"changed".to_vec());
+        tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, 1).unwrap_test();
+        let root = query_tree.root_hash().to_vec();
+
+        for i in 0..20u8 {
+            let proof = CommitmentProof {
+                proof: Some(Proof::Exist(query_tree.get_proof(&[i]).unwrap_test())),
+            };
+
+            assert!(verify_membership::<HostFunctionsManager>(
+                &proof,
+                &iavl_spec(),
+                &root,
+                &[i],
+                &[i, i]
+            ));
+            assert!(!verify_membership::<HostFunctionsManager>(
+                &proof,
+                &iavl_spec(),
+                &root,
+                &[i],
+                b"changed"
+            ));
+        }
+
+        assert_eq!(query_tree.get_proof(&[20]), None);
+    }
+
     #[test]
     fn new_query_tree_works_empty_tree() {
         let db = MemDB::new();
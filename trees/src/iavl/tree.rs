@@ -7,17 +7,18 @@ use std::{
 
 use database::Database;
 use extensions::corruption::UnwrapCorrupt;
+use ics23::{HashOp, InnerOp, LeafOp, LengthOp};
 use integer_encoding::VarInt;
 use nutype::nutype;
 use sha2::{Digest, Sha256};
 
 use crate::{
     error::{constants::LEAF_ROTATE_ERROR, InternalError},
-    merkle::{Sha256Hash, EMPTY_HASH},
+    merkle::{Sha256Hash, EMPTY_HASH, HASH_LENGTH},
     Error,
 };
 
-use super::node_db::NodeDB;
+use super::{node_db::NodeDB, query_tree::Readers};
 
 #[derive(Debug, Clone, PartialEq, Hash, Default)]
 pub(crate) struct InnerNode {
@@ -133,6 +134,40 @@ impl InnerNode {
             version: self.version,
         }
     }
+
+    fn hash_header(&self) -> Vec<u8> {
+        // NOTE: i64 is used here for parameters for compatibility wih cosmos
+        let height: i64 = self.height.into();
+        let size: i64 = self.size.into();
+        let version: i64 = self.version.into();
+
+        let mut header = height.encode_var_vec();
+        header.extend(size.encode_var_vec());
+        header.extend(version.encode_var_vec());
+
+        header
+    }
+
+    /// Returns the ICS23 step which hashes this node from the hash of one of its children,
+    /// `left_child` is true when the proven key is in the left subtree.
+    pub(crate) fn inner_op(&self, left_child: bool) -> InnerOp {
+        let mut prefix = self.hash_header();
+        let mut suffix = Vec::new();
+
+        if left_child {
+            prefix.extend(HASH_LENGTH.encode_var_vec());
+            suffix.extend(encode_bytes(&self.right_hash));
+        } else {
+            prefix.extend(encode_bytes(&self.left_hash));
+            prefix.extend(HASH_LENGTH.encode_var_vec());
+        }
+
+        InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix,
+            suffix,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Hash, Default)]
@@ -143,21 +178,39 @@ pub(crate) struct LeafNode {
 }
 
 impl LeafNode {
-    fn hash_serialize(&self) -> Vec<u8> {
+    fn hash_header(&self) -> Vec<u8> {
         // NOTE: i64 is used here for parameters for compatibility wih cosmos
         let height: i64 = 0;
         let size: i64 = 1;
         let version: i64 = self.version.into();
+
+        let mut header = height.encode_var_vec();
+        header.extend(size.encode_var_vec());
+        header.extend(version.encode_var_vec());
+
+        header
+    }
+
+    fn hash_serialize(&self) -> Vec<u8> {
         let hashed_value = Sha256::digest(&self.value);
 
-        let mut serialized = height.encode_var_vec();
-        serialized.extend(size.encode_var_vec());
-        serialized.extend(version.encode_var_vec());
+        let mut serialized = self.hash_header();
         serialized.extend(encode_bytes(&self.key));
         serialized.extend(encode_bytes(&hashed_value));
 
         serialized
     }
+
+    /// Returns the ICS23 op which hashes this leaf from its key and value
+    pub(crate) fn leaf_op(&self) -> LeafOp {
+        LeafOp {
+            hash: HashOp::Sha256.into(),
+            prehash_key: HashOp::NoHash.into(),
+            prehash_value: HashOp::Sha256.into(),
+            length: LengthOp::VarProto.into(),
+            prefix: self.hash_header(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Hash)]
@@ -398,6 +451,13 @@ impl Node {
         }
     }
 
+    pub(crate) fn get_version(&self) -> u32 {
+        match self {
+            Node::Leaf(leaf) => leaf.version,
+            Node::Inner(inner) => inner.version,
+        }
+    }
+
     pub fn new_leaf(key: Vec<u8>, value: Vec<u8>, version: u32) -> Node {
         Node::Leaf(LeafNode {
             value,
@@ -415,14 +475,7 @@ impl Node {
         match &self {
             Node::Leaf(node) => node.hash_serialize(),
             Node::Inner(node) => {
-                // NOTE: i64 is used here for parameters for compatibility wih cosmos
-                let height: i64 = node.height.into();
-                let size: i64 = node.size.into();
-                let version: i64 = node.version.into();
-
-                let mut serialized = height.encode_var_vec();
-                serialized.extend(size.encode_var_vec());
-                serialized.extend(version.encode_var_vec());
+                let mut serialized = node.hash_header();
                 serialized.extend(encode_bytes(&node.left_hash));
                 serialized.extend(encode_bytes(&node.right_hash));
 
@@ -513,6 +566,7 @@ pub struct Tree<T> {
     pub(crate) node_db: NodeDB<T>,
     pub(crate) loaded_version: u32,
     pub(crate) versions: BTreeSet<u32>,
+    pub(crate) readers: Readers,
     _name: Option<String>,
 }
 
@@ -540,6 +594,7 @@ where
                 loaded_version: target_version,
                 node_db,
                 versions,
+                readers: Readers::default(),
                 _name: name,
             })
         } else {
@@ -553,6 +608,7 @@ where
                     loaded_version: *latest_version,
                     node_db,
                     versions,
+                    readers: Readers::default(),
                     _name: name,
                 })
             } else {
@@ -561,6 +617,7 @@ where
                     loaded_version: 0,
                     node_db,
                     versions,
+                    readers: Readers::default(),
                     _name: name,
                 })
             }
@@ -610,6 +667,35 @@ where
         Ok((root_hash, self.loaded_version))
     }
 
+    /// Deletes a saved version so it can no longer be loaded, along with the nodes which
+    /// aren't referenced by any other version.
+    /// Returns an error if the version doesn't exist, is the latest version or is being
+    /// read by a [`QueryTree`](super::QueryTree).
+    pub fn delete_version(&mut self, version: u32) -> Result<(), Error> {
+        if version == self.loaded_version {
+            return Err(Error::DeleteLatest);
+        }
+
+        if self.readers.is_read(version) {
+            return Err(Error::VersionInUse(version));
+        }
+
+        if !self.versions.remove(&version) {
+            return Err(Error::VersionNotFound(version));
+        }
+
+        let previous = self.versions.range(..version).next_back().copied();
+        let next = self.versions.range(version..).next().copied();
+        self.node_db.delete_version(version, previous, next);
+
+        Ok(())
+    }
+
+    /// Returns all saved versions.
+    pub fn versions(&self) -> &BTreeSet<u32> {
+        &self.versions
+    }
+
     pub fn root_hash(&self) -> [u8; 32] {
         match &self.root {
             Some(root) => root.hash(),
@@ -1078,6 +1164,7 @@ mod tests {
     use std::vec;
 
     use super::*;
+    use crate::iavl::QueryTree;
     use cmp::max;
     use database::MemDB;
     use extensions::testing::UnwrapTesting;
@@ -1441,6 +1528,85 @@ mod tests {
         assert_eq!(expected, tree.root_hash());
     }
 
+    #[test]
+    fn delete_version_works() {
+        let db = MemDB::new();
+        let mut tree =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        tree.save_version().unwrap_test();
+        tree.set(b"bob".to_vec(), b"123".to_vec());
+        tree.save_version().unwrap_test();
+
+        tree.delete_version(1).unwrap_test();
+
+        assert_eq!(tree.versions(), &BTreeSet::from([2]));
+        assert_eq!(tree.delete_version(1), Err(Error::VersionNotFound(1)));
+        assert_eq!(tree.delete_version(2), Err(Error::DeleteLatest));
+        assert!(matches!(
+            Tree::new(db.clone(), Some(1), 100.try_into().unwrap_test(), None),
+            Err(Error::VersionNotFound(1))
+        ));
+
+        let tree = Tree::new(db, Some(2), 100.try_into().unwrap_test(), None).unwrap_test();
+        assert_eq!(tree.get(b"alice"), Some(b"abc".to_vec()));
+    }
+
+    #[test]
+    fn delete_version_keeps_queried_versions() {
+        let db = MemDB::new();
+        let mut tree =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        tree.save_version().unwrap_test();
+        tree.set(b"alice".to_vec(), b"123".to_vec());
+        tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, 1).unwrap_test();
+
+        assert_eq!(tree.delete_version(1), Err(Error::VersionInUse(1)));
+        assert_eq!(query_tree.get(b"alice"), Some(b"abc".to_vec()));
+
+        drop(query_tree);
+
+        tree.delete_version(1).unwrap_test();
+        assert_eq!(tree.versions(), &BTreeSet::from([2]));
+    }
+
+    #[test]
+    fn delete_version_removes_orphaned_nodes() {
+        let db = MemDB::new();
+        let node_count = || db.prefix_iterator(vec![2]).count();
+        let mut tree =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+
+        for key in [b"a", b"b", b"c", b"d"] {
+            tree.set(key.to_vec(), b"1".to_vec());
+        }
+        tree.save_version().unwrap_test();
+        tree.set(b"a".to_vec(), b"2".to_vec());
+        tree.save_version().unwrap_test();
+        tree.set(b"e".to_vec(), b"1".to_vec());
+        tree.save_version().unwrap_test();
+        tree.set(b"d".to_vec(), b"2".to_vec());
+        tree.save_version().unwrap_test();
+
+        let before = node_count();
+        tree.delete_version(2).unwrap_test();
+        assert!(node_count() < before);
+
+        tree.delete_version(1).unwrap_test();
+        tree.delete_version(3).unwrap_test();
+
+        // only the nodes of the latest tree with five leaves remain
+        assert_eq!(node_count(), 9);
+
+        let tree = Tree::new(db, Some(4), 100.try_into().unwrap_test(), None).unwrap_test();
+        for (key, value) in [(b"a", b"2"), (b"b", b"1"), (b"d", b"2"), (b"e", b"1")] {
+            assert_eq!(tree.get(key), Some(value.to_vec()));
+        }
+    }
+
     #[test]
     fn get_works() {
         let db = MemDB::new();
@@ -1,3 +1,4 @@
+use ics23::{HashOp, InnerOp, LeafOp, LengthOp};
 use sha2::{Digest, Sha256};
 
 const LEAF_PREFIX: [u8; 1] = [0];
@@ -24,6 +25,46 @@ pub fn root_hash(items: &[Vec<u8>]) -> [u8; 32] {
     }
 }
 
+/// Returns the ICS23 op which hashes a leaf made of a length prefixed key and a length
+/// prefixed sha256 hash of the value, as used by the multi store.
+pub fn leaf_op() -> LeafOp {
+    LeafOp {
+        hash: HashOp::Sha256.into(),
+        prehash_key: HashOp::NoHash.into(),
+        prehash_value: HashOp::Sha256.into(),
+        length: LengthOp::VarProto.into(),
+        prefix: LEAF_PREFIX.to_vec(),
+    }
+}
+
+/// Returns the ICS23 steps which hash the item at `index` up to the root hash of `items`,
+/// ordered from the leaf to the root.
+pub fn inner_ops(items: &[Vec<u8>], index: usize) -> Vec<InnerOp> {
+    if items.len() <= 1 {
+        return Vec::new();
+    }
+
+    let k = get_split_point(items.len());
+    let (mut path, op) = if index < k {
+        let op = InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: INNER_PREFIX.to_vec(),
+            suffix: root_hash(&items[k..]).to_vec(),
+        };
+        (inner_ops(&items[..k], index), op)
+    } else {
+        let op = InnerOp {
+            hash: HashOp::Sha256.into(),
+            prefix: [INNER_PREFIX.as_slice(), &root_hash(&items[..k])].concat(),
+            suffix: Vec::new(),
+        };
+        (inner_ops(&items[k..], index - k), op)
+    };
+
+    path.push(op);
+    path
+}
+
 /// Returns sha256(0x00 || leaf)
 fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
     Sha256::digest([&LEAF_PREFIX, leaf].concat()).into()
@@ -14,10 +14,12 @@ use gears::types::tx::raw::TxWithRaw;
 use gears::types::tx::NullTxMsg;
 use gears::x::module::Module;
 use serde::Serialize;
+use strum::IntoEnumIterator;
 
 use crate::query::{
     QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest, QueryAccountsResponse,
-    QueryParamsRequest, QueryParamsResponse,
+    QueryModuleAccountsRequest, QueryModuleAccountsResponse, QueryParamsRequest,
+    QueryParamsResponse,
 };
 use crate::{GenesisState, Keeper};
 
@@ -26,6 +28,7 @@ use crate::{GenesisState, Keeper};
 pub enum AuthNodeQueryRequest {
     Account(QueryAccountRequest),
     Accounts(QueryAccountsRequest),
+    ModuleAccounts(QueryModuleAccountsRequest),
     Params(QueryParamsRequest),
 }
 
@@ -41,6 +44,7 @@ impl QueryRequest for AuthNodeQueryRequest {
 pub enum AuthNodeQueryResponse {
     Account(QueryAccountResponse),
     Accounts(QueryAccountsResponse),
+    ModuleAccounts(QueryModuleAccountsResponse),
     Params(QueryParamsResponse),
 }
 
@@ -49,7 +53,9 @@ pub struct AuthABCIHandler<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> {
     keeper: Keeper<SK, PSK, M>,
 }
 
-impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHandler<SK, PSK, M> {
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module + IntoEnumIterator> ABCIHandler
+    for AuthABCIHandler<SK, PSK, M>
+{
     type Message = NullTxMsg;
 
     type Genesis = GenesisState;
@@ -74,6 +80,10 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
                 let res = self.keeper.query_accounts(ctx, req);
                 AuthNodeQueryResponse::Accounts(res)
             }
+            AuthNodeQueryRequest::ModuleAccounts(req) => {
+                let res = self.keeper.query_module_accounts(ctx, req);
+                AuthNodeQueryResponse::ModuleAccounts(res)
+            }
             AuthNodeQueryRequest::Params(req) => {
                 let res = self.keeper.query_params(ctx, req);
                 AuthNodeQueryResponse::Params(res)
@@ -108,6 +118,13 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
         Vec::new()
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+    ) -> Option<Self::Genesis> {
+        Some(self.keeper.export_genesis(ctx))
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -124,6 +141,11 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
 
                 Ok(self.keeper.query_accounts(ctx, req).encode_vec())
             }
+            "/cosmos.auth.v1beta1.Query/ModuleAccounts" => {
+                let req = QueryModuleAccountsRequest::decode(query.data)?;
+
+                Ok(self.keeper.query_module_accounts(ctx, req).encode_vec())
+            }
             "/cosmos.auth.v1beta1.Query/Params" => {
                 let req = QueryParamsRequest::decode(query.data)?;
 
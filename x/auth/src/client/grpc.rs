@@ -89,10 +89,16 @@ where
 
     async fn module_accounts(
         &self,
-        _request: Request<QueryModuleAccountsRequest>,
+        request: Request<QueryModuleAccountsRequest>,
     ) -> Result<Response<QueryModuleAccountsResponse>, Status> {
-        // TODO: Discuss implementation of this. Easiest way is to implement them with strum enum iterator
-        Err(Status::internal(UNIMPLEMENTED_MSG))
+        info!("Received a gRPC request auth::module_accounts");
+        let req = AuthNodeQueryRequest::ModuleAccounts(request.into_inner().try_into()?);
+        let response = self.app.typed_query(req)?;
+        let response: AuthNodeQueryResponse = response.try_into()?;
+        let AuthNodeQueryResponse::ModuleAccounts(response) = response else {
+            return Err(Status::internal(ERROR_STATE_MSG));
+        };
+        Ok(Response::new(response.into()))
     }
 
     async fn module_account_by_name(
@@ -16,6 +16,8 @@ mod inner {
     pub use gears::core::query::response::auth::QueryAccountResponse;
     pub use ibc_proto::cosmos::auth::v1beta1::QueryAccountsRequest;
     pub use ibc_proto::cosmos::auth::v1beta1::QueryAccountsResponse;
+    pub use ibc_proto::cosmos::auth::v1beta1::QueryModuleAccountsRequest;
+    pub use ibc_proto::cosmos::auth::v1beta1::QueryModuleAccountsResponse;
     pub use ibc_proto::cosmos::auth::v1beta1::QueryParamsRequest;
     pub use ibc_proto::cosmos::auth::v1beta1::QueryParamsResponse;
 }
@@ -65,6 +67,21 @@ pub struct QueryAccountsResponse {
     pub pagination: Option<PaginationResponse>,
 }
 
+/// QueryModuleAccountsRequest is the request type for the Query/ModuleAccounts RPC method.
+#[derive(Clone, PartialEq, Message, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/ModuleAccounts")]
+#[proto(raw = "inner::QueryModuleAccountsRequest")]
+pub struct QueryModuleAccountsRequest {}
+
+/// QueryModuleAccountsResponse is the response type for the Query/ModuleAccounts RPC method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryModuleAccountsResponse")]
+pub struct QueryModuleAccountsResponse {
+    /// accounts are the module accounts with their names and permissions
+    #[proto(repeated)]
+    pub accounts: Vec<Account>,
+}
+
 /// QueryParamsResponse is the response type for the Query/Params RPC method
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
 #[proto(raw = "inner::QueryParamsResponse")]
@@ -1,36 +1,146 @@
-use crate::{query::QueryAccountRequest, AuthNodeQueryRequest, AuthNodeQueryResponse};
+use crate::{
+    query::{
+        QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest, QueryModuleAccountsRequest,
+    },
+    AuthNodeQueryRequest, AuthNodeQueryResponse,
+};
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     routing::get,
     Json, Router,
 };
 use gears::types::address::AccAddress;
 use gears::{
-    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, RestState},
+    baseapp::{HeightQueryRequest, NodeQueryHandler, QueryResponse},
+    rest::{error::HTTPError, Pagination, QueryHeight, RestState},
 };
 
 /// Get a particular account data.
 pub async fn get_account<
-    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<AuthNodeQueryRequest>,
     QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(address): Path<AccAddress>,
+    Query(height): Query<QueryHeight>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthNodeQueryRequest::Account(QueryAccountRequest {
+        address: address.clone(),
+    });
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
+    Ok(Json(ensure_account(res, &address)?))
+}
+
+/// Maps a response without an account to a not found error.
+fn ensure_account<QRes: TryInto<AuthNodeQueryResponse> + Clone>(
+    res: QRes,
+    address: &AccAddress,
+) -> Result<QRes, HTTPError> {
+    match res.clone().try_into() {
+        Ok(AuthNodeQueryResponse::Account(QueryAccountResponse { account: None })) => Err(
+            HTTPError::not_found_with_msg(format!("account {address} not found")),
+        ),
+        Ok(_) => Ok(res),
+        Err(_) => Err(HTTPError::internal_server_error()),
+    }
+}
+
+/// Get all the accounts, paginated.
+pub async fn get_accounts<
+    QReq: HeightQueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    pagination: Query<Pagination>,
+    Query(height): Query<QueryHeight>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthNodeQueryRequest::Accounts(QueryAccountsRequest {
+        pagination: pagination.0.into(),
+    });
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
+    Ok(Json(res))
+}
+
+/// Get all the module accounts with their permissions.
+pub async fn get_module_accounts<
+    QReq: HeightQueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
-    let req = AuthNodeQueryRequest::Account(QueryAccountRequest { address });
-    let res = rest_state.app.typed_query(req)?;
+    let req = AuthNodeQueryRequest::ModuleAccounts(QueryModuleAccountsRequest {});
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub fn get_router<
-    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<AuthNodeQueryRequest>,
     QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
-    Router::new().route(
-        "/v1beta1/accounts/:address",
-        get(get_account::<QReq, QRes, App>),
-    )
+    Router::new()
+        .route("/v1beta1/accounts", get(get_accounts::<QReq, QRes, App>))
+        .route(
+            "/v1beta1/accounts/:address",
+            get(get_account::<QReq, QRes, App>),
+        )
+        .route(
+            "/v1beta1/module_accounts",
+            get(get_module_accounts::<QReq, QRes, App>),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        http::{header, StatusCode},
+        response::IntoResponse,
+    };
+    use gears::{
+        extensions::testing::UnwrapTesting,
+        types::account::{Account, BaseAccount},
+    };
+
+    use super::*;
+
+    fn address() -> AccAddress {
+        AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux").unwrap_test()
+    }
+
+    #[test]
+    fn missing_account_is_not_found() {
+        let res = AuthNodeQueryResponse::Account(QueryAccountResponse { account: None });
+
+        let response = ensure_account(res, &address())
+            .expect_err("account is missing")
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    }
+
+    #[test]
+    fn existing_account_is_returned() {
+        let res = AuthNodeQueryResponse::Account(QueryAccountResponse {
+            account: Some(Account::Base(BaseAccount {
+                address: address(),
+                pub_key: None,
+                account_number: 0,
+                sequence: 0,
+            })),
+        });
+
+        let ensured = ensure_account(res, &address()).unwrap_test();
+
+        assert!(matches!(
+            ensured,
+            AuthNodeQueryResponse::Account(QueryAccountResponse {
+                account: Some(Account::Base(_))
+            })
+        ));
+    }
 }
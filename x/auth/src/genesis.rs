@@ -1,5 +1,7 @@
+use std::collections::HashSet;
+
 use gears::{
-    baseapp::genesis::{Genesis, GenesisError},
+    baseapp::genesis::{Genesis, GenesisError, GenesisValidationError},
     types::{
         account::{Account, BaseAccount},
         address::AccAddress,
@@ -38,6 +40,21 @@ impl Genesis for GenesisState {
     ) -> Result<(), GenesisError> {
         self.add_genesis_account(address)
     }
+
+    fn validate(&self) -> Result<(), GenesisValidationError> {
+        let mut addresses = HashSet::new();
+        for account in &self.accounts {
+            let address = account.get_address();
+            if !addresses.insert(address) {
+                return Err(GenesisValidationError {
+                    module: "auth",
+                    reason: format!("duplicate account found for address {address}"),
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl GenesisState {
@@ -1,6 +1,7 @@
 use crate::query::{
     QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest, QueryAccountsResponse,
-    QueryParamsRequest, QueryParamsResponse,
+    QueryModuleAccountsRequest, QueryModuleAccountsResponse, QueryParamsRequest,
+    QueryParamsResponse,
 };
 use crate::{AuthParamsKeeper, AuthsParams, GenesisState};
 use bytes::Bytes;
@@ -22,6 +23,7 @@ use gears::types::store::gas::errors::GasStoreErrors;
 use gears::x::keepers::auth::AuthKeeper;
 use gears::x::module::Module;
 use prost::Message;
+use strum::IntoEnumIterator;
 
 const ACCOUNT_STORE_PREFIX: [u8; 1] = [1];
 const GLOBAL_ACCOUNT_NUMBER_KEY: [u8; 19] = [
@@ -164,6 +166,23 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> Keeper<SK, PSK, M> {
             .unwrap_gas();
     }
 
+    /// Exports the auth state as a genesis state, the counterpart of `init_genesis`.
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let auth_store = ctx.kv_store(&self.store_key);
+        let mut accounts = auth_store
+            .prefix_store(ACCOUNT_STORE_PREFIX)
+            .into_range(..)
+            .map(|(_k, bytes)| Account::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect::<Vec<_>>();
+        // keep the account numbers in the same order when initializing from the exported state
+        accounts.sort_by_key(|a| a.get_account_number());
+
+        GenesisState {
+            accounts,
+            params: self.auth_params_keeper.get(ctx),
+        }
+    }
+
     pub fn query_account<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -205,6 +224,30 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> Keeper<SK, PSK, M> {
         }
     }
 
+    /// query_module_accounts returns the accounts of the registered modules sorted by module name
+    pub fn query_module_accounts<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        _req: QueryModuleAccountsRequest,
+    ) -> QueryModuleAccountsResponse
+    where
+        M: IntoEnumIterator,
+    {
+        let mut modules = M::iter().collect::<Vec<_>>();
+        modules.sort_by_key(|module| module.get_name());
+
+        let accounts = modules
+            .iter()
+            .filter_map(|module| {
+                self.get_account(ctx, &module.get_address())
+                    .unwrap_gas()
+                    .filter(|account| matches!(account, Account::Module(_)))
+            })
+            .collect();
+
+        QueryModuleAccountsResponse { accounts }
+    }
+
     pub fn query_params<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -259,3 +302,102 @@ fn create_auth_store_key(address: AccAddress) -> Vec<u8> {
 
     prefix
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB, query::QueryMultiStore},
+        tendermint::types::{chain_id::ChainId, time::timestamp::Timestamp},
+        types::pagination::request::{PaginationKind, PaginationRequest},
+        utils::node::build_init_ctx,
+    };
+
+    use crate::test_utils::{address, test_keeper, SubspaceKey};
+
+    use super::*;
+
+    #[test]
+    fn query_accounts_paginates() {
+        let keeper = test_keeper();
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        for id in 1..=3 {
+            keeper
+                .create_new_base_account(&mut ctx, &address(id))
+                .unwrap_test();
+        }
+        multi_store.commit();
+
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            ChainId::default(),
+            Some(Timestamp::UNIX_EPOCH),
+        )
+        .unwrap_test();
+
+        let QueryAccountsResponse {
+            accounts,
+            pagination,
+        } = keeper.query_accounts(
+            &ctx,
+            QueryAccountsRequest {
+                pagination: PaginationRequest {
+                    kind: PaginationKind::Offset { offset: 0 },
+                    limit: 2,
+                    count_total: false,
+                },
+            },
+        );
+
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|this| this.get_address().clone())
+                .collect::<Vec<_>>(),
+            vec![address(1), address(2)]
+        );
+        assert!(!pagination.unwrap_test().next_key.is_empty());
+    }
+
+    #[test]
+    fn query_module_accounts_lists_fee_collector() {
+        let keeper = test_keeper();
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        keeper.init_genesis(&mut ctx, GenesisState::default());
+        keeper
+            .create_new_base_account(&mut ctx, &address(1))
+            .unwrap_test();
+        multi_store.commit();
+
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            ChainId::default(),
+            Some(Timestamp::UNIX_EPOCH),
+        )
+        .unwrap_test();
+
+        let QueryModuleAccountsResponse { accounts } =
+            keeper.query_module_accounts(&ctx, QueryModuleAccountsRequest {});
+
+        let [Account::Module(fee_collector)] = accounts.as_slice() else {
+            panic!("expected only the fee collector module account, got {accounts:?}");
+        };
+        assert_eq!(fee_collector.name, "fee_collector");
+        assert_eq!(
+            fee_collector.base_account.address.to_string(),
+            "cosmos17xpfvakm2amg962yls6f84z3kell8c5lserqta"
+        );
+        assert!(fee_collector.permissions.is_empty());
+    }
+}
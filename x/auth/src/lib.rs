@@ -4,6 +4,9 @@ mod genesis;
 mod keeper;
 mod params;
 
+#[cfg(test)]
+mod test_utils;
+
 pub use abci_handler::*;
 pub use client::*;
 pub use genesis::*;
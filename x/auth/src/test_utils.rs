@@ -0,0 +1,22 @@
+use gears::{
+    extensions::testing::UnwrapTesting, types::address::AccAddress,
+    x::keepers::mocks::module::MockModules,
+};
+
+use crate::Keeper;
+
+pub use gears::x::keepers::mocks::module::SubspaceKey;
+
+pub type TestKeeper = Keeper<SubspaceKey, SubspaceKey, MockModules>;
+
+pub fn test_keeper() -> TestKeeper {
+    Keeper::new(
+        SubspaceKey::Auth,
+        SubspaceKey::Params,
+        MockModules::FeeCollector,
+    )
+}
+
+pub fn address(id: u8) -> AccAddress {
+    AccAddress::try_from(vec![id; 20]).unwrap_test()
+}
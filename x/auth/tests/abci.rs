@@ -40,7 +40,7 @@ fn test_init_and_few_blocks() {
     );
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, strum::EnumIter)]
 pub enum AuthModules {
     FeeCollector,
 }
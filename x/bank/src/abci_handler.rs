@@ -12,7 +12,9 @@ use gears::params::ParamsSubspaceKey;
 use gears::store::database::Database;
 use gears::store::StoreKey;
 use gears::tendermint::types::request::query::RequestQuery;
+use gears::types::base::coin::UnsignedCoin;
 use gears::types::pagination::response::PaginationResponse;
+use gears::types::uint::Uint256;
 use gears::x::keepers::auth::AuthKeeper;
 use gears::x::keepers::bank::BankKeeper;
 use gears::x::module::Module;
@@ -128,17 +130,15 @@ impl<
                 pagination,
             }) => {
                 // TODO: edit error "handling"
-                let (spendable, pagination_result) = self
+                let (balances, pagination) = self
                     .keeper
                     .spendable_coins(ctx, &address, pagination.map(Pagination::from))
-                    .map(|(spendable, _, pag)| {
-                        (spendable.map(Vec::from), pag.map(PaginationResponse::from))
-                    })
+                    .map(|(spendable, _, pag)| (spendable, pag.map(PaginationResponse::from)))
                     .unwrap_or_default();
 
                 BankNodeQueryResponse::Spendable(QuerySpendableBalancesResponse {
-                    balances: spendable.unwrap_or_default(),
-                    pagination: pagination_result,
+                    balances,
+                    pagination,
                 })
             }
         }
@@ -161,10 +161,16 @@ impl<
         let result = match msg {
             Message::Send(msg_send) => self
                 .keeper
-                .send_coins_from_account_to_account(ctx, msg_send),
+                .is_send_enabled_coins(ctx, &msg_send.amount)
+                .and_then(|_| {
+                    self.keeper
+                        .send_coins_from_account_to_account(ctx, msg_send)
+                        .map_err(BankTxError::from)
+                }),
+            Message::MultiSend(msg) => self.keeper.multi_send(ctx, msg),
         };
 
-        result.map_err(|e| Into::<BankTxError>::into(e).into::<MI>())
+        result.map_err(|e| e.into::<MI>())
     }
 
     fn init_genesis<DB: Database>(
@@ -177,6 +183,13 @@ impl<
         Vec::new()
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+    ) -> Option<Self::Genesis> {
+        Some(self.keeper.export_genesis(ctx))
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -291,8 +304,10 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module, MI:
         ctx: &QueryContext<DB, SK>,
         QuerySupplyOfRequest { denom }: QuerySupplyOfRequest,
     ) -> QuerySupplyOfResponse {
-        let supply = self.keeper.supply(ctx, &denom).unwrap_gas();
-        QuerySupplyOfResponse { amount: supply }
+        let supply = self.keeper.supply_of(ctx, &denom).unwrap_gas();
+        QuerySupplyOfResponse {
+            amount: Some(supply),
+        }
     }
 
     pub fn query_balance<DB: Database>(
@@ -300,8 +315,224 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module, MI:
         ctx: &QueryContext<DB, SK>,
         QueryBalanceRequest { address, denom }: QueryBalanceRequest,
     ) -> QueryBalanceResponse {
-        let balance = self.keeper.balance(ctx, &address, &denom).unwrap_gas();
+        // Cosmos SDK returns an explicit zero coin rather than null when nothing is held
+        let balance = self
+            .keeper
+            .balance(ctx, &address, &denom)
+            .unwrap_gas()
+            .unwrap_or(UnsignedCoin {
+                denom,
+                amount: Uint256::zero(),
+            });
+
+        QueryBalanceResponse {
+            balance: Some(balance),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{extract::Query, http::Uri};
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        extensions::testing::UnwrapTesting,
+        rest,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB, query::QueryMultiStore},
+        tendermint::types::{chain_id::ChainId, proto::header::Header, time::timestamp::Timestamp},
+        types::{base::coins::UnsignedCoins, gas::GasMeter, msg::send::MsgSend},
+        utils::node::build_init_ctx,
+    };
+
+    use crate::{
+        test_utils::{address, coin, test_keeper, BankModuleInfo, SubspaceKey},
+        Balance, BankParams, SendEnabled,
+    };
+
+    use super::*;
+
+    #[test]
+    fn msg_send_rejects_disabled_denom() {
+        let handler = BankABCIHandler::<_, _, _, _, BankModuleInfo>::new(test_keeper());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        handler.genesis(
+            &mut ctx,
+            GenesisState {
+                balances: vec![Balance {
+                    address: address(1),
+                    coins: UnsignedCoins::new(vec![coin("50uatom"), coin("50ustake")])
+                        .unwrap_test(),
+                }],
+                params: BankParams {
+                    send_enabled: vec![SendEnabled {
+                        denom: "ustake".try_into().unwrap_test(),
+                        enabled: false,
+                    }],
+                    default_send_enabled: true,
+                },
+                denom_metadata: vec![],
+            },
+        );
+        multi_store.commit();
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let send = |amount: &str| {
+            Message::Send(MsgSend {
+                from_address: address(1),
+                to_address: address(2),
+                amount: UnsignedCoins::new(vec![coin(amount)]).unwrap_test(),
+            })
+        };
+
+        let err = handler.msg(&mut ctx, &send("10ustake")).unwrap_err();
+        assert_eq!(err.msg, "ustake transfers are currently disabled");
 
-        QueryBalanceResponse { balance }
+        handler.msg(&mut ctx, &send("10uatom")).unwrap_test();
+        let balance = |denom: &str| {
+            handler
+                .keeper
+                .balance(&ctx, &address(2), &denom.try_into().unwrap_test())
+                .unwrap_test()
+        };
+        assert_eq!(balance("uatom"), Some(coin("10uatom")));
+        assert_eq!(balance("ustake"), None);
+    }
+
+    #[test]
+    fn supply_of_matches_total_supply() {
+        let handler = BankABCIHandler::<_, _, _, _, BankModuleInfo>::new(test_keeper());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        handler.genesis(
+            &mut ctx,
+            GenesisState {
+                balances: vec![
+                    Balance {
+                        address: address(1),
+                        coins: UnsignedCoins::new(vec![coin("50uatom"), coin("20ustake")])
+                            .unwrap_test(),
+                    },
+                    Balance {
+                        address: address(2),
+                        coins: UnsignedCoins::new(vec![coin("30uatom")]).unwrap_test(),
+                    },
+                ],
+                params: BankParams::default(),
+                denom_metadata: vec![],
+            },
+        );
+        multi_store.commit();
+
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            ChainId::default(),
+            Some(Timestamp::UNIX_EPOCH),
+        )
+        .unwrap_test();
+
+        let (_, total_supply) = handler.keeper.total_supply(&ctx, None);
+        let supply_of = |denom: &str| {
+            handler
+                .query_supply_of(
+                    &ctx,
+                    QuerySupplyOfRequest {
+                        denom: denom.try_into().unwrap_test(),
+                    },
+                )
+                .amount
+                .unwrap_test()
+        };
+
+        for denom in ["uatom", "ustake"] {
+            let filtered = total_supply
+                .iter()
+                .find(|this| this.denom.as_str() == denom)
+                .unwrap_test();
+            assert_eq!(&supply_of(denom), filtered);
+        }
+        assert_eq!(supply_of("uatom"), coin("80uatom"));
+        assert_eq!(supply_of("unknown"), coin("0unknown"));
+    }
+
+    #[test]
+    fn query_balances_counts_total_when_requested() {
+        let handler = BankABCIHandler::<_, _, _, _, BankModuleInfo>::new(test_keeper());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        handler.genesis(
+            &mut ctx,
+            GenesisState {
+                balances: vec![Balance {
+                    address: address(1),
+                    coins: UnsignedCoins::new(vec![
+                        coin("10uatom"),
+                        coin("20uosmo"),
+                        coin("30ustake"),
+                    ])
+                    .unwrap_test(),
+                }],
+                params: BankParams::default(),
+                denom_metadata: vec![],
+            },
+        );
+        multi_store.commit();
+
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            ChainId::default(),
+            Some(Timestamp::UNIX_EPOCH),
+        )
+        .unwrap_test();
+
+        let query_balances = |query: &str| {
+            let uri: Uri = format!("/v1beta1/balances/addr?{query}")
+                .parse()
+                .unwrap_test();
+            let Query(pagination) = Query::<rest::Pagination>::try_from_uri(&uri).unwrap_test();
+
+            handler.query_balances(
+                &ctx,
+                QueryAllBalancesRequest {
+                    address: address(1),
+                    pagination: Some(pagination.into()),
+                },
+            )
+        };
+
+        let QueryAllBalancesResponse {
+            balances,
+            pagination,
+        } = query_balances("limit=1&count_total=true");
+        assert_eq!(balances, vec![coin("10uatom")]);
+        let pagination = pagination.unwrap_test();
+        assert!(!pagination.next_key.is_empty());
+        assert_eq!(pagination.total, 3);
+
+        let QueryAllBalancesResponse { pagination, .. } = query_balances("limit=1");
+        let pagination = pagination.unwrap_test();
+        assert!(!pagination.next_key.is_empty());
+        assert_eq!(pagination.total, 0);
     }
 }
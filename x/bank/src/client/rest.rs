@@ -1,7 +1,8 @@
 use crate::{
     types::query::{
         QueryAllBalancesRequest, QueryBalanceRequest, QueryDenomMetadataRequest,
-        QuerySupplyOfRequest, QueryTotalSupplyRequest,
+        QueryDenomMetadataResponse, QuerySpendableBalancesRequest, QuerySupplyOfRequest,
+        QueryTotalSupplyRequest,
     },
     BankNodeQueryRequest, BankNodeQueryResponse,
 };
@@ -11,122 +12,183 @@ use axum::{
     Json, Router,
 };
 use gears::{
-    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, Pagination, RestState},
+    baseapp::{HeightQueryRequest, NodeQueryHandler, QueryResponse},
+    rest::{error::HTTPError, Pagination, QueryHeight, RestState},
     types::{address::AccAddress, denom::Denom, pagination::request::PaginationRequest},
 };
 use serde::Deserialize;
 
 /// Gets the total supply of every denom
 pub async fn supply<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     pagination: Query<Pagination>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = BankNodeQueryRequest::TotalSupply(QueryTotalSupplyRequest {
         pagination: Some(PaginationRequest::from(pagination.0)),
     });
 
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 /// Gets the total supply of every denom
 pub async fn supply_by_denom<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     query: Query<QueryData>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = BankNodeQueryRequest::SupplyOf(QuerySupplyOfRequest {
-        denom: query.0.denom,
+        denom: query.0.denom()?,
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 /// Gets the total supply of every denom
 pub async fn supply_by_denom_path<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(denom): Path<Denom>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = BankNodeQueryRequest::SupplyOf(QuerySupplyOfRequest { denom });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 /// Get all balances for a given address
 pub async fn get_balances<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse,
     App: NodeQueryHandler<QReq, QRes>,
 >(
-    Path(address): Path<AccAddress>,
+    Path(address): Path<String>,
     pagination: Query<Pagination>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = BankNodeQueryRequest::AllBalances(QueryAllBalancesRequest {
-        address,
+        address: parse_address(&address)?,
         pagination: Some(pagination.0.into()),
     });
 
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
 
     Ok(Json(res))
 }
 
+/// Get the spendable balances for a given address, i.e. balances minus locked coins
+pub async fn get_spendable_balances<
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
+    QRes: QueryResponse,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(address): Path<String>,
+    pagination: Query<Pagination>,
+    Query(height): Query<QueryHeight>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = BankNodeQueryRequest::Spendable(QuerySpendableBalancesRequest {
+        address: parse_address(&address)?,
+        pagination: Some(pagination.0.into()),
+    });
+
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
+
+    Ok(Json(res))
+}
+
+/// Parses a bech32 address from the request path so that a malformed address
+/// results in a bad request rather than a plain text rejection.
+fn parse_address(address: &str) -> Result<AccAddress, HTTPError> {
+    AccAddress::from_bech32(address)
+        .map_err(|e| HTTPError::bad_request(format!("invalid address: {e}")))
+}
+
 #[derive(Deserialize)]
 pub struct QueryData {
-    denom: Denom,
+    denom: String,
+}
+
+impl QueryData {
+    /// Validates the raw denom from the query string so that a malformed denom
+    /// results in a bad request rather than an internal error.
+    pub fn denom(self) -> Result<Denom, HTTPError> {
+        Denom::try_from(self.denom.as_str())
+            .map_err(|_| HTTPError::bad_request(format!("invalid denom: {}", self.denom)))
+    }
 }
 
-// TODO: returns {"balance":null} if balance is zero, is this expected?
 /// Get balance for a given address and denom
 //#[get("/cosmos/bank/v1beta1/balances/<addr>/by_denom?<denom>")]
 pub async fn get_balances_by_denom<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse + TryInto<BankNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
-    Path(address): Path<AccAddress>,
+    Path(address): Path<String>,
     query: Query<QueryData>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = BankNodeQueryRequest::Balance(QueryBalanceRequest {
-        address,
-        denom: query.0.denom,
+        address: parse_address(&address)?,
+        denom: query.0.denom()?,
     });
 
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
 
     Ok(Json(res))
 }
 
 /// get_denom_metadata queries the client metadata for all registered coin denominations.
 pub async fn get_denom_metadata<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse + TryInto<BankNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
-    Path(denom): Path<Denom>,
+    Path(denom): Path<String>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
-    let req = BankNodeQueryRequest::DenomMetadata(QueryDenomMetadataRequest { denom });
-    let res = rest_state.app.typed_query(req)?;
-    Ok(Json(res))
+    let denom = QueryData { denom }.denom()?;
+    let req = BankNodeQueryRequest::DenomMetadata(QueryDenomMetadataRequest {
+        denom: denom.clone(),
+    });
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
+    Ok(Json(ensure_denom_metadata(res, &denom)?))
+}
+
+/// Maps a response without metadata to a not found error.
+fn ensure_denom_metadata<QRes: TryInto<BankNodeQueryResponse> + Clone>(
+    res: QRes,
+    denom: &Denom,
+) -> Result<QRes, HTTPError> {
+    match res.clone().try_into() {
+        Ok(BankNodeQueryResponse::DenomMetadata(QueryDenomMetadataResponse { metadata: None })) => {
+            Err(HTTPError::not_found_with_msg(format!(
+                "client metadata for denom {denom} not found"
+            )))
+        }
+        Ok(_) => Ok(res),
+        Err(_) => Err(HTTPError::internal_server_error()),
+    }
 }
 
 pub fn get_router<
-    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<BankNodeQueryRequest>,
     QRes: QueryResponse + TryInto<BankNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
@@ -139,8 +201,68 @@ pub fn get_router<
             "/v1beta1/balances/:address/by_denom",
             get(get_balances_by_denom::<QReq, QRes, App>),
         )
+        .route(
+            "/v1beta1/spendable_balances/:address",
+            get(get_spendable_balances),
+        )
         .route(
             "/v1beta1/denoms_metadata/:denom",
             get(get_denom_metadata::<QReq, QRes, App>),
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        http::{header, StatusCode, Uri},
+        response::IntoResponse,
+    };
+
+    use super::*;
+
+    #[test]
+    fn query_data_valid_denom() {
+        let uri: Uri = "/v1beta1/balances/addr/by_denom?denom=uatom"
+            .parse()
+            .expect("hardcoded uri is valid");
+        let query = Query::<QueryData>::try_from_uri(&uri).expect("query is well formed");
+
+        let denom = query.0.denom().expect("denom is valid");
+        assert_eq!(denom.as_str(), "uatom");
+    }
+
+    #[test]
+    fn query_data_invalid_denom_is_bad_request() {
+        let uri: Uri = "/v1beta1/balances/addr/by_denom?denom=INVALID!"
+            .parse()
+            .expect("hardcoded uri is valid");
+        let query = Query::<QueryData>::try_from_uri(&uri).expect("query is well formed");
+
+        let err = query.0.denom().expect_err("denom is invalid");
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn malformed_address_is_bad_request() {
+        let response = parse_address("cosmos1invalid")
+            .expect_err("address is malformed")
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    }
+
+    #[test]
+    fn unknown_denom_metadata_is_not_found() {
+        let denom = Denom::try_from("unknown").expect("hardcoded denom is valid");
+        let res =
+            BankNodeQueryResponse::DenomMetadata(QueryDenomMetadataResponse { metadata: None });
+
+        let response = ensure_denom_metadata(res, &denom)
+            .expect_err("metadata is missing")
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    }
+}
@@ -1,5 +1,6 @@
 use gears::{
     application::handlers::node::{ModuleInfo, TxError},
+    types::{denom::Denom, store::gas::errors::GasStoreErrors},
     x::errors::BankKeeperError,
 };
 use thiserror::Error;
@@ -8,12 +9,24 @@ use thiserror::Error;
 pub enum BankTxError {
     #[error(transparent)]
     Keeper(#[from] BankKeeperError),
+    #[error("sum inputs != sum outputs")]
+    InputOutputMismatch,
+    #[error("{0} transfers are currently disabled")]
+    SendDisabled(Denom),
+}
+
+impl From<GasStoreErrors> for BankTxError {
+    fn from(value: GasStoreErrors) -> Self {
+        Self::Keeper(value.into())
+    }
 }
 
 impl BankTxError {
     pub fn into<MI: ModuleInfo>(self) -> TxError {
         let code = match &self {
             BankTxError::Keeper(_) => nz::u16!(1),
+            BankTxError::InputOutputMismatch => nz::u16!(4),
+            BankTxError::SendDisabled(_) => nz::u16!(5),
         };
 
         TxError::new::<MI>(self.to_string(), code)
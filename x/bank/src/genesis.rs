@@ -1,10 +1,12 @@
+use std::collections::HashSet;
+
 use gears::{
-    baseapp::genesis::Genesis,
+    baseapp::genesis::{Genesis, GenesisValidationError},
     types::{address::AccAddress, base::coins::UnsignedCoins, tx::metadata::Metadata},
 };
 use serde::{Deserialize, Serialize};
 
-use crate::BankParams;
+use crate::{BankParams, SendEnabled};
 
 // TODO: should remove total supply since it can be derived from the balances
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -74,4 +76,29 @@ impl Genesis for GenesisState {
 
         Ok(())
     }
+
+    fn validate(&self) -> Result<(), GenesisValidationError> {
+        let invalid = |reason| GenesisValidationError {
+            module: "bank",
+            reason,
+        };
+
+        let mut denoms = HashSet::new();
+        for SendEnabled { denom, .. } in &self.params.send_enabled {
+            if !denoms.insert(denom) {
+                return Err(invalid(format!(
+                    "duplicate send enabled parameter found: {denom}"
+                )));
+            }
+        }
+
+        let mut addresses = HashSet::new();
+        for Balance { address, .. } in &self.balances {
+            if !addresses.insert(address) {
+                return Err(invalid(format!("duplicate balance for address {address}")));
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
+use crate::errors::BankTxError;
 use crate::types::iter::balances::BalanceIterator;
-use crate::{BankParams, BankParamsKeeper, GenesisState};
+use crate::types::tx::MsgMultiSend;
+use crate::{Balance, BankParams, BankParamsKeeper, GenesisState};
 use bytes::Bytes;
 use gears::application::keepers::params::ParamsKeeper;
 use gears::context::{init::InitContext, query::QueryContext};
@@ -326,6 +328,50 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         }
     }
 
+    /// Exports the bank state as a genesis state, the counterpart of `init_genesis`. The total
+    /// supply isn't exported since it's derived from the balances.
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let bank_store = ctx.kv_store(&self.store_key);
+
+        // keys are the length prefixed address followed by the denom so all balances of an
+        // address are adjacent
+        let mut balances: Vec<Balance> = vec![];
+        for (key, coin) in bank_store
+            .prefix_store(ADDRESS_BALANCES_STORE_PREFIX)
+            .into_range(..)
+        {
+            let addr_len = *key.first().unwrap_or_corrupt() as usize;
+            let address = key
+                .get(..=addr_len)
+                .and_then(|key| AccAddress::try_from_prefix_length_bytes(key).ok())
+                .unwrap_or_corrupt();
+            let coin: UnsignedCoin = UnsignedCoin::decode::<Bytes>(coin.into_owned().into())
+                .ok()
+                .unwrap_or_corrupt();
+            if coin.amount.is_zero() {
+                continue;
+            }
+
+            match balances.last_mut() {
+                Some(balance) if balance.address == address => {
+                    let mut coins = balance.coins.clone().into_inner();
+                    coins.push(coin);
+                    balance.coins = UnsignedCoins::new(coins).ok().unwrap_or_corrupt();
+                }
+                _ => balances.push(Balance {
+                    address,
+                    coins: UnsignedCoins::new([coin]).ok().unwrap_or_corrupt(),
+                }),
+            }
+        }
+
+        GenesisState {
+            balances,
+            params: self.bank_params_keeper.get(ctx),
+            denom_metadata: self.denoms_metadata(ctx, None).1,
+        }
+    }
+
     pub fn params<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> BankParams {
         self.bank_params_keeper.get(ctx)
     }
@@ -481,6 +527,84 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         Ok(())
     }
 
+    /// multi_send moves the coins of every input to the outputs. It fails if the sum of
+    /// inputs doesn't match the sum of outputs per denom or if any denom is not sendable.
+    pub fn multi_send<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        msg: &MsgMultiSend,
+    ) -> Result<(), BankTxError> {
+        for input in &msg.inputs {
+            self.is_send_enabled_coins(ctx, &input.coins)?;
+        }
+
+        let sum = |mut coins: Vec<&UnsignedCoins>| -> Result<UnsignedCoins, BankTxError> {
+            let first = coins.pop().ok_or(BankTxError::InputOutputMismatch)?;
+            coins.into_iter().try_fold(first.clone(), |acc, coins| {
+                acc.checked_add(coins)
+                    .map_err(|e| BankTxError::Keeper(e.into()))
+            })
+        };
+        let inputs_sum = sum(msg.inputs.iter().map(|this| &this.coins).collect())?;
+        let outputs_sum = sum(msg.outputs.iter().map(|this| &this.coins).collect())?;
+        if inputs_sum != outputs_sum {
+            return Err(BankTxError::InputOutputMismatch);
+        }
+
+        for input in &msg.inputs {
+            self.sub_unlocked_coins(ctx, &input.address, &input.coins)?;
+        }
+
+        for output in &msg.outputs {
+            self.add_coins(ctx, &output.address, output.coins.clone().into_inner())?;
+
+            ctx.push_event(Event::new(
+                "transfer",
+                [
+                    EventAttribute::new(
+                        "recipient".into(),
+                        String::from(output.address.clone()).into(),
+                        true,
+                    ),
+                    EventAttribute::new(
+                        "amount".into(),
+                        serde_json::to_string(&output.coins)
+                            .expect("serialization of coins won't fail")
+                            .into(),
+                        true,
+                    ),
+                ],
+            ));
+
+            // Create account if recipient does not exist
+            if !self.auth_keeper.has_account(ctx, &output.address)? {
+                self.auth_keeper
+                    .create_new_base_account(ctx, &output.address)?;
+            };
+        }
+
+        Ok(())
+    }
+
+    /// is_send_enabled_coins checks the coins provided and returns an error if any
+    /// of the denoms are not sendable according to the module's params.
+    pub fn is_send_enabled_coins<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        coins: &UnsignedCoins,
+    ) -> Result<(), BankTxError> {
+        let params = self.bank_params_keeper.try_get(ctx)?;
+
+        match coins
+            .inner()
+            .iter()
+            .find(|coin| !params.is_send_enabled(&coin.denom))
+        {
+            Some(coin) => Err(BankTxError::SendDisabled(coin.denom.clone())),
+            None => Ok(()),
+        }
+    }
+
     /// send_coins_from_module_to_module delegates coins and transfers them from a
     /// delegator account to a module account. It creates the module accounts if it don't exist.
     /// It's safe operation because the modules are app generic parameter
@@ -628,6 +752,19 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         }
     }
 
+    /// supply_of reads the supply of a single denom, returning a zero coin if nothing
+    /// of the denom has been minted
+    pub fn supply_of<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        denom: &Denom,
+    ) -> Result<UnsignedCoin, GasStoreErrors> {
+        Ok(self.supply(ctx, denom)?.unwrap_or(UnsignedCoin {
+            denom: denom.clone(),
+            amount: Uint256::zero(),
+        }))
+    }
+
     fn get_address_balances_store<'a, DB: Database>(
         &'a self,
         ctx: &'a mut impl TransactionalContext<DB, SK>,
@@ -847,7 +984,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         for coin in amount.inner() {
             if let Some(mut balance) = self.balance(ctx, addr, &coin.denom)? {
                 let locked_amount = amount_of(&locked_coins, &coin.denom);
-                let spendable = balance.amount - locked_amount;
+                let spendable = balance.amount.saturating_sub(locked_amount);
 
                 if spendable.checked_sub(coin.amount).is_err() {
                     Err(BankCoinsError::Amount {
@@ -892,18 +1029,12 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         &self,
         ctx: &CTX,
         addr: &AccAddress,
-        // TODO: consider to add struct Coins that can have empty coins list
     ) -> Result<Vec<UnsignedCoin>, BankKeeperError> {
-        if let Some(_acc) = self.auth_keeper.get_account(ctx, addr)? {
-            //     vacc, ok := acc.(vestexported.VestingAccount)
-            //     if ok {
-            //         return vacc.LockedCoins(ctx.BlockTime())
-            //     }
-            // TODO: logic with vesting accounts
-            Ok(vec![])
-        } else {
-            Ok(vec![])
-        }
+        Ok(self
+            .auth_keeper
+            .get_account(ctx, addr)?
+            .map(|acc| acc.locked_coins())
+            .unwrap_or_default())
     }
 
     /// track_delegation tracks the delegation of the given account if it is a vesting account
@@ -956,8 +1087,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         pagination: Option<Pagination>,
     ) -> Result<
         (
-            Option<UnsignedCoins>,
-            UnsignedCoins,
+            Vec<UnsignedCoin>,
+            Vec<UnsignedCoin>,
             Option<PaginationResult>,
         ),
         BankKeeperError,
@@ -965,13 +1096,26 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module>
         let (pagination, total) = self.all_balances(ctx, addr.clone(), pagination)?;
         let locked = self.locked_coins(ctx, addr)?;
 
-        let total = UnsignedCoins::new(total)?;
-        let locked = UnsignedCoins::new(locked)?;
+        // locked coins may exceed the balance once vesting coins were delegated, so the
+        // subtraction saturates per denom instead of failing on the whole set
+        let spendable = total
+            .iter()
+            .filter_map(|coin| {
+                let locked_amount = locked
+                    .iter()
+                    .find(|this| this.denom == coin.denom)
+                    .map(|this| this.amount)
+                    .unwrap_or_default();
+                let amount = coin.amount.saturating_sub(locked_amount);
+
+                (!amount.is_zero()).then(|| UnsignedCoin {
+                    denom: coin.denom.clone(),
+                    amount,
+                })
+            })
+            .collect();
 
-        match total.checked_sub(&locked) {
-            Ok(spendable) => Ok((Some(spendable), total, pagination)),
-            Err(_) => Ok((None, total, pagination)),
-        }
+        Ok((spendable, total, pagination))
     }
 }
 
@@ -995,3 +1139,164 @@ fn create_denom_balance_prefix(addr: AccAddress) -> Vec<u8> {
 }
 
 //TODO: copy tests across
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        context::tx::TxContext,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::header::Header,
+        types::{
+            account::{Account, BaseAccount, BaseVestingAccount, PermanentLockedAccount},
+            gas::GasMeter,
+        },
+        x::keepers::mocks::auth::MockAuthKeeper,
+    };
+
+    use crate::test_utils::{address, coin, input, output, test_keeper, SubspaceKey};
+
+    use super::*;
+
+    #[test]
+    fn multi_send_moves_balanced_coins() {
+        let keeper = test_keeper();
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        keeper
+            .set_balance(&mut ctx, &address(1), coin("60uatom"))
+            .unwrap_test();
+        keeper
+            .set_balance(&mut ctx, &address(2), coin("40uatom"))
+            .unwrap_test();
+
+        let msg = MsgMultiSend {
+            inputs: vec![input(1, "60uatom"), input(2, "40uatom")],
+            outputs: vec![
+                output(3, "30uatom"),
+                output(4, "50uatom"),
+                output(5, "20uatom"),
+            ],
+        };
+        keeper.multi_send(&mut ctx, &msg).unwrap_test();
+
+        let balance = |ctx: &TxContext<'_, _, _>, id| {
+            keeper
+                .balance(ctx, &address(id), &"uatom".try_into().unwrap_test())
+                .unwrap_test()
+        };
+        assert_eq!(balance(&ctx, 1), None);
+        assert_eq!(balance(&ctx, 2), None);
+        assert_eq!(balance(&ctx, 3), Some(coin("30uatom")));
+        assert_eq!(balance(&ctx, 4), Some(coin("50uatom")));
+        assert_eq!(balance(&ctx, 5), Some(coin("20uatom")));
+
+        let transfers = ctx
+            .events()
+            .iter()
+            .filter(|e| e.r#type == "transfer")
+            .count();
+        assert_eq!(transfers, 3);
+    }
+
+    #[test]
+    fn multi_send_rejects_mismatched_sums() {
+        let keeper = test_keeper();
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        keeper
+            .set_balance(&mut ctx, &address(1), coin("60uatom"))
+            .unwrap_test();
+
+        let msg = MsgMultiSend {
+            inputs: vec![input(1, "60uatom")],
+            outputs: vec![output(2, "30uatom"), output(3, "20uatom")],
+        };
+        let err = keeper.multi_send(&mut ctx, &msg).unwrap_err();
+
+        assert!(matches!(err, BankTxError::InputOutputMismatch));
+        assert_eq!(
+            keeper
+                .balance(&ctx, &address(1), &"uatom".try_into().unwrap_test())
+                .unwrap_test(),
+            Some(coin("60uatom"))
+        );
+    }
+
+    #[test]
+    fn spendable_coins_exclude_locked_vesting_coins() {
+        let account = Account::PermanentLocked(PermanentLockedAccount {
+            base_vesting_account: BaseVestingAccount {
+                base_account: BaseAccount {
+                    address: address(1),
+                    pub_key: None,
+                    account_number: 0,
+                    sequence: 0,
+                },
+                original_vesting: vec![coin("40uatom")],
+                delegated_free: vec![],
+                delegated_vesting: vec![],
+                end_time: 0,
+            },
+        });
+        let keeper = Keeper::<_, _, _, gears::x::keepers::mocks::module::MockModules>::new(
+            SubspaceKey::Bank,
+            SubspaceKey::Params,
+            MockAuthKeeper::former().get_account(Some(account)).form(),
+        );
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        keeper
+            .set_balance(&mut ctx, &address(1), coin("100uatom"))
+            .unwrap_test();
+        keeper
+            .set_balance(&mut ctx, &address(1), coin("5ustake"))
+            .unwrap_test();
+
+        let (spendable, total, _) = keeper
+            .spendable_coins(&ctx, &address(1), None)
+            .unwrap_test();
+
+        assert_eq!(total, vec![coin("100uatom"), coin("5ustake")]);
+        assert_eq!(spendable, vec![coin("60uatom"), coin("5ustake")]);
+    }
+}
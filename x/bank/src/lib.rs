@@ -5,6 +5,8 @@ mod genesis;
 mod keeper;
 mod message;
 mod params;
+#[cfg(test)]
+mod test_utils;
 pub mod types;
 
 pub use abci_handler::*;
@@ -2,24 +2,158 @@ use gears::{
     derive::AppMessage,
     signing::{
         handler::MetadataGetter,
-        renderer::value_renderer::{RenderError, ValueRenderer},
+        renderer::{
+            primitives::send_coins::CoinsField,
+            value_renderer::{
+                DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+                TryPrimitiveValueRenderer, ValueRenderer,
+            },
+        },
+    },
+    types::{
+        address::AccAddress,
+        base::coins::UnsignedCoins,
+        msg::send::MsgSend,
+        rendering::screen::{Indent, Screen},
     },
-    types::{msg::send::MsgSend, rendering::screen::Screen},
 };
 use serde::Serialize;
 
+use crate::types::tx::MsgMultiSend;
+
 #[derive(Debug, Clone, Serialize, AppMessage)]
 #[serde(tag = "@type")]
 pub enum Message {
     #[serde(rename = "/cosmos.bank.v1beta1.MsgSend")]
     #[msg(url(path = MsgSend::TYPE_URL))]
     Send(MsgSend),
+    #[serde(rename = "/cosmos.bank.v1beta1.MsgMultiSend")]
+    #[msg(url(path = MsgMultiSend::TYPE_URL))]
+    MultiSend(MsgMultiSend),
 }
 
 impl ValueRenderer for Message {
     fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
         match self {
             Message::Send(msg) => msg.format(get_metadata),
+            Message::MultiSend(msg) => msg.format(get_metadata),
+        }
+    }
+}
+
+impl ValueRenderer for MsgMultiSend {
+    /// Format `MsgMultiSend` as its inputs followed by its outputs
+    /// Note: This implementation doesn't include `Screen` with information about beginning of message and name
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = transfers_screens(
+            "Inputs",
+            self.inputs
+                .iter()
+                .map(|input| (&input.address, &input.coins)),
+            get_metadata,
+        )?;
+        screens.extend(transfers_screens(
+            "Outputs",
+            self.outputs
+                .iter()
+                .map(|output| (&output.address, &output.coins)),
+            get_metadata,
+        )?);
+
+        Ok(screens)
+    }
+}
+
+/// Renders a repeated field of transfers: the number of transfers, then the address and coins of
+/// each of them and finally the end of the field
+fn transfers_screens<'a, MG: MetadataGetter>(
+    field: &str,
+    transfers: impl ExactSizeIterator<Item = (&'a AccAddress, &'a UnsignedCoins)>,
+    get_metadata: &MG,
+) -> Result<Vec<Screen>, RenderError> {
+    let count = transfers.len();
+
+    let mut screens = vec![Screen {
+        title: field.to_owned(),
+        content: DefaultPrimitiveRenderer::format(count as u64),
+        indent: Some(Indent::two()),
+        expert: false,
+    }];
+
+    for (i, (address, coins)) in transfers.enumerate() {
+        screens.push(Screen {
+            title: format!("{field} ({}/{count})", i + 1),
+            content: DefaultPrimitiveRenderer::format(address.to_owned()),
+            indent: Some(Indent::two()),
+            expert: false,
+        });
+        screens.extend(
+            CoinsField {
+                title: "Coins",
+                coins,
+                indent: Some(Indent::try_new(3).expect("indent is less than 16")),
+            }
+            .format(get_metadata)?,
+        );
+    }
+
+    screens.push(Screen {
+        title: String::new(),
+        content: DefaultPrimitiveRenderer::try_format(format!("End of {field}"))
+            .expect("hard coded String is not empty"),
+        indent: Some(Indent::two()),
+        expert: false,
+    });
+
+    Ok(screens)
+}
+
+#[cfg(test)]
+mod tests {
+    use gears::{
+        extensions::testing::UnwrapTesting,
+        signing::{handler::MetadataGetter, renderer::value_renderer::ValueRenderer},
+        types::{denom::Denom, rendering::screen::Screen, tx::metadata::Metadata},
+    };
+
+    use crate::{
+        test_utils::{input, output},
+        types::tx::MsgMultiSend,
+    };
+
+    struct NoMetadataGetter;
+
+    impl MetadataGetter for NoMetadataGetter {
+        type Error = std::io::Error;
+
+        fn metadata(&self, _denom: &Denom) -> Result<Option<Metadata>, Self::Error> {
+            Ok(None)
         }
     }
+
+    #[test]
+    fn msg_multi_send_renders_inputs_and_outputs() {
+        let msg = MsgMultiSend {
+            inputs: vec![input(1, "30uatom")],
+            outputs: vec![output(2, "10uatom"), output(3, "20uatom")],
+        };
+
+        let expected: Vec<Screen> = serde_json::from_str(
+            r#"[
+                { "title": "Inputs", "content": "1", "indent": 2 },
+                { "title": "Inputs (1/1)", "content": "cosmos1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du", "indent": 2 },
+                { "title": "Coins", "content": "30 uatom", "indent": 3 },
+                { "content": "End of Inputs", "indent": 2 },
+                { "title": "Outputs", "content": "2", "indent": 2 },
+                { "title": "Outputs (1/2)", "content": "cosmos1qgpqyqszqgpqyqszqgpqyqszqgpqyqszrh8mx2", "indent": 2 },
+                { "title": "Coins", "content": "10 uatom", "indent": 3 },
+                { "title": "Outputs (2/2)", "content": "cosmos1qvpsxqcrqvpsxqcrqvpsxqcrqvpsxqcrz8x6vt", "indent": 2 },
+                { "title": "Coins", "content": "20 uatom", "indent": 3 },
+                { "content": "End of Outputs", "indent": 2 }
+            ]"#,
+        )
+        .unwrap_test();
+
+        assert_eq!(msg.format(&NoMetadataGetter).unwrap_test(), expected);
+    }
 }
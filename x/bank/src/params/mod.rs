@@ -36,6 +36,18 @@ pub const DEFAULT_PARAMS: BankParams = BankParams {
     default_send_enabled: true,
 };
 
+impl BankParams {
+    /// is_send_enabled returns the send flag of the denom if it's listed in `send_enabled`,
+    /// otherwise it falls back to `default_send_enabled`
+    pub fn is_send_enabled(&self, denom: &Denom) -> bool {
+        self.send_enabled
+            .iter()
+            .find(|this| this.denom == *denom)
+            .map(|this| this.enabled)
+            .unwrap_or(self.default_send_enabled)
+    }
+}
+
 impl Default for BankParams {
     fn default() -> Self {
         DEFAULT_PARAMS.clone()
@@ -55,9 +67,6 @@ impl ParamsSerialize for BankParams {
                 KEY_DEFAULT_SEND_ENABLED,
                 self.default_send_enabled.to_string().into_bytes(),
             ),
-            // TODO: if params are missing in the cosmos SDK (e.g the send_enabled field is missing from the genesis json file)
-            // then they are set to "null" i.e. [110, 117, 108, 108] when stored. This is different to the Gears behaviour
-            // which would fail to parse if e.g. the send enabled field is missing
             (
                 KEY_SEND_ENABLED,
                 serde_json::to_vec(&self.send_enabled)
@@ -74,13 +83,16 @@ impl ParamsDeserialize for BankParams {
                 .parse_param(fields.remove(KEY_DEFAULT_SEND_ENABLED).unwrap_or_corrupt())
                 .boolean()
                 .unwrap_or_corrupt(),
-            send_enabled: serde_json::from_slice(
+            // if params are missing in the cosmos SDK (e.g the send_enabled field is missing from the genesis json file)
+            // then they are set to "null" i.e. [110, 117, 108, 108] when stored
+            send_enabled: serde_json::from_slice::<Option<Vec<SendEnabled>>>(
                 &ParamKind::Bytes
                     .parse_param(fields.remove(KEY_SEND_ENABLED).unwrap_or_corrupt())
                     .bytes()
                     .unwrap_or_corrupt(),
             )
-            .unwrap_or_corrupt(),
+            .unwrap_or_corrupt()
+            .unwrap_or_default(),
         }
     }
 }
@@ -99,12 +111,50 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for BankParamsKeeper<PSK> {
 
     fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
         match String::from_utf8_lossy(key.as_ref()).as_ref() {
-            KEY_SEND_ENABLED => ParamKind::Bool
+            KEY_SEND_ENABLED => serde_json::from_slice::<Vec<SendEnabled>>(value.as_ref()).is_ok(),
+            KEY_DEFAULT_SEND_ENABLED => ParamKind::Bool
                 .parse_param(value.as_ref().to_vec())
                 .boolean()
                 .is_some(),
-            KEY_DEFAULT_SEND_ENABLED => false, // add logic when we start setting this key
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gears::extensions::testing::UnwrapTesting;
+
+    use crate::test_utils::SubspaceKey;
+
+    use super::*;
+
+    fn params() -> BankParams {
+        BankParams {
+            send_enabled: vec![SendEnabled {
+                denom: "ustake".try_into().unwrap_test(),
+                enabled: false,
+            }],
+            default_send_enabled: true,
+        }
+    }
+
+    #[test]
+    fn send_enabled_params_store_round_trip() {
+        let raw = params().to_raw();
+
+        for (key, value) in &raw {
+            assert!(BankParamsKeeper::<SubspaceKey>::validate(key, value));
+        }
+
+        assert_eq!(BankParams::from_raw(raw.into_iter().collect()), params());
+    }
+
+    #[test]
+    fn send_enabled_falls_back_to_default() {
+        let params = params();
+
+        assert!(!params.is_send_enabled(&"ustake".try_into().unwrap_test()));
+        assert!(params.is_send_enabled(&"uatom".try_into().unwrap_test()));
+    }
+}
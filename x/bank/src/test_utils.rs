@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use gears::{
+    application::handlers::node::ModuleInfo,
+    extensions::testing::UnwrapTesting,
+    types::{
+        address::AccAddress,
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+    },
+    x::keepers::mocks::{auth::MockAuthKeeper, module::MockModules},
+};
+
+use crate::{
+    types::tx::{Input, Output},
+    Keeper,
+};
+
+pub use gears::x::keepers::mocks::module::SubspaceKey;
+
+pub type TestKeeper = Keeper<SubspaceKey, SubspaceKey, MockAuthKeeper, MockModules>;
+
+pub fn test_keeper() -> TestKeeper {
+    Keeper::new(
+        SubspaceKey::Bank,
+        SubspaceKey::Params,
+        MockAuthKeeper::former().form(),
+    )
+}
+
+pub fn address(id: u8) -> AccAddress {
+    AccAddress::try_from(vec![id; 20]).unwrap_test()
+}
+
+pub fn coin(coin: &str) -> UnsignedCoin {
+    UnsignedCoin::from_str(coin).unwrap_test()
+}
+
+pub fn input(id: u8, coins: &str) -> Input {
+    Input {
+        address: address(id),
+        coins: UnsignedCoins::new(vec![coin(coins)]).unwrap_test(),
+    }
+}
+
+pub fn output(id: u8, coins: &str) -> Output {
+    Output {
+        address: address(id),
+        coins: UnsignedCoins::new(vec![coin(coins)]).unwrap_test(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BankModuleInfo;
+
+impl ModuleInfo for BankModuleInfo {
+    const NAME: &'static str = "bank";
+}
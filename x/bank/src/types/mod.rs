@@ -1,2 +1,3 @@
 pub mod iter;
 pub mod query;
+pub mod tx;
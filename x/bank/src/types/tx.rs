@@ -0,0 +1,170 @@
+use bytes::Bytes;
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    types::{
+        address::AccAddress,
+        base::{coin::UnsignedCoin, coins::UnsignedCoins, errors::CoinError},
+        tx::TxMessage,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+mod inner {
+    pub use ibc_proto::cosmos::bank::v1beta1::Input;
+    pub use ibc_proto::cosmos::bank::v1beta1::MsgMultiSend;
+    pub use ibc_proto::cosmos::bank::v1beta1::Output;
+}
+
+/// Input models transaction input.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Input {
+    pub address: AccAddress,
+    pub coins: UnsignedCoins,
+}
+
+/// Output models transaction outputs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+    pub address: AccAddress,
+    pub coins: UnsignedCoins,
+}
+
+/// MsgMultiSend represents an arbitrary multi-in, multi-out send message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MsgMultiSend {
+    pub inputs: Vec<Input>,
+    pub outputs: Vec<Output>,
+}
+
+impl MsgMultiSend {
+    pub const TYPE_URL: &'static str = "/cosmos.bank.v1beta1.MsgMultiSend";
+}
+
+impl Protobuf<inner::MsgMultiSend> for MsgMultiSend {}
+
+impl TxMessage for MsgMultiSend {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        self.inputs.iter().map(|input| &input.address).collect()
+    }
+
+    fn type_url(&self) -> &'static str {
+        MsgMultiSend::TYPE_URL
+    }
+}
+
+fn coins_from_raw(
+    coins: Vec<ibc_proto::cosmos::base::v1beta1::Coin>,
+) -> Result<UnsignedCoins, CoreError> {
+    let coins = coins
+        .into_iter()
+        .map(UnsignedCoin::try_from)
+        .collect::<Result<Vec<_>, CoinError>>()
+        .map_err(|e| CoreError::Coin(e.to_string()))?;
+
+    UnsignedCoins::new(coins).map_err(|e| CoreError::Coins(e.to_string()))
+}
+
+impl TryFrom<inner::Input> for Input {
+    type Error = CoreError;
+
+    fn try_from(inner::Input { address, coins }: inner::Input) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: AccAddress::from_bech32(&address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            coins: coins_from_raw(coins)?,
+        })
+    }
+}
+
+impl From<Input> for inner::Input {
+    fn from(Input { address, coins }: Input) -> Self {
+        Self {
+            address: address.into(),
+            coins: coins.into_iter().map(|this| this.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<inner::Output> for Output {
+    type Error = CoreError;
+
+    fn try_from(inner::Output { address, coins }: inner::Output) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: AccAddress::from_bech32(&address)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            coins: coins_from_raw(coins)?,
+        })
+    }
+}
+
+impl From<Output> for inner::Output {
+    fn from(Output { address, coins }: Output) -> Self {
+        Self {
+            address: address.into(),
+            coins: coins.into_iter().map(|this| this.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<inner::MsgMultiSend> for MsgMultiSend {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::MsgMultiSend { inputs, outputs }: inner::MsgMultiSend,
+    ) -> Result<Self, Self::Error> {
+        if inputs.is_empty() {
+            Err(CoreError::DecodeGeneral(
+                "no inputs to send transaction".into(),
+            ))?
+        }
+
+        if outputs.is_empty() {
+            Err(CoreError::DecodeGeneral(
+                "no outputs to send transaction".into(),
+            ))?
+        }
+
+        Ok(Self {
+            inputs: inputs
+                .into_iter()
+                .map(Input::try_from)
+                .collect::<Result<_, _>>()?,
+            outputs: outputs
+                .into_iter()
+                .map(Output::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<MsgMultiSend> for inner::MsgMultiSend {
+    fn from(MsgMultiSend { inputs, outputs }: MsgMultiSend) -> Self {
+        Self {
+            inputs: inputs.into_iter().map(inner::Input::from).collect(),
+            outputs: outputs.into_iter().map(inner::Output::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgMultiSend {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        if value.type_url != Self::TYPE_URL {
+            Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            ))?
+        }
+        MsgMultiSend::decode::<Bytes>(value.value.into())
+            .map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+    }
+}
+
+impl From<MsgMultiSend> for Any {
+    fn from(msg: MsgMultiSend) -> Self {
+        Any {
+            type_url: MsgMultiSend::TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
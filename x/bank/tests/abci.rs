@@ -1,11 +1,15 @@
 use std::str::FromStr;
 
-use bank::{BankABCIHandler, GenesisState, Keeper, Message};
+use bank::{
+    types::query::{QueryBalanceRequest, QueryBalanceResponse},
+    BankABCIHandler, GenesisState, Keeper, Message,
+};
 use gears::{
     application::handlers::node::ModuleInfo,
+    core::Protobuf,
     derive::{ParamsKeys, StoreKeys},
     extensions::testing::UnwrapTesting,
-    tendermint::types::time::timestamp::Timestamp,
+    tendermint::types::{request::query::RequestQuery, time::timestamp::Timestamp},
     types::{
         address::AccAddress,
         base::{
@@ -13,6 +17,7 @@ use gears::{
             coins::{Coins, UnsignedCoins},
         },
         msg::send::MsgSend,
+        uint::Uint256,
     },
     utils::node::{acc_address, generate_txs, init_node, GenesisSource, MockOptionsFormer},
     x::{keepers::mocks::auth::MockAuthKeeper, module::Module},
@@ -106,6 +111,55 @@ fn test_init_and_sending_tx() {
     );
 }
 
+#[test]
+/// In this scenario, we query the balance of a denom the account doesn't hold
+fn test_query_zero_balance() {
+    let mut genesis = GenesisState::default();
+
+    genesis.add_genesis_account(
+        acc_address(),
+        UnsignedCoins::new(vec![UnsignedCoin::from_str("30uatom").unwrap_test()]).unwrap_test(),
+    );
+
+    let opt: MockOptionsFormer<
+        SubspaceKey,
+        BankABCIHandler<SpaceKey, SubspaceKey, MockAuthKeeper, BankModules, BankModuleInfo>,
+        GenesisState,
+    > = MockOptionsFormer::new()
+        .abci_handler(BankABCIHandler::new(Keeper::new(
+            SpaceKey::Auth,
+            SubspaceKey::Auth,
+            MockAuthKeeper::former().form(),
+        )))
+        .baseapp_sbs_key(SubspaceKey::BaseApp)
+        .genesis(GenesisSource::Genesis(genesis));
+
+    let (mut node, _) = init_node(opt);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let query = QueryBalanceRequest {
+        address: acc_address(),
+        denom: "ustake".try_into().unwrap_test(),
+    };
+
+    let res = node.query(RequestQuery {
+        data: query.encode_vec().into(),
+        path: "/cosmos.bank.v1beta1.Query/Balance".to_string(),
+        height: 0,
+        prove: false,
+    });
+
+    let res = QueryBalanceResponse::decode(res.value).unwrap_test();
+    assert_eq!(
+        res.balance,
+        Some(UnsignedCoin {
+            denom: "ustake".try_into().unwrap_test(),
+            amount: Uint256::zero(),
+        })
+    );
+}
+
 #[derive(Debug, Clone)]
 struct BankModuleInfo;
 
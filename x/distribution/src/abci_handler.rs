@@ -1,8 +1,8 @@
 use crate::{
     errors::DistributionTxError, GenesisState, Keeper, Message, QueryCommunityPoolRequest,
-    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
-    QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+    QueryDelegatorParams, QueryDelegatorTotalRewardsResponse, QueryParamsRequest,
+    QueryParamsResponse, QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
     QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
 };
@@ -33,6 +33,7 @@ pub enum DistributionNodeQueryRequest {
     ValidatorCommission(QueryValidatorCommissionRequest),
     ValidatorSlashes(QueryValidatorSlashesRequest),
     DelegatorTotalRewards(QueryDelegatorParams),
+    DelegationRewards(QueryDelegationRewardsRequest),
     CommunityPool(QueryCommunityPoolRequest),
     Params(QueryParamsRequest),
 }
@@ -42,6 +43,7 @@ pub enum DistributionNodeQueryResponse {
     ValidatorCommission(QueryValidatorCommissionResponse),
     ValidatorSlashes(QueryValidatorSlashesResponse),
     DelegatorTotalRewards(QueryDelegatorTotalRewardsResponse),
+    DelegationRewards(QueryDelegationRewardsResponse),
     CommunityPool(QueryCommunityPoolResponse),
     Params(QueryParamsResponse),
 }
@@ -195,6 +197,13 @@ impl<
                     self.keeper.query_delegator_total_rewards(ctx, req),
                 )
             }
+            DistributionNodeQueryRequest::DelegationRewards(req) => {
+                DistributionNodeQueryResponse::DelegationRewards(
+                    self.keeper
+                        .query_delegation_rewards(ctx, req)
+                        .unwrap_or(QueryDelegationRewardsResponse { rewards: None }),
+                )
+            }
             DistributionNodeQueryRequest::CommunityPool(req) => {
                 DistributionNodeQueryResponse::CommunityPool(
                     self.keeper.query_community_pool(ctx, req),
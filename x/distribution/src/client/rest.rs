@@ -1,43 +1,62 @@
 use crate::{
     DistributionNodeQueryRequest, DistributionNodeQueryResponse, DistributionParams,
-    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegatorParams,
-    QueryParamsRequest, QueryParamsResponse,
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegatorParams, QueryParamsRequest, QueryParamsResponse,
 };
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     routing::get,
     Json, Router,
 };
 use gears::{
-    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, RestState},
-    types::address::AccAddress,
+    baseapp::{HeightQueryRequest, NodeQueryHandler, QueryResponse},
+    rest::{error::HTTPError, QueryHeight, RestState},
+    types::address::{AccAddress, ValAddress},
 };
 
 pub async fn delegation_delegator_rewards<
-    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(delegator_address): Path<AccAddress>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::DelegatorTotalRewards(QueryDelegatorParams {
         delegator_address,
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
+    Ok(Json(res))
+}
+
+pub async fn delegation_rewards<
+    QReq: HeightQueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((delegator_address, validator_address)): Path<(AccAddress, ValAddress)>,
+    Query(height): Query<QueryHeight>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegationRewards(QueryDelegationRewardsRequest {
+        delegator_address,
+        validator_address,
+    });
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub async fn community_pool<
-    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::CommunityPool(QueryCommunityPoolRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
@@ -56,14 +75,15 @@ pub async fn const_community_pool() -> Result<Json<QueryCommunityPoolResponse>,
 }
 
 pub async fn params<
-    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = DistributionNodeQueryRequest::Params(QueryParamsRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
@@ -75,7 +95,7 @@ pub async fn const_params() -> Result<Json<QueryParamsResponse>, HTTPError> {
 }
 
 pub fn get_router<
-    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
@@ -88,6 +108,10 @@ pub fn get_router<
             "/v1beta1/delegators/:delegator_address/rewards",
             get(delegation_delegator_rewards),
         )
+        .route(
+            "/v1beta1/delegators/:delegator_address/rewards/:validator_address",
+            get(delegation_rewards),
+        )
         // TODO: remove const handler and route after integration and update route
         .route("/v1beta1/params/current", get(params))
         .route("/v1beta1/params", get(const_params))
@@ -70,26 +70,46 @@ impl<
 
         // calculate fraction votes
         // TODO: reduce conversions
-        let previous_fraction_votes = Decimal256::from_atomics(sum_previous_precommit_power, 0)
-            .map_err(NumericError::from)?
-            .checked_div(
-                Decimal256::from_atomics(total_previous_power, 0).map_err(NumericError::from)?,
-            )
-            .map_err(|_| NumericError::Overflow(MathOperation::Div))?;
+        let sum_previous_precommit_power_dec =
+            Decimal256::from_atomics(sum_previous_precommit_power, 0)
+                .map_err(|e| NumericError::decimal_range("sum_previous_precommit_power", e))?;
+        let total_previous_power_dec = Decimal256::from_atomics(total_previous_power, 0)
+            .map_err(|e| NumericError::decimal_range("total_previous_power", e))?;
+        let previous_fraction_votes = sum_previous_precommit_power_dec
+            .checked_div(total_previous_power_dec)
+            .map_err(|_| {
+                NumericError::overflow(
+                    MathOperation::Div,
+                    sum_previous_precommit_power_dec,
+                    total_previous_power_dec,
+                )
+            })?;
 
         let params = self.params_keeper.get(ctx);
         // calculate previous proposer reward
         let base_proposer_reward = params.base_proposer_reward;
         let bonus_proposer_reward = params.bonus_proposer_reward;
-        let proposer_multiplier = base_proposer_reward
-            .checked_add(
-                bonus_proposer_reward
-                    .checked_mul(previous_fraction_votes)
-                    .map_err(|_| NumericError::Overflow(MathOperation::Mul))?
-                    // TODO: check the operation
-                    .floor(),
-            )
-            .map_err(|_| NumericError::Overflow(MathOperation::Add))?;
+        let bonus_multiplier = bonus_proposer_reward
+            .checked_mul(previous_fraction_votes)
+            .map_err(|_| {
+                NumericError::overflow(
+                    MathOperation::Mul,
+                    bonus_proposer_reward,
+                    previous_fraction_votes,
+                )
+            })?
+            // TODO: check the operation
+            .floor();
+        let proposer_multiplier =
+            base_proposer_reward
+                .checked_add(bonus_multiplier)
+                .map_err(|_| {
+                    NumericError::overflow(
+                        MathOperation::Add,
+                        base_proposer_reward,
+                        bonus_multiplier,
+                    )
+                })?;
         let proposer_reward = fees_collected.checked_mul_dec_truncate(proposer_multiplier)?;
 
         // pay previous proposer
@@ -140,11 +160,14 @@ impl<
 
         // calculate fraction allocated to validators
         let community_tax = params.community_tax;
-        let vote_multiplier = ONE_DEC
-            .checked_sub(proposer_multiplier)
-            .map_err(|_| NumericError::Overflow(MathOperation::Sub))?
+        let non_proposer_fraction = ONE_DEC.checked_sub(proposer_multiplier).map_err(|_| {
+            NumericError::overflow(MathOperation::Sub, ONE_DEC, proposer_multiplier)
+        })?;
+        let vote_multiplier = non_proposer_fraction
             .checked_sub(community_tax)
-            .map_err(|_| NumericError::Overflow(MathOperation::Sub))?;
+            .map_err(|_| {
+                NumericError::overflow(MathOperation::Sub, non_proposer_fraction, community_tax)
+            })?;
         let fee_multiplier = fees_collected.checked_mul_dec_truncate(vote_multiplier)?;
 
         // allocate tokens proportionally to voting power
@@ -161,13 +184,17 @@ impl<
             //
             // Ref: https://github.com/cosmos/cosmos-sdk/issues/2525#issuecomment-430838701
 
-            let power_fraction = Decimal256::from_atomics(u64::from(vote.validator.power), 0)
-                .map_err(NumericError::from)?
-                .checked_div(
-                    Decimal256::from_atomics(total_previous_power, 0)
-                        .map_err(NumericError::from)?,
-                )
-                .map_err(|_| NumericError::Overflow(MathOperation::Div))?
+            let validator_power = Decimal256::from_atomics(u64::from(vote.validator.power), 0)
+                .map_err(|e| NumericError::decimal_range("validator_power", e))?;
+            let power_fraction = validator_power
+                .checked_div(total_previous_power_dec)
+                .map_err(|_| {
+                    NumericError::overflow(
+                        MathOperation::Div,
+                        validator_power,
+                        total_previous_power_dec,
+                    )
+                })?
                 .floor();
             let reward = fee_multiplier.checked_mul_dec_truncate(power_fraction)?;
             self.allocate_tokens_to_validator(
@@ -8,7 +8,7 @@ use gears::{
     error::{MathOperation, NumericError},
     tendermint::types::proto::event::{Event, EventAttribute},
     types::{
-        base::coins::DecimalCoins,
+        base::{coins::DecimalCoins, errors::CoinsError},
         decimal256::{Decimal256, ONE_DEC, SMALLEST_DEC},
     },
     x::types::delegation::StakingDelegation,
@@ -87,18 +87,79 @@ impl<
         // end current period and calculate rewards
         let ending_period =
             self.increment_validator_period(ctx, validator.operator(), validator.tokens())?;
-        let rewards_raw = self
-            .calculate_delegation_rewards(
-                ctx,
-                validator.operator(),
-                delegation.delegator(),
-                validator.tokens_from_shares(*delegation.shares())?,
-                ending_period,
-            )?
-            .ok_or(DistributionError::DelegationRewardsNotFound(
+        let rewards_raw = self.calculate_delegation_rewards(
+            ctx,
+            validator.operator(),
+            delegation.delegator(),
+            validator.tokens_from_shares(*delegation.shares())?,
+            ending_period,
+        )?;
+
+        // no rewards were accrued, nothing to transfer
+        let final_rewards = if let Some(rewards_raw) = rewards_raw {
+            self.withdraw_accrued_rewards(ctx, &delegation, rewards_raw)?
+        } else {
+            None
+        };
+
+        // decrement reference count of starting period
+        let starting_info = self
+            .delegator_starting_info(ctx, delegation.validator(), delegation.delegator())?
+            .ok_or(DistributionError::DelegatorStartingInfoNotFound(
                 delegation.delegator().clone(),
-                validator.operator().clone(),
+                delegation.validator().clone(),
             ))?;
+        let starting_period = starting_info.previous_period;
+        self.decrement_reference_count(ctx, delegation.validator(), starting_period)?;
+
+        // remove delegator starting info
+        self.delete_delegator_starting_info(ctx, delegation.validator(), delegation.delegator())?;
+
+        // TODO: do we need this branch?
+        // We cannot create invalid coins struct with zero amount coin so it seems like we can skip
+        // it and return optional value. Otherwise, consider to implement some restricted struct
+        // ZeroCoins and some enum for combining different coins types
+        // if final_rewards.is_none() {
+        //     baseDenom, _ := sdk.GetBaseDenom()
+        //     if baseDenom == "" {
+        //         baseDenom = sdk.DefaultBondDenom
+        //     }
+        //
+        //     // Note, we do not call the NewCoins constructor as we do not want the zero
+        //     // coin removed.
+        //     finalRewards = sdk.Coins{sdk.NewCoin(baseDenom, sdk.ZeroInt())}
+        // }
+
+        ctx.push_event(Event {
+            r#type: "withdraw_rewards".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "amount".into(),
+                    // TODO: stringify coins structs
+                    value: serde_json::to_string(&final_rewards)
+                        .expect("serde can't fail")
+                        .into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "validator".into(),
+                    value: validator.operator().to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        Ok(final_rewards)
+    }
+
+    /// transfer accrued rewards of the delegation to the withdraw address and return
+    /// the truncated decimal remainder to the community pool
+    fn withdraw_accrued_rewards<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        delegation: &impl StakingDelegation,
+        rewards_raw: DecimalCoins,
+    ) -> Result<Option<UnsignedCoins>, DistributionError> {
         let outstanding = self
             .validator_outstanding_rewards(ctx, delegation.validator())?
             .ok_or(DistributionError::ValidatorOutstandingRewardsNotFound(
@@ -115,7 +176,7 @@ impl<
                 name: "rounding error withdrawing rewards from validator",
                 target: "module::distribution",
                 delegator = delegation.delegator().to_string(),
-                validator = validator.operator().to_string(),
+                validator = delegation.validator().to_string(),
                 // TODO: implement
                 // got = rewards.to_string(),
                 // expected = rewards_raw.to_string()
@@ -126,19 +187,16 @@ impl<
         let (final_rewards, remainder) = rewards.truncate_decimal();
 
         // add coins to user account
-        if final_rewards.is_some() {
+        if let Some(final_rewards) = &final_rewards {
+            // rewards go to the delegator unless a withdraw address is set
             let withdraw_address = self
                 .delegator_withdraw_addr(ctx, delegation.delegator())?
-                .ok_or(DistributionError::AccountNotFound(
-                    delegation.delegator().clone().into(),
-                ))?;
+                .unwrap_or_else(|| delegation.delegator().clone());
             self.bank_keeper.send_coins_from_module_to_account(
                 ctx,
                 &withdraw_address,
                 &self.distribution_module,
-                final_rewards
-                    .clone()
-                    .expect("expect after check of existence cannot fail"),
+                final_rewards.clone(),
             )?;
         }
 
@@ -157,53 +215,6 @@ impl<
             self.set_fee_pool(ctx, &fee_pool)?;
         }
 
-        // decrement reference count of starting period
-        let starting_info = self
-            .delegator_starting_info(ctx, delegation.validator(), delegation.delegator())?
-            .ok_or(DistributionError::DelegatorStartingInfoNotFound(
-                delegation.delegator().clone(),
-                delegation.validator().clone(),
-            ))?;
-        let starting_period = starting_info.previous_period;
-        self.decrement_reference_count(ctx, delegation.validator(), starting_period)?;
-
-        // remove delegator starting info
-        self.delete_delegator_starting_info(ctx, delegation.validator(), delegation.delegator())?;
-
-        // TODO: do we need this branch?
-        // We cannot create invalid coins struct with zero amount coin so it seems like we can skip
-        // it and return optional value. Otherwise, consider to implement some restricted struct
-        // ZeroCoins and some enum for combining different coins types
-        // if final_rewards.is_none() {
-        //     baseDenom, _ := sdk.GetBaseDenom()
-        //     if baseDenom == "" {
-        //         baseDenom = sdk.DefaultBondDenom
-        //     }
-        //
-        //     // Note, we do not call the NewCoins constructor as we do not want the zero
-        //     // coin removed.
-        //     finalRewards = sdk.Coins{sdk.NewCoin(baseDenom, sdk.ZeroInt())}
-        // }
-
-        ctx.push_event(Event {
-            r#type: "withdraw_rewards".to_string(),
-            attributes: vec![
-                EventAttribute {
-                    key: "amount".into(),
-                    // TODO: stringify coins structs
-                    value: serde_json::to_string(&final_rewards)
-                        .expect("serde can't fail")
-                        .into(),
-                    index: false,
-                },
-                EventAttribute {
-                    key: "validator".into(),
-                    value: validator.operator().to_string().into(),
-                    index: false,
-                },
-            ],
-        });
-
         Ok(final_rewards)
     }
 
@@ -215,6 +226,43 @@ impl<
         delegator_address: &AccAddress,
         tokens: Decimal256,
         ending_period: u64,
+    ) -> Result<Option<DecimalCoins>, DistributionError> {
+        self.calculate_delegation_rewards_until(
+            ctx,
+            validator_address,
+            delegator_address,
+            tokens,
+            RewardsEnd::Period(ending_period),
+        )
+    }
+
+    /// calculate the rewards accrued by a delegation until now without ending the current
+    /// period of the validator, so it can be used in queries
+    pub fn delegation_rewards<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        validator: &impl StakingValidator,
+        delegation: &impl StakingDelegation,
+    ) -> Result<Option<DecimalCoins>, DistributionError> {
+        let ending_ratio =
+            self.current_reward_ratio(ctx, validator.operator(), validator.tokens())?;
+
+        self.calculate_delegation_rewards_until(
+            ctx,
+            validator.operator(),
+            delegation.delegator(),
+            validator.tokens_from_shares(*delegation.shares())?,
+            RewardsEnd::Ratio(ending_ratio),
+        )
+    }
+
+    fn calculate_delegation_rewards_until<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        validator_address: &ValAddress,
+        delegator_address: &AccAddress,
+        tokens: Decimal256,
+        end: RewardsEnd,
     ) -> Result<Option<DecimalCoins>, DistributionError> {
         // fetch starting info for delegation
         let starting_info = self
@@ -267,19 +315,24 @@ impl<
                         ending_period,
                         stake,
                     )?;
-                    rewards = if let Some(r) = rewards {
-                        Some(r.checked_add(&addition)?)
-                    } else {
-                        Some(addition)
-                    };
+                    rewards = add_rewards(rewards, addition)?;
                     // Note: It is necessary to truncate so we don't allow withdrawing
                     // more rewards than owed.
+                    let remaining_fraction = ONE_DEC.checked_sub(event.fraction).map_err(|_e| {
+                        DistributionError::Numeric(NumericError::overflow(
+                            MathOperation::Sub,
+                            ONE_DEC,
+                            event.fraction,
+                        ))
+                    })?;
                     stake = stake
-                        .checked_mul(ONE_DEC.checked_sub(event.fraction).map_err(|_e| {
-                            DistributionError::Numeric(NumericError::Overflow(MathOperation::Sub))
-                        })?)
+                        .checked_mul(remaining_fraction)
                         .map_err(|_e| {
-                            DistributionError::Numeric(NumericError::Overflow(MathOperation::Mul))
+                            DistributionError::Numeric(NumericError::overflow(
+                                MathOperation::Mul,
+                                stake,
+                                remaining_fraction,
+                            ))
                         })?
                         .floor();
                     starting_period = ending_period;
@@ -321,7 +374,11 @@ impl<
                 );
             if stake
                 <= (current_stake.checked_add(margin_of_err).map_err(|_e| {
-                    DistributionError::Numeric(NumericError::Overflow(MathOperation::Add))
+                    DistributionError::Numeric(NumericError::overflow(
+                        MathOperation::Add,
+                        current_stake,
+                        margin_of_err,
+                    ))
                 })?)
             {
                 stake = current_stake;
@@ -337,20 +394,23 @@ impl<
         }
 
         // calculate rewards for final period
-
-        let addition = self.calculate_delegation_rewards_between(
-            ctx,
-            validator_address,
-            starting_period,
-            ending_period,
-            stake,
-        )?;
-        rewards = if let Some(r) = rewards {
-            Some(r.checked_add(&addition)?)
-        } else {
-            Some(addition)
+        let addition = match end {
+            RewardsEnd::Period(ending_period) => self.calculate_delegation_rewards_between(
+                ctx,
+                validator_address,
+                starting_period,
+                ending_period,
+                stake,
+            )?,
+            RewardsEnd::Ratio(ending_ratio) => self.calculate_delegation_rewards_to_ratio(
+                ctx,
+                validator_address,
+                starting_period,
+                &ending_ratio,
+                stake,
+            )?,
         };
-        Ok(rewards)
+        add_rewards(rewards, addition)
     }
 
     /// calculate the rewards accrued by a delegation between two periods
@@ -361,29 +421,75 @@ impl<
         starting_period: u64,
         ending_period: u64,
         stake: Decimal256,
-    ) -> Result<DecimalCoins, DistributionError> {
+    ) -> Result<Option<DecimalCoins>, DistributionError> {
         // sanity check
         if starting_period > ending_period {
             panic!("starting_period cannot be greater than ending_period");
         }
 
-        // return staking * (ending - starting)
-        let starting = self
-            .validator_historical_rewards(ctx, validator_address, starting_period)?
+        let ending = self
+            .validator_historical_rewards(ctx, validator_address, ending_period)?
             .ok_or(DistributionError::ValidatorHistoricalRewardsNotFound(
                 validator_address.clone(),
             ))?;
-        let ending = self
-            .validator_historical_rewards(ctx, validator_address, ending_period)?
+
+        self.calculate_delegation_rewards_to_ratio(
+            ctx,
+            validator_address,
+            starting_period,
+            &ending.cumulative_reward_ratio,
+            stake,
+        )
+    }
+
+    /// calculate the rewards accrued by a delegation from the starting period up to the
+    /// cumulative reward ratio
+    fn calculate_delegation_rewards_to_ratio<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        validator_address: &ValAddress,
+        starting_period: u64,
+        ending_ratio: &DecimalCoins,
+        stake: Decimal256,
+    ) -> Result<Option<DecimalCoins>, DistributionError> {
+        // return staking * (ending - starting)
+        let starting = self
+            .validator_historical_rewards(ctx, validator_address, starting_period)?
             .ok_or(DistributionError::ValidatorHistoricalRewardsNotFound(
                 validator_address.clone(),
             ))?;
         // TODO: panics if there are some negative values
-        let difference = ending
-            .cumulative_reward_ratio
-            .checked_sub(&starting.cumulative_reward_ratio)?;
+        let difference = match ending_ratio.checked_sub(&starting.cumulative_reward_ratio) {
+            Ok(difference) => difference,
+            // the ratio didn't change, so no rewards were accrued
+            Err(CoinsError::EmptyList) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
 
         // note: necessary to truncate so we don't allow withdrawing more rewards than owed
-        Ok(difference.checked_mul_dec_truncate(stake)?)
+        match difference.checked_mul_dec_truncate(stake) {
+            Ok(rewards) => Ok(Some(rewards)),
+            // all rewards were truncated to zero
+            Err(CoinsError::EmptyList) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// End of the rewards accumulation of a delegation
+enum RewardsEnd {
+    /// ended validator period with stored historical rewards
+    Period(u64),
+    /// cumulative reward ratio of the current validator period
+    Ratio(DecimalCoins),
+}
+
+fn add_rewards(
+    rewards: Option<DecimalCoins>,
+    addition: Option<DecimalCoins>,
+) -> Result<Option<DecimalCoins>, DistributionError> {
+    match (rewards, addition) {
+        (Some(rewards), Some(addition)) => Ok(Some(rewards.checked_add(&addition)?)),
+        (rewards, addition) => Ok(rewards.or(addition)),
     }
 }
@@ -33,6 +33,8 @@ mod allocation;
 mod delegation;
 mod query;
 mod store;
+#[cfg(test)]
+pub(crate) mod test_utils;
 mod tx;
 mod validator;
 
@@ -86,59 +86,29 @@ impl<
             validator_address,
         }: QueryDelegationRewardsRequest,
     ) -> Result<QueryDelegationRewardsResponse, QueryError> {
-        // TODO: original logic, can't implement and it's wrong idea to modify state via query
-        //       do we have a way to have isolated transactional context that doesn't affect state?
-        //     // branch the context to isolate state changes
-        //     let validator = self
-        //         .staking_keeper
-        //         .validator(ctx, &validator_address)
-        //         .unwrap_gas()
-        //         .ok_or(AppError::AccountNotFound)?;
-        //     let delegation = self
-        //         .staking_keeper
-        //         .delegation(ctx, &delegator_address, &validator_address)
-        //         .unwrap_gas()
-        //         .ok_or(AppError::Custom("delegation is not found".to_string()))?;
-        // let ending_period =
-        //     self.increment_validator_period(ctx, validator.operator(), validator.tokens())?;
-        // let rewards = self.calculate_delegation_rewards(
-        //     ctx,
-        //     &validator_address,
-        //     &delegator_address,
-        //     validator.tokens_from_shares(*delegation.shares())?,
-        //     ending_period,
-        // )?;
-
-        // logic is checked the ending_period is the period from current rewards
-        if let Some(rew) = self
+        if self
             .validator_current_rewards(ctx, &validator_address)
             .unwrap_gas()
+            .is_none()
         {
-            let validator = self
-                .staking_keeper
-                .validator(ctx, &validator_address)
-                .unwrap_gas()
-                .ok_or(QueryError::TODO(anyhow!("account is not found")))?;
-            let delegation = self
-                .staking_keeper
-                .delegation(ctx, &delegator_address, &validator_address)
-                .unwrap_gas()
-                .ok_or(QueryError::TODO(anyhow!("delegation is not found")))?;
-            let rewards = self
-                .calculate_delegation_rewards(
-                    ctx,
-                    &validator_address,
-                    &delegator_address,
-                    validator
-                        .tokens_from_shares(*delegation.shares())
-                        .map_err(|e| QueryError::TODO(anyhow!(e.to_string())))?,
-                    rew.period,
-                )
-                .map_err(|e| QueryError::TODO(anyhow!(e.to_string())))?;
-            Ok(QueryDelegationRewardsResponse { rewards })
-        } else {
-            Ok(QueryDelegationRewardsResponse { rewards: None })
+            return Ok(QueryDelegationRewardsResponse { rewards: None });
         }
+
+        let validator = self
+            .staking_keeper
+            .validator(ctx, &validator_address)
+            .unwrap_gas()
+            .ok_or(QueryError::TODO(anyhow!("validator does not exist")))?;
+        let delegation = self
+            .staking_keeper
+            .delegation(ctx, &delegator_address, &validator_address)
+            .unwrap_gas()
+            .ok_or(QueryError::TODO(anyhow!("delegation does not exist")))?;
+        let rewards = self
+            .delegation_rewards(ctx, &validator, &delegation)
+            .map_err(|e| QueryError::TODO(anyhow!(e.to_string())))?;
+
+        Ok(QueryDelegationRewardsResponse { rewards })
     }
 
     pub fn query_delegator_total_rewards<DB: Database>(
@@ -158,29 +128,12 @@ impl<
                 .validator(ctx, validator_address)
                 .unwrap_gas()
             {
-                if let Ok(shares) = validator.tokens_from_shares(*delegation.shares()) {
-                    if let Some(rew) = self
-                        .validator_current_rewards(ctx, validator_address)
-                        .unwrap_gas()
-                    {
-                        // TODO: original logic, can't implement and it's wrong idea to modify state via query
-                        // let ending_period =
-                        //     self.increment_validator_period(ctx, validator_address, validator.tokens());
-                        let ending_period = rew.period;
-                        del_rewards.push(
-                            self.calculate_delegation_rewards(
-                                ctx,
-                                validator_address,
-                                &delegator_address,
-                                shares,
-                                ending_period,
-                            )
-                            .ok()
-                            .flatten()
-                            .map(|coins| (coins, validator_address.clone())),
-                        );
-                    }
-                }
+                del_rewards.push(
+                    self.delegation_rewards(ctx, &validator, &delegation)
+                        .ok()
+                        .flatten()
+                        .map(|coins| (coins, validator_address.clone())),
+                );
             }
         }
 
@@ -248,3 +201,63 @@ impl<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        context::{block::BlockContext, query::QueryContext},
+        extensions::testing::UnwrapTesting,
+        store::{
+            bank::multi::{ApplicationMultiBank, QueryMultiStore},
+            database::MemDB,
+        },
+        tendermint::types::{chain_id::ChainId, proto::header::Header, time::timestamp::Timestamp},
+    };
+
+    use crate::keeper::test_utils::{
+        allocate_rewards, dec_coins, delegator_address, staking_keeper, test_keeper,
+        validator_address, SubspaceKey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn query_delegation_rewards_returns_proportional_rewards() {
+        let keeper = test_keeper(staking_keeper());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+        );
+        allocate_rewards(&keeper, &mut ctx);
+
+        multi_store.commit();
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            ChainId::default(),
+            Some(Timestamp::UNIX_EPOCH),
+        )
+        .unwrap_test();
+
+        let res = keeper
+            .query_delegation_rewards(
+                &ctx,
+                QueryDelegationRewardsRequest {
+                    delegator_address: delegator_address(),
+                    validator_address: validator_address(),
+                },
+            )
+            .unwrap_test();
+
+        // a quarter of the 1000uatom accrued by the validator's delegators
+        assert_eq!(res.rewards, Some(dec_coins("250uatom")));
+    }
+}
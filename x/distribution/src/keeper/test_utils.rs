@@ -0,0 +1,147 @@
+use std::{collections::HashMap, str::FromStr};
+
+use gears::{
+    context::block::BlockContext,
+    extensions::testing::UnwrapTesting,
+    store::database::Database,
+    tendermint::types::proto::crypto::PublicKey,
+    types::{
+        address::{AccAddress, ValAddress},
+        base::{coin::DecimalCoin, coins::DecimalCoins},
+        decimal256::Decimal256,
+        uint::Uint256,
+    },
+    x::{
+        keepers::mocks::{
+            auth::MockAuthKeeper,
+            module::MockModules,
+            staking::{MockDelegation, MockStakingKeeper, MockValidator},
+        },
+        types::validator::BondStatus,
+    },
+};
+
+use crate::{
+    DelegatorStartingInfo, Keeper, ValidatorCurrentRewards, ValidatorHistoricalRewards,
+    ValidatorHistoricalRewardsReferenceCount,
+};
+
+pub use gears::x::keepers::mocks::module::SubspaceKey;
+
+pub type TestBankKeeper = bank::Keeper<SubspaceKey, SubspaceKey, MockAuthKeeper, MockModules>;
+
+pub type TestKeeper = Keeper<
+    SubspaceKey,
+    SubspaceKey,
+    MockAuthKeeper,
+    TestBankKeeper,
+    MockStakingKeeper,
+    MockModules,
+>;
+
+/// Keeper over mocked auth and staking keepers for tests of the distribution store
+pub fn test_keeper(staking_keeper: MockStakingKeeper) -> TestKeeper {
+    Keeper::new(
+        SubspaceKey::Distribution,
+        SubspaceKey::Params,
+        MockAuthKeeper::former().form(),
+        bank::Keeper::new(
+            SubspaceKey::Bank,
+            SubspaceKey::Bank,
+            MockAuthKeeper::former().form(),
+        ),
+        staking_keeper,
+        MockModules::FeeCollector,
+        MockModules::Distribution,
+        HashMap::new(),
+    )
+}
+
+pub fn dec_coins(coins: &str) -> DecimalCoins {
+    DecimalCoins::new(vec![DecimalCoin::from_str(coins).unwrap_test()]).unwrap_test()
+}
+
+pub fn validator_address() -> ValAddress {
+    ValAddress::try_from(vec![2; 20]).unwrap_test()
+}
+
+pub fn delegator_address() -> AccAddress {
+    AccAddress::try_from(vec![3; 20]).unwrap_test()
+}
+
+/// Bonded validator with 1000 tokens, where the delegator owns a quarter of the shares
+pub fn staking_keeper() -> MockStakingKeeper {
+    MockStakingKeeper::former()
+        .validator(
+            MockValidator::former()
+                .operator(validator_address())
+                .tokens(Uint256::from(1000u64))
+                .delegator_shares(Decimal256::from_atomics(1000u64, 0).unwrap_test())
+                .cons_pub_key(PublicKey::Ed25519(vec![1; 32]))
+                .jailed(false)
+                .min_self_delegation(Uint256::one())
+                .commission(Decimal256::from_str("0.1").unwrap_test())
+                .status(BondStatus::Bonded)
+                .form(),
+        )
+        .delegation(
+            MockDelegation::former()
+                .delegator(delegator_address())
+                .validator(validator_address())
+                .shares(Decimal256::from_atomics(250u64, 0).unwrap_test())
+                .form(),
+        )
+        .max_validators(100)
+        .form()
+}
+
+/// Starts the delegation at period 0 and allocates 1000uatom to the validator.
+/// The cumulative reward ratio grows from 1uatom to 2uatom, so the delegation accrues 250uatom.
+pub fn allocate_rewards<DB: Database>(
+    keeper: &TestKeeper,
+    ctx: &mut BlockContext<'_, DB, SubspaceKey>,
+) {
+    keeper
+        .set_validator_historical_rewards(
+            ctx,
+            &validator_address(),
+            0,
+            &ValidatorHistoricalRewards {
+                cumulative_reward_ratio: dec_coins("1uatom"),
+                reference_count: ValidatorHistoricalRewardsReferenceCount::new(2).unwrap_test(),
+            },
+        )
+        .unwrap_test();
+    keeper
+        .set_validator_current_rewards(
+            ctx,
+            &validator_address(),
+            &ValidatorCurrentRewards {
+                rewards: dec_coins("100uatom"),
+                period: 1,
+            },
+        )
+        .unwrap_test();
+    keeper
+        .set_delegator_starting_info(
+            ctx,
+            &validator_address(),
+            &delegator_address(),
+            &DelegatorStartingInfo {
+                previous_period: 0,
+                stake: Decimal256::from_atomics(250u64, 0).unwrap_test(),
+                height: 0,
+            },
+        )
+        .unwrap_test();
+
+    // 900uatom are left for delegators after the 10% commission
+    keeper
+        .allocate_tokens_to_validator(
+            ctx,
+            &validator_address(),
+            Decimal256::from_str("0.1").unwrap_test(),
+            &dec_coins("1000uatom"),
+        )
+        .unwrap_test();
+}
@@ -109,3 +109,116 @@ impl<
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        context::block::BlockContext,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::header::Header,
+        types::{base::coin::UnsignedCoin, gas::GasMeter},
+        x::keepers::mocks::module::MockModules,
+    };
+
+    use crate::{
+        keeper::test_utils::{
+            allocate_rewards, dec_coins, delegator_address, staking_keeper, test_keeper,
+            validator_address, SubspaceKey,
+        },
+        FeePool,
+    };
+
+    use super::*;
+
+    #[test]
+    fn withdraw_delegator_reward_transfers_truncated_rewards() {
+        let keeper = test_keeper(staking_keeper());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+        );
+        allocate_rewards(&keeper, &mut ctx);
+        keeper
+            .set_fee_pool(
+                &mut ctx,
+                &FeePool {
+                    community_pool: dec_coins("1uatom"),
+                },
+            )
+            .unwrap_test();
+        keeper
+            .bank_keeper
+            .set_balance(
+                &mut ctx,
+                &MockModules::Distribution.get_address(),
+                UnsignedCoin::from_str("1000uatom").unwrap_test(),
+            )
+            .unwrap_test();
+        multi_store.commit();
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            2,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        keeper
+            .withdraw_delegator_reward_and_commission(
+                &mut ctx,
+                &MsgWithdrawDelegatorReward {
+                    validator_address: validator_address(),
+                    delegator_address: delegator_address(),
+                    withdraw_commission: false,
+                },
+            )
+            .unwrap_test();
+
+        let balance = |ctx: &TxContext<'_, _, _>| {
+            keeper
+                .bank_keeper
+                .balance(ctx, &delegator_address(), &"uatom".try_into().unwrap_test())
+                .unwrap_test()
+        };
+        assert_eq!(
+            balance(&ctx),
+            Some(UnsignedCoin::from_str("250uatom").unwrap_test())
+        );
+        assert!(ctx
+            .events
+            .iter()
+            .any(|event| event.r#type == "withdraw_rewards"));
+
+        // the delegation starts over from the period ended by the withdrawal
+        let starting_info = keeper
+            .delegator_starting_info(&ctx, &validator_address(), &delegator_address())
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(starting_info.previous_period, 1);
+        assert_eq!(starting_info.height, 2);
+
+        // nothing is accrued within the same block
+        let rewards = keeper
+            .withdraw_delegation_rewards(&mut ctx, &delegator_address(), &validator_address())
+            .unwrap_test();
+        assert_eq!(rewards, None);
+        assert_eq!(
+            balance(&ctx),
+            Some(UnsignedCoin::from_str("250uatom").unwrap_test())
+        );
+    }
+}
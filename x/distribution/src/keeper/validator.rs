@@ -2,7 +2,11 @@ use super::*;
 use crate::{
     ValidatorCurrentRewards, ValidatorHistoricalRewards, ValidatorHistoricalRewardsReferenceCount,
 };
-use gears::types::{decimal256::Decimal256, uint::Uint256};
+use gears::{
+    context::QueryableContext,
+    error::NumericError,
+    types::{decimal256::Decimal256, uint::Uint256},
+};
 
 impl<
         SK: StoreKey,
@@ -62,12 +66,11 @@ impl<
             None
         } else {
             // note: necessary to truncate so we don't allow withdrawing more rewards than owed
-            Some(
-                rewards.rewards.checked_quo_dec_truncate(
-                    Decimal256::from_atomics(validator_tokens, 0)
-                        .map_err(|e| DistributionError::Numeric(e.into()))?,
-                )?,
-            )
+            Some(rewards.rewards.checked_quo_dec_truncate(
+                Decimal256::from_atomics(validator_tokens, 0).map_err(|e| {
+                    DistributionError::Numeric(NumericError::decimal_range("validator_tokens", e))
+                })?,
+            )?)
         };
 
         // fetch historical rewards for last period
@@ -115,6 +118,46 @@ impl<
         Ok(rewards.period)
     }
 
+    /// cumulative reward ratio of the current validator period as it would be stored when the
+    /// period ends, without modifying state
+    pub fn current_reward_ratio<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        validator_operator_addr: &ValAddress,
+        validator_tokens: Uint256,
+    ) -> Result<DecimalCoins, DistributionError> {
+        let rewards = self
+            .validator_current_rewards(ctx, validator_operator_addr)?
+            .ok_or(DistributionError::ValidatorCurrentRewardsNotFound(
+                validator_operator_addr.clone(),
+            ))?;
+        // the first period has no previous one, a validator is initialized at period 1
+        let historical = rewards
+            .period
+            .checked_sub(1)
+            .map(|period| self.validator_historical_rewards(ctx, validator_operator_addr, period))
+            .transpose()?
+            .flatten()
+            .ok_or(DistributionError::ValidatorHistoricalRewardsNotFound(
+                validator_operator_addr.clone(),
+            ))?
+            .cumulative_reward_ratio;
+
+        // zero-token validators add their rewards to the community pool
+        if validator_tokens.is_zero() {
+            return Ok(historical);
+        }
+
+        // note: necessary to truncate so we don't allow withdrawing more rewards than owed
+        let current = rewards.rewards.checked_quo_dec_truncate(
+            Decimal256::from_atomics(validator_tokens, 0).map_err(|e| {
+                DistributionError::Numeric(NumericError::decimal_range("validator_tokens", e))
+            })?,
+        )?;
+
+        Ok(historical.checked_add(&current)?)
+    }
+
     /// decrement the reference count for a historical rewards value, and delete if zero references remain
     pub fn decrement_reference_count<DB: Database>(
         &self,
@@ -147,3 +190,55 @@ impl<
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        context::block::BlockContext,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::header::Header,
+    };
+
+    use crate::keeper::test_utils::{
+        dec_coins, staking_keeper, test_keeper, validator_address, SubspaceKey,
+    };
+
+    use super::*;
+
+    #[test]
+    fn current_reward_ratio_at_period_zero_not_found() {
+        let keeper = test_keeper(staking_keeper());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = BlockContext::new(
+            &mut multi_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+        );
+        keeper
+            .set_validator_current_rewards(
+                &mut ctx,
+                &validator_address(),
+                &ValidatorCurrentRewards {
+                    rewards: dec_coins("100uatom"),
+                    period: 0,
+                },
+            )
+            .unwrap_test();
+
+        let err = keeper
+            .current_reward_ratio(&ctx, &validator_address(), Uint256::from(1000u64))
+            .expect_err("period 0 has no previous period");
+
+        assert!(matches!(
+            err,
+            DistributionError::ValidatorHistoricalRewardsNotFound(addr) if addr == validator_address()
+        ));
+    }
+}
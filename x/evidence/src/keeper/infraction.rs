@@ -66,11 +66,19 @@ where
         let age_duration = ctx
             .get_time()
             .checked_sub(&infraction_time)
-            .ok_or(NumericError::Overflow(MathOperation::Sub))?;
+            .ok_or_else(|| {
+                NumericError::overflow(
+                    MathOperation::Sub,
+                    format!("{:?}", ctx.get_time()),
+                    format!("{:?}", infraction_time),
+                )
+            })?;
         let age_blocks = ctx
             .height()
             .checked_sub(infraction_height.try_into()?)
-            .ok_or(NumericError::Overflow(MathOperation::Sub))?;
+            .ok_or_else(|| {
+                NumericError::overflow(MathOperation::Sub, ctx.height(), infraction_height)
+            })?;
 
         // Reject evidence if the double-sign is too old. Evidence is considered stale
         // if the difference in time and number of blocks is greater than the allowed
@@ -148,7 +156,13 @@ where
         // That's fine since this is just used to filter unbonding delegations & redelegations.
         let distribution_height = infraction_height
             .checked_sub(VALIDATOR_UPDATE_DELAY as i64)
-            .ok_or(NumericError::Overflow(MathOperation::Sub))?;
+            .ok_or_else(|| {
+                NumericError::overflow(
+                    MathOperation::Sub,
+                    infraction_height,
+                    VALIDATOR_UPDATE_DELAY,
+                )
+            })?;
 
         // Slash validator. The `power` is the int64 power of the validator as provided
         // to/by Tendermint. This value is validator.Tokens as sent to Tendermint via
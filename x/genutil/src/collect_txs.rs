@@ -1,9 +1,21 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
 };
 
-use gears::types::{address::AccAddress, base::coins::UnsignedCoins, tx::Tx};
+use gears::{
+    core::{
+        signing::SignDoc,
+        tx::mode_info::{ModeInfo, SignMode},
+    },
+    tendermint::types::chain_id::ChainId,
+    types::{
+        address::AccAddress,
+        base::coins::UnsignedCoins,
+        tx::{raw::TxWithRaw, Tx},
+    },
+};
+use prost::Message;
 use staking::CreateValidator;
 
 use crate::{errors::SERDE_JSON_CONVERSION, genesis::GenutilGenesis, utils::GenesisBalanceIter};
@@ -34,16 +46,22 @@ pub fn gen_app_state_from_config(
 
     let txs_iter = GenesisBalanceIter::new(balance_sk, &genesis_file)?; // todo: better way to get path to genesis file
 
+    let mut genesis: serde_json::Value =
+        serde_json::from_reader(std::fs::File::open(&genesis_file)?)?;
+
+    let chain_id: ChainId = genesis
+        .get("chain_id")
+        .and_then(serde_json::Value::as_str)
+        .ok_or(anyhow::anyhow!("Failed to read `chain_id` from genesis"))?
+        .parse()?;
+
     let (persistent_peers, app_gen_txs) =
-        collect_txs(gentx_dir, read_moniker_cfg(&home)?, txs_iter)?;
+        collect_txs(gentx_dir, read_moniker_cfg(&home)?, &chain_id, txs_iter)?;
 
     if app_gen_txs.is_empty() {
         return Err(anyhow::anyhow!("there must be at least one genesis tx"));
     }
 
-    let mut genesis: serde_json::Value =
-        serde_json::from_reader(std::fs::File::open(&genesis_file)?)?;
-
     let mut existed_gen_txs = match genesis.pointer_mut("genutil/gen_txs") {
         Some(val) => serde_json::from_value(val.take()).expect(SERDE_JSON_CONVERSION),
         None => GenutilGenesis::default(),
@@ -180,12 +198,13 @@ fn add_peers_to_tm_toml_config(
 fn collect_txs(
     dir: impl AsRef<Path>,
     moniker: String,
+    chain_id: &ChainId,
     balance: impl IntoIterator<Item = (AccAddress, UnsignedCoins)>,
 ) -> anyhow::Result<(Peers, Vec<Tx<CreateValidator>>)> {
     let balance = balance.into_iter().collect::<HashMap<_, _>>();
 
-    let items = if dir.as_ref().is_dir() {
-        let mut entities = Vec::new();
+    let files = if dir.as_ref().is_dir() {
+        let mut files = Vec::new();
 
         for dir in dir.as_ref().read_dir()? {
             let dir = dir?;
@@ -198,19 +217,32 @@ fn collect_txs(
                 continue;
             }
 
-            let file_content = std::fs::read_to_string(dir.path())?;
-            let tx: Tx<CreateValidator> = serde_json::from_str(&file_content)?;
-
-            entities.push(tx);
+            files.push(dir.path());
         }
 
-        entities
+        // sort to keep the collected txs independent of the directory listing order
+        files.sort();
+        files
     } else {
-        let file_content = std::fs::read_to_string(dir.as_ref())?;
+        vec![dir.as_ref().to_owned()]
+    };
+
+    let mut gentxs = Vec::with_capacity(files.len());
+    for file in files {
+        let file_content = std::fs::read_to_string(&file)?;
         let tx: Tx<CreateValidator> = serde_json::from_str(&file_content)?;
 
-        vec![tx]
-    };
+        verify_gentx_signatures(&tx, chain_id)
+            .map_err(|e| anyhow::anyhow!("invalid gentx in {}: {e}", file.display()))?;
+
+        gentxs.push(tx);
+    }
+
+    let mut validators = HashSet::new();
+    let items = gentxs
+        .into_iter()
+        .filter(|tx| validators.insert(tx.get_msgs().first().validator_address.clone()))
+        .collect::<Vec<_>>();
 
     let mut addresses_ip = Vec::with_capacity(items.len());
     for tx in &items {
@@ -256,6 +288,55 @@ fn collect_txs(
     Ok((Peers(addresses_ip), items))
 }
 
+/// Checks the signatures of a gentx against the public keys of its signers. Genesis transactions
+/// are always signed with account number 0, see https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/x/auth/ante/sigverify.go#L272
+fn verify_gentx_signatures(tx: &Tx<CreateValidator>, chain_id: &ChainId) -> anyhow::Result<()> {
+    let signers = tx.get_signers();
+    let signer_infos = &tx.auth_info.signer_infos;
+    let signatures = tx.get_signatures();
+
+    if signer_infos.len() != signers.len() || signatures.len() != signers.len() {
+        Err(anyhow::anyhow!(
+            "wrong number of signatures; expected {}, got {}",
+            signers.len(),
+            signatures.len()
+        ))?
+    }
+
+    let tx = TxWithRaw::from(tx.clone());
+    let sign_bytes = SignDoc {
+        body_bytes: tx.raw.body_bytes,
+        auth_info_bytes: tx.raw.auth_info_bytes,
+        chain_id: chain_id.to_string(),
+        account_number: 0,
+    }
+    .encode_to_vec();
+
+    for ((signer, signer_info), signature) in signers.into_iter().zip(signer_infos).zip(signatures)
+    {
+        if !matches!(signer_info.mode_info, ModeInfo::Single(SignMode::Direct)) {
+            Err(anyhow::anyhow!("only direct sign mode is supported"))?
+        }
+
+        let public_key = signer_info
+            .public_key
+            .as_ref()
+            .ok_or(anyhow::anyhow!("missing public key for signer {signer}"))?;
+
+        if &public_key.get_address() != signer {
+            Err(anyhow::anyhow!(
+                "public key doesn't belong to signer {signer}"
+            ))?
+        }
+
+        public_key
+            .verify_signature(&sign_bytes, signature)
+            .map_err(|e| anyhow::anyhow!("invalid signature: {e}"))?;
+    }
+
+    Ok(())
+}
+
 #[derive(Debug, Clone)]
 pub struct Peers(pub Vec<String>);
 
@@ -264,3 +345,117 @@ impl std::fmt::Display for Peers {
         write!(f, "{}", self.0.join(","))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gears::extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    /// Gentx signed for the default chain id, taken from the gaia genesis test assets
+    const GENTX: &str = r#"{
+    "body": {
+        "messages": [
+            {
+                "@type": "/cosmos.staking.v1beta1.MsgCreateValidator",
+                "description": {
+                    "moniker": "test",
+                    "identity": "",
+                    "website": "",
+                    "security_contact": "",
+                    "details": ""
+                },
+                "commission": {
+                    "rate": "0.100000000000000000",
+                    "max_rate": "0.200000000000000000",
+                    "max_change_rate": "0.010000000000000000"
+                },
+                "min_self_delegation": "1",
+                "delegator_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+                "validator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+                "pubkey": {
+                    "type": "tendermint/PubKeyEd25519",
+                    "value": "AFn3B2/Dvyu9csqfifLNiW1B+D8FvcabD5NW+fGZLPc="
+                },
+                "value": {
+                    "denom": "uatom",
+                    "amount": "10000000000"
+                }
+            }
+        ],
+        "memo": "14f23480fbf55b2b5f09fa4fdcd94f842e1e0e5b@192.168.1.73:26656",
+        "timeout_height": "0",
+        "extension_options": [],
+        "non_critical_extension_options": []
+    },
+    "auth_info": {
+        "signer_infos": [
+            {
+                "public_key": {
+                    "@type": "/cosmos.crypto.secp256k1.PubKey",
+                    "key": "AvUEsFHbsr40nTSmWh7CWYRZHGwf4cpRLtJlaRO4VAoq"
+                },
+                "mode_info": {
+                    "Single": "Direct"
+                },
+                "sequence": "0"
+            }
+        ],
+        "fee": {
+            "amount": null,
+            "gas_limit": "200000",
+            "granter": "",
+            "payer": null
+        }
+    },
+    "signatures": [
+        "NY+fuDkOOXOhItbJOXJQGcbKpvfOzCdCcSef11MTOMswBtNc9x8XHhwEzT8lO2YPj3MBJZJmtAZ9vMgknpLSUQ=="
+    ]
+}"#;
+
+    fn balances() -> Vec<(AccAddress, UnsignedCoins)> {
+        vec![(
+            "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+                .parse()
+                .unwrap_test(),
+            "1000000000000uatom".parse().unwrap_test(),
+        )]
+    }
+
+    #[test]
+    fn collect_txs_rejects_tampered_gentx() {
+        let dir = PathBuf::from("./tmp/collect_txs_tampered_gentx");
+        std::fs::create_dir_all(&dir).unwrap_test();
+
+        std::fs::write(dir.join("valid.json"), GENTX).unwrap_test();
+        std::fs::write(
+            dir.join("tampered.json"),
+            GENTX.replace("\"moniker\": \"test\"", "\"moniker\": \"tampered\""),
+        )
+        .unwrap_test();
+
+        let result = collect_txs(&dir, "test".to_owned(), &ChainId::default(), balances());
+
+        std::fs::remove_dir_all(&dir).unwrap_test();
+
+        let err = result.expect_err("tampered gentx signature is invalid");
+        assert!(err.to_string().contains("tampered.json"));
+        assert!(!err.to_string().contains("valid.json"));
+    }
+
+    #[test]
+    fn collect_txs_deduplicates_validators() {
+        let dir = PathBuf::from("./tmp/collect_txs_duplicate_gentx");
+        std::fs::create_dir_all(&dir).unwrap_test();
+
+        std::fs::write(dir.join("first.json"), GENTX).unwrap_test();
+        std::fs::write(dir.join("second.json"), GENTX).unwrap_test();
+
+        let result = collect_txs(&dir, "test".to_owned(), &ChainId::default(), balances());
+
+        std::fs::remove_dir_all(&dir).unwrap_test();
+
+        let (_, txs) = result.unwrap_test();
+        assert_eq!(txs.len(), 1);
+    }
+}
@@ -109,7 +109,7 @@ impl TxHandler for GentxTxHandler {
                     account_number,
                     sequence,
                 }))),
-                AccountProvider::Online => {
+                AccountProvider::Online { .. } => {
                    Err(anyhow::anyhow!("Can't use online mode for gentx account. You need to specify `account-number` and `sequence`"))
                 }
             }
@@ -5,6 +5,7 @@ use gears::core::errors::CoreError;
 use gears::params::gas::subspace_mut;
 use gears::store::database::Database;
 use gears::store::StoreKey;
+use gears::tendermint::types::proto::event::{Event, EventAttribute};
 
 use gears::types::store::gas::errors::GasStoreErrors;
 use gears::{
@@ -55,10 +56,20 @@ impl<PSK: ParamsSubspaceKey, PK: ParamsKeeper<PSK>> SubmissionHandler<PK, PSK, P
             Err(SubmissionHandlingError::InvalidProposal)?
         }
 
-        let mut store = subspace_mut(ctx, subspace_key);
+        let event = Event::new(
+            "param_change",
+            vec![
+                EventAttribute::new("subspace".into(), subspace_key.name().into(), true),
+                EventAttribute::new("key".into(), proposal.key.clone().into(), true),
+                EventAttribute::new("value".into(), proposal.value.clone().into(), true),
+            ],
+        );
 
+        let mut store = subspace_mut(ctx, subspace_key);
         store.raw_key_set(proposal.key, proposal.value)?;
 
+        ctx.push_event(event);
+
         Ok(())
     }
 }
@@ -1,8 +1,15 @@
 use std::marker::PhantomData;
 
 use crate::{
-    errors, ics02_client::client::cli::query::client_states::STATES_URL, keeper::Keeper,
-    message::Message, types::genesis::GenesisState,
+    errors,
+    ics02_client::client::cli::query::{
+        client_params::PARAMS_URL, client_state::STATE_URL, client_states::STATES_URL,
+        client_status::STATUS_URL, consensus_state::CONSENSUS_STATE_URL,
+        consensus_states::CONSENSUS_STATES_URL,
+    },
+    keeper::Keeper,
+    message::Message,
+    types::genesis::GenesisState,
 };
 use gears::{
     application::handlers::node::{ModuleInfo, TxError},
@@ -12,6 +19,7 @@ use gears::{
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
 };
+use ibc::core::client::types::proto::v1::QueryClientParamsRequest;
 use ibc::primitives::proto::Protobuf;
 //use ibc::core::client::types::{
 //    msgs::{MsgUpdateClient, MsgUpgradeClient},
@@ -48,68 +56,24 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
         msg: Message,
     ) -> Result<(), TxError> {
         match msg {
-            Message::ClientCreate(msg) => {
-                // let MsgCreateClient {
-                //     client_state:,
-                //     consensus_state,
-                //     signer: _signer,
-                // } = msg;
-
-                // let _ = self
-                //     .tx_keeper
-                //     .client_create(ctx, &client_state, consensus_state.into())?;
-
-                self.keeper
-                    .client_create(ctx, msg)
-                    .map_err(|e| TxError::new::<MI>(e.to_string(), nz::u16!(1)))?;
-
-                Ok(())
-            } // Message::ClientUpdate(msg) => {
-              //     let MsgUpdateClient {
-              //         client_id,
-              //         client_message,
-              //         signer: _signer,
-              //     } = msg;
-
-              //     self.tx_keeper
-              //         .client_update(ctx, &client_id, client_message)?;
-
-              //     Ok(())
-              // }
-              // Message::ClientUpgrade(msg) => {
-              //     let MsgUpgradeClient {
-              //         client_id,
-              //         upgraded_client_state,
-              //         upgraded_consensus_state,
-              //         proof_upgrade_client,
-              //         proof_upgrade_consensus_state,
-              //         signer: _signer,
-              //     } = msg;
-
-              //     self.tx_keeper.client_upgrade(
-              //         ctx,
-              //         &client_id,
-              //         upgraded_client_state,
-              //         upgraded_consensus_state,
-              //         proof_upgrade_client,
-              //         proof_upgrade_consensus_state,
-              //     )?;
-
-              //     Ok(())
-              // }
-              // Message::RecoverClient(msg) => {
-              //     let MsgRecoverClient {
-              //         subject_client_id,
-              //         substitute_client_id,
-              //         signer: _signer,
-              //     } = msg;
-
-              //     self.tx_keeper
-              //         .recover_client(ctx, &subject_client_id, &substitute_client_id)?;
-
-              //     Ok(())
-              // }
+            Message::ClientCreate(msg) => self
+                .keeper
+                .client_create(ctx, msg)
+                .map_err(|e| e.to_string()),
+            Message::ClientUpdate(msg) => self
+                .keeper
+                .client_update(ctx, msg)
+                .map_err(|e| e.to_string()),
+            Message::ClientUpgrade(msg) => self
+                .keeper
+                .client_upgrade(ctx, msg)
+                .map_err(|e| e.to_string()),
+            Message::RecoverClient(msg) => self
+                .keeper
+                .client_recover(ctx, msg)
+                .map_err(|e| e.to_string()),
         }
+        .map_err(|e| TxError::new::<MI>(e, nz::u16!(1)))
     }
 
     pub fn query<DB: Database + Send + Sync>(
@@ -118,15 +82,21 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
         query: gears::tendermint::types::request::query::RequestQuery,
     ) -> Result<Vec<u8>, QueryError> {
         match query.path.as_str() {
-            // "/ibc.core.client.v1.Query/ClientParams" => {
-            //     //Ok(self.query_keeper.client_params(ctx)?.encode_vec().into())
-            //     Ok(vec![].into())
-            // }
-            // "/ibc.core.client.v1.Query/UpgradedClientState" => Ok(self
-            //     .query_keeper
-            //     .client_state(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
+            PARAMS_URL => {
+                // a kind of type check
+                let _req = QueryClientParamsRequest::decode(query.data)
+                    .map_err(|e| QueryError::Proto(e.to_string()))?;
+
+                Ok(self.keeper.client_params(ctx).encode_to_vec())
+            }
+            STATE_URL => Ok(self
+                .keeper
+                .client_state(
+                    ctx,
+                    ProstMessage::decode(query.data)
+                        .map_err(|e| QueryError::Proto(e.to_string()))?,
+                )?
+                .encode_to_vec()),
             STATES_URL => Ok(self
                 .keeper
                 .client_states(
@@ -135,26 +105,35 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
                         .map_err(|e| QueryError::Proto(e.to_string()))?,
                 )
                 .encode_vec()),
-            // "/ibc.core.client.v1.Query/ClientStatus" => Ok(self
-            //     .query_keeper
-            //     .client_status(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
+            STATUS_URL => Ok(self
+                .keeper
+                .client_status(
+                    ctx,
+                    ProstMessage::decode(query.data)
+                        .map_err(|e| QueryError::Proto(e.to_string()))?,
+                )?
+                .encode_to_vec()),
             // "/ibc.core.client.v1.Query/ConsensusStateHeights" => Ok(self
             //     .query_keeper
             //     .consensus_state_heights(ctx, ProstMessage::decode(query.data)?)?
             //     .encode_vec()
             //     .into()),
-            // "/ibc.core.client.v1.Query/ConsensusState" => Ok(self
-            //     .query_keeper
-            //     .consensus_state(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
-            // "/ibc.core.client.v1.Query/ConsensusStates" => Ok(self
-            //     .query_keeper
-            //     .consensus_states(ctx, ProstMessage::decode(query.data)?)?
-            //     .encode_vec()
-            //     .into()),
+            CONSENSUS_STATE_URL => Ok(self
+                .keeper
+                .consensus_state(
+                    ctx,
+                    ProstMessage::decode(query.data)
+                        .map_err(|e| QueryError::Proto(e.to_string()))?,
+                )?
+                .encode_to_vec()),
+            CONSENSUS_STATES_URL => Ok(self
+                .keeper
+                .consensus_states(
+                    ctx,
+                    ProstMessage::decode(query.data)
+                        .map_err(|e| QueryError::Proto(e.to_string()))?,
+                )?
+                .encode_to_vec()),
             _ => Err(QueryError::PathNotFound),
         }
     }
@@ -162,4 +141,8 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, MI: ModuleInfo> ABCIHandler<SK, PSK,
     pub fn genesis<DB: Database>(&self, ctx: &mut InitContext<'_, DB, SK>, genesis: GenesisState) {
         self.keeper.init_genesis(ctx, genesis)
     }
+
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        self.keeper.export_genesis(ctx)
+    }
 }
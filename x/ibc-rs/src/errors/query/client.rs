@@ -1,4 +1,5 @@
 //use gears::error::SearchError;
+use gears::baseapp::errors::QueryError;
 use ibc::core::{client::types::error::ClientError, host::types::error::IdentifierError};
 use prost::DecodeError;
 // use proto_messages::cosmos::ibc::types::core::{
@@ -57,6 +58,18 @@ pub enum StateError {
     IdentifierError(#[from] IdentifierError),
 }
 
+impl From<StateError> for QueryError {
+    fn from(err: StateError) -> Self {
+        match err {
+            StateError::ClientError(ClientError::ClientStateNotFound { .. }) => {
+                QueryError::NotFound(err.to_string())
+            }
+            StateError::IdentifierError(_) => QueryError::Proto(err.to_string()),
+            _ => QueryError::TODO(err.into()),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum StatesError {
     #[error("{0}")]
@@ -81,6 +94,18 @@ pub enum StatusError {
     ClientError(#[from] ClientError),
 }
 
+impl From<StatusError> for QueryError {
+    fn from(err: StatusError) -> Self {
+        match err {
+            StatusError::ClientError(ClientError::ClientStateNotFound { .. }) => {
+                QueryError::NotFound(err.to_string())
+            }
+            StatusError::IdentifierError(_) => QueryError::Proto(err.to_string()),
+            _ => QueryError::TODO(err.into()),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ConsensusStateHeightError {
     #[error("Invalid client_id: {0}")]
@@ -99,6 +124,21 @@ pub enum ConsensusStateError {
     ClientError(#[from] ClientError),
 }
 
+impl From<ConsensusStateError> for QueryError {
+    fn from(err: ConsensusStateError) -> Self {
+        match err {
+            ConsensusStateError::ClientError(
+                ClientError::ClientStateNotFound { .. }
+                | ClientError::ConsensusStateNotFound { .. },
+            ) => QueryError::NotFound(err.to_string()),
+            ConsensusStateError::IdentifierError(_) | ConsensusStateError::ClientError(_) => {
+                QueryError::Proto(err.to_string())
+            }
+            _ => QueryError::TODO(err.into()),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ConsensusStatesError {
     #[error("Invalid client_id: {0}")]
@@ -108,3 +148,12 @@ pub enum ConsensusStatesError {
     #[error("Client: {0}")]
     ClientError(#[from] ClientError),
 }
+
+impl From<ConsensusStatesError> for QueryError {
+    fn from(err: ConsensusStatesError) -> Self {
+        match err {
+            ConsensusStatesError::IdentifierError(_) => QueryError::Proto(err.to_string()),
+            _ => QueryError::TODO(err.into()),
+        }
+    }
+}
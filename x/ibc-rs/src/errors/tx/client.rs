@@ -18,6 +18,9 @@ use ibc::core::{
     },
 };
 
+use gears::types::{address::AccAddress, store::gas::errors::GasStoreErrors};
+use ibc::core::handler::types::error::ContextError;
+
 use crate::errors::query::client::SearchError;
 
 #[derive(Debug, thiserror::Error)]
@@ -36,6 +39,11 @@ pub enum ClientErrors {
 
 #[derive(Debug, thiserror::Error)]
 pub enum ClientRecoverError {
+    #[error("expected {expected} to be the signer, got {signer}")]
+    Unauthorized {
+        expected: AccAddress,
+        signer: AccAddress,
+    },
     #[error(
         "subject client state latest height is greater or equal to substitute client state latest height ({subject} >= {substitute})"
     )]
@@ -48,6 +56,8 @@ pub enum ClientRecoverError {
     ClientError(#[from] ClientError),
     #[error("SearchError: {0}")]
     SearchError(#[from] SearchError),
+    #[error("{0}")]
+    Context(#[from] ContextError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -88,6 +98,10 @@ pub enum ClientCreateError {
     ClientError(#[from] ClientError),
     #[error("SearchError: {0}")]
     SearchError(#[from] SearchError),
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+    #[error("{0}")]
+    Context(#[from] ContextError),
     #[error("Unexpected error: {0}")]
     CustomError(String),
 }
@@ -7,7 +7,7 @@ use ibc::core::{
 //     client::context::types::proto::v1::QueryClientStateRequest, host::identifiers::ClientId,
 // };
 
-pub(crate) const STATE_URL: &str = "/ibc.core.client.v1.Query/UpgradedClientState";
+pub(crate) const STATE_URL: &str = "/ibc.core.client.v1.Query/ClientState";
 
 /// Query a client state
 #[derive(Args, Debug, Clone)]
@@ -5,12 +5,15 @@ use ibc::core::client::types::proto::v1::{
 };
 //use prost::bytes::Bytes;
 //use prost_012::Message;
+use prost::Message;
 
 use crate::ics02_client::types::query::QueryClientStatesResponse;
 
 use super::{
+    client_params, client_state,
     client_states::{self, CliClientStates},
-    ClientQuery, ClientQueryCli, ClientQueryCommands, ClientQueryResponse,
+    consensus_state, consensus_states, ClientQuery, ClientQueryCli, ClientQueryCommands,
+    ClientQueryResponse,
 };
 use ibc::primitives::proto::Protobuf;
 
@@ -32,34 +35,41 @@ impl QueryHandler for ClientQueryHandler {
         command: &Self::QueryCommands,
     ) -> anyhow::Result<Self::QueryRequest> {
         let res = match &command.command {
-            ClientQueryCommands::ClientParams(_) => todo!(),
-            ClientQueryCommands::ClientState(_) => todo!(),
+            ClientQueryCommands::ClientParams(args) => {
+                Self::QueryRequest::ClientParams(client_params::handle_query(args))
+            }
+            ClientQueryCommands::ClientState(args) => {
+                Self::QueryRequest::ClientState(client_state::handle_query(args))
+            }
             ClientQueryCommands::ClientStates(args) => {
                 Self::QueryRequest::ClientStates(client_states::handle_query(args))
             }
             ClientQueryCommands::ClientStatus(_) => todo!(),
-            ClientQueryCommands::ConsensusState(_) => todo!(),
-            ClientQueryCommands::ConsensusStates(_) => todo!(),
-            // IbcQueryCommands::Client(command) => {
-            //     Self::QueryRequest::Client(ClientQueryHandler.prepare_query_request(command)?)
-            // } // IbcQueryCommands::ClientParams(args) => {
-            //   //     Self::QueryRequest::ClientParams(client_params::handle_query(args))
-            //   // }
-            //   // IbcQueryCommands::ClientState(args) => {
-            //   //     Self::QueryRequest::ClientState(client_state::handle_query(args))
-            //   // }
-            //   // IbcQueryCommands::ClientStates(args) => {
-            //   //     Self::QueryRequest::ClientStates(client_states::handle_query(args))
-            //   // }
-            //   // IbcQueryCommands::ClientStatus(args) => {
-            //   //     Self::QueryRequest::ClientStatus(client_status::handle_query(args))
-            //   // }
-            //   // IbcQueryCommands::ConsensusState(args) => {
-            //   //     Self::QueryRequest::ConsensusState(consensus_state::handle_query(args))
-            //   // }
-            //   // IbcQueryCommands::ConsensusStates(args) => {
-            //   //     Self::QueryRequest::ConsensusStates(consensus_states::handle_query(args))
-            //   // }
+            ClientQueryCommands::ConsensusState(args) => {
+                Self::QueryRequest::ConsensusState(consensus_state::handle_query(args))
+            }
+            ClientQueryCommands::ConsensusStates(args) => {
+                Self::QueryRequest::ConsensusStates(consensus_states::handle_query(args))
+            } // IbcQueryCommands::Client(command) => {
+              //     Self::QueryRequest::Client(ClientQueryHandler.prepare_query_request(command)?)
+              // } // IbcQueryCommands::ClientParams(args) => {
+              //   //     Self::QueryRequest::ClientParams(client_params::handle_query(args))
+              //   // }
+              //   // IbcQueryCommands::ClientState(args) => {
+              //   //     Self::QueryRequest::ClientState(client_state::handle_query(args))
+              //   // }
+              //   // IbcQueryCommands::ClientStates(args) => {
+              //   //     Self::QueryRequest::ClientStates(client_states::handle_query(args))
+              //   // }
+              //   // IbcQueryCommands::ClientStatus(args) => {
+              //   //     Self::QueryRequest::ClientStatus(client_status::handle_query(args))
+              //   // }
+              //   // IbcQueryCommands::ConsensusState(args) => {
+              //   //     Self::QueryRequest::ConsensusState(consensus_state::handle_query(args))
+              //   // }
+              //   // IbcQueryCommands::ConsensusStates(args) => {
+              //   //     Self::QueryRequest::ConsensusStates(consensus_states::handle_query(args))
+              //   // }
         };
 
         Ok(res)
@@ -71,14 +81,22 @@ impl QueryHandler for ClientQueryHandler {
         command: &Self::QueryCommands,
     ) -> anyhow::Result<Self::QueryResponse> {
         let res = match &command.command {
-            ClientQueryCommands::ClientParams(_) => todo!(),
-            ClientQueryCommands::ClientState(_) => todo!(),
+            ClientQueryCommands::ClientParams(_) => ClientQueryResponse::ClientParams(
+                QueryClientParamsResponse::decode(query_bytes.as_slice())?,
+            ),
+            ClientQueryCommands::ClientState(_) => ClientQueryResponse::ClientState(
+                QueryClientStateResponse::decode(query_bytes.as_slice())?,
+            ),
             ClientQueryCommands::ClientStates(_) => ClientQueryResponse::ClientStates(
                 QueryClientStatesResponse::decode_vec(&query_bytes)?,
             ),
             ClientQueryCommands::ClientStatus(_) => todo!(),
-            ClientQueryCommands::ConsensusState(_) => todo!(),
-            ClientQueryCommands::ConsensusStates(_) => todo!(),
+            ClientQueryCommands::ConsensusState(_) => ClientQueryResponse::ConsensusState(
+                QueryConsensusStateResponse::decode(query_bytes.as_slice())?,
+            ),
+            ClientQueryCommands::ConsensusStates(_) => ClientQueryResponse::ConsensusStates(
+                QueryConsensusStatesResponse::decode(query_bytes.as_slice())?,
+            ),
             //IbcQueryCommands::Client(_) => todo!(),
             // // *This is fine*.png
             // IbcQueryCommands::ClientParams(_) => IbcQueryResponse::ClientParams(
@@ -35,9 +35,7 @@ impl Default for GenesisState {
             clients: vec![],
             clients_consensus: vec![],
             clients_metadata: vec![],
-            params: ClientParams {
-                allowed_clients: vec!["06-solomachine".into(), "07-tendermint".into()],
-            },
+            params: ClientParams::module_default(),
             create_localhost: false,
             next_client_sequence: 0,
         }
@@ -1,24 +1,50 @@
 use gears::context::init::InitContext;
 use gears::context::query::QueryContext;
+use gears::core::query::request::PageRequest;
+use gears::extensions::corruption::UnwrapCorrupt;
+use gears::extensions::pagination::{IteratorPaginate, Pagination};
 use gears::params::ParamsSubspaceKey;
 use gears::store::database::prefix::PrefixDB;
 use gears::store::store::prefix::mutable::MutablePrefixStore;
+use gears::types::pagination::{request::PaginationRequest, response::PaginationResponse};
 use gears::types::store::gas::errors::GasStoreErrors;
 use gears::types::store::prefix::mutable::PrefixStoreMut;
 use gears::{
     context::QueryableContext,
     store::{database::Database, StoreKey},
 };
-use ibc::primitives::ToVec;
+use ibc::core::client::context::client_state::{ClientStateCommon, ClientStateValidation};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::proto::v1::{
+    ConsensusStateWithHeight, Height as RawHeight, QueryClientStateRequest,
+    QueryClientStateResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+    QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+};
+use ibc::core::client::types::{Height, Status};
+use ibc::core::commitment_types::proto::v1::MerkleProof as RawMerkleProof;
+use ibc::primitives::proto::Any;
 use ibc::{core::host::types::path::ClientStatePath, primitives::proto::Protobuf};
+use prost::Message;
 
-use crate::ics02_client::types::{client_state::ClientState, query::IdentifiedClientState};
-use crate::types::context::CLIENT_STATE_KEY;
+use crate::errors::query::client::{
+    ConsensusStateError, ConsensusStatesError, StateError, StatusError,
+};
+use crate::errors::tx::client::ClientCreateError;
+use crate::ics02_client::types::{
+    client_state::ClientState,
+    query::{IdentifiedClientState, PageResponse},
+};
+use crate::types::context::{QueryClientContext, CLIENT_STATE_KEY, KEY_CONSENSUS_STATE_PREFIX};
 
-use super::{params::ClientParamsKeeper, types::query::QueryClientStatesResponse, GenesisState};
-use gears::context::{InfallibleContextMut, TransactionalContext};
+use super::{
+    params::{ClientParams, ClientParamsKeeper},
+    types::query::{QueryClientStatesResponse, QueryConsensusStatesResponse},
+    GenesisState,
+};
+use gears::context::{InfallibleContext, InfallibleContextMut, TransactionalContext};
 use ibc::core::{
-    client::types::proto::v1::QueryClientStatesRequest, host::types::identifiers::ClientId,
+    client::types::proto::v1::{QueryClientParamsResponse, QueryClientStatesRequest},
+    host::types::identifiers::{ClientId, ClientType},
 };
 
 pub const KEY_NEXT_CLIENT_SEQUENCE: &[u8; 18] = b"nextClientSequence";
@@ -46,7 +72,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         ctx: &mut InitContext<'_, DB, SK>,
         genesis: GenesisState,
     ) {
-        self.client_params_keeper.set(ctx, genesis.params.clone());
+        self.params_set(ctx, genesis.params.clone());
 
         // TODO: the following lines(from ibc-go) have not been implemented yet:
 
@@ -83,6 +109,25 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         self.set_next_client_sequence(ctx, genesis.next_client_sequence);
     }
 
+    /// Exports the state set by `init_genesis` i.e. the params and the next client sequence.
+    // TODO: export clients, consensus states and metadata once they are set by `init_genesis`
+    pub fn export_genesis<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> GenesisState {
+        let next_client_sequence = ctx
+            .infallible_store(&self.store_key)
+            .get(KEY_NEXT_CLIENT_SEQUENCE)
+            .map(|raw| u64::from_be_bytes(raw.try_into().unwrap_or_corrupt()))
+            .unwrap_or_default();
+
+        GenesisState {
+            params: self.client_params_keeper.get(ctx),
+            next_client_sequence,
+            ..Default::default()
+        }
+    }
+
     pub fn set_next_client_sequence<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, SK>,
@@ -92,55 +137,264 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         ibc_store.set(KEY_NEXT_CLIENT_SEQUENCE.to_owned(), sequence.to_be_bytes())
     }
 
+    /// Sets the IBC client parameters
+    pub fn params_set<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        params: ClientParams,
+    ) {
+        self.client_params_keeper.set(ctx, params)
+    }
+
+    /// Returns an error if clients of `client_type` aren't allowed to be created
+    pub fn client_type_check<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        client_type: ClientType,
+    ) -> Result<(), ClientCreateError> {
+        let params = self.client_params_keeper.try_get(ctx)?;
+
+        match params.is_client_allowed(&client_type) {
+            true => Ok(()),
+            false => Err(ClientCreateError::NotAllowed(client_type)),
+        }
+    }
+
+    /// Query the IBC client parameters
+    pub fn client_params<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> QueryClientParamsResponse {
+        QueryClientParamsResponse {
+            params: Some(self.client_params_keeper.get(ctx).into()),
+        }
+    }
+
     /// Query all client states
-    pub fn client_states<DB: Database>(
+    pub fn client_states<DB: Database, CTX: InfallibleContext<DB, SK>>(
         &self,
-        ctx: &QueryContext<DB, SK>,
-        _req: QueryClientStatesRequest,
+        ctx: &CTX,
+        QueryClientStatesRequest { pagination }: QueryClientStatesRequest,
     ) -> QueryClientStatesResponse {
+        let pagination = pagination.map(|page| {
+            Pagination::from(PaginationRequest::from(PageRequest {
+                key: page.key,
+                offset: page.offset,
+                limit: page.limit,
+                count_total: page.count_total,
+                reverse: page.reverse,
+            }))
+        });
+
         let store = ctx
-            .kv_store(&self.store_key)
+            .infallible_store(&self.store_key)
             .prefix_store(KEY_CLIENT_STORE_PREFIX.to_string().into_bytes());
 
-        let mut client_states = vec![];
+        // the client store also holds consensus states so entries are filtered before paginating
+        let client_states = store.into_range(..).filter_map(|(key, raw_state)| {
+            let client_id = {
+                let key = std::str::from_utf8(&key).ok()?;
 
-        for (key, raw_state) in store.into_range(..) {
-            let Ok(key) = String::from_utf8(key.to_vec()) else {
-                continue;
-            };
+                let key_split: Vec<&str> = key.split('/').collect();
+                let [_, client_id, this_client_state_key] = key_split[..] else {
+                    return None;
+                };
 
-            let key_split: Vec<&str> = key.split('/').collect();
-            let [_, client_id, this_client_state_key] = key_split[..] else {
-                continue;
+                if this_client_state_key != CLIENT_STATE_KEY {
+                    return None;
+                }
+
+                client_id.parse::<ClientId>().ok()?
             };
 
-            if this_client_state_key != CLIENT_STATE_KEY {
-                continue;
+            let client_state = ClientState::decode_vec(&raw_state).ok()?;
+
+            Some((
+                key,
+                IdentifiedClientState {
+                    client_id,
+                    client_state,
+                },
+            ))
+        });
+
+        // client states are listed in the order of their keys, sorting a page would mix the
+        // order of consecutive pages
+        let (p_result, iter) = client_states.maybe_paginate(pagination);
+        let client_states = iter.map(|(_, state)| state).collect::<Vec<_>>();
+
+        QueryClientStatesResponse {
+            client_states,
+            pagination: p_result.map(|p_result| {
+                let PaginationResponse { next_key, total } = PaginationResponse::from(p_result);
+                PageResponse { next_key, total }
+            }),
+        }
+    }
+
+    /// Query the status of a client, expiry is checked against the latest block time
+    pub fn client_status<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryClientStatusRequest { client_id }: QueryClientStatusRequest,
+    ) -> Result<QueryClientStatusResponse, StatusError> {
+        let client_id: ClientId = client_id.parse()?;
+        let client_state = self.client_state_get(ctx, &client_id)?;
+
+        let params = self.client_params_keeper.get(ctx);
+
+        let status = if !params.is_client_allowed(&client_state.client_type()) {
+            Status::Unauthorized
+        } else {
+            let ClientState::Tendermint(client_state) = client_state;
+            client_state.status(
+                &QueryClientContext::new(ctx, self.store_key.clone()),
+                &client_id,
+            )?
+        };
+
+        Ok(QueryClientStatusResponse {
+            status: status.to_string(),
+        })
+    }
+
+    /// Query a client state along with its proof against the commit hash of the queried height
+    pub fn client_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryClientStateRequest { client_id }: QueryClientStateRequest,
+    ) -> Result<QueryClientStateResponse, StateError> {
+        let client_id: ClientId = client_id.parse()?;
+        let client_state = self.client_state_get(ctx, &client_id)?;
+
+        let key = format!("{KEY_CLIENT_STORE_PREFIX}/{client_id}/{CLIENT_STATE_KEY}");
+
+        Ok(QueryClientStateResponse {
+            client_state: Some(client_state.into()),
+            proof: self.merkle_proof(ctx, &key),
+            proof_height: Some(proof_height(ctx)),
+        })
+    }
+
+    /// Query a consensus state of a client, at its latest height if `latest_height` is set,
+    /// along with its proof against the commit hash of the queried height
+    pub fn consensus_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConsensusStateRequest {
+            client_id,
+            revision_number,
+            revision_height,
+            latest_height,
+        }: QueryConsensusStateRequest,
+    ) -> Result<QueryConsensusStateResponse, ConsensusStateError> {
+        let client_id: ClientId = client_id.parse()?;
+
+        let height = match latest_height {
+            true => self.client_state_get(ctx, &client_id)?.latest_height(),
+            false => Height::new(revision_number, revision_height)?,
+        };
+
+        let key = format!(
+            "{KEY_CLIENT_STORE_PREFIX}/{client_id}/{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+            height.revision_number(),
+            height.revision_height()
+        );
+
+        let consensus_state = ctx
+            .infallible_store(&self.store_key)
+            .get(key.as_bytes())
+            .ok_or(ClientError::ConsensusStateNotFound { client_id, height })?;
+
+        Ok(QueryConsensusStateResponse {
+            consensus_state: Some(Any::decode(consensus_state.as_slice()).unwrap_or_corrupt()),
+            proof: self.merkle_proof(ctx, &key),
+            proof_height: Some(proof_height(ctx)),
+        })
+    }
+
+    /// Query all consensus states of a client
+    pub fn consensus_states<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryConsensusStatesRequest {
+            client_id,
+            pagination,
+        }: QueryConsensusStatesRequest,
+    ) -> Result<QueryConsensusStatesResponse, ConsensusStatesError> {
+        let client_id: ClientId = client_id.parse()?;
+
+        let pagination = pagination.map(|page| {
+            Pagination::from(PaginationRequest::from(PageRequest {
+                key: page.key,
+                offset: page.offset,
+                limit: page.limit,
+                count_total: page.count_total,
+                reverse: page.reverse,
+            }))
+        });
+
+        let store = ctx.infallible_store(&self.store_key).prefix_store(
+            format!("{KEY_CLIENT_STORE_PREFIX}/{client_id}/{KEY_CONSENSUS_STATE_PREFIX}/")
+                .into_bytes(),
+        );
+
+        // the processed time and height of each consensus state are stored under its key
+        // so entries are filtered before paginating
+        let consensus_states = store.into_range(..).filter_map(|(key, raw_state)| {
+            let height = std::str::from_utf8(&key).ok()?;
+            if height.contains('/') {
+                return None;
             }
+            let height: Height = height.parse().ok()?;
 
-            let Ok(client_id) = client_id.parse::<ClientId>() else {
-                continue;
-            };
+            Some((
+                key,
+                ConsensusStateWithHeight {
+                    height: Some(height.into()),
+                    consensus_state: Some(Any::decode(raw_state.as_slice()).ok()?),
+                },
+            ))
+        });
 
-            let Ok(client_state) = ClientState::decode_vec(&raw_state) else {
-                continue;
-            };
+        let (p_result, iter) = consensus_states.maybe_paginate(pagination);
 
-            let identified = IdentifiedClientState {
-                client_id,
-                client_state,
-            };
+        Ok(QueryConsensusStatesResponse {
+            consensus_states: iter.map(|(_, state)| state).collect(),
+            pagination: p_result.map(|p_result| {
+                let PaginationResponse { next_key, total } = PaginationResponse::from(p_result);
+                PageResponse { next_key, total }
+            }),
+        })
+    }
 
-            client_states.push(identified);
-        }
+    fn client_state_get<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        client_id: &ClientId,
+    ) -> Result<ClientState, ClientError> {
+        let client_state = ctx
+            .infallible_store(&self.store_key)
+            .prefix_store(format!("{KEY_CLIENT_STORE_PREFIX}/{client_id}/").into_bytes())
+            .get(CLIENT_STATE_KEY.as_bytes())
+            .ok_or_else(|| ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?;
 
-        // sort client_states (as is done in ibc-go) https://github.com/cosmos/ibc-go/blob/46e020640e66f9043c14c53a4d215a5b457d6703/modules/core/02-client/keeper/grpc_query.go#L91
-        client_states.sort();
+        ClientState::decode_vec(&client_state).map_err(|e| ClientError::Other {
+            description: e.to_string(),
+        })
+    }
 
-        QueryClientStatesResponse {
-            client_states,
-            pagination: None,
+    /// Returns the encoded proof of an existing `key` against the commit hash of the queried height
+    fn merkle_proof<DB: Database>(&self, ctx: &QueryContext<DB, SK>, key: &str) -> Vec<u8> {
+        RawMerkleProof {
+            proofs: ctx
+                .get_proof(&self.store_key, key.as_bytes())
+                .unwrap_or_corrupt()
+                .to_vec(),
         }
+        .encode_to_vec()
     }
 
     /// Writes the client state to the store
@@ -165,3 +419,414 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
         ctx.kv_store_mut(&self.store_key).prefix_store_mut(prefix)
     }
 }
+
+fn proof_height<DB: Database, SK: StoreKey>(ctx: &QueryContext<DB, SK>) -> RawHeight {
+    RawHeight {
+        revision_number: ctx.chain_id().revision_number(),
+        revision_height: ctx.height().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use gears::{
+        baseapp::{errors::QueryError, ConsensusParams},
+        derive::{ParamsKeys, StoreKeys},
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB, query::QueryMultiStore},
+        tendermint::types::time::timestamp::Timestamp as BlockTime,
+        utils::node::build_init_ctx,
+    };
+
+    use ibc::{
+        clients::tendermint::types::{
+            proto::v1::ConsensusState as RawTmConsensusState, AllowUpdate,
+            ClientState as ClientStateType, ConsensusState as ConsensusStateType, TrustThreshold,
+        },
+        core::{
+            commitment_types::{proto::v1::MerkleRoot, specs::ProofSpecs},
+            host::types::identifiers::ChainId,
+        },
+        primitives::{proto::Timestamp, ToVec},
+    };
+    use ics23::{
+        calculate_existence_root, commitment_proof::Proof, iavl_spec, tendermint_spec,
+        verify_membership, HostFunctionsManager,
+    };
+
+    use crate::ics02_client::types::consensus_state::ConsensusState;
+
+    use super::*;
+
+    #[test]
+    fn client_params_query() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let allowed_clients = vec!["06-solomachine".to_owned(), "07-tendermint".to_owned()];
+        keeper.client_params_keeper.set(
+            &mut ctx,
+            ClientParams {
+                allowed_clients: allowed_clients.clone(),
+            },
+        );
+
+        let res = keeper.client_params(&ctx);
+
+        assert_eq!(res.params.unwrap_test().allowed_clients, allowed_clients);
+    }
+
+    #[test]
+    fn client_params_query_defaults() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let res = keeper.client_params(&ctx);
+
+        assert_eq!(
+            res.params.unwrap_test().allowed_clients,
+            ClientParams::module_default().allowed_clients
+        );
+    }
+
+    #[test]
+    fn client_status_query() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let client_id = create_tm_client(&keeper, &mut multi_store);
+
+        let status = |seconds| {
+            keeper
+                .client_status(
+                    &query_ctx(&multi_store, seconds),
+                    QueryClientStatusRequest {
+                        client_id: client_id.to_string(),
+                    },
+                )
+                .unwrap_test()
+                .status
+        };
+
+        // the latest consensus state is from t = 1s and the trusting period is 1000s
+        assert_eq!(status(500), "Active");
+        assert_eq!(status(1002), "Expired");
+    }
+
+    #[test]
+    fn client_status_query_unauthorized() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let client_id = create_tm_client(&keeper, &mut multi_store);
+
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper.params_set(
+            &mut ctx,
+            ClientParams {
+                allowed_clients: vec!["06-solomachine".to_owned()],
+            },
+        );
+        multi_store.commit();
+
+        let res = keeper
+            .client_status(
+                &query_ctx(&multi_store, 500),
+                QueryClientStatusRequest {
+                    client_id: client_id.to_string(),
+                },
+            )
+            .unwrap_test();
+
+        assert_eq!(res.status, "Unauthorized");
+    }
+
+    #[test]
+    fn client_status_query_unknown_client() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        multi_store.commit();
+
+        let err = keeper
+            .client_status(
+                &query_ctx(&multi_store, 500),
+                QueryClientStatusRequest {
+                    client_id: "07-tendermint-0".to_owned(),
+                },
+            )
+            .expect_err("client doesn't exist");
+
+        assert!(matches!(
+            err,
+            StatusError::ClientError(ClientError::ClientStateNotFound { .. })
+        ));
+        assert!(matches!(QueryError::from(err), QueryError::NotFound(_)));
+    }
+
+    #[test]
+    fn client_state_query_proof() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let client_id = create_tm_client(&keeper, &mut multi_store);
+
+        let res = keeper
+            .client_state(
+                &query_ctx(&multi_store, 500),
+                QueryClientStateRequest {
+                    client_id: client_id.to_string(),
+                },
+            )
+            .unwrap_test();
+
+        let client_state = res.client_state.unwrap_test();
+        assert_eq!(client_state, ClientState::from(tm_client_state()).into());
+        assert_eq!(
+            res.proof_height.unwrap_test().revision_height,
+            u64::from(multi_store.head_version())
+        );
+
+        assert_proof(
+            &res.proof,
+            format!("clients/{client_id}/clientState").as_bytes(),
+            &client_state.to_vec(),
+            &multi_store.head_commit_hash(),
+        );
+    }
+
+    #[test]
+    fn consensus_state_query_proof() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let client_id = create_tm_client(&keeper, &mut multi_store);
+        let ctx = query_ctx(&multi_store, 500);
+
+        let request = |revision_height, latest_height| QueryConsensusStateRequest {
+            client_id: client_id.to_string(),
+            revision_number: 1,
+            revision_height,
+            latest_height,
+        };
+
+        // the client's latest height is 1-3
+        let res = keeper.consensus_state(&ctx, request(0, true)).unwrap_test();
+        assert_eq!(
+            res,
+            keeper
+                .consensus_state(&ctx, request(3, false))
+                .unwrap_test()
+        );
+
+        assert_proof(
+            &res.proof,
+            format!("clients/{client_id}/consensusStates/1-3").as_bytes(),
+            &res.consensus_state.unwrap_test().to_vec(),
+            &multi_store.head_commit_hash(),
+        );
+
+        let err = keeper
+            .consensus_state(&ctx, request(4, false))
+            .expect_err("there's no consensus state at 1-4");
+        assert!(matches!(QueryError::from(err), QueryError::NotFound(_)));
+    }
+
+    #[test]
+    fn consensus_states_query() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let client_id = create_tm_client(&keeper, &mut multi_store);
+
+        let res = keeper
+            .consensus_states(
+                &query_ctx(&multi_store, 500),
+                QueryConsensusStatesRequest {
+                    client_id: client_id.to_string(),
+                    pagination: None,
+                },
+            )
+            .unwrap_test();
+
+        assert_eq!(res.consensus_states.len(), 1);
+        assert_eq!(
+            res.consensus_states[0].height,
+            Some(Height::new(1, 3).unwrap_test().into())
+        );
+    }
+
+    #[test]
+    fn client_states_query_paginated() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        for i in 0..5 {
+            keeper
+                .client_state_set(
+                    &mut ctx,
+                    ClientStatePath::new(format!("07-tendermint-{i}").parse().unwrap_test()),
+                    tm_client_state().into(),
+                )
+                .unwrap_test();
+        }
+
+        let request = |key: Vec<u8>, limit: u64| {
+            let mut pagination = QueryClientStatesRequest::default()
+                .pagination
+                .unwrap_or_default();
+            pagination.key = key;
+            pagination.limit = limit;
+
+            QueryClientStatesRequest {
+                pagination: Some(pagination),
+            }
+        };
+        let client_ids = |res: &QueryClientStatesResponse| {
+            res.client_states
+                .iter()
+                .map(|state| state.client_id.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let res = keeper.client_states(&ctx, request(Vec::new(), 2));
+        assert_eq!(client_ids(&res), ["07-tendermint-0", "07-tendermint-1"]);
+
+        let next_key = res.pagination.unwrap_test().next_key;
+        assert!(!next_key.is_empty());
+
+        let res = keeper.client_states(&ctx, request(next_key, 2));
+        assert_eq!(client_ids(&res), ["07-tendermint-2", "07-tendermint-3"]);
+
+        let res = keeper.client_states(&ctx, request(Vec::new(), 10));
+        assert_eq!(res.client_states.len(), 5);
+        assert!(res.pagination.unwrap_test().next_key.is_empty());
+    }
+
+    /// Stores a tendermint client with a consensus state at its latest height and commits it
+    fn create_tm_client(
+        keeper: &Keeper<SubspaceKey, SubspaceKey>,
+        multi_store: &mut ApplicationMultiBank<MemDB, SubspaceKey>,
+    ) -> ClientId {
+        let mut ctx = build_init_ctx(multi_store, ConsensusParams::default());
+
+        let client_id: ClientId = "07-tendermint-0".parse().unwrap_test();
+        keeper
+            .client_state_set(
+                &mut ctx,
+                ClientStatePath::new(client_id.clone()),
+                tm_client_state().into(),
+            )
+            .unwrap_test();
+
+        let consensus_state = ConsensusStateType::try_from(RawTmConsensusState {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 0,
+            }),
+            root: Some(MerkleRoot { hash: vec![1; 32] }),
+            next_validators_hash: vec![1; 32],
+        })
+        .unwrap_test();
+        let any: Any = ConsensusState::from(consensus_state).into();
+        keeper
+            .client_store_mut(&mut ctx, &client_id)
+            .set(
+                format!("{KEY_CONSENSUS_STATE_PREFIX}/1-3").into_bytes(),
+                any.to_vec(),
+            )
+            .unwrap_test();
+
+        multi_store.commit();
+
+        client_id
+    }
+
+    /// Asserts that `proof` proves `key` and `value` in the IBC store against `commit_hash`
+    fn assert_proof(proof: &[u8], key: &[u8], value: &[u8], commit_hash: &[u8; 32]) {
+        let RawMerkleProof { proofs } = RawMerkleProof::decode(proof).unwrap_test();
+        let [store_proof, multi_store_proof] = proofs.as_slice() else {
+            panic!("expected a store and a multi store proof");
+        };
+
+        let Some(Proof::Exist(existence_proof)) = &store_proof.proof else {
+            panic!("expected an existence proof");
+        };
+        let store_root =
+            calculate_existence_root::<HostFunctionsManager>(existence_proof).unwrap_test();
+
+        assert!(verify_membership::<HostFunctionsManager>(
+            store_proof,
+            &iavl_spec(),
+            &store_root,
+            key,
+            value
+        ));
+        assert!(verify_membership::<HostFunctionsManager>(
+            multi_store_proof,
+            &tendermint_spec(),
+            &commit_hash.to_vec(),
+            b"ibc",
+            &store_root
+        ));
+    }
+
+    fn query_ctx(
+        multi_store: &ApplicationMultiBank<MemDB, SubspaceKey>,
+        seconds: i64,
+    ) -> QueryContext<MemDB, SubspaceKey> {
+        QueryContext::new(
+            QueryMultiStore::new(multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            gears::tendermint::types::chain_id::ChainId::default(),
+            Some(BlockTime::try_new(seconds, 0).unwrap_test()),
+        )
+        .unwrap_test()
+    }
+
+    fn tm_client_state() -> ClientStateType {
+        ClientStateType::new(
+            ChainId::new("test-1").unwrap_test(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(1000),
+            Duration::from_secs(2000),
+            Duration::from_secs(2000),
+            Height::new(1, 3).unwrap_test(),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap_test()
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
+    #[skey(params = Params)]
+    enum SubspaceKey {
+        #[skey(to_string = "ibc")]
+        #[pkey(to_string = "ibc")]
+        Ibc,
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
+}
@@ -8,12 +8,20 @@ use ibc::{
     core::{
         client::types::{
             error::ClientError,
-            msgs::{ClientMsg, MsgCreateClient as IBCMsgCreateClient},
-            proto::v1::MsgCreateClient as RawMsgCreateClient,
+            msgs::{
+                ClientMsg, MsgCreateClient as IBCMsgCreateClient,
+                MsgRecoverClient as IBCMsgRecoverClient, MsgUpdateClient as IBCMsgUpdateClient,
+                MsgUpgradeClient as IBCMsgUpgradeClient,
+            },
+            proto::v1::{
+                MsgCreateClient as RawMsgCreateClient, MsgRecoverClient as RawMsgRecoverClient,
+                MsgUpdateClient as RawMsgUpdateClient, MsgUpgradeClient as RawMsgUpgradeClient,
+            },
         },
         handler::types::msgs::MsgEnvelope,
+        host::types::identifiers::ClientId,
     },
-    primitives::proto::Protobuf,
+    primitives::proto::{Any as IbcAny, Protobuf},
 };
 use serde::Serialize;
 
@@ -25,6 +33,8 @@ pub struct MsgCreateClient {
 }
 
 impl MsgCreateClient {
+    pub const TYPE_URL: &'static str = "/ibc.core.client.v1.MsgCreateClient";
+
     pub fn new(
         client_state: ClientState,
         consensus_state: ConsensusState,
@@ -53,7 +63,7 @@ impl TxMessage for MsgCreateClient {
     }
 
     fn type_url(&self) -> &'static str {
-        "/ibc.core.client.v1.MsgCreateClient"
+        Self::TYPE_URL
     }
 }
 
@@ -71,7 +81,7 @@ impl TryFrom<Any> for MsgCreateClient {
 
     fn try_from(value: Any) -> Result<Self, Self::Error> {
         match value.type_url.as_str() {
-            "/ibc.core.client.v1.MsgCreateClient" => {
+            MsgCreateClient::TYPE_URL => {
                 MsgCreateClient::decode::<Bytes>(value.value.clone().into())
                     .map_err(|e| gears::core::errors::CoreError::DecodeProtobuf(e.to_string()))
             }
@@ -94,15 +104,10 @@ impl TryFrom<RawMsgCreateClient> for MsgCreateClient {
             .consensus_state
             .ok_or(ClientError::MissingRawConsensusState)?;
 
-        let signer =
-            AccAddress::from_bech32(&raw.signer).map_err(|e| ClientError::InvalidSigner {
-                reason: e.to_string(),
-            })?;
-
         Ok(MsgCreateClient::new(
             raw_client_state.try_into()?,
             raw_consensus_state.try_into()?,
-            signer,
+            parse_signer(&raw.signer)?,
         ))
     }
 }
@@ -116,3 +121,283 @@ impl From<MsgCreateClient> for RawMsgCreateClient {
         }
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MsgUpdateClient {
+    pub client_id: ClientId,
+    pub client_message: IbcAny,
+    pub signer: AccAddress,
+}
+
+impl MsgUpdateClient {
+    pub const TYPE_URL: &'static str = "/ibc.core.client.v1.MsgUpdateClient";
+}
+
+impl From<MsgUpdateClient> for MsgEnvelope {
+    fn from(msg: MsgUpdateClient) -> Self {
+        let raw_msg = RawMsgUpdateClient::from(msg);
+        let msg = IBCMsgUpdateClient::try_from(raw_msg).unwrap();
+        MsgEnvelope::Client(ClientMsg::UpdateClient(msg))
+    }
+}
+
+impl TxMessage for MsgUpdateClient {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.signer]
+    }
+
+    fn type_url(&self) -> &'static str {
+        Self::TYPE_URL
+    }
+}
+
+impl From<MsgUpdateClient> for Any {
+    fn from(msg: MsgUpdateClient) -> Self {
+        Any {
+            type_url: msg.type_url().to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgUpdateClient {
+    type Error = gears::core::errors::CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        match value.type_url.as_str() {
+            MsgUpdateClient::TYPE_URL => {
+                MsgUpdateClient::decode::<Bytes>(value.value.clone().into())
+                    .map_err(|e| gears::core::errors::CoreError::DecodeProtobuf(e.to_string()))
+            }
+            _ => Err(gears::core::errors::CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            )),
+        }
+    }
+}
+
+impl Protobuf<RawMsgUpdateClient> for MsgUpdateClient {}
+
+impl TryFrom<RawMsgUpdateClient> for MsgUpdateClient {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgUpdateClient) -> Result<Self, Self::Error> {
+        Ok(MsgUpdateClient {
+            client_id: parse_client_id(&raw.client_id)?,
+            client_message: raw.client_message.ok_or(ClientError::Other {
+                description: "missing client message".into(),
+            })?,
+            signer: parse_signer(&raw.signer)?,
+        })
+    }
+}
+
+impl From<MsgUpdateClient> for RawMsgUpdateClient {
+    fn from(msg: MsgUpdateClient) -> Self {
+        RawMsgUpdateClient {
+            client_id: msg.client_id.to_string(),
+            client_message: Some(msg.client_message),
+            signer: msg.signer.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MsgUpgradeClient {
+    pub client_id: ClientId,
+    pub upgraded_client_state: IbcAny,
+    pub upgraded_consensus_state: IbcAny,
+    pub proof_upgrade_client: Vec<u8>,
+    pub proof_upgrade_consensus_state: Vec<u8>,
+    pub signer: AccAddress,
+}
+
+impl MsgUpgradeClient {
+    pub const TYPE_URL: &'static str = "/ibc.core.client.v1.MsgUpgradeClient";
+}
+
+impl From<MsgUpgradeClient> for MsgEnvelope {
+    fn from(msg: MsgUpgradeClient) -> Self {
+        let raw_msg = RawMsgUpgradeClient::from(msg);
+        let msg = IBCMsgUpgradeClient::try_from(raw_msg).unwrap();
+        MsgEnvelope::Client(ClientMsg::UpgradeClient(msg))
+    }
+}
+
+impl TxMessage for MsgUpgradeClient {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.signer]
+    }
+
+    fn type_url(&self) -> &'static str {
+        Self::TYPE_URL
+    }
+}
+
+impl From<MsgUpgradeClient> for Any {
+    fn from(msg: MsgUpgradeClient) -> Self {
+        Any {
+            type_url: msg.type_url().to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgUpgradeClient {
+    type Error = gears::core::errors::CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        match value.type_url.as_str() {
+            MsgUpgradeClient::TYPE_URL => {
+                MsgUpgradeClient::decode::<Bytes>(value.value.clone().into())
+                    .map_err(|e| gears::core::errors::CoreError::DecodeProtobuf(e.to_string()))
+            }
+            _ => Err(gears::core::errors::CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            )),
+        }
+    }
+}
+
+impl Protobuf<RawMsgUpgradeClient> for MsgUpgradeClient {}
+
+impl TryFrom<RawMsgUpgradeClient> for MsgUpgradeClient {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgUpgradeClient) -> Result<Self, Self::Error> {
+        if raw.proof_upgrade_client.is_empty() {
+            return Err(ClientError::Other {
+                description: "empty upgrade client proof".into(),
+            });
+        }
+
+        if raw.proof_upgrade_consensus_state.is_empty() {
+            return Err(ClientError::Other {
+                description: "empty upgrade consensus state proof".into(),
+            });
+        }
+
+        Ok(MsgUpgradeClient {
+            client_id: parse_client_id(&raw.client_id)?,
+            upgraded_client_state: raw.client_state.ok_or(ClientError::MissingRawClientState)?,
+            upgraded_consensus_state: raw
+                .consensus_state
+                .ok_or(ClientError::MissingRawConsensusState)?,
+            proof_upgrade_client: raw.proof_upgrade_client,
+            proof_upgrade_consensus_state: raw.proof_upgrade_consensus_state,
+            signer: parse_signer(&raw.signer)?,
+        })
+    }
+}
+
+impl From<MsgUpgradeClient> for RawMsgUpgradeClient {
+    fn from(msg: MsgUpgradeClient) -> Self {
+        RawMsgUpgradeClient {
+            client_id: msg.client_id.to_string(),
+            client_state: Some(msg.upgraded_client_state),
+            consensus_state: Some(msg.upgraded_consensus_state),
+            proof_upgrade_client: msg.proof_upgrade_client,
+            proof_upgrade_consensus_state: msg.proof_upgrade_consensus_state,
+            signer: msg.signer.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct MsgRecoverClient {
+    pub subject_client_id: ClientId,
+    pub substitute_client_id: ClientId,
+    pub signer: AccAddress,
+}
+
+impl MsgRecoverClient {
+    pub const TYPE_URL: &'static str = "/ibc.core.client.v1.MsgRecoverClient";
+}
+
+impl From<MsgRecoverClient> for MsgEnvelope {
+    fn from(msg: MsgRecoverClient) -> Self {
+        let raw_msg = RawMsgRecoverClient::from(msg);
+        let msg = IBCMsgRecoverClient::try_from(raw_msg).unwrap();
+        MsgEnvelope::Client(ClientMsg::RecoverClient(msg))
+    }
+}
+
+impl TxMessage for MsgRecoverClient {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.signer]
+    }
+
+    fn type_url(&self) -> &'static str {
+        Self::TYPE_URL
+    }
+}
+
+impl From<MsgRecoverClient> for Any {
+    fn from(msg: MsgRecoverClient) -> Self {
+        Any {
+            type_url: msg.type_url().to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgRecoverClient {
+    type Error = gears::core::errors::CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        match value.type_url.as_str() {
+            MsgRecoverClient::TYPE_URL => {
+                MsgRecoverClient::decode::<Bytes>(value.value.clone().into())
+                    .map_err(|e| gears::core::errors::CoreError::DecodeProtobuf(e.to_string()))
+            }
+            _ => Err(gears::core::errors::CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            )),
+        }
+    }
+}
+
+impl Protobuf<RawMsgRecoverClient> for MsgRecoverClient {}
+
+impl TryFrom<RawMsgRecoverClient> for MsgRecoverClient {
+    type Error = ClientError;
+
+    fn try_from(raw: RawMsgRecoverClient) -> Result<Self, Self::Error> {
+        let subject_client_id = parse_client_id(&raw.subject_client_id)?;
+        let substitute_client_id = parse_client_id(&raw.substitute_client_id)?;
+
+        if subject_client_id == substitute_client_id {
+            return Err(ClientError::Other {
+                description: "subject and substitute client identifiers must differ".into(),
+            });
+        }
+
+        Ok(MsgRecoverClient {
+            subject_client_id,
+            substitute_client_id,
+            signer: parse_signer(&raw.signer)?,
+        })
+    }
+}
+
+impl From<MsgRecoverClient> for RawMsgRecoverClient {
+    fn from(msg: MsgRecoverClient) -> Self {
+        RawMsgRecoverClient {
+            subject_client_id: msg.subject_client_id.to_string(),
+            substitute_client_id: msg.substitute_client_id.to_string(),
+            signer: msg.signer.to_string(),
+        }
+    }
+}
+
+fn parse_client_id(client_id: &str) -> Result<ClientId, ClientError> {
+    client_id.parse().map_err(|e| ClientError::Other {
+        description: format!("invalid client id: {e}"),
+    })
+}
+
+fn parse_signer(signer: &str) -> Result<AccAddress, ClientError> {
+    AccAddress::from_bech32(signer).map_err(|e| ClientError::InvalidSigner {
+        reason: e.to_string(),
+    })
+}
@@ -8,4 +8,5 @@ pub mod types;
 pub use genesis::GenesisState;
 pub use keeper::Keeper;
 pub use keeper::KEY_NEXT_CLIENT_SEQUENCE; //TODO: don't export when we have a better solution
+pub use params::ClientParams;
 pub use params::ClientParamsKeeper; //TODO: don't export when we have a better solution
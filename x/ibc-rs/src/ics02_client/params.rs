@@ -18,6 +18,8 @@ use gears::{
         StoreKey,
     },
 };
+use ibc::core::client::types::proto::v1::Params as RawClientParams;
+use ibc::core::host::types::identifiers::ClientType;
 use serde::{Deserialize, Serialize};
 
 const KEY_ALLOWED_CLIENTS: &str = "AllowedClients";
@@ -33,6 +35,28 @@ pub struct ClientParams {
     pub allowed_clients: Vec<String>,
 }
 
+impl ClientParams {
+    /// Params used by the module when none are stored, matches the ibc-go defaults
+    pub fn module_default() -> Self {
+        Self {
+            allowed_clients: vec!["06-solomachine".into(), "07-tendermint".into()],
+        }
+    }
+
+    /// Checks if the client type is in the allowlist
+    pub fn is_client_allowed(&self, client_type: &ClientType) -> bool {
+        self.allowed_clients
+            .iter()
+            .any(|allowed| allowed == client_type.as_str())
+    }
+}
+
+impl From<ClientParams> for RawClientParams {
+    fn from(ClientParams { allowed_clients }: ClientParams) -> Self {
+        Self { allowed_clients }
+    }
+}
+
 impl ParamsSerialize for ClientParams {
     fn keys() -> HashSet<&'static str> {
         [KEY_ALLOWED_CLIENTS].into_iter().collect()
@@ -73,7 +97,7 @@ impl<PSK: ParamsSubspaceKey> ClientParamsKeeper<PSK> {
     ) -> ClientParams {
         let store = infallible_subspace(ctx, &self.params_subspace_key);
 
-        store.params().unwrap_or_default()
+        store.params().unwrap_or_else(ClientParams::module_default)
     }
 
     pub fn set<DB: Database, SK: StoreKey, CTX: InfallibleContextMut<DB, SK>>(
@@ -92,7 +116,7 @@ impl<PSK: ParamsSubspaceKey> ClientParamsKeeper<PSK> {
     ) -> Result<ClientParams, GasStoreErrors> {
         let store = gas::subspace(ctx, &self.params_subspace_key);
 
-        Ok(store.params()?.unwrap_or_default())
+        Ok(store.params()?.unwrap_or_else(ClientParams::module_default))
     }
 
     pub fn try_set<DB: Database, SK: StoreKey, CTX: TransactionalContext<DB, SK>>(
@@ -1,7 +1,9 @@
 use gears::{core::errors::CoreError, error::ProtobufError};
 use ibc::{
     core::{
-        client::types::proto::v1::IdentifiedClientState as RawIdentifiedClientState,
+        client::types::proto::v1::{
+            ConsensusStateWithHeight, IdentifiedClientState as RawIdentifiedClientState,
+        },
         host::types::identifiers::ClientId,
     },
     primitives::proto::Protobuf,
@@ -128,3 +130,14 @@ pub(crate) struct RawQueryClientStatesResponse {
     #[prost(message, optional, tag = "2")]
     pagination: Option<PageResponse>,
 }
+
+/// We implement this ourselves because the IBC crate doesn't export PageResponse.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryConsensusStatesResponse {
+    /// consensus states associated with the identifier
+    #[prost(message, repeated, tag = "1")]
+    pub consensus_states: Vec<ConsensusStateWithHeight>,
+    /// pagination response
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
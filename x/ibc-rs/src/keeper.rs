@@ -1,12 +1,19 @@
 use gears::{
-    context::{init::InitContext, query::QueryContext, tx::TxContext},
+    context::{init::InitContext, query::QueryContext, tx::TxContext, InfallibleContextMut},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
+    types::address::AccAddress,
 };
 
 use crate::{
+    errors::{
+        query::client::{ConsensusStateError, ConsensusStatesError, StateError, StatusError},
+        tx::client::{ClientCreateError, ClientRecoverError},
+    },
     ics02_client::{
-        message::MsgCreateClient, types::query::QueryClientStatesResponse, Keeper as ClientKeeper,
+        message::{MsgCreateClient, MsgRecoverClient, MsgUpdateClient, MsgUpgradeClient},
+        types::query::{QueryClientStatesResponse, QueryConsensusStatesResponse},
+        ClientParams, Keeper as ClientKeeper,
     },
     ics03_connection::Keeper as ConnectionKeeper,
     ics04_channel::Keeper as ChannelKeeper,
@@ -15,7 +22,16 @@ use crate::{
         genesis::GenesisState,
     },
 };
-use ibc::core::{client::types::proto::v1::QueryClientStatesRequest, entrypoint::dispatch};
+use ibc::core::{
+    client::context::client_state::ClientStateCommon,
+    client::types::proto::v1::{
+        QueryClientParamsResponse, QueryClientStateRequest, QueryClientStateResponse,
+        QueryClientStatesRequest, QueryClientStatusRequest, QueryClientStatusResponse,
+        QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+    },
+    entrypoint::dispatch,
+    handler::types::msgs::MsgEnvelope,
+};
 
 #[derive(Debug, Clone)]
 pub struct Keeper<SK, PSK> {
@@ -23,15 +39,18 @@ pub struct Keeper<SK, PSK> {
     client_keeper: ClientKeeper<SK, PSK>,
     connection_keeper: ConnectionKeeper<SK, PSK>,
     channel_keeper: ChannelKeeper<SK>,
+    /// Address allowed to recover clients, the governance module account in the cosmos sdk
+    authority: AccAddress,
 }
 
 impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
-    pub fn new(store_key: SK, params_subspace_key: PSK) -> Self {
+    pub fn new(store_key: SK, params_subspace_key: PSK, authority: AccAddress) -> Self {
         Self {
             _store_key: store_key.clone(),
             client_keeper: ClientKeeper::new(store_key.clone(), params_subspace_key.clone()),
             connection_keeper: ConnectionKeeper::new(store_key.clone(), params_subspace_key),
             channel_keeper: ChannelKeeper::new(store_key),
+            authority,
         }
     }
 
@@ -47,10 +66,60 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
             .init_genesis(ctx, genesis.channel_genesis);
     }
 
+    /// Exports the IBC state as a genesis state, connections and channels aren't exported yet.
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        GenesisState {
+            client_genesis: self.client_keeper.export_genesis(ctx),
+            ..Default::default()
+        }
+    }
+
     pub fn client_create<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
         msg: MsgCreateClient,
+    ) -> Result<(), ClientCreateError> {
+        self.client_keeper
+            .client_type_check(ctx, msg.client_state.client_type())?;
+
+        Ok(self.dispatch(ctx, msg)?)
+    }
+
+    pub fn client_update<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: MsgUpdateClient,
+    ) -> Result<(), ibc::core::handler::types::error::ContextError> {
+        self.dispatch(ctx, msg)
+    }
+
+    pub fn client_upgrade<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: MsgUpgradeClient,
+    ) -> Result<(), ibc::core::handler::types::error::ContextError> {
+        self.dispatch(ctx, msg)
+    }
+
+    pub fn client_recover<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: MsgRecoverClient,
+    ) -> Result<(), ClientRecoverError> {
+        if msg.signer != self.authority {
+            return Err(ClientRecoverError::Unauthorized {
+                expected: self.authority.clone(),
+                signer: msg.signer,
+            });
+        }
+
+        Ok(self.dispatch(ctx, msg)?)
+    }
+
+    fn dispatch<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: impl Into<MsgEnvelope>,
     ) -> Result<(), ibc::core::handler::types::error::ContextError> {
         let mut ctx = Context {
             gears_ctx: ctx,
@@ -62,9 +131,33 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
 
         let mut router = ClientRouter;
 
+        // events of a failed message are discarded with the rest of the transaction
         dispatch(&mut ctx, &mut router, msg.into())
     }
 
+    pub fn params_set<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        params: ClientParams,
+    ) {
+        self.client_keeper.params_set(ctx, params)
+    }
+
+    pub fn client_params<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+    ) -> QueryClientParamsResponse {
+        self.client_keeper.client_params(ctx)
+    }
+
+    pub fn client_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        req: QueryClientStateRequest,
+    ) -> Result<QueryClientStateResponse, StateError> {
+        self.client_keeper.client_state(ctx, req)
+    }
+
     pub fn client_states<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -72,4 +165,250 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey> Keeper<SK, PSK> {
     ) -> QueryClientStatesResponse {
         self.client_keeper.client_states(ctx, req)
     }
+
+    pub fn client_status<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        req: QueryClientStatusRequest,
+    ) -> Result<QueryClientStatusResponse, StatusError> {
+        self.client_keeper.client_status(ctx, req)
+    }
+
+    pub fn consensus_state<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        req: QueryConsensusStateRequest,
+    ) -> Result<QueryConsensusStateResponse, ConsensusStateError> {
+        self.client_keeper.consensus_state(ctx, req)
+    }
+
+    pub fn consensus_states<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        req: QueryConsensusStatesRequest,
+    ) -> Result<QueryConsensusStatesResponse, ConsensusStatesError> {
+        self.client_keeper.consensus_states(ctx, req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        context::TransactionalContext,
+        derive::{ParamsKeys, StoreKeys},
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::header::Header,
+        types::{address::AccAddress, gas::GasMeter},
+        utils::node::build_init_ctx,
+    };
+    use ibc::{
+        clients::tendermint::{
+            client_state::ClientState as TmClientState,
+            consensus_state::ConsensusState as TmConsensusState,
+            types::{
+                proto::v1::ConsensusState as RawTmConsensusState, AllowUpdate,
+                ClientState as ClientStateType, ConsensusState as ConsensusStateType,
+                TrustThreshold,
+            },
+        },
+        core::{
+            client::types::Height,
+            commitment_types::{proto::v1::MerkleRoot, specs::ProofSpecs},
+            host::types::identifiers::ChainId,
+        },
+        primitives::proto::Timestamp,
+    };
+
+    use crate::{
+        ics02_client::KEY_NEXT_CLIENT_SEQUENCE,
+        types::context::{CLIENT_STATE_KEY, KEY_CLIENT_STORE_PREFIX},
+    };
+
+    use super::*;
+
+    #[test]
+    fn failed_client_create_emits_no_events() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc, authority());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut init_ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper
+            .client_keeper
+            .set_next_client_sequence(&mut init_ctx, 0);
+        multi_store.commit();
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let msg = msg_create_client(Some(Height::new(1, 2).unwrap_test()));
+
+        keeper
+            .client_create(&mut ctx, msg)
+            .expect_err("frozen clients can't be created");
+
+        assert!(ctx
+            .events()
+            .iter()
+            .all(|event| event.r#type != "create_client"));
+    }
+
+    #[test]
+    fn client_create_disallowed_client_type() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc, authority());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut init_ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper
+            .client_keeper
+            .set_next_client_sequence(&mut init_ctx, 0);
+        keeper.params_set(
+            &mut init_ctx,
+            ClientParams {
+                allowed_clients: vec!["06-solomachine".to_owned()],
+            },
+        );
+        multi_store.commit();
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let err = keeper
+            .client_create(&mut ctx, msg_create_client(None))
+            .expect_err("tendermint clients aren't allowed");
+
+        assert!(
+            matches!(err, ClientCreateError::NotAllowed(client_type) if client_type.as_str() == "07-tendermint")
+        );
+
+        let store = ctx.kv_store(&SubspaceKey::Ibc);
+        assert_eq!(
+            store.get(KEY_NEXT_CLIENT_SEQUENCE).unwrap_test(),
+            Some(0_u64.to_be_bytes().to_vec())
+        );
+        assert_eq!(
+            store
+                .get(
+                    format!("{KEY_CLIENT_STORE_PREFIX}/07-tendermint-0/{CLIENT_STATE_KEY}")
+                        .as_bytes()
+                )
+                .unwrap_test(),
+            None
+        );
+        assert!(ctx.events().is_empty());
+    }
+
+    #[test]
+    fn client_recover_rejects_non_authority() {
+        let keeper = Keeper::new(SubspaceKey::Ibc, SubspaceKey::Ibc, authority());
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            1,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let signer =
+            AccAddress::from_bech32("cosmos15qzm75pjh0jqsv3u40hzp2vzs2hdp47fkz7j5q").unwrap_test();
+        let msg = MsgRecoverClient {
+            subject_client_id: "07-tendermint-0".parse().unwrap_test(),
+            substitute_client_id: "07-tendermint-1".parse().unwrap_test(),
+            signer: signer.clone(),
+        };
+
+        let err = keeper
+            .client_recover(&mut ctx, msg)
+            .expect_err("only the authority can recover clients");
+
+        assert!(matches!(
+            err,
+            ClientRecoverError::Unauthorized { expected, signer: got }
+                if expected == authority() && got == signer
+        ));
+        assert!(ctx.events().is_empty());
+    }
+
+    fn authority() -> AccAddress {
+        AccAddress::from_bech32("cosmos10d07y265gmmuvt4z0w9aw880jnsr700j6zn9kn").unwrap_test()
+    }
+
+    /// Builds a message creating a tendermint client, frozen at `frozen_height` if set
+    fn msg_create_client(frozen_height: Option<Height>) -> MsgCreateClient {
+        let client_state = ClientStateType::new(
+            ChainId::new("test-1").unwrap_test(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(1000),
+            Duration::from_secs(2000),
+            Duration::from_secs(2000),
+            Height::new(1, 3).unwrap_test(),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap_test();
+        let client_state = match frozen_height {
+            Some(height) => client_state.with_frozen_height(height),
+            None => client_state,
+        };
+        let consensus_state = ConsensusStateType::try_from(RawTmConsensusState {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 0,
+            }),
+            root: Some(MerkleRoot { hash: vec![1; 32] }),
+            next_validators_hash: vec![1; 32],
+        })
+        .unwrap_test();
+
+        MsgCreateClient::new(
+            TmClientState::from(client_state),
+            TmConsensusState::from(consensus_state),
+            AccAddress::from_bech32("cosmos15qzm75pjh0jqsv3u40hzp2vzs2hdp47fkz7j5q").unwrap_test(),
+        )
+    }
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
+    #[skey(params = Params)]
+    enum SubspaceKey {
+        #[skey(to_string = "ibc")]
+        #[pkey(to_string = "ibc")]
+        Ibc,
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "params")]
+        Params,
+    }
 }
@@ -1,12 +1,156 @@
 use gears::derive::AppMessage;
 
-use crate::ics02_client::message::MsgCreateClient;
+use crate::ics02_client::message::{
+    MsgCreateClient, MsgRecoverClient, MsgUpdateClient, MsgUpgradeClient,
+};
 
-#[derive(Debug, Clone, serde::Serialize, AppMessage)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, AppMessage)]
 pub enum Message {
-    #[msg(url(string = "/ibc.core.client.v1"))]
+    #[msg(url(path = MsgCreateClient::TYPE_URL))]
     ClientCreate(MsgCreateClient),
-    // ClientUpdate(MsgUpdateClient),
-    // ClientUpgrade(MsgUpgradeClient),
-    // RecoverClient(MsgRecoverClient),
+    #[msg(url(path = MsgUpdateClient::TYPE_URL))]
+    ClientUpdate(MsgUpdateClient),
+    #[msg(url(path = MsgUpgradeClient::TYPE_URL))]
+    ClientUpgrade(MsgUpgradeClient),
+    #[msg(url(path = MsgRecoverClient::TYPE_URL))]
+    RecoverClient(MsgRecoverClient),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use gears::{
+        core::any::google::Any,
+        extensions::testing::UnwrapTesting,
+        types::{address::AccAddress, tx::TxMessage},
+    };
+    use ibc::{
+        clients::tendermint::types::{
+            proto::v1::ConsensusState as RawTmConsensusState, AllowUpdate,
+            ClientState as ClientStateType, ConsensusState as ConsensusStateType, TrustThreshold,
+        },
+        core::{
+            client::types::Height,
+            commitment_types::{proto::v1::MerkleRoot, specs::ProofSpecs},
+            host::types::identifiers::{ChainId, ClientId},
+        },
+        primitives::proto::{Any as IbcAny, Timestamp},
+    };
+
+    use super::*;
+
+    fn signer() -> AccAddress {
+        "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux"
+            .parse()
+            .unwrap_test()
+    }
+
+    fn client_id(id: &str) -> ClientId {
+        id.parse().unwrap_test()
+    }
+
+    fn client_state() -> ClientStateType {
+        ClientStateType::new(
+            ChainId::new("test-1").unwrap_test(),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(1000),
+            Duration::from_secs(2000),
+            Duration::from_secs(2000),
+            Height::new(1, 3).unwrap_test(),
+            ProofSpecs::cosmos(),
+            Vec::new(),
+            AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+        )
+        .unwrap_test()
+    }
+
+    fn consensus_state() -> ConsensusStateType {
+        ConsensusStateType::try_from(RawTmConsensusState {
+            timestamp: Some(Timestamp {
+                seconds: 1,
+                nanos: 0,
+            }),
+            root: Some(MerkleRoot { hash: vec![1; 32] }),
+            next_validators_hash: vec![1; 32],
+        })
+        .unwrap_test()
+    }
+
+    fn round_trip(msg: Message, type_url: &str) {
+        let any = Any::from(msg.clone());
+        assert_eq!(any.type_url, type_url);
+
+        let decoded = Message::try_from(any).unwrap_test();
+        assert_eq!(decoded, msg);
+        assert_eq!(decoded.get_signers(), vec![&signer()]);
+    }
+
+    #[test]
+    fn client_create_round_trip() {
+        round_trip(
+            Message::ClientCreate(MsgCreateClient::new(
+                client_state().into(),
+                consensus_state().into(),
+                signer(),
+            )),
+            "/ibc.core.client.v1.MsgCreateClient",
+        );
+    }
+
+    #[test]
+    fn client_update_round_trip() {
+        round_trip(
+            Message::ClientUpdate(MsgUpdateClient {
+                client_id: client_id("07-tendermint-0"),
+                client_message: IbcAny {
+                    type_url: "/ibc.lightclients.tendermint.v1.Header".to_owned(),
+                    value: vec![1, 2, 3],
+                },
+                signer: signer(),
+            }),
+            "/ibc.core.client.v1.MsgUpdateClient",
+        );
+    }
+
+    #[test]
+    fn client_upgrade_round_trip() {
+        round_trip(
+            Message::ClientUpgrade(MsgUpgradeClient {
+                client_id: client_id("07-tendermint-0"),
+                upgraded_client_state: client_state().into(),
+                upgraded_consensus_state: consensus_state().into(),
+                proof_upgrade_client: vec![1],
+                proof_upgrade_consensus_state: vec![2],
+                signer: signer(),
+            }),
+            "/ibc.core.client.v1.MsgUpgradeClient",
+        );
+    }
+
+    #[test]
+    fn recover_client_round_trip() {
+        round_trip(
+            Message::RecoverClient(MsgRecoverClient {
+                subject_client_id: client_id("07-tendermint-0"),
+                substitute_client_id: client_id("07-tendermint-1"),
+                signer: signer(),
+            }),
+            "/ibc.core.client.v1.MsgRecoverClient",
+        );
+    }
+
+    #[test]
+    fn recover_client_with_same_clients_rejected() {
+        let any = Any::from(Message::RecoverClient(MsgRecoverClient {
+            subject_client_id: client_id("07-tendermint-0"),
+            substitute_client_id: client_id("07-tendermint-0"),
+            signer: signer(),
+        }));
+
+        assert!(Message::try_from(any).is_err());
+    }
 }
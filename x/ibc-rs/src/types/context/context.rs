@@ -57,7 +57,7 @@ pub const KEY_CONSENSUS_STATE_PREFIX: &str = "consensusStates";
 pub const KEY_PROCESSED_TIME: &str = "/processedTime";
 
 // KeyProcessedHeight is appended to consensus state key to store the processed height
-const KEY_PROCESSED_HEIGHT: &str = "/processedHeight";
+pub const KEY_PROCESSED_HEIGHT: &str = "/processedHeight";
 const KEY_ITERATE_CONSENSUS_STATE_PREFIX: &[u8; 22] = b"iterateConsensusStates";
 
 pub struct Context<'a, 'b, DB, SK, PSK> {
@@ -1,3 +1,5 @@
 mod context;
+mod query;
 
 pub use context::*;
+pub use query::*;
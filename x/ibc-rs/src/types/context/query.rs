@@ -0,0 +1,209 @@
+use std::str::FromStr;
+
+use gears::context::{query::QueryContext, QueryableContext};
+use gears::store::database::{prefix::PrefixDB, Database};
+use gears::store::store::prefix::immutable::ImmutablePrefixStore;
+use gears::store::StoreKey;
+use ibc::clients::tendermint::client_state::ClientState as TmClientState;
+use ibc::core::client::context::{ClientValidationContext, ExtClientValidationContext};
+use ibc::core::client::types::error::ClientError;
+use ibc::core::client::types::Height;
+use ibc::core::handler::types::error::ContextError;
+use ibc::core::host::types::identifiers::ClientId;
+use ibc::core::host::types::path::ClientConsensusStatePath;
+use ibc::primitives::proto::{Any, Protobuf};
+use ibc::primitives::Timestamp;
+use prost::Message;
+
+use crate::ics02_client::types::{client_state::ClientState, consensus_state::ConsensusState};
+
+use super::{
+    CLIENT_STATE_KEY, KEY_CLIENT_STORE_PREFIX, KEY_CONSENSUS_STATE_PREFIX, KEY_PROCESSED_HEIGHT,
+    KEY_PROCESSED_TIME,
+};
+
+/// Read only IBC context over committed state. It lets light clients evaluate their status
+/// (e.g. expiry against the block time) when serving queries.
+pub struct QueryClientContext<'a, DB, SK> {
+    gears_ctx: &'a QueryContext<DB, SK>,
+    store_key: SK,
+}
+
+impl<'a, DB: Database, SK: StoreKey> QueryClientContext<'a, DB, SK> {
+    pub fn new(gears_ctx: &'a QueryContext<DB, SK>, store_key: SK) -> Self {
+        Self {
+            gears_ctx,
+            store_key,
+        }
+    }
+
+    fn client_store(&self, client_id: &ClientId) -> ImmutablePrefixStore<'a, PrefixDB<DB>> {
+        self.gears_ctx
+            .kv_store(&self.store_key)
+            .prefix_store(format!("{KEY_CLIENT_STORE_PREFIX}/{client_id}/").into_bytes())
+    }
+
+    fn consensus_state_at(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<ConsensusState, ContextError> {
+        let raw = self
+            .client_store(client_id)
+            .get(
+                format!(
+                    "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+                    height.revision_number(),
+                    height.revision_height()
+                )
+                .as_bytes(),
+            )
+            .ok_or_else(|| ClientError::ConsensusStateNotFound {
+                client_id: client_id.clone(),
+                height: *height,
+            })?;
+
+        let any = Any::decode(raw.as_slice()).map_err(other)?;
+
+        Ok(ConsensusState::try_from(any)?)
+    }
+}
+
+impl<'a, DB: Database, SK: StoreKey> ClientValidationContext for QueryClientContext<'a, DB, SK> {
+    type ClientStateRef = TmClientState;
+    type ConsensusStateRef = ConsensusState;
+
+    fn client_state(&self, client_id: &ClientId) -> Result<Self::ClientStateRef, ContextError> {
+        let raw = self
+            .client_store(client_id)
+            .get(CLIENT_STATE_KEY.as_bytes())
+            .ok_or_else(|| ClientError::ClientStateNotFound {
+                client_id: client_id.clone(),
+            })?;
+
+        let ClientState::Tendermint(client_state) = ClientState::decode_vec(&raw).map_err(other)?;
+
+        Ok(client_state)
+    }
+
+    fn consensus_state(
+        &self,
+        client_cons_state_path: &ClientConsensusStatePath,
+    ) -> Result<Self::ConsensusStateRef, ContextError> {
+        let height = Height::new(
+            client_cons_state_path.revision_number,
+            client_cons_state_path.revision_height,
+        )
+        .map_err(other)?;
+
+        self.consensus_state_at(&client_cons_state_path.client_id, &height)
+    }
+
+    fn client_update_meta(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<(Timestamp, Height), ContextError> {
+        let store = self.client_store(client_id);
+        let key = format!(
+            "{KEY_CONSENSUS_STATE_PREFIX}/{}-{}",
+            height.revision_number(),
+            height.revision_height()
+        );
+        let not_found = || {
+            other(format!(
+                "update metadata for client {client_id} at height {height} not found"
+            ))
+        };
+
+        let processed_time = store
+            .get(format!("{key}{KEY_PROCESSED_TIME}").as_bytes())
+            .ok_or_else(not_found)?;
+        let processed_time = u64::from_be_bytes(
+            processed_time
+                .try_into()
+                .map_err(|_| other(format!("invalid processed time for client {client_id}")))?,
+        );
+
+        let processed_height = store
+            .get(format!("{key}{KEY_PROCESSED_HEIGHT}").as_bytes())
+            .ok_or_else(not_found)?;
+        let processed_height = String::from_utf8(processed_height).map_err(other)?;
+
+        Ok((
+            Timestamp::from_nanoseconds(processed_time).map_err(other)?,
+            Height::from_str(&processed_height).map_err(other)?,
+        ))
+    }
+}
+
+impl<'a, DB: Database, SK: StoreKey> ExtClientValidationContext for QueryClientContext<'a, DB, SK> {
+    fn host_timestamp(&self) -> Result<Timestamp, ContextError> {
+        let time = self
+            .gears_ctx
+            .get_time()
+            .ok_or_else(|| other("host timestamp is only known for the latest height"))?;
+        let nanoseconds = i128::from(time.timestamp_nanoseconds());
+
+        Timestamp::from_nanoseconds(u64::try_from(nanoseconds).map_err(other)?).map_err(other)
+    }
+
+    fn host_height(&self) -> Result<Height, ContextError> {
+        Height::new(
+            self.gears_ctx.chain_id().revision_number(),
+            self.gears_ctx.height().into(),
+        )
+        .map_err(other)
+    }
+
+    fn consensus_state_heights(&self, client_id: &ClientId) -> Result<Vec<Height>, ContextError> {
+        // the consensus state prefix also holds the processed time and height of each state
+        let heights = self
+            .client_store(client_id)
+            .into_range(..)
+            .filter_map(|(key, _)| {
+                let key = String::from_utf8(key).ok()?;
+                let height = key.strip_prefix(&format!("{KEY_CONSENSUS_STATE_PREFIX}/"))?;
+
+                match height.contains('/') {
+                    true => None,
+                    false => Some(Height::from_str(height).map_err(other)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(heights)
+    }
+
+    fn next_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.consensus_state_heights(client_id)?
+            .into_iter()
+            .filter(|this| this > height)
+            .min()
+            .map(|next| self.consensus_state_at(client_id, &next))
+            .transpose()
+    }
+
+    fn prev_consensus_state(
+        &self,
+        client_id: &ClientId,
+        height: &Height,
+    ) -> Result<Option<Self::ConsensusStateRef>, ContextError> {
+        self.consensus_state_heights(client_id)?
+            .into_iter()
+            .filter(|this| this < height)
+            .max()
+            .map(|prev| self.consensus_state_at(client_id, &prev))
+            .transpose()
+    }
+}
+
+fn other(description: impl ToString) -> ContextError {
+    ContextError::ClientError(ClientError::Other {
+        description: description.to_string(),
+    })
+}
@@ -1,7 +1,11 @@
-use axum::{extract::State, routing::get, Json, Router};
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
 use gears::{
-    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, RestState},
+    baseapp::{HeightQueryRequest, NodeQueryHandler, QueryResponse},
+    rest::{error::HTTPError, QueryHeight, RestState},
 };
 
 use crate::{
@@ -10,14 +14,15 @@ use crate::{
 };
 
 pub async fn params<
-    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<SlashingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = SlashingNodeQueryRequest::Params(QueryParamsRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
@@ -29,7 +34,7 @@ pub async fn const_params() -> Result<Json<QueryParamsResponse>, HTTPError> {
 }
 
 pub fn get_router<
-    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<SlashingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
@@ -1,13 +1,13 @@
 use gears::{
     application::handlers::node::TxError,
     error::NumericError,
+    tendermint::types::time::timestamp::Timestamp,
     types::{
         address::{ConsAddress, ValAddress},
-        decimal256::{Decimal256, Decimal256RangeExceeded},
+        decimal256::Decimal256,
         store::gas::errors::GasStoreErrors,
         uint::Uint256,
     },
-    x::errors::AccountNotFound,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -40,12 +40,17 @@ pub enum ValidatorHandlingError {
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum UnjailError {
-    #[error("validator is jailed: {0}")]
-    Jailed(ConsAddress),
+    #[error("validator is tombstoned and cannot be unjailed: {0}")]
+    Tombstoned(ConsAddress),
+    #[error("validator {address} is still jailed until {}", .jailed_until.format_string_rounded())]
+    StillJailed {
+        address: ConsAddress,
+        jailed_until: Timestamp,
+    },
     #[error("validator is not jailed: {0}")]
     NotJailed(ValAddress),
-    #[error(transparent)]
-    AccountNotFound(#[from] AccountNotFound),
+    #[error("validator does not exist: {0}")]
+    ValidatorNotFound(ValAddress),
     #[error("self delegation is not found")]
     DelegationNotFound,
     #[error(transparent)]
@@ -55,9 +60,3 @@ pub enum UnjailError {
     #[error("{0}")]
     Gas(#[from] GasStoreErrors),
 }
-
-impl From<Decimal256RangeExceeded> for UnjailError {
-    fn from(value: Decimal256RangeExceeded) -> Self {
-        Self::Numeric(NumericError::DecimalRange(value))
-    }
-}
@@ -14,6 +14,7 @@ use gears::{
         InfallibleContextMut, QueryableContext, TransactionalContext,
     },
     core::Protobuf,
+    error::NumericError,
     extensions::{
         corruption::UnwrapCorrupt,
         pagination::{IteratorPaginate, Pagination, PaginationResult},
@@ -34,7 +35,6 @@ use gears::{
         store::gas::errors::GasStoreErrors,
     },
     x::{
-        errors::AccountNotFound,
         keepers::staking::{SlashingStakingKeeper, VALIDATOR_UPDATE_DELAY},
         module::Module,
         types::{delegation::StakingDelegation, validator::StakingValidator},
@@ -206,14 +206,14 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
 
         // if we are past the minimum height and the validator has missed too many blocks, punish them
         if height > min_height && sign_info.missed_blocks_counter > max_missed {
-            let validator_is_jailed = self
+            let validator_is_bonded = self
                 .staking_keeper
                 .validator_by_cons_addr(ctx, &cons_addr)
                 .unwrap_gas()
-                .map(|v| v.is_jailed())
+                .map(|v| !v.is_jailed())
                 .unwrap_or_default();
 
-            if validator_is_jailed {
+            if validator_is_bonded {
                 // Downtime confirmed: slash and jail the validator
                 // We need to retrieve the stake distribution which signed the block, so we subtract ValidatorUpdateDelay from the evidence height,
                 // and subtract an additional 1 since this is the LastCommit.
@@ -259,8 +259,11 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
                 self.staking_keeper.jail(ctx, &cons_addr).unwrap_gas();
 
                 let time = ctx.get_time();
-                let delta = Duration::try_new(params.downtime_jail_duration, 0).unwrap();
-                let jailed_until = time.checked_add(delta).unwrap();
+                // the jail duration is stored in nanoseconds
+                let delta = Duration::new_from_nanos(params.downtime_jail_duration);
+                let jailed_until = time
+                    .checked_add(delta)
+                    .expect("jail duration is bounded by i64 nanoseconds");
                 sign_info.jailed_until = jailed_until;
                 // We need to reset the counter & array so that the validator won't be immediately slashed for downtime upon rebonding.
                 sign_info.missed_blocks_counter = 0;
@@ -361,19 +364,17 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         let validator = self
             .staking_keeper
             .validator(ctx, validator_address)?
-            .ok_or(AccountNotFound::from(validator_address.to_owned()))?;
+            .ok_or(UnjailError::ValidatorNotFound(validator_address.to_owned()))?;
         // cannot be unjailed if no self-delegation exists
         let self_delegation = self
             .staking_keeper
             .delegation(ctx, delegator_address, validator_address)?
             .ok_or(UnjailError::DelegationNotFound)?;
-        let tokens = validator.tokens_from_shares(Decimal256::from_atomics(
-            self_delegation.shares().to_uint_floor(),
-            0,
-        )?)?;
+        let shares = Decimal256::from_atomics(self_delegation.shares().to_uint_floor(), 0)
+            .map_err(|e| NumericError::decimal_range("shares", e))?;
+        let tokens = validator.tokens_from_shares(shares)?;
         let min_self_bond = validator.min_self_delegation();
-        // TODO: check equation
-        if tokens.to_uint_ceil() < min_self_bond {
+        if tokens.to_uint_floor() < min_self_bond {
             return Err(UnjailError::LowDelegation {
                 lower: tokens,
                 bigger: min_self_bond,
@@ -385,7 +386,6 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
             return Err(UnjailError::NotJailed(validator_address.to_owned()));
         }
 
-        // TODO: do we need it?
         let cons_addr: ConsAddress = validator.cons_pub_key().clone().into();
         // If the validator has a ValidatorSigningInfo object that signals that the
         // validator was bonded and so we must check that the validator is not tombstoned
@@ -398,12 +398,15 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         if let Some(info) = self.validator_signing_info(ctx, &cons_addr)? {
             // cannot be unjailed if tombstoned
             if info.tombstoned {
-                return Err(UnjailError::Jailed(cons_addr.to_owned()));
+                return Err(UnjailError::Tombstoned(cons_addr));
             }
 
             // cannot be unjailed until out of jail
             if ctx.get_time() < info.jailed_until {
-                return Err(UnjailError::Jailed(cons_addr.to_owned()));
+                return Err(UnjailError::StillJailed {
+                    address: cons_addr,
+                    jailed_until: info.jailed_until,
+                });
             }
         }
 
@@ -451,7 +454,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         let store = ctx.kv_store(&self.store_key);
         let key = validator_signing_info_key(addr.clone());
         store.get(&key).map(|sign_info| {
-            sign_info.map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
+            sign_info.map(|bytes| ValidatorSigningInfo::decode_vec(&bytes).unwrap_or_corrupt())
         })
     }
 
@@ -536,3 +539,182 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::{proto::header::Header, time::timestamp::Timestamp},
+        types::gas::GasMeter,
+        utils::node::build_init_ctx,
+    };
+
+    use crate::{
+        test_utils::{
+            cons_address, delegator_address, genesis, staking_keeper, test_keeper,
+            validator_address, SubspaceKey,
+        },
+        SlashingParams,
+    };
+
+    use super::*;
+
+    #[test]
+    fn handle_validator_signature_jails_and_slashes_once_below_threshold() {
+        let keeper = test_keeper(staking_keeper(false));
+        let params = SlashingParams::default();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper.init_genesis(&mut ctx, genesis(params.clone()));
+        multi_store.commit();
+
+        let power = VotingPower::try_from(1000u64).unwrap_test();
+        let mut slashes = 0;
+        let mut slash_time = None;
+        for height in 1..=11 {
+            let mut ctx = BlockContext::new(
+                &mut multi_store,
+                height,
+                Header::default(),
+                ConsensusParams::default(),
+            );
+            keeper
+                .handle_validator_signature(&mut ctx, cons_address(), power, false)
+                .unwrap_test();
+
+            let slashed = ctx.events.iter().filter(|e| e.r#type == "slash").count();
+            if slashed > 0 {
+                slash_time = Some(ctx.get_time());
+            }
+            slashes += slashed;
+
+            // the validator cannot be punished before a full window has passed
+            assert_eq!(slashes, if height < 11 { 0 } else { 1 });
+            multi_store.commit();
+        }
+
+        let ctx = BlockContext::new(
+            &mut multi_store,
+            12,
+            Header::default(),
+            ConsensusParams::default(),
+        );
+        let info = keeper
+            .validator_signing_info(&ctx, &cons_address())
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(info.missed_blocks_counter, 0);
+        assert_eq!(info.index_offset, 0);
+        assert_eq!(
+            info.jailed_until,
+            slash_time
+                .unwrap_test()
+                .checked_add(Duration::new_from_nanos(params.downtime_jail_duration))
+                .unwrap_test()
+        );
+        assert!(!keeper.get_validator_missed_block_bit_array(&ctx, &cons_address(), 0));
+
+        // the staking module has jailed the validator, so further misses are not punished
+        let keeper = test_keeper(staking_keeper(true));
+        for height in 12..=30 {
+            let mut ctx = BlockContext::new(
+                &mut multi_store,
+                height,
+                Header::default(),
+                ConsensusParams::default(),
+            );
+            keeper
+                .handle_validator_signature(&mut ctx, cons_address(), power, false)
+                .unwrap_test();
+
+            assert!(ctx.events.iter().all(|e| e.r#type != "slash"));
+            multi_store.commit();
+        }
+    }
+
+    /// Store with a jailed validator whose jail period ends at 600 seconds after the epoch
+    fn jailed_validator_store() -> (ApplicationMultiBank<MemDB, SubspaceKey>, Timestamp) {
+        let keeper = test_keeper(staking_keeper(true));
+        let jailed_until = Timestamp::UNIX_EPOCH
+            .checked_add(Duration::new_from_secs(600))
+            .unwrap_test();
+
+        let mut genesis = genesis(SlashingParams::default());
+        genesis.signing_infos[0].validator_signing_info.jailed_until = jailed_until;
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper.init_genesis(&mut ctx, genesis);
+        multi_store.commit();
+
+        (multi_store, jailed_until)
+    }
+
+    fn unjail_at(
+        multi_store: &mut ApplicationMultiBank<MemDB, SubspaceKey>,
+        time: Timestamp,
+    ) -> (Result<(), UnjailError>, Vec<Event>) {
+        let keeper = test_keeper(staking_keeper(true));
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = TxContext::new(
+            &mut tx_store,
+            2,
+            Header {
+                time,
+                ..Default::default()
+            },
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+        );
+
+        let res = keeper.unjail_tx_handler(
+            &mut ctx,
+            &MsgUnjail {
+                validator_address: validator_address(),
+                from_address: delegator_address(),
+            },
+        );
+
+        (res, ctx.events().to_vec())
+    }
+
+    #[test]
+    fn unjail_fails_within_jail_period() {
+        let (mut multi_store, jailed_until) = jailed_validator_store();
+
+        let time = jailed_until
+            .checked_add(Duration::new_from_secs(-1))
+            .unwrap_test();
+        let (res, events) = unjail_at(&mut multi_store, time);
+
+        assert!(matches!(
+            res,
+            Err(UnjailError::StillJailed { address, jailed_until: until })
+                if address == cons_address() && until == jailed_until
+        ));
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn unjail_succeeds_after_jail_period() {
+        let (mut multi_store, jailed_until) = jailed_validator_store();
+
+        let (res, events) = unjail_at(&mut multi_store, jailed_until);
+
+        res.unwrap_test();
+        assert!(events
+            .iter()
+            .any(|e| e.r#type == "message" && e.attributes.iter().any(|a| a.value == "slashing")));
+    }
+}
@@ -6,6 +6,8 @@ mod keeper;
 mod keys;
 mod message;
 mod params;
+#[cfg(test)]
+mod test_utils;
 mod types;
 
 pub use abci_handler::*;
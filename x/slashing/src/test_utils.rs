@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use gears::{
+    extensions::testing::UnwrapTesting,
+    tendermint::types::{proto::crypto::PublicKey, time::timestamp::Timestamp},
+    types::{
+        address::{AccAddress, ConsAddress, ValAddress},
+        decimal256::Decimal256,
+        uint::Uint256,
+    },
+    x::{
+        keepers::mocks::{
+            module::MockModules,
+            staking::{MockDelegation, MockStakingKeeper, MockValidator},
+        },
+        types::validator::BondStatus,
+    },
+};
+
+use crate::{GenesisState, Keeper, SignerInfo, SlashingParams, ValidatorSigningInfo};
+
+pub use gears::x::keepers::mocks::module::SubspaceKey;
+
+pub type TestKeeper = Keeper<SubspaceKey, SubspaceKey, MockStakingKeeper, MockModules>;
+
+pub fn test_keeper(staking_keeper: MockStakingKeeper) -> TestKeeper {
+    Keeper::new(SubspaceKey::Slashing, SubspaceKey::Params, staking_keeper)
+}
+
+pub fn validator_address() -> ValAddress {
+    ValAddress::try_from(vec![2; 20]).unwrap_test()
+}
+
+pub fn delegator_address() -> AccAddress {
+    AccAddress::try_from(vec![2; 20]).unwrap_test()
+}
+
+pub fn cons_pub_key() -> PublicKey {
+    PublicKey::Ed25519(vec![1; 32])
+}
+
+pub fn cons_address() -> ConsAddress {
+    cons_pub_key().into()
+}
+
+/// Validator with 1000 tokens which are all self delegated
+pub fn staking_keeper(jailed: bool) -> MockStakingKeeper {
+    MockStakingKeeper::former()
+        .validator(
+            MockValidator::former()
+                .operator(validator_address())
+                .tokens(Uint256::from(1000u64))
+                .delegator_shares(Decimal256::from_atomics(1000u64, 0).unwrap_test())
+                .cons_pub_key(cons_pub_key())
+                .jailed(jailed)
+                .min_self_delegation(Uint256::one())
+                .commission(Decimal256::from_str("0.1").unwrap_test())
+                .status(if jailed {
+                    BondStatus::Unbonding
+                } else {
+                    BondStatus::Bonded
+                })
+                .form(),
+        )
+        .delegation(
+            MockDelegation::former()
+                .delegator(delegator_address())
+                .validator(validator_address())
+                .shares(Decimal256::from_atomics(1000u64, 0).unwrap_test())
+                .form(),
+        )
+        .max_validators(100)
+        .form()
+}
+
+/// Signed blocks window of 10 blocks, where the validator may miss at most 5 of them
+pub fn genesis(params: SlashingParams) -> GenesisState {
+    GenesisState {
+        params: SlashingParams {
+            signed_blocks_window: 10,
+            min_signed_per_window: Decimal256::from_str("0.5").unwrap_test(),
+            ..params
+        },
+        signing_infos: vec![SignerInfo {
+            address: cons_address(),
+            validator_signing_info: ValidatorSigningInfo {
+                address: cons_address(),
+                start_height: 0,
+                index_offset: 0,
+                jailed_until: Timestamp::UNIX_EPOCH,
+                tombstoned: false,
+                missed_blocks_counter: 0,
+            },
+        }],
+        missed_blocks: vec![],
+    }
+}
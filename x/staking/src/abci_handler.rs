@@ -185,6 +185,13 @@ impl<
         self.genesis(ctx, genesis)
     }
 
+    fn export_genesis<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+    ) -> Option<Self::Genesis> {
+        Some(self.keeper.export_genesis(ctx))
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -49,6 +49,9 @@ pub struct ValidatorCommand {
 /// Validators implements the query all validators command
 #[derive(Args, Debug, Clone)]
 pub struct ValidatorsCommand {
+    /// Filter validators by status: bonded, unbonding or unbonded
+    #[arg(long)]
+    pub status: Option<BondStatus>,
     #[command(flatten)]
     pub pagination: Option<CliPaginationRequest>,
 }
@@ -120,9 +123,9 @@ impl QueryHandler for StakingQueryHandler {
                     validator_addr: address.clone(),
                 })
             }
-            StakingCommands::Validators(ValidatorsCommand { pagination }) => {
+            StakingCommands::Validators(ValidatorsCommand { status, pagination }) => {
                 StakingQuery::Validators(QueryValidatorsRequest {
-                    status: BondStatus::Unspecified,
+                    status: *status,
                     pagination: pagination.to_owned().try_map(PaginationRequest::try_from)?,
                 })
             }
@@ -9,8 +9,8 @@ use axum::{
     Json, Router,
 };
 use gears::{
-    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    rest::{error::HTTPError, Pagination, RestState},
+    baseapp::{HeightQueryRequest, NodeQueryHandler, QueryResponse},
+    rest::{error::HTTPError, Pagination, QueryHeight, RestState},
     types::{
         address::{AccAddress, ValAddress},
         pagination::request::PaginationRequest,
@@ -20,15 +20,16 @@ use gears::{
 use serde::{Deserialize, Serialize};
 
 pub async fn validator<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(validator_addr): Path<ValAddress>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Validator(QueryValidatorRequest { validator_addr });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
@@ -41,7 +42,7 @@ pub struct ValidatorsQuery {
 }
 
 pub async fn validators<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
@@ -50,56 +51,60 @@ pub async fn validators<
         offset,
         limit,
     }): Query<ValidatorsQuery>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Validators(QueryValidatorsRequest {
-        status: status.unwrap_or(BondStatus::Unspecified),
+        status,
         pagination: Some(PaginationRequest::from(Pagination::new(offset, limit))),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub async fn delegation<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path((validator_addr, delegator_addr)): Path<(ValAddress, AccAddress)>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Delegation(QueryDelegationRequest {
         delegator_addr,
         validator_addr,
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub async fn delegator_delegations<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(delegator_addr): Path<AccAddress>,
     Query(pagination): Query<Pagination>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Delegations(QueryDelegatorDelegationsRequest {
         delegator_addr,
         pagination: Some(PaginationRequest::from(pagination)),
     });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub async fn unbonding_delegations<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
     Path(delegator_addr): Path<AccAddress>,
     Query(pagination): Query<Pagination>,
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req =
@@ -107,36 +112,38 @@ pub async fn unbonding_delegations<
             delegator_addr: delegator_addr.clone(),
             pagination: Some(PaginationRequest::from(pagination)),
         });
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub async fn pool<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Pool(QueryPoolRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub async fn params<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >(
+    Query(height): Query<QueryHeight>,
     State(rest_state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<QRes>, HTTPError> {
     let req = StakingNodeQueryRequest::Params(crate::QueryParamsRequest {});
-    let res = rest_state.app.typed_query(req)?;
+    let res = rest_state.app.typed_query(height.apply(QReq::from(req)))?;
     Ok(Json(res))
 }
 
 pub fn get_router<
-    QReq: QueryRequest + From<StakingNodeQueryRequest>,
+    QReq: HeightQueryRequest + From<StakingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<StakingNodeQueryResponse>,
     App: NodeQueryHandler<QReq, QRes>,
 >() -> Router<RestState<QReq, QRes, App>> {
@@ -1,7 +1,10 @@
 use crate::{
     Delegation, LastValidatorPower, Redelegation, StakingParams, UnbondingDelegation, Validators,
 };
-use gears::{baseapp::genesis::Genesis, types::uint::Uint256};
+use gears::{
+    baseapp::genesis::{Genesis, GenesisValidationError},
+    types::uint::Uint256,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
@@ -26,6 +29,32 @@ impl Genesis for GenesisState {
     ) -> Result<(), gears::baseapp::genesis::GenesisError> {
         Ok(()) // TODO
     }
+
+    fn validate(&self) -> Result<(), GenesisValidationError> {
+        let StakingParams {
+            unbonding_time,
+            max_validators,
+            max_entries,
+            historical_entries,
+            bond_denom,
+            min_commission_rate,
+        } = self.params.clone();
+
+        StakingParams::new(
+            unbonding_time,
+            max_validators,
+            max_entries,
+            historical_entries,
+            bond_denom,
+            min_commission_rate,
+        )
+        .map_err(|e| GenesisValidationError {
+            module: "staking",
+            reason: e.to_string(),
+        })?;
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -42,6 +42,17 @@ impl<
         }
     }
 
+    pub fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &Validator,
+        fraction: Decimal256,
+    ) {
+        if let Some(ref hooks) = self.hooks_keeper {
+            hooks.before_validator_slashed(ctx, validator.operator_address.clone(), fraction);
+        }
+    }
+
     pub fn before_delegation_created<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
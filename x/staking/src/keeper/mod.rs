@@ -8,10 +8,11 @@ use anyhow::anyhow;
 use gears::{
     application::keepers::params::ParamsKeeper,
     context::{
-        block::BlockContext, init::InitContext, InfallibleContext, QueryableContext,
-        TransactionalContext,
+        block::BlockContext, init::InitContext, query::QueryContext, InfallibleContext,
+        QueryableContext, TransactionalContext,
     },
-    extensions::gas::GasResultExt,
+    core::Protobuf,
+    extensions::{corruption::UnwrapCorrupt, gas::GasResultExt},
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::types::{
@@ -47,6 +48,9 @@ mod hooks;
 mod mock_hook_keeper;
 mod query;
 mod redelegation;
+mod slashing;
+#[cfg(test)]
+pub(crate) mod test_utils;
 mod tx;
 mod unbonded;
 mod unbonding;
@@ -278,6 +282,59 @@ impl<
         Ok(res)
     }
 
+    /// Exports the staking state as a genesis state, the counterpart of `init_genesis`.
+    pub fn export_genesis<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> GenesisState {
+        let store = ctx.kv_store(&self.store_key);
+
+        let validators = store
+            .clone()
+            .prefix_store(VALIDATORS_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| Validator::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect::<Vec<_>>();
+
+        let last_validator_powers = store
+            .clone()
+            .prefix_store(LAST_VALIDATOR_POWER_KEY)
+            .into_range(..)
+            .map(|(key, bytes)| LastValidatorPower {
+                address: ValAddress::try_from_prefix_length_bytes(&key).unwrap_or_corrupt(),
+                power: <i64 as prost::Message>::decode(bytes.as_slice()).unwrap_or_corrupt(),
+            })
+            .collect();
+
+        let delegations = store
+            .clone()
+            .prefix_store(DELEGATION_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| Delegation::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        let unbonding_delegations = store
+            .clone()
+            .prefix_store(UNBONDING_DELEGATION_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| UnbondingDelegation::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        let redelegations = store
+            .prefix_store(REDELEGATION_KEY)
+            .into_range(..)
+            .map(|(_, bytes)| Redelegation::decode_vec(&bytes).unwrap_or_corrupt())
+            .collect();
+
+        GenesisState {
+            params: self.staking_params_keeper.get(ctx),
+            validators: validators.try_into().unwrap_or_corrupt(),
+            last_total_power: self.last_total_power(ctx).unwrap_or_default(),
+            exported: true,
+            last_validator_powers,
+            delegations,
+            unbonding_delegations,
+            redelegations,
+        }
+    }
+
     /// BlockValidatorUpdates calculates the ValidatorUpdates for the current block
     /// Called in each EndBlock
     pub fn block_validator_updates<DB: Database>(
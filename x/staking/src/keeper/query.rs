@@ -54,21 +54,10 @@ impl<
         let pagination = query
             .pagination
             .map(gears::extensions::pagination::Pagination::from);
-        let (validators, p_result) = if query.status == BondStatus::Unspecified {
-            let (p_result, iterator) = iterator.maybe_paginate(pagination);
-            (
-                iterator.map(|(_k, v)| v).map(Into::into).collect(),
-                p_result,
-            )
-        } else {
-            let (p_result, iterator) = iterator
-                .filter(|(_k, v)| v.status == query.status)
-                .maybe_paginate(pagination);
-            (
-                iterator.map(|(_k, v)| v).map(Into::into).collect(),
-                p_result,
-            )
-        };
+        let (p_result, iterator) = iterator
+            .filter(|(_k, v)| query.status.map_or(true, |status| v.status == status))
+            .maybe_paginate(pagination);
+        let validators = iterator.map(|(_k, v)| v).map(Into::into).collect();
 
         QueryValidatorsResponse {
             validators,
@@ -235,3 +224,86 @@ impl<
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB, query::QueryMultiStore},
+        tendermint::types::{
+            chain_id::ChainId, proto::crypto::PublicKey, time::timestamp::Timestamp,
+        },
+        utils::node::build_init_ctx,
+    };
+
+    use crate::{
+        keeper::test_utils::{test_keeper, SubspaceKey},
+        Description,
+    };
+
+    use super::*;
+
+    #[test]
+    fn query_validators_filters_by_status() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let statuses = [
+            BondStatus::Bonded,
+            BondStatus::Unbonding,
+            BondStatus::Unbonded,
+            BondStatus::Bonded,
+        ];
+        for (i, status) in statuses.into_iter().enumerate() {
+            let mut validator = Validator::new_with_defaults(
+                ValAddress::try_from(vec![i as u8 + 1; 20]).unwrap_test(),
+                PublicKey::Ed25519(vec![i as u8 + 1; 32]),
+                Description::default(),
+            );
+            validator.status = status;
+
+            keeper.set_validator(&mut ctx, &validator).unwrap_test();
+        }
+
+        multi_store.commit();
+        let ctx = QueryContext::new(
+            QueryMultiStore::new(&multi_store, multi_store.head_version()).unwrap_test(),
+            multi_store.head_version(),
+            ChainId::default(),
+            Some(Timestamp::UNIX_EPOCH),
+        )
+        .unwrap_test();
+
+        let query = |status| {
+            keeper
+                .query_validators(
+                    &ctx,
+                    QueryValidatorsRequest {
+                        status,
+                        pagination: None,
+                    },
+                )
+                .validators
+        };
+
+        let bonded = query(Some(BondStatus::Bonded));
+        assert_eq!(bonded.len(), 2);
+        assert!(bonded.iter().all(|v| v.status == BondStatus::Bonded));
+
+        let unbonding = query(Some(BondStatus::Unbonding));
+        assert_eq!(unbonding.len(), 1);
+        assert_eq!(unbonding[0].status, BondStatus::Unbonding);
+
+        let unbonded = query(Some(BondStatus::Unbonded));
+        assert_eq!(unbonded.len(), 1);
+        assert_eq!(unbonded[0].status, BondStatus::Unbonded);
+
+        assert_eq!(query(None).len(), statuses.len());
+    }
+}
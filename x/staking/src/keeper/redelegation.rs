@@ -39,7 +39,7 @@ impl<
         let src_validator = if let Some(validator) = self.validator(ctx, val_src_addr)? {
             validator
         } else {
-            return Err(anyhow::anyhow!("bad redelegation src: {}", val_dst_addr));
+            return Err(anyhow::anyhow!("bad redelegation src: {}", val_src_addr));
         };
 
         // check if this is a transitive redelegation
@@ -305,7 +305,9 @@ impl<
 
         let key = completion_time.format_bytes_rounded(); //TODO: check if this is correct
         if let Some(bytes) = store.get(&key)? {
-            Ok(serde_json::from_slice(&bytes).unwrap_or_corrupt())
+            Ok(DvvTriplets::decode::<Bytes>(bytes.into())
+                .unwrap_or_corrupt()
+                .triplets)
         } else {
             Ok(vec![])
         }
@@ -361,3 +363,163 @@ impl<
         mature_redelegations
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gears::{
+        baseapp::ConsensusParams,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::crypto::PublicKey,
+        utils::node::build_init_ctx,
+    };
+
+    use crate::{
+        keeper::test_utils::{test_keeper, SubspaceKey},
+        Description,
+    };
+
+    use super::*;
+
+    fn redelegation(src: u8, dst: u8) -> Redelegation {
+        Redelegation {
+            delegator_address: AccAddress::try_from(vec![1; 20]).unwrap_test(),
+            validator_src_address: ValAddress::try_from(vec![src; 20]).unwrap_test(),
+            validator_dst_address: ValAddress::try_from(vec![dst; 20]).unwrap_test(),
+            entries: vec![],
+        }
+    }
+
+    #[test]
+    fn insert_redelegation_queue_appends_to_time_slice() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let completion_time = Timestamp::UNIX_EPOCH;
+        let first = redelegation(2, 3);
+        let second = redelegation(3, 4);
+
+        keeper
+            .insert_redelegation_queue(&mut ctx, &first, completion_time)
+            .unwrap_test();
+        keeper
+            .insert_redelegation_queue(&mut ctx, &second, completion_time)
+            .unwrap_test();
+
+        let triplets = keeper
+            .redelegation_queue_time_slice(&mut ctx, completion_time)
+            .unwrap_test();
+        let expected = [first, second]
+            .into_iter()
+            .map(|red| DvvTriplet {
+                del_addr: red.delegator_address,
+                val_src_addr: red.validator_src_address,
+                val_dst_addr: red.validator_dst_address,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(triplets, expected);
+        assert_eq!(
+            keeper.dequeue_all_mature_redelegation_queue(&mut ctx, &completion_time),
+            expected
+        );
+        assert!(keeper
+            .redelegation_queue_time_slice(&mut ctx, completion_time)
+            .unwrap_test()
+            .is_empty());
+    }
+
+    #[test]
+    fn begin_redelegation_moves_shares_and_queues_entry() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let bonded_validator = |addr: u8| {
+            let mut validator = Validator::new_with_defaults(
+                ValAddress::try_from(vec![addr; 20]).unwrap_test(),
+                PublicKey::Ed25519(vec![addr; 32]),
+                Description::default(),
+            );
+            validator.tokens = Uint256::from(1_000_000u64);
+            validator.delegator_shares = Decimal256::from_atomics(1_000_000u64, 0).unwrap_test();
+            validator.status = BondStatus::Bonded;
+            validator
+        };
+        let src = bonded_validator(2);
+        let dst = bonded_validator(3);
+        for validator in [&src, &dst] {
+            keeper.set_validator(&mut ctx, validator).unwrap_test();
+            keeper
+                .set_validator_by_power_index(&mut ctx, validator)
+                .unwrap_test();
+        }
+
+        let del_addr = AccAddress::try_from(vec![1; 20]).unwrap_test();
+        let shares = Decimal256::from_atomics(1_000u64, 0).unwrap_test();
+        keeper
+            .set_delegation(
+                &mut ctx,
+                &Delegation {
+                    delegator_address: del_addr.clone(),
+                    validator_address: src.operator_address.clone(),
+                    shares,
+                },
+            )
+            .unwrap_test();
+        assert!(keeper
+            .delegation(&ctx, &del_addr, &dst.operator_address)
+            .unwrap_test()
+            .is_none());
+
+        let completion_time = keeper
+            .begin_redelegation(
+                &mut ctx,
+                &del_addr,
+                &src.operator_address,
+                &dst.operator_address,
+                shares,
+            )
+            .unwrap_test();
+
+        let dst_delegation = keeper
+            .delegation(&ctx, &del_addr, &dst.operator_address)
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(dst_delegation.shares, shares);
+        assert!(keeper
+            .delegation(&ctx, &del_addr, &src.operator_address)
+            .unwrap_test()
+            .is_none());
+
+        let redelegation = keeper
+            .redelegation(
+                &ctx,
+                &del_addr,
+                &src.operator_address,
+                &dst.operator_address,
+            )
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(redelegation.entries.len(), 1);
+        assert_eq!(redelegation.entries[0].completion_time, completion_time);
+
+        assert_eq!(
+            keeper
+                .redelegation_queue_time_slice(&mut ctx, completion_time)
+                .unwrap_test(),
+            vec![DvvTriplet {
+                del_addr,
+                val_src_addr: src.operator_address,
+                val_dst_addr: dst.operator_address,
+            }]
+        );
+    }
+}
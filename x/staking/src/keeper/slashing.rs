@@ -0,0 +1,619 @@
+use gears::{
+    core::Protobuf, extensions::corruption::UnwrapCorrupt,
+    tendermint::types::proto::validator::VotingPower, types::address::ConsAddress,
+    x::keepers::staking::SlashingStakingKeeper,
+};
+
+use crate::types::keys;
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > SlashingStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+    type Validator = Validator;
+    type Delegation = Delegation;
+
+    fn validators_iter<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<impl Iterator<Item = Result<Validator, GasStoreErrors>>, GasStoreErrors> {
+        let validators = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(VALIDATORS_KEY)
+            .into_range(..)
+            .map(|next| next.map(|(_, v)| Validator::decode_vec(&v).unwrap_or_corrupt()))
+            .collect::<Vec<_>>();
+
+        Ok(validators.into_iter())
+    }
+
+    fn validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ValAddress,
+    ) -> Result<Option<Validator>, GasStoreErrors> {
+        Keeper::validator(self, ctx, addr)
+    }
+
+    fn validator_by_cons_addr<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<Validator>, GasStoreErrors> {
+        Keeper::validator_by_cons_addr(self, ctx, addr)
+    }
+
+    /// slash a validator for an infraction committed at a known height. Unbonding delegations and
+    /// redelegations which started after the infraction are slashed first, then the remaining
+    /// amount is burned from the validator's tokens.
+    ///
+    /// Like the cosmos sdk this panics when slashing an unbonded validator or an infraction
+    /// from a future height, both are bugs of the caller.
+    fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        height: u32,
+        power: VotingPower,
+        slash_factor: Decimal256,
+    ) -> Result<(), GasStoreErrors> {
+        // amount of slashing = slash slash_factor * power at time of infraction
+        let amount = Uint256::from(u64::from(power)) * Uint256::from(self.power_reduction(ctx));
+        let slash_amount = mul_truncate(slash_factor, amount);
+
+        // the validator may have already been unbonded and removed from the store
+        let Some(mut validator) = Keeper::validator_by_cons_addr(self, ctx, addr)? else {
+            return Ok(());
+        };
+
+        assert!(
+            validator.status != BondStatus::Unbonded,
+            "should not be slashing unbonded validator: {}",
+            validator.operator_address
+        );
+        assert!(
+            height <= ctx.height(),
+            "impossible attempt to slash future infraction at height {height} but we are at height {}",
+            ctx.height()
+        );
+
+        self.before_validator_slashed(ctx, &validator, slash_factor);
+
+        let mut remaining_slash_amount = slash_amount;
+        if height < ctx.height() {
+            // stake which started unbonding or redelegating after the infraction contributed to it
+            for ubd in
+                self.unbonding_delegations_from_validator(ctx, &validator.operator_address)?
+            {
+                let amount_slashed =
+                    self.slash_unbonding_delegation(ctx, ubd, height, slash_factor)?;
+                remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+            }
+
+            for redelegation in
+                self.redelegations_from_src_validator(ctx, &validator.operator_address)?
+            {
+                let amount_slashed =
+                    self.slash_redelegation(ctx, redelegation, height, slash_factor)?;
+                remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+            }
+        }
+
+        // cannot decrease balance below zero
+        let tokens_to_burn = remaining_slash_amount.min(validator.tokens);
+
+        self.delete_validator_by_power_index(ctx, &validator)?;
+        validator.tokens -= tokens_to_burn;
+        self.set_validator(ctx, &validator)?;
+        self.set_validator_by_power_index(ctx, &validator)?;
+
+        match validator.status {
+            BondStatus::Bonded => self.burn_tokens(ctx, &self.bonded_module, tokens_to_burn),
+            BondStatus::Unbonding | BondStatus::Unbonded => {
+                self.burn_tokens(ctx, &self.not_bonded_module, tokens_to_burn)
+            }
+            BondStatus::Unspecified => panic!("invalid validator status"),
+        }
+    }
+
+    fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = Keeper::validator_by_cons_addr(self, ctx, addr)?
+            .unwrap_or_else(|| panic!("validator with consensus address {addr} not found"));
+        self.jail_validator(ctx, &mut validator)
+    }
+
+    fn unjail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = Keeper::validator_by_cons_addr(self, ctx, addr)?
+            .unwrap_or_else(|| panic!("validator with consensus address {addr} not found"));
+        self.unjail_validator(ctx, &mut validator)
+    }
+
+    fn delegation<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        delegator_address: &AccAddress,
+        validator_address: &ValAddress,
+    ) -> Result<Option<Delegation>, GasStoreErrors> {
+        Keeper::delegation(self, ctx, delegator_address, validator_address)
+    }
+
+    fn max_validators<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<u32, GasStoreErrors> {
+        Ok(self.staking_params_keeper.try_get(ctx)?.max_validators())
+    }
+}
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, KH, M>
+{
+    fn unbonding_delegations_from_validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        val_addr: &ValAddress,
+    ) -> Result<Vec<UnbondingDelegation>, GasStoreErrors> {
+        let del_addrs = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(keys::get_ubds_by_val_index_key(val_addr))
+            .into_range(..)
+            .map(|next| {
+                next.map(|(k, _)| AccAddress::try_from_prefix_length_bytes(&k).unwrap_or_corrupt())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut ubds = Vec::with_capacity(del_addrs.len());
+        for del_addr in del_addrs {
+            ubds.push(
+                self.unbonding_delegation(ctx, &del_addr, val_addr)?
+                    .unwrap_or_corrupt(),
+            );
+        }
+        Ok(ubds)
+    }
+
+    fn redelegations_from_src_validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        val_src_addr: &ValAddress,
+    ) -> Result<Vec<Redelegation>, GasStoreErrors> {
+        let addrs = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(keys::redelegations_from_val_src_index_key(val_src_addr))
+            .into_range(..)
+            .map(|next| {
+                next.map(|(k, _)| {
+                    // key is of the form del_addr.len() (1 byte) || del_addr || val_dst_addr.len() (1 byte) || val_dst_addr
+                    let (del_addr, val_dst_addr) = k.split_at(usize::from(k[0]) + 1);
+                    (
+                        AccAddress::try_from_prefix_length_bytes(del_addr).unwrap_or_corrupt(),
+                        ValAddress::try_from_prefix_length_bytes(val_dst_addr).unwrap_or_corrupt(),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut redelegations = Vec::with_capacity(addrs.len());
+        for (del_addr, val_dst_addr) in addrs {
+            redelegations.push(
+                self.redelegation(ctx, &del_addr, val_src_addr, &val_dst_addr)?
+                    .unwrap_or_corrupt(),
+            );
+        }
+        Ok(redelegations)
+    }
+
+    /// slash an unbonding delegation and update the pool. Returns the amount that would have
+    /// been slashed assuming the unbonding delegation had enough stake to slash (the amount
+    /// actually slashed may be less if there's insufficient stake remaining).
+    fn slash_unbonding_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        mut ubd: UnbondingDelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut burned_amount = Uint256::zero();
+
+        for entry in ubd.entries.iter_mut() {
+            // stake which started unbonding before the infraction or has already finished
+            // unbonding didn't contribute to it
+            if entry.creation_height < infraction_height || entry.is_mature(&now) {
+                continue;
+            }
+
+            let slash_amount = mul_truncate(slash_factor, entry.initial_balance);
+            total_slash_amount += slash_amount;
+
+            // the unbonding delegation may have been slashed already
+            let unbonding_slash_amount = slash_amount.min(entry.balance);
+            burned_amount += unbonding_slash_amount;
+            entry.balance -= unbonding_slash_amount;
+        }
+
+        self.set_unbonding_delegation(ctx, &ubd)?;
+        self.burn_tokens(ctx, &self.not_bonded_module, burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// slash a redelegation by unbonding the slashed shares from the destination validator.
+    /// Returns the amount that would have been slashed assuming the redelegation had enough
+    /// stake to slash (the amount actually slashed may be less if there's insufficient stake
+    /// remaining).
+    fn slash_redelegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        redelegation: Redelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut bonded_burned_amount = Uint256::zero();
+        let mut not_bonded_burned_amount = Uint256::zero();
+
+        let del_addr = &redelegation.delegator_address;
+        let val_dst_addr = &redelegation.validator_dst_address;
+
+        for entry in &redelegation.entries {
+            // stake which started redelegating before the infraction or has already finished
+            // redelegating didn't contribute to it
+            if entry.creation_height < infraction_height || entry.is_mature(&now) {
+                continue;
+            }
+
+            let slash_amount = mul_truncate(slash_factor, entry.initial_balance);
+            total_slash_amount += slash_amount;
+
+            let shares_to_unbond = slash_factor
+                .checked_mul(entry.share_dst)
+                .expect("slash factor is at most one");
+            if shares_to_unbond.is_zero() {
+                continue;
+            }
+
+            // the delegation may have been completely unbonded already
+            let Some(delegation) = Keeper::delegation(self, ctx, del_addr, val_dst_addr)? else {
+                continue;
+            };
+
+            let tokens_to_burn = self
+                .unbond(
+                    ctx,
+                    del_addr,
+                    val_dst_addr,
+                    shares_to_unbond.min(delegation.shares),
+                )
+                .map_err(|e| {
+                    e.downcast::<GasStoreErrors>()
+                        .unwrap_or_else(|e| panic!("failed to unbond redelegated shares: {e}"))
+                })?;
+
+            // the destination validator holds the delegation so it can't have been removed
+            let dst_validator = Keeper::validator(self, ctx, val_dst_addr)?.unwrap_or_corrupt();
+            match dst_validator.status {
+                BondStatus::Bonded => bonded_burned_amount += tokens_to_burn,
+                BondStatus::Unbonding | BondStatus::Unbonded => {
+                    not_bonded_burned_amount += tokens_to_burn
+                }
+                BondStatus::Unspecified => panic!("unknown validator status"),
+            }
+        }
+
+        self.burn_tokens(ctx, &self.bonded_module, bonded_burned_amount)?;
+        self.burn_tokens(ctx, &self.not_bonded_module, not_bonded_burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    fn burn_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        module: &M,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let params = self.staking_params_keeper.try_get(ctx)?;
+        let coins = UnsignedCoins::new(vec![UnsignedCoin {
+            denom: params.bond_denom().clone(),
+            amount,
+        }])
+        .expect("positive amount of a single denom is valid");
+
+        // original routine is infallible, all errors in sdk panics in this method
+        self.bank_keeper
+            .coins_burn(ctx, module, &coins)
+            .expect("staking pools hold the tokens of their validators");
+
+        Ok(())
+    }
+}
+
+/// Returns `amount * fraction` truncated to an integer
+fn mul_truncate(fraction: Decimal256, amount: Uint256) -> Uint256 {
+    Decimal256::from_atomics(amount, 0)
+        .ok()
+        .and_then(|amount| amount.checked_mul(fraction).ok())
+        .expect("slashed amounts are bounded by the token supply")
+        .to_uint_floor()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use gears::{
+        baseapp::{options::NodeOptions, ConsensusParams},
+        context::tx::TxContext,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::{
+            proto::{crypto::PublicKey, header::Header},
+            time::timestamp::Timestamp,
+        },
+        types::gas::GasMeter,
+        utils::node::build_init_ctx,
+    };
+
+    use crate::{
+        keeper::test_utils::{test_keeper, SubspaceKey, TestKeeper},
+        Description, RedelegationEntry, StakingParams, UnbondingDelegationEntry,
+    };
+
+    use super::*;
+
+    #[test]
+    fn unjail_restores_validator_in_power_index() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        let mut validator = Validator::new_with_defaults(
+            ValAddress::try_from(vec![1; 20]).unwrap_test(),
+            PublicKey::Ed25519(vec![1; 32]),
+            Description::default(),
+        );
+        validator.tokens = Uint256::from(1_000_000u64);
+        validator.status = BondStatus::Bonded;
+        keeper.set_validator(&mut ctx, &validator).unwrap_test();
+        keeper
+            .set_validator_by_cons_addr(&mut ctx, &validator)
+            .unwrap_test();
+        keeper
+            .set_validator_by_power_index(&mut ctx, &validator)
+            .unwrap_test();
+
+        let in_power_index = |ctx: &InitContext<'_, MemDB, SubspaceKey>| {
+            keeper
+                .validators_power_store_vals_vec(ctx)
+                .unwrap_test()
+                .contains(&validator.operator_address)
+        };
+        assert!(in_power_index(&ctx));
+
+        SlashingStakingKeeper::jail(&keeper, &mut ctx, &validator.cons_addr()).unwrap_test();
+        assert!(!in_power_index(&ctx));
+
+        SlashingStakingKeeper::unjail(&keeper, &mut ctx, &validator.cons_addr()).unwrap_test();
+        assert!(in_power_index(&ctx));
+        assert!(
+            !Keeper::validator(&keeper, &ctx, &validator.operator_address)
+                .unwrap_test()
+                .unwrap_test()
+                .jailed
+        );
+    }
+
+    #[test]
+    fn slash_current_infraction_burns_validator_tokens() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut init_ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper
+            .staking_params_keeper
+            .set(&mut init_ctx, StakingParams::default());
+        multi_store.commit();
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = tx_ctx(&mut tx_store, &mut block_gas_meter);
+
+        let validator = bonded_validator(1, 1_000_000);
+        store_validator(&keeper, &mut ctx, &validator);
+
+        SlashingStakingKeeper::slash(
+            &keeper,
+            &mut ctx,
+            &validator.cons_addr(),
+            10,
+            VotingPower::new(1).unwrap_test(),
+            Decimal256::from_str("0.1").unwrap_test(),
+        )
+        .unwrap_test();
+
+        let slashed = Keeper::validator(&keeper, &ctx, &validator.operator_address)
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(slashed.tokens, Uint256::from(900_000u64));
+    }
+
+    #[test]
+    fn slash_past_infraction_slashes_later_unbondings_and_redelegations() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut init_ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+        keeper
+            .staking_params_keeper
+            .set(&mut init_ctx, StakingParams::default());
+        multi_store.commit();
+
+        let mut tx_store = multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::infinite();
+        let mut ctx = tx_ctx(&mut tx_store, &mut block_gas_meter);
+
+        let delegator = AccAddress::try_from(vec![9; 20]).unwrap_test();
+        let src_validator = bonded_validator(1, 1_000_000);
+        store_validator(&keeper, &mut ctx, &src_validator);
+        let dst_validator = bonded_validator(2, 1_000);
+        store_validator(&keeper, &mut ctx, &dst_validator);
+        keeper
+            .set_delegation(
+                &mut ctx,
+                &Delegation {
+                    delegator_address: delegator.clone(),
+                    validator_address: dst_validator.operator_address.clone(),
+                    shares: Decimal256::from_atomics(1_000u64, 0).unwrap_test(),
+                },
+            )
+            .unwrap_test();
+
+        let completion_time = Timestamp::try_new(100, 0).unwrap_test();
+        let ubd_entry = |creation_height, balance: u64| UnbondingDelegationEntry {
+            creation_height,
+            completion_time,
+            initial_balance: Uint256::from(balance),
+            balance: Uint256::from(balance),
+        };
+        keeper
+            .set_unbonding_delegation(
+                &mut ctx,
+                &UnbondingDelegation {
+                    delegator_address: delegator.clone(),
+                    validator_address: src_validator.operator_address.clone(),
+                    entries: vec![ubd_entry(2, 100), ubd_entry(7, 200)],
+                },
+            )
+            .unwrap_test();
+        keeper
+            .set_redelegation(
+                &mut ctx,
+                &Redelegation {
+                    delegator_address: delegator.clone(),
+                    validator_src_address: src_validator.operator_address.clone(),
+                    validator_dst_address: dst_validator.operator_address.clone(),
+                    entries: vec![RedelegationEntry {
+                        creation_height: 7,
+                        completion_time,
+                        initial_balance: Uint256::from(1_000u64),
+                        share_dst: Decimal256::from_atomics(1_000u64, 0).unwrap_test(),
+                    }],
+                },
+            )
+            .unwrap_test();
+
+        SlashingStakingKeeper::slash(
+            &keeper,
+            &mut ctx,
+            &src_validator.cons_addr(),
+            5,
+            VotingPower::new(1).unwrap_test(),
+            Decimal256::from_str("0.1").unwrap_test(),
+        )
+        .unwrap_test();
+
+        // only the entry created after the infraction is slashed
+        let ubd = keeper
+            .unbonding_delegation(&ctx, &delegator, &src_validator.operator_address)
+            .unwrap_test()
+            .unwrap_test();
+        let balances = ubd
+            .entries
+            .iter()
+            .map(|entry| entry.balance)
+            .collect::<Vec<_>>();
+        assert_eq!(balances, vec![Uint256::from(100u64), Uint256::from(180u64)]);
+
+        // the redelegated stake is unbonded from the destination validator
+        let delegation =
+            Keeper::delegation(&keeper, &ctx, &delegator, &dst_validator.operator_address)
+                .unwrap_test()
+                .unwrap_test();
+        assert_eq!(
+            delegation.shares,
+            Decimal256::from_atomics(900u64, 0).unwrap_test()
+        );
+
+        // the validator covers what the unbonding and the redelegation didn't
+        let slashed = Keeper::validator(&keeper, &ctx, &src_validator.operator_address)
+            .unwrap_test()
+            .unwrap_test();
+        assert_eq!(
+            slashed.tokens,
+            Uint256::from(1_000_000u64 - 100_000 + 20 + 100)
+        );
+    }
+
+    fn tx_ctx<'a>(
+        tx_store: &'a mut gears::store::bank::multi::TransactionMultiBank<MemDB, SubspaceKey>,
+        block_gas_meter: &'a mut GasMeter<gears::types::gas::kind::BlockKind>,
+    ) -> TxContext<'a, MemDB, SubspaceKey> {
+        TxContext::new(
+            tx_store,
+            10,
+            Header::default(),
+            ConsensusParams::default(),
+            GasMeter::infinite(),
+            block_gas_meter,
+            NodeOptions::default(),
+        )
+    }
+
+    fn bonded_validator(byte: u8, tokens: u64) -> Validator {
+        let mut validator = Validator::new_with_defaults(
+            ValAddress::try_from(vec![byte; 20]).unwrap_test(),
+            PublicKey::Ed25519(vec![byte; 32]),
+            Description::default(),
+        );
+        validator.tokens = Uint256::from(tokens);
+        validator.delegator_shares = Decimal256::from_atomics(tokens, 0).unwrap_test();
+        validator.status = BondStatus::Bonded;
+        validator
+    }
+
+    fn store_validator(
+        keeper: &TestKeeper,
+        ctx: &mut TxContext<'_, MemDB, SubspaceKey>,
+        validator: &Validator,
+    ) {
+        keeper.set_validator(ctx, validator).unwrap_test();
+        keeper
+            .set_validator_by_cons_addr(ctx, validator)
+            .unwrap_test();
+        keeper
+            .set_validator_by_power_index(ctx, validator)
+            .unwrap_test();
+    }
+}
@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+use gears::{
+    extensions::testing::UnwrapTesting,
+    types::base::coin::UnsignedCoin,
+    x::keepers::mocks::{auth::MockAuthKeeper, bank::MockBankKeeper, module::MockModules},
+};
+
+use crate::{Keeper, MockHookKeeper};
+
+pub use gears::x::keepers::mocks::module::SubspaceKey;
+
+pub type TestKeeper = Keeper<
+    SubspaceKey,
+    SubspaceKey,
+    MockAuthKeeper,
+    MockBankKeeper,
+    MockHookKeeper<SubspaceKey, MockAuthKeeper, MockModules>,
+    MockModules,
+>;
+
+/// The mocked bank keeper reports a balance of 34uatom for every account
+pub fn test_keeper() -> TestKeeper {
+    Keeper::new(
+        SubspaceKey::Staking,
+        SubspaceKey::Staking,
+        MockAuthKeeper::former().form(),
+        MockBankKeeper::former()
+            .balance(UnsignedCoin::from_str("34uatom").unwrap_test())
+            .form(),
+        None,
+        MockModules::BondedPool,
+        MockModules::NotBondedPool,
+    )
+}
@@ -25,6 +25,13 @@ impl<
     ) -> Result<(), anyhow::Error> {
         let params = self.staking_params_keeper.try_get(ctx)?;
 
+        if msg.commission.rate() < params.min_commission_rate() {
+            return Err(anyhow::anyhow!(
+                "cannot set validator commission to less than minimum rate of {}",
+                params.min_commission_rate()
+            ));
+        }
+
         if self.validator(ctx, &msg.validator_address)?.is_some() {
             return Err(anyhow::anyhow!("Account {} exists", msg.validator_address));
         };
@@ -142,6 +149,17 @@ impl<
         validator.description = description;
 
         if let Some(rate) = msg.commission_rate {
+            let min_commission_rate = self
+                .staking_params_keeper
+                .try_get(ctx)?
+                .min_commission_rate();
+            if rate < min_commission_rate {
+                return Err(anyhow::anyhow!(
+                    "cannot set validator commission to less than minimum rate of {}",
+                    min_commission_rate
+                ));
+            }
+
             let commission = self
                 .create_updated_validator_commission(ctx, &validator, rate)
                 .map_err(|e| anyhow::anyhow!(e.to_string()))?;
@@ -445,3 +463,68 @@ impl<
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use gears::{
+        baseapp::ConsensusParams,
+        extensions::testing::UnwrapTesting,
+        store::{bank::multi::ApplicationMultiBank, database::MemDB},
+        tendermint::types::proto::crypto::PublicKey,
+        utils::node::build_init_ctx,
+    };
+
+    use crate::{
+        keeper::test_utils::{test_keeper, SubspaceKey},
+        CommissionRates, Description, StakingParams,
+    };
+
+    use super::*;
+
+    #[test]
+    fn create_validator_rejects_commission_below_min_rate() {
+        let keeper = test_keeper();
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+        keeper.staking_params_keeper.set(
+            &mut ctx,
+            StakingParams {
+                min_commission_rate: Decimal256::from_str("0.05").unwrap_test(),
+                ..Default::default()
+            },
+        );
+
+        let msg = CreateValidator {
+            description: Description::default(),
+            commission: CommissionRates::new(
+                Decimal256::from_str("0.01").unwrap_test(),
+                Decimal256::from_str("0.2").unwrap_test(),
+                Decimal256::from_str("0.01").unwrap_test(),
+            )
+            .unwrap_test(),
+            min_self_delegation: Uint256::one(),
+            delegator_address: AccAddress::try_from(vec![1; 20]).unwrap_test(),
+            validator_address: ValAddress::try_from(vec![1; 20]).unwrap_test(),
+            pubkey: PublicKey::Ed25519(vec![1; 32]),
+            value: UnsignedCoin::from_str("1000000uatom").unwrap_test(),
+        };
+
+        let err = keeper
+            .create_validator(&mut ctx, ValidatorParams::default(), &msg)
+            .expect_err("commission is below the min commission rate");
+
+        assert_eq!(
+            err.to_string(),
+            "cannot set validator commission to less than minimum rate of 0.05"
+        );
+        assert!(keeper
+            .validator(&ctx, &msg.validator_address)
+            .unwrap_test()
+            .is_none());
+    }
+}
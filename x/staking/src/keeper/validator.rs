@@ -62,6 +62,24 @@ impl<
         Ok(())
     }
 
+    /// unjail_validator removes the jailed flag and restores the validator in the power index,
+    /// so it can be bonded again on the next validator set update
+    pub fn unjail_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &mut Validator,
+    ) -> Result<(), GasStoreErrors> {
+        assert!(
+            validator.jailed,
+            "cannot unjail already unjailed validator, validator: {}",
+            validator.operator_address
+        );
+        validator.jailed = false;
+        self.set_validator(ctx, validator)?;
+        self.set_validator_by_power_index(ctx, validator)?;
+        Ok(())
+    }
+
     /// create_updated_validator_commission attempts to create a validator's commission rate.
     /// An error is returned if the new commission rate is invalid.
     pub fn create_updated_validator_commission<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -15,8 +15,6 @@ impl<
     > Keeper<SK, PSK, AK, BK, KH, M>
 {
     /// Load the last total validator power.
-    // TODO: this isn't used
-    // TODO: implementation doesn't look right: it's decoding Uint256 from bytes, instead of Uint256Proto
     pub fn last_total_power<DB: Database, CTX: InfallibleContext<DB, SK>>(
         &self,
         ctx: &CTX,
@@ -24,7 +22,7 @@ impl<
         let store = InfallibleContext::infallible_store(ctx, &self.store_key);
         store
             .get(&LAST_TOTAL_POWER_KEY)
-            .map(|bytes| Uint256::from_be_bytes(bytes.try_into().unwrap_or_corrupt()))
+            .map(|bytes| Uint256Proto::decode_vec(&bytes).unwrap_or_corrupt().uint)
     }
 
     pub fn set_last_total_power<DB: Database, CTX: TransactionalContext<DB, SK>>(
@@ -2,12 +2,18 @@ use anyhow::anyhow;
 use gears::{
     application::keepers::params::ParamsKeeper,
     extensions::corruption::UnwrapCorrupt,
-    params::{ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey},
+    params::{ParamKind, ParamsDeserialize, ParamsError, ParamsSerialize, ParamsSubspaceKey},
     tendermint::types::time::duration::Duration,
-    types::denom::Denom,
+    types::{
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+        denom::Denom,
+    },
 };
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 mod environment;
 
@@ -20,11 +26,13 @@ const KEY_MAX_VALIDATORS: &str = "MaxValidators";
 const KEY_MAX_ENTRIES: &str = "MaxEntries";
 const KEY_HISTORICAL_ENTRIES: &str = "HistoricalEntries";
 const KEY_BOND_DENOM: &str = "BondDenom";
+const KEY_MIN_COMMISSION_RATE: &str = "MinCommissionRate";
 
 /// ['Params'] defines the parameters for the staking module. The params are guaranteed to be valid:
 /// - unbonding_time is non negative
 /// - max_validators is positive
 /// - max_entries is positive
+/// - min_commission_rate is not greater than one
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(try_from = "RawStakingParams")]
 pub struct StakingParams {
@@ -33,16 +41,44 @@ pub struct StakingParams {
     pub max_entries: u32,
     pub historical_entries: u32,
     pub bond_denom: Denom,
+    pub min_commission_rate: Decimal256,
 }
 
 /// [`RawParams`] exists to allow us to validate params when deserializing them
 #[derive(Deserialize)]
 struct RawStakingParams {
-    unbonding_time: Duration,
+    unbonding_time: RawUnbondingTime,
     max_validators: u32,
     max_entries: u32,
     historical_entries: u32,
     bond_denom: Denom,
+    #[serde(default)]
+    min_commission_rate: Decimal256,
+}
+
+/// Unbonding time is a duration string like "1814400s". Genesis files written before
+/// the switch to [`Duration`] hold raw nanoseconds, as a number or a numeric string.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawUnbondingTime {
+    Duration(Duration),
+    Nanos(i64),
+    NanosString(String),
+}
+
+impl TryFrom<RawUnbondingTime> for Duration {
+    type Error = anyhow::Error;
+
+    fn try_from(value: RawUnbondingTime) -> Result<Self, Self::Error> {
+        match value {
+            RawUnbondingTime::Duration(duration) => Ok(duration),
+            RawUnbondingTime::Nanos(nanos) => Ok(Duration::new_from_nanos(nanos)),
+            RawUnbondingTime::NanosString(nanos) => nanos
+                .parse::<i64>()
+                .map(Duration::new_from_nanos)
+                .map_err(|_| anyhow!("invalid unbonding time: {nanos}")),
+        }
+    }
 }
 
 impl TryFrom<RawStakingParams> for StakingParams {
@@ -50,11 +86,12 @@ impl TryFrom<RawStakingParams> for StakingParams {
 
     fn try_from(params: RawStakingParams) -> Result<Self, Self::Error> {
         StakingParams::new(
-            params.unbonding_time,
+            params.unbonding_time.try_into()?,
             params.max_validators,
             params.max_entries,
             params.historical_entries,
             params.bond_denom,
+            params.min_commission_rate,
         )
     }
 }
@@ -69,7 +106,7 @@ impl TryFrom<inner::Params> for StakingParams {
             max_entries,
             historical_entries,
             bond_denom,
-            min_commission_rate: _,
+            min_commission_rate,
         }: inner::Params,
     ) -> Result<Self, Self::Error> {
         StakingParams::new(
@@ -80,6 +117,7 @@ impl TryFrom<inner::Params> for StakingParams {
             max_entries,
             historical_entries,
             bond_denom.try_into()?,
+            Decimal256::from_cosmos_proto_string(&min_commission_rate)?,
         )
     }
 }
@@ -92,6 +130,7 @@ impl From<StakingParams> for inner::Params {
             max_entries,
             historical_entries,
             bond_denom,
+            min_commission_rate,
         }: StakingParams,
     ) -> Self {
         inner::Params {
@@ -100,7 +139,7 @@ impl From<StakingParams> for inner::Params {
             max_entries,
             historical_entries,
             bond_denom: bond_denom.to_string(),
-            min_commission_rate: "0.0".to_string(),
+            min_commission_rate: min_commission_rate.to_cosmos_proto_string(),
         }
     }
 }
@@ -116,6 +155,7 @@ impl Default for StakingParams {
             max_entries: 7,
             bond_denom,
             historical_entries: 10_000,
+            min_commission_rate: Decimal256::zero(),
         }
     }
 }
@@ -128,6 +168,7 @@ impl ParamsSerialize for StakingParams {
             KEY_MAX_ENTRIES,
             KEY_HISTORICAL_ENTRIES,
             KEY_BOND_DENOM,
+            KEY_MIN_COMMISSION_RATE,
         ]
         .into_iter()
         .collect()
@@ -135,6 +176,7 @@ impl ParamsSerialize for StakingParams {
 
     fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
         vec![
+            // the sdk params store keeps amino JSON of `time.Duration` which is a nanoseconds string
             (
                 KEY_UNBONDING_TIME,
                 format!(
@@ -156,50 +198,66 @@ impl ParamsSerialize for StakingParams {
                 KEY_BOND_DENOM,
                 format!("\"{}\"", self.bond_denom).into_bytes(),
             ),
+            (
+                KEY_MIN_COMMISSION_RATE,
+                format!("\"{}\"", self.min_commission_rate).into_bytes(),
+            ),
         ]
     }
 }
 
 impl ParamsDeserialize for StakingParams {
-    fn from_raw(mut fields: HashMap<&'static str, Vec<u8>>) -> Self {
-        let unbonding_time = ParamKind::I64
-            .parse_param(fields.remove(KEY_UNBONDING_TIME).unwrap_or_corrupt())
-            .signed_64()
-            .expect("param serialized as i64 should be deserialized without errors");
-        let max_validators =
-            String::from_utf8(fields.remove(KEY_MAX_VALIDATORS).unwrap_or_corrupt())
-                .expect("should be valid utf-8")
-                .parse::<u32>()
-                .expect("should be valid u32");
-        let max_entries = String::from_utf8(fields.remove(KEY_MAX_ENTRIES).unwrap_or_corrupt())
-            .expect("should be valid utf-8")
-            .parse::<u32>()
-            .expect("should be valid u32");
-        let historical_entries =
-            String::from_utf8(fields.remove(KEY_HISTORICAL_ENTRIES).unwrap_or_corrupt())
-                .expect("should be valid utf-8")
-                .parse::<u32>()
-                .expect("should be valid u32");
-        let bond_denom = ParamKind::String
-            .parse_param(fields.remove(KEY_BOND_DENOM).unwrap_or_corrupt())
-            .string()
-            .expect("param serialized as string should be deserialized without errors")
-            .strip_prefix('\"')
-            .unwrap_or_corrupt()
-            .strip_suffix('\"')
-            .unwrap_or_corrupt()
-            .try_into()
-            .unwrap_or_corrupt();
+    fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self {
+        Self::from_raw_checked(fields).unwrap_or_corrupt()
+    }
+}
+
+/// Removes `key` from `fields` and parses its value with any surrounding quotes stripped.
+fn parse_field<T, E: std::fmt::Display>(
+    fields: &mut HashMap<&'static str, Vec<u8>>,
+    key: &'static str,
+    parse: impl FnOnce(&str) -> Result<T, E>,
+) -> Result<T, ParamsError> {
+    let raw = fields.remove(key).ok_or(ParamsError::MissingKey(key))?;
+    let value = std::str::from_utf8(&raw).map_err(|_| ParamsError::InvalidUtf8(key))?;
+    let value = value
+        .strip_prefix('\"')
+        .and_then(|this| this.strip_suffix('\"'))
+        .unwrap_or(value);
+
+    parse(value).map_err(|e| ParamsError::Parse {
+        key,
+        reason: e.to_string(),
+    })
+}
+
+impl StakingParams {
+    /// Fallible counterpart of [`ParamsDeserialize::from_raw`] which reports malformed
+    /// stored params instead of panicking.
+    pub fn from_raw_checked(
+        mut fields: HashMap<&'static str, Vec<u8>>,
+    ) -> Result<Self, ParamsError> {
+        let unbonding_time = parse_field(&mut fields, KEY_UNBONDING_TIME, i64::from_str)?;
+        let max_validators = parse_field(&mut fields, KEY_MAX_VALIDATORS, u32::from_str)?;
+        let max_entries = parse_field(&mut fields, KEY_MAX_ENTRIES, u32::from_str)?;
+        let historical_entries = parse_field(&mut fields, KEY_HISTORICAL_ENTRIES, u32::from_str)?;
+        let bond_denom = parse_field(&mut fields, KEY_BOND_DENOM, Denom::from_str)?;
+        // stores written before the min commission rate param was added don't have it
+        let min_commission_rate = match fields.contains_key(KEY_MIN_COMMISSION_RATE) {
+            true => parse_field(&mut fields, KEY_MIN_COMMISSION_RATE, Decimal256::from_str)?,
+            false => Decimal256::zero(),
+        };
 
         // TODO: should we validate the params here?
 
-        StakingParams {
+        Ok(StakingParams {
             unbonding_time: Duration::new_from_nanos(unbonding_time),
             max_validators,
             max_entries,
             bond_denom,
             historical_entries,
-        }
+            min_commission_rate,
+        })
     }
 }
 
@@ -210,6 +268,7 @@ impl StakingParams {
         max_entries: u32,
         historical_entries: u32,
         bond_denom: Denom,
+        min_commission_rate: Decimal256,
     ) -> Result<Self, anyhow::Error> {
         if unbonding_time < Duration::ZERO {
             return Err(anyhow::anyhow!(format!(
@@ -232,12 +291,20 @@ impl StakingParams {
             )));
         }
 
+        if min_commission_rate > Decimal256::one() {
+            return Err(anyhow::anyhow!(format!(
+                "min commission rate cannot be greater than 100%: {}",
+                min_commission_rate
+            )));
+        }
+
         Ok(StakingParams {
             unbonding_time,
             max_validators,
             max_entries,
             bond_denom,
             historical_entries,
+            min_commission_rate,
         })
     }
 
@@ -260,6 +327,10 @@ impl StakingParams {
     pub fn bond_denom(&self) -> &Denom {
         &self.bond_denom
     }
+
+    pub fn min_commission_rate(&self) -> Decimal256 {
+        self.min_commission_rate
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -281,22 +352,160 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for StakingParamsKeeper<PSK> {
                 .is_some(),
             KEY_MAX_VALIDATORS => ParamKind::U32
                 .parse_param(value.as_ref().to_vec())
-                .signed_64()
-                .is_some(),
+                .unsigned_32()
+                .is_some_and(|max_validators| max_validators > 0),
             KEY_MAX_ENTRIES => ParamKind::U32
                 .parse_param(value.as_ref().to_vec())
-                .signed_64()
+                .unsigned_32()
                 .is_some(),
             KEY_HISTORICAL_ENTRIES => ParamKind::U32
                 .parse_param(value.as_ref().to_vec())
-                .signed_64()
+                .unsigned_32()
                 .is_some(),
             KEY_BOND_DENOM => ParamKind::String
                 .parse_param(value.as_ref().to_vec())
                 .string()
                 .is_some(),
+            KEY_MIN_COMMISSION_RATE => ParamKind::String
+                .parse_param(value.as_ref().to_vec())
+                .string()
+                .map(|rate| Decimal256::from_str(rate.trim_matches('\"')).is_ok())
+                .unwrap_or(false),
 
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gears::extensions::testing::UnwrapTesting;
+
+    use crate::keeper::test_utils::SubspaceKey;
+
+    use super::*;
+
+    fn params() -> StakingParams {
+        StakingParams {
+            min_commission_rate: Decimal256::from_str("0.05").unwrap_test(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn min_commission_rate_params_store_round_trip() {
+        let raw = params().to_raw();
+
+        for (key, value) in &raw {
+            assert!(StakingParamsKeeper::<SubspaceKey>::validate(key, value));
+        }
+
+        assert_eq!(StakingParams::from_raw(raw.into_iter().collect()), params());
+    }
+
+    #[test]
+    fn from_raw_checked_reports_corrupted_max_validators() {
+        let mut raw: HashMap<_, _> = params().to_raw().into_iter().collect();
+        raw.insert(KEY_MAX_VALIDATORS, b"one hundred".to_vec());
+
+        assert!(matches!(
+            StakingParams::from_raw_checked(raw.clone()),
+            Err(ParamsError::Parse {
+                key: KEY_MAX_VALIDATORS,
+                ..
+            })
+        ));
+
+        raw.insert(KEY_MAX_VALIDATORS, vec![0xff, 0xfe]);
+        assert_eq!(
+            StakingParams::from_raw_checked(raw.clone()),
+            Err(ParamsError::InvalidUtf8(KEY_MAX_VALIDATORS))
+        );
+
+        raw.remove(KEY_MAX_VALIDATORS);
+        assert_eq!(
+            StakingParams::from_raw_checked(raw),
+            Err(ParamsError::MissingKey(KEY_MAX_VALIDATORS))
+        );
+    }
+
+    #[test]
+    fn from_raw_checked_defaults_missing_min_commission_rate() {
+        let mut raw: HashMap<_, _> = params().to_raw().into_iter().collect();
+        raw.remove(KEY_MIN_COMMISSION_RATE);
+
+        assert_eq!(
+            StakingParams::from_raw_checked(raw).unwrap_test(),
+            StakingParams {
+                min_commission_rate: Decimal256::zero(),
+                ..params()
+            }
+        );
+    }
+
+    #[test]
+    fn min_commission_rate_proto_round_trip() {
+        let raw = inner::Params::from(params());
+
+        assert_eq!(raw.min_commission_rate, "50000000000000000");
+        assert_eq!(StakingParams::try_from(raw).unwrap_test(), params());
+    }
+
+    #[test]
+    fn unbonding_time_from_duration_string() {
+        let params: StakingParams = serde_json::from_str(
+            r#"{
+                "unbonding_time": "1814400s",
+                "max_validators": 100,
+                "max_entries": 7,
+                "historical_entries": 10000,
+                "bond_denom": "uatom"
+            }"#,
+        )
+        .unwrap_test();
+
+        assert_eq!(params.unbonding_time(), Duration::new_from_secs(1_814_400));
+    }
+
+    #[test]
+    fn unbonding_time_from_legacy_nanos() {
+        for unbonding_time in ["1814400000000000", r#""1814400000000000""#] {
+            let params: StakingParams = serde_json::from_str(&format!(
+                r#"{{
+                    "unbonding_time": {unbonding_time},
+                    "max_validators": 100,
+                    "max_entries": 7,
+                    "historical_entries": 10000,
+                    "bond_denom": "uatom"
+                }}"#
+            ))
+            .unwrap_test();
+
+            assert_eq!(params.unbonding_time(), Duration::new_from_secs(1_814_400));
+        }
+    }
+
+    #[test]
+    fn unbonding_time_rejects_invalid_string() {
+        serde_json::from_str::<StakingParams>(
+            r#"{
+                "unbonding_time": "three weeks",
+                "max_validators": 100,
+                "max_entries": 7,
+                "historical_entries": 10000,
+                "bond_denom": "uatom"
+            }"#,
+        )
+        .expect_err("unbonding time isn't a duration");
+    }
+
+    #[test]
+    fn min_commission_rate_json_round_trip() {
+        let json = serde_json::to_string(&params()).unwrap_test();
+
+        assert_eq!(
+            serde_json::from_str::<StakingParams>(&json).unwrap_test(),
+            params()
+        );
+    }
+}
@@ -46,7 +46,7 @@ pub fn redelegation_by_val_src_index_key(
 
 // Returns a key prefix for indexing a redelegation to
 // a source validator.
-fn redelegations_from_val_src_index_key(val_src_addr: &ValAddress) -> Vec<u8> {
+pub fn redelegations_from_val_src_index_key(val_src_addr: &ValAddress) -> Vec<u8> {
     [
         &REDELEGATION_BY_VAL_SRC_INDEX_KEY,
         val_src_addr.prefix_len_bytes().as_slice(),
@@ -109,7 +109,7 @@ pub fn get_ubd_by_val_index_key(del_addr: &AccAddress, val_addr: &ValAddress) ->
 }
 
 /// Creates the prefix keyspace for the indexes of unbonding delegations for a validator
-fn get_ubds_by_val_index_key(val_addr: &ValAddress) -> Vec<u8> {
+pub fn get_ubds_by_val_index_key(val_addr: &ValAddress) -> Vec<u8> {
     [
         &UNBONDING_DELEGATION_BY_VAL_INDEX_KEY,
         val_addr.prefix_len_bytes().as_slice(),
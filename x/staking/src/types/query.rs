@@ -56,17 +56,49 @@ pub struct QueryValidatorRequest {
 }
 
 /// QueryValidatorsRequest is request type for Query/Validators RPC method.
-#[derive(Clone, Debug, PartialEq, Query, Protobuf)]
+#[derive(Clone, Debug, PartialEq, Query)]
 #[query(url = "/cosmos.staking.v1beta1.Query/Validators")]
-#[proto(raw = "inner::QueryValidatorsRequest")]
 pub struct QueryValidatorsRequest {
     /// status enables to query for validators matching a given status.
-    pub status: BondStatus,
+    /// All validators are returned if status isn't set.
+    pub status: Option<BondStatus>,
     /// pagination defines an optional pagination for the request.
-    #[proto(optional)]
     pub pagination: Option<PaginationRequest>,
 }
 
+impl TryFrom<inner::QueryValidatorsRequest> for QueryValidatorsRequest {
+    type Error = CoreError;
+
+    fn try_from(raw: inner::QueryValidatorsRequest) -> Result<Self, Self::Error> {
+        // empty and unspecified statuses don't filter validators
+        let status = match raw.status.as_str() {
+            "" => None,
+            status => match BondStatus::try_from(status.to_string())
+                .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?
+            {
+                BondStatus::Unspecified => None,
+                status => Some(status),
+            },
+        };
+
+        Ok(QueryValidatorsRequest {
+            status,
+            pagination: raw.pagination.map(PaginationRequest::from),
+        })
+    }
+}
+
+impl From<QueryValidatorsRequest> for inner::QueryValidatorsRequest {
+    fn from(query: QueryValidatorsRequest) -> inner::QueryValidatorsRequest {
+        Self {
+            status: query.status.map(String::from).unwrap_or_default(),
+            pagination: query.pagination.map(PageRequest::from),
+        }
+    }
+}
+
+impl Protobuf<inner::QueryValidatorsRequest> for QueryValidatorsRequest {}
+
 /// QueryDelegationRequest is request type for the Query/Delegation RPC method.
 #[derive(Clone, Debug, PartialEq, Query, Protobuf)]
 #[query(url = "/cosmos.staking.v1beta1.Query/Delegation")]
@@ -200,11 +200,13 @@ impl Validator {
 
     /// calculate the token worth of provided shares
     pub fn tokens_from_shares(&self, shares: Decimal256) -> Result<Decimal256, NumericError> {
-        shares
-            .checked_mul(Decimal256::from_atomics(self.tokens, 0)?)
-            .map_err(|_| NumericError::Overflow(MathOperation::Mul))?
-            .checked_div(self.delegator_shares)
-            .map_err(|_| NumericError::Overflow(MathOperation::Div))
+        let tokens = Decimal256::from_atomics(self.tokens, 0)
+            .map_err(|e| NumericError::decimal_range("tokens", e))?;
+        let mul = shares
+            .checked_mul(tokens)
+            .map_err(|_| NumericError::overflow(MathOperation::Mul, shares, tokens))?;
+        mul.checked_div(self.delegator_shares)
+            .map_err(|_| NumericError::overflow(MathOperation::Div, mul, self.delegator_shares))
     }
 
     /// add_tokens_from_del adds tokens to a validator
@@ -741,7 +743,7 @@ pub enum ValidatorsError {
 #[cfg(test)]
 mod tests {
     use data_encoding::HEXLOWER;
-    use gears::extensions::testing::UnwrapTesting;
+    use gears::{extensions::testing::UnwrapTesting, types::decimal256::ONE_DEC};
 
     use super::*;
 
@@ -800,6 +802,53 @@ mod tests {
         )
     }
 
+    #[test]
+    fn tokens_from_shares_overflow_includes_operands() {
+        let val_raw = r#"{
+          "commission": {
+            "commission_rates": {
+              "max_change_rate": "0.100000000000000000",
+              "max_rate": "0.200000000000000000",
+              "rate": "0.100000000000000000"
+            },
+            "update_time": "2024-07-30T17:10:11.032635319Z"
+          },
+          "consensus_pubkey": {
+            "type": "tendermint/PubKeyEd25519",
+            "value": "6Ob7SEB++IzwqXQQ/pgsD/bkxXNl+LDBhJZwpKuvnMo="
+          },
+          "delegator_shares": "5.000000000000000000",
+          "description": {
+            "details": "",
+            "identity": "",
+            "moniker": "my_val",
+            "security_contact": "",
+            "website": ""
+          },
+          "jailed": false,
+          "min_self_delegation": "1",
+          "operator_address": "cosmosvaloper1syavy2npfyt9tcncdtsdzf7kny9lh777yfrfs4",
+          "status": "BOND_STATUS_BONDED",
+          "tokens": "5",
+          "unbonding_height": "0",
+          "unbonding_time": "1970-01-01T00:00:00Z"
+        }"#;
+        let val: Validator = serde_json::from_str(val_raw).unwrap_test();
+
+        let err = val
+            .tokens_from_shares(Decimal256::MAX)
+            .expect_err("multiplication overflows");
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "overflow on Mul of {} and {}",
+                Decimal256::MAX,
+                Decimal256::from_atomics(5_u64, 0).unwrap_test()
+            )
+        );
+    }
+
     #[test]
     fn test_key_by_power_index_key() {
         let val_raw = r#"
@@ -880,4 +929,51 @@ mod tests {
         let res = val.tokens_to_consensus_power(1000000);
         assert_eq!(res, 1099511627776);
     }
+
+    #[test]
+    fn test_tokens_from_shares_out_of_range_names_field() {
+        let val_raw = r#"
+{
+    "operator_address": "cosmosvaloper1v0thzgvzp8vt6q7ystmfm7a9wvg0ppsfetur3d",
+    "consensus_pubkey": {
+        "type": "tendermint/PubKeyEd25519",
+        "value": "CiBO6qrfEwEg7eOTlqlaSKRjd+GoFQOxFhp3cRblbJyBdA=="
+    },
+    "jailed": false,
+    "status": "BOND_STATUS_UNBONDED",
+    "tokens": "1",
+    "delegator_shares": "1.000000000000000000",
+    "description": {
+        "moniker": "",
+        "identity": "",
+        "website": "",
+        "security_contact": "",
+        "details": ""
+    },
+    "unbonding_height": "0",
+    "unbonding_time": "1970-01-01T00:00:00Z",
+    "commission": {
+        "commission_rates": {
+            "rate": "0.000000000000000000",
+            "max_rate": "0.000000000000000000",
+            "max_change_rate": "0.000000000000000000"
+        },
+        "update_time": "1970-01-01T00:00:00Z"
+    },
+    "min_self_delegation": "1"
+}
+          "#;
+
+        let mut val: Validator = serde_json::from_str(val_raw).unwrap_test();
+        val.tokens = Uint256::MAX;
+
+        let err = val
+            .tokens_from_shares(ONE_DEC)
+            .expect_err("tokens are out of decimal range");
+
+        assert!(matches!(
+            err,
+            NumericError::DecimalRange { ref field, .. } if field == "tokens"
+        ));
+    }
 }